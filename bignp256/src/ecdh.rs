@@ -44,12 +44,12 @@
 //! # }
 //! ```
 
-pub use elliptic_curve::ecdh::diffie_hellman;
+pub use primeorder::ecdh::{diffie_hellman, diffie_hellman_x_only};
 
 use crate::BignP256;
 
 /// BignP256 Ephemeral Diffie-Hellman Secret.
-pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<BignP256>;
+pub type EphemeralSecret = primeorder::ecdh::EphemeralSecret<BignP256>;
 
 /// Shared secret value computed via ECDH key agreement.
 pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BignP256>;
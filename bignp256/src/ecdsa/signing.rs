@@ -22,12 +22,13 @@ use belt_block::BeltBlock;
 use belt_hash::{BeltHash, Digest};
 use core::fmt::{self, Debug};
 use elliptic_curve::{
-    Curve, Field, Generate, Group, PrimeField,
+    Curve, Field, Generate, PrimeField,
     array::{Array, sizes::U32, typenum::Unsigned},
     ops::Reduce,
     point::AffineCoordinates,
     subtle::{Choice, ConstantTimeEq},
 };
+use primeorder::BlindingRng;
 use rand_core::TryCryptoRng;
 use signature::{Error, KeypairRef, MultipartSigner, Result, Signer, hazmat::PrehashSigner};
 
@@ -134,7 +135,14 @@ impl PrehashSigner<Signature> for SigningKey {
         };
 
         // 3. Set 𝑅 ← 𝑘𝐺.
-        let R = ProjectivePoint::mul_by_generator(&k).to_affine();
+        //
+        // The scalar multiplication is blinded with further output of the `k` generator, which is
+        // unpredictable without the secret key.
+        let mut rng = BlindingRng::new(|block: &mut FieldBytes| {
+            kgen.fill_next_k(block);
+        });
+        let Ok(R) = ProjectivePoint::try_mul_by_generator_blinded(&k, &mut rng);
+        let R = R.to_affine();
         let Rx = R.x();
 
         // 4. Set 𝑆0 ← ⟨︀belt-hash(OID(ℎ) ‖ ⟨𝑅⟩2𝑙 ‖ 𝐻)⟩︀_𝑙.
@@ -35,6 +35,7 @@ use elliptic_curve::{ByteOrder, Error, bigint::Odd, consts::U32};
 #[cfg(feature = "arithmetic")]
 pub use {
     arithmetic::{AffinePoint, ProjectivePoint, scalar::Scalar},
+    primeorder::ValidationError,
     public_key::PublicKey,
     secret_key::SecretKey,
 };
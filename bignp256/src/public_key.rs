@@ -11,6 +11,7 @@ use elliptic_curve::pkcs8::{
     spki::{AlgorithmIdentifier, AssociatedAlgorithmIdentifier},
 };
 use elliptic_curve::{Error, array::Array, point::NonIdentity, sec1::ToSec1Point};
+use primeorder::ValidationError;
 
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, fmt};
@@ -64,6 +65,52 @@ impl PublicKey {
         elliptic_curve::PublicKey::try_from(&point).map(Self)
     }
 
+    /// Parse a [`PublicKey`] from the raw (untagged) point encoding defined
+    /// in STB 34.101.45, performing the partial public key validation
+    /// routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.4.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that
+    /// fails.
+    pub fn from_bytes_partial(bytes: &[u8]) -> Result<Self, ValidationError> {
+        let bytes = Array::try_from(bytes).map_err(|_| ValidationError::Encoding)?;
+        let point = AffinePoint::from_sec1_point_partial(&Sec1Point::from_untagged_bytes(&bytes))?;
+        Self::from_affine(point).map_err(|_| ValidationError::Identity)
+    }
+
+    /// Parse a [`PublicKey`] from the raw (untagged) point encoding defined
+    /// in STB 34.101.45, performing the full public key validation routine
+    /// of NIST SP 800-56A Rev. 3 § 5.6.2.3.3.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that
+    /// fails.
+    pub fn from_bytes_full(bytes: &[u8]) -> Result<Self, ValidationError> {
+        let public_key = Self::from_bytes_partial(bytes)?;
+        public_key.validate_full()?;
+        Ok(public_key)
+    }
+
+    /// Perform the partial public key validation routine of
+    /// NIST SP 800-56A Rev. 3 § 5.6.2.3.4 on this [`PublicKey`].
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that
+    /// fails.
+    pub fn validate_partial(&self) -> Result<(), ValidationError> {
+        self.as_affine().validate_partial()
+    }
+
+    /// Perform the full public key validation routine of
+    /// NIST SP 800-56A Rev. 3 § 5.6.2.3.3 on this [`PublicKey`].
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that
+    /// fails.
+    pub fn validate_full(&self) -> Result<(), ValidationError> {
+        self.as_affine().validate_full()
+    }
+
     /// Serialize this [`PublicKey`] using the raw (untagged) point encoding
     /// defined in STB 34.101.45.
     #[cfg(feature = "alloc")]
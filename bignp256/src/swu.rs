@@ -1,39 +1,19 @@
 //! SWU implementation for `BignP256`
 
-use crate::{BignP256, ProjectivePoint, Sec1Point, U256, arithmetic::FieldElement};
+use crate::{BignP256, ProjectivePoint, Sec1Point, arithmetic::FieldElement};
 use belt_kwp::{BeltKwp, KeyInit};
 use core::num::{NonZero, NonZeroU16};
 use elliptic_curve::{
     Field,
     array::Array,
-    bigint::U384,
     consts::{U32, U48, U128},
     sec1::FromSec1Point,
     subtle::ConditionallySelectable,
 };
 use hash2curve::{ExpandMsg, Expander, MapToCurve};
-use primefield::bigint::{NonZero as BigintNonZero, Reduce};
+use primefield::bigint::Reduce;
 use primeorder::PrimeCurveParams;
 
-impl Reduce<Array<u8, U48>> for FieldElement {
-    #[allow(clippy::arithmetic_side_effects)]
-    fn reduce(value: &Array<u8, U48>) -> Self {
-        const WIDE_MODULUS: BigintNonZero<U384> = BigintNonZero::<U384>::new_unwrap(
-            U384::from_be_hex(
-                "00000000000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF43",
-            ),
-        );
-
-        let value = U384::from_le_slice(value);
-        let value = value % WIDE_MODULUS;
-
-        let mut words = [0; U256::LIMBS];
-        words.copy_from_slice(&value.to_words()[..U256::LIMBS]);
-
-        FieldElement::from_uint_unchecked(U256::from_words(words))
-    }
-}
-
 /// Expander with `belt-keywrap` algorithm
 struct BeltKwpExpander {
     /// Output buffer containing the 48-byte result of belt-keywrap
@@ -0,0 +1,97 @@
+//! bignp256 ECDH tests.
+
+#![cfg(feature = "ecdh")]
+
+use elliptic_curve::{ops::ReduceNonZero, point::DecompressPoint};
+use hex_literal::hex;
+use proptest::prelude::*;
+
+use bignp256::{
+    AffinePoint, FieldBytes, NonZeroScalar, PublicKey, Scalar, Sec1Point, SecretKey,
+    ecdh::{diffie_hellman, diffie_hellman_x_only},
+};
+
+// Key pair from https://apmi.bsu.by/assets/files/std/met-v10.zip
+// file met-10145-10-01.pdf, section 6.2
+const ALICE_SECRET: [u8; 32] =
+    hex!("1F66B5B8 4B733967 4533F032 9C74F218 34281FED 0732429E 0C79235F C273E269");
+const ALICE_PUBLIC: [u8; 64] = hex!(
+    "BD1A5650 179D79E0 3FCEE49D 4C2BD5DD F54CE46D 0CF11E4F F87BF7A8 90857FD0"
+    "7AC6A603 61E8C817 3491686D 461B2826 190C2EDA 5909054A 9AB84D2A B9D99A90"
+);
+
+const BOB_SECRET: [u8; 32] =
+    hex!("698FC37E CA73F141 9598ECD0 63F50B11 C241690E 4C8AF951 A77B035B 1A2F5B28");
+const BOB_PUBLIC: [u8; 64] = hex!(
+    "19C9C104 61DF7672 6E906775 C26FE435 B67F5B86 49AA0160 744E5304 01FF5765"
+    "B9A351CA C0A5FE5C 043D4520 F8A18E17 4FDBABE3 06E951F0 8072F1D2 23C7B505"
+);
+
+// Computed with an independent affine-coordinate implementation of the curve arithmetic
+const SHARED_SECRET: [u8; 32] =
+    hex!("FB5C6780 C9D98DDA 2D5CA2EC 6696970E DA348177 02D17584 3833E699 76EC2A25");
+
+prop_compose! {
+    fn nonzero_scalar()(bytes in any::<[u8; 32]>()) -> NonZeroScalar {
+        NonZeroScalar::reduce_nonzero(&FieldBytes::from(bytes))
+    }
+}
+
+proptest! {
+    #[test]
+    fn diffie_hellman_matches_projective(a in nonzero_scalar(), b in nonzero_scalar()) {
+        let public = PublicKey::from_secret_scalar(&b);
+        let expected = elliptic_curve::ecdh::diffie_hellman(a, public.as_affine());
+        let shared = diffie_hellman(a, public.as_affine());
+        prop_assert_eq!(expected.raw_secret_bytes(), shared.raw_secret_bytes());
+    }
+}
+
+#[test]
+fn known_answer() {
+    for (secret, public) in [(ALICE_SECRET, BOB_PUBLIC), (BOB_SECRET, ALICE_PUBLIC)] {
+        let secret = SecretKey::from_slice(&secret).unwrap();
+        let public = PublicKey::from_bytes(&public).unwrap();
+
+        let shared = diffie_hellman(secret.to_nonzero_scalar(), public.as_affine());
+        assert_eq!(shared.raw_secret_bytes().as_slice(), &SHARED_SECRET);
+
+        let x = &public.to_sec1_point().as_bytes()[1..33];
+        let shared = diffie_hellman_x_only(secret.to_nonzero_scalar(), x).unwrap();
+        assert_eq!(shared.raw_secret_bytes().as_slice(), &SHARED_SECRET);
+    }
+
+    let alice = SecretKey::from_slice(&ALICE_SECRET).unwrap();
+    assert_eq!(
+        alice.public_key(),
+        PublicKey::from_bytes(&ALICE_PUBLIC).unwrap()
+    );
+}
+
+#[test]
+fn rejects_invalid_public_keys() {
+    let secret = NonZeroScalar::new(Scalar::ONE).unwrap();
+
+    // identity
+    assert!(PublicKey::from_sec1_point(Sec1Point::identity()).is_err());
+    assert!(diffie_hellman_x_only(secret, &[0]).is_err());
+
+    // off-curve point
+    let mut off_curve = BOB_PUBLIC;
+    off_curve[32] ^= 1;
+    assert!(PublicKey::from_bytes(&off_curve).is_err());
+    assert!(PublicKey::from_bytes(&[0; 64]).is_err());
+
+    // first x-coordinate which is on the quadratic twist rather than the curve
+    let twist_x = (0u64..)
+        .map(|i| {
+            let mut x = FieldBytes::default();
+            x[..8].copy_from_slice(&i.to_le_bytes());
+            x
+        })
+        .find(|x| AffinePoint::decompress(x, 0.into()).is_none().into())
+        .unwrap();
+
+    assert!(diffie_hellman_x_only(secret, &twist_x).is_err());
+    assert!(diffie_hellman_x_only(secret, &twist_x[1..]).is_err());
+}
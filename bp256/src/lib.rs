@@ -52,6 +52,8 @@ pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "arithmetic")]
 pub use crate::arithmetic::scalar::Scalar;
+#[cfg(feature = "arithmetic")]
+pub use primeorder::{PublicKeyValidation, ValidationError};
 
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
@@ -0,0 +1,95 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES)
+//!
+//! See [`primeorder::ecies`] for the supported options. The hash function defaults to
+//! SHA-256.
+
+pub use super::BrainpoolP256r1;
+pub use primeorder::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params, PointEncoding};
+
+/// ECIES/brainpoolP256r1 encrypting key, using the hash function `D`.
+pub type EncryptingKey<D = sha2::Sha256> = primeorder::ecies::EncryptingKey<BrainpoolP256r1, D>;
+
+/// ECIES/brainpoolP256r1 decrypting key, using the hash function `D`.
+pub type DecryptingKey<D = sha2::Sha256> = primeorder::ecies::DecryptingKey<BrainpoolP256r1, D>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    primeorder::test_ecies!(
+        BrainpoolP256r1,
+        sha2::Sha256,
+        secret_key: hex!("81db1ee100150ff2ea338d708271be38300cb54241d79950f77b063039804f1d"),
+        public_key: hex!(
+            "0444106e913f92bc02a1705d9953a8414db95e1aaa49e81d9e85f929a8e3100be58ab4846f11caccb73ce49cbdd120f5a900a69fd32c272223f789ef10eb089bdc"
+        ),
+        ephemeral_key: hex!("55e40bc41e37e3e2ad25c3c6654511ffa8474a91a0032087593852d3e7d76bd3"),
+        test_vectors: [
+            // SEC 1 `ecies-with-x9-63-kdf`
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: false,
+                ciphertext: hex!(
+                    "048d2d688c6cf93e1160ad04cc4429117dc2c41825e1e9fca0addd34e6f1b39f7b990c57520812be512641e47034832106bc7d3e8dd0e4c7f1136d7006547cec6a5a10d30159a1f153f75cd55f5375832f8643c757085bd0bdfd4becbf0b458fe9020ddfc68cbb6a5e0c8e1ee3c0d9360856f3152f85d9ce0efadf00eeb143c207afb714beca171061aa8afe5fdd"
+                ),
+            },
+            // SEC 1 with shared information
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "048d2d688c6cf93e1160ad04cc4429117dc2c41825e1e9fca0addd34e6f1b39f7b990c57520812be512641e47034832106bc7d3e8dd0e4c7f1136d7006547cec6ad388c7601691ab184b1b2d68fac18e2a2bec8bf971dbf39948e8ca70907b053a51a6dc10baf77fe1da78cda7ca40afdcd821c948901022600cf559414bb1cd1fe062aa63cd507bf5a70e30a167"
+                ),
+            },
+            // HKDF over `R̄ || Z`, compressed ephemeral key and truncated tag
+            {
+                params: Params {
+                    kdf: Kdf::Hkdf,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Compressed,
+                    mac_tag: MacTag::Truncated,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "028d2d688c6cf93e1160ad04cc4429117dc2c41825e1e9fca0addd34e6f1b39f7ba2d806de93d26c7bfe8c9b2ebec6514319837bd6fab32203c3869f126300066133e1727ac0f63d3b8ff8c0d6f5c3a408b1e29a8e6b943dd66797282fd9"
+                ),
+            },
+            // Bouncy Castle `IESEngine` layout without a block cipher and with a 128-bit MAC key
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::MacKeyFirst,
+                    mac_key_len: Some(16),
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "048d2d688c6cf93e1160ad04cc4429117dc2c41825e1e9fca0addd34e6f1b39f7b990c57520812be512641e47034832106bc7d3e8dd0e4c7f1136d7006547cec6a06695de940af3d1579c39e997bc3323ee8ec796e52cbea6c952865ff24c3f5c0f76e1a6cb3163c18e1e452a099d1afee659dd27529a4b7e8759ea2fc22cc5cb2e81b8dd363cdcb821610899ab1"
+                ),
+            },
+        ],
+    );
+}
@@ -2,6 +2,8 @@
 
 #[cfg(feature = "ecdsa")]
 pub mod ecdsa;
+#[cfg(feature = "ecies")]
+pub mod ecies;
 
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
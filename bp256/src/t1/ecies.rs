@@ -0,0 +1,95 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES)
+//!
+//! See [`primeorder::ecies`] for the supported options. The hash function defaults to
+//! SHA-256.
+
+pub use super::BrainpoolP256t1;
+pub use primeorder::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params, PointEncoding};
+
+/// ECIES/brainpoolP256t1 encrypting key, using the hash function `D`.
+pub type EncryptingKey<D = sha2::Sha256> = primeorder::ecies::EncryptingKey<BrainpoolP256t1, D>;
+
+/// ECIES/brainpoolP256t1 decrypting key, using the hash function `D`.
+pub type DecryptingKey<D = sha2::Sha256> = primeorder::ecies::DecryptingKey<BrainpoolP256t1, D>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    primeorder::test_ecies!(
+        BrainpoolP256t1,
+        sha2::Sha256,
+        secret_key: hex!("81db1ee100150ff2ea338d708271be38300cb54241d79950f77b063039804f1d"),
+        public_key: hex!(
+            "04a5f81698aeee80c41a71796fc5e51fcc09b679bb3076c78e9af22f8373dc503ea59f316bb8d9acad8ac8467bd94878b6f294306e70034575fe6bf8d2eadd58cb"
+        ),
+        ephemeral_key: hex!("55e40bc41e37e3e2ad25c3c6654511ffa8474a91a0032087593852d3e7d76bd3"),
+        test_vectors: [
+            // SEC 1 `ecies-with-x9-63-kdf`
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: false,
+                ciphertext: hex!(
+                    "04a935a9a9fe5c7bdb914f4e49d37255887b7122339b37f179a668a4666298693f004e506e16438ea28b534c1782917137ecf99face2f24a7a54f763d90fccfb60e1d6dcb615f7581530ea2a3045e7bf8411dd31a15ebe93f2322b30487790d85f84c5179842d091fddfdb435e9ee78a1829dbd1980a63faa761c00c254b1c592d5d1e492c8d17a40ca905143450"
+                ),
+            },
+            // SEC 1 with shared information
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04a935a9a9fe5c7bdb914f4e49d37255887b7122339b37f179a668a4666298693f004e506e16438ea28b534c1782917137ecf99face2f24a7a54f763d90fccfb6019d34cf180fa448e52ab830de1d1b22cd3cc2375dcf8fe0945ffe7da2b660419ce97d60237f304c4f6421e9a561ea879c2c0bd3521ff5a0ece706b9af44ea8203316b5d03e19a1c81997a1961a"
+                ),
+            },
+            // HKDF over `R̄ || Z`, compressed ephemeral key and truncated tag
+            {
+                params: Params {
+                    kdf: Kdf::Hkdf,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Compressed,
+                    mac_tag: MacTag::Truncated,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "02a935a9a9fe5c7bdb914f4e49d37255887b7122339b37f179a668a4666298693f3c87217a9c0e352c3a5501af28ff13f786070131dfb99570c460a5760bb2fa0dd3066d0671117d136e964efc8006a435d2118c08f1f4849a34d42c5510"
+                ),
+            },
+            // Bouncy Castle `IESEngine` layout without a block cipher and with a 128-bit MAC key
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::MacKeyFirst,
+                    mac_key_len: Some(16),
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04a935a9a9fe5c7bdb914f4e49d37255887b7122339b37f179a668a4666298693f004e506e16438ea28b534c1782917137ecf99face2f24a7a54f763d90fccfb603853cd96b88b5db88de974266a618693a821dfc0159142e0e03f0b2435502b4df8efde0666bfccefdc54e0c3da4116d076d64015ae70da6a3393f55313d5e5282773adec7b6c7e717d42ed4292"
+                ),
+            },
+        ],
+    );
+}
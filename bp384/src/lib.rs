@@ -55,6 +55,8 @@ pub use elliptic_curve::{
 
 #[cfg(feature = "arithmetic")]
 pub use crate::arithmetic::scalar::Scalar;
+#[cfg(feature = "arithmetic")]
+pub use primeorder::{PublicKeyValidation, ValidationError};
 
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
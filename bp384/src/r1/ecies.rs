@@ -0,0 +1,99 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES)
+//!
+//! See [`primeorder::ecies`] for the supported options. The hash function defaults to
+//! SHA-384.
+
+pub use super::BrainpoolP384r1;
+pub use primeorder::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params, PointEncoding};
+
+/// ECIES/brainpoolP384r1 encrypting key, using the hash function `D`.
+pub type EncryptingKey<D = sha2::Sha384> = primeorder::ecies::EncryptingKey<BrainpoolP384r1, D>;
+
+/// ECIES/brainpoolP384r1 decrypting key, using the hash function `D`.
+pub type DecryptingKey<D = sha2::Sha384> = primeorder::ecies::DecryptingKey<BrainpoolP384r1, D>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    primeorder::test_ecies!(
+        BrainpoolP384r1,
+        sha2::Sha384,
+        secret_key: hex!(
+            "1e20f5e048a5886f1f157c74e91bde2b98c8b52d58e5003d57053fc4b0bd65d6f15eb5d1ee1610df870795143627d042"
+        ),
+        public_key: hex!(
+            "0468b665dd91c195800650cdd363c625f4e742e8134667b767b1b476793588f885ab698c852d4a6e77a252d6380fcaf06855bc91a39c9ec01dee36017b7d673a931236d2f1f5c83942d049e3fa20607493e0d038ff2fd30c2ab67d15c85f7faa59"
+        ),
+        ephemeral_key: hex!(
+            "032640bc6003c59260f7250c3db58ce647f98e1260acce4acda3dd869f74e01f8ba5e0324309db6a9831497abac96670"
+        ),
+        test_vectors: [
+            // SEC 1 `ecies-with-x9-63-kdf`
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: false,
+                ciphertext: hex!(
+                    "044d44326f269a597a5b58bba565da5556ed7fd9a8a9eb76c25f46db69d19dc8ce6ad18e404b15738b2086df37e71d1eb462d692136de56cbe93bf5fa3188ef58bc8a3a0ec6c1e151a21038a42e9185329b5b275903d192f8d4e1f32fe9cc78c48b31d6d8f7ed3be3b51eb89e71ed77982f2963fd8d331132b902ee980789389a652df4d2e262e854dcfc8413ad8fc478bec9cafe77359308c07b4938be9a6172223939c203d4703074baa2b63f09df3c7c72a891fd14b28a1297f34c1b0"
+                ),
+            },
+            // SEC 1 with shared information
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "044d44326f269a597a5b58bba565da5556ed7fd9a8a9eb76c25f46db69d19dc8ce6ad18e404b15738b2086df37e71d1eb462d692136de56cbe93bf5fa3188ef58bc8a3a0ec6c1e151a21038a42e9185329b5b275903d192f8d4e1f32fe9cc78c48c382c831129b2489eb5ed2b67cdd793be3e0c6350f04826a2a9a0c298a8737055cdc69a9c3dca1693526f2eb8277a2a6cde7d23e7e87acb269255ab25849a783eb263bff8a1239238af1f86f120edb4e454a6041c88a3a34f9972bb953"
+                ),
+            },
+            // HKDF over `R̄ || Z`, compressed ephemeral key and truncated tag
+            {
+                params: Params {
+                    kdf: Kdf::Hkdf,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Compressed,
+                    mac_tag: MacTag::Truncated,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "024d44326f269a597a5b58bba565da5556ed7fd9a8a9eb76c25f46db69d19dc8ce6ad18e404b15738b2086df37e71d1eb4518b4e8900512f55c59eac1c656e45500a67e4f051b8b3c36c6e77a64c242bda6b01c11f3ec14e30038c6a2a09c845915bdc5ebe920ab596a690ac2111c6ed17a1a6c0e4f3"
+                ),
+            },
+            // Bouncy Castle `IESEngine` layout without a block cipher and with a 128-bit MAC key
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::MacKeyFirst,
+                    mac_key_len: Some(16),
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "044d44326f269a597a5b58bba565da5556ed7fd9a8a9eb76c25f46db69d19dc8ce6ad18e404b15738b2086df37e71d1eb462d692136de56cbe93bf5fa3188ef58bc8a3a0ec6c1e151a21038a42e9185329b5b275903d192f8d4e1f32fe9cc78c4810fedba54283928ef79b568399ff7f355990c4d6bad6c094a247e8ef8d5ba6faa0cd804112f74c9f51eea6946c06ecac390ab3de3e17b2a1adc75a88e537b09a27c5a469cd17e28a27fb7249dafa8cafca7821f4c113c8c78151fc0941"
+                ),
+            },
+        ],
+    );
+}
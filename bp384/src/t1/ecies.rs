@@ -0,0 +1,99 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES)
+//!
+//! See [`primeorder::ecies`] for the supported options. The hash function defaults to
+//! SHA-384.
+
+pub use super::BrainpoolP384t1;
+pub use primeorder::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params, PointEncoding};
+
+/// ECIES/brainpoolP384t1 encrypting key, using the hash function `D`.
+pub type EncryptingKey<D = sha2::Sha384> = primeorder::ecies::EncryptingKey<BrainpoolP384t1, D>;
+
+/// ECIES/brainpoolP384t1 decrypting key, using the hash function `D`.
+pub type DecryptingKey<D = sha2::Sha384> = primeorder::ecies::DecryptingKey<BrainpoolP384t1, D>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    primeorder::test_ecies!(
+        BrainpoolP384t1,
+        sha2::Sha384,
+        secret_key: hex!(
+            "1e20f5e048a5886f1f157c74e91bde2b98c8b52d58e5003d57053fc4b0bd65d6f15eb5d1ee1610df870795143627d042"
+        ),
+        public_key: hex!(
+            "0430883b5f8686f6be6c2f2fdd1d1ad997429887208451fc1f87bdb59c98c12de42dfe6872bf1a987a0e4e74ac54101b6207a9529d9a26174106ca47d66b56696a89c48f54f9489e1dc8c40fbe97e833a104f12b55a728d08e018af2e784c32fcd"
+        ),
+        ephemeral_key: hex!(
+            "032640bc6003c59260f7250c3db58ce647f98e1260acce4acda3dd869f74e01f8ba5e0324309db6a9831497abac96670"
+        ),
+        test_vectors: [
+            // SEC 1 `ecies-with-x9-63-kdf`
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: false,
+                ciphertext: hex!(
+                    "04346abd196a03555941cfa9cd927d2e5a38a879f22f21ea073b05e9020da8d1270d353e0066492be2009ee84a643036e5456634266f22aaa0db788c833ad931ca89467aacd700002644b6f49f6cb4a92396d5194692ce03555ceb443f632f5ab5e6baed91ffb5dca2e123154c0177902a0c1783522fc26ab96e5f1569aa530e95019586706ce420b14646e57e4da9ed6e7a84f1bccaf239037fb6b81041517736dc3cb6d43bbacc5cf5db4bcb4387e065a38f8b38b4d3f09b81bcfada5a"
+                ),
+            },
+            // SEC 1 with shared information
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04346abd196a03555941cfa9cd927d2e5a38a879f22f21ea073b05e9020da8d1270d353e0066492be2009ee84a643036e5456634266f22aaa0db788c833ad931ca89467aacd700002644b6f49f6cb4a92396d5194692ce03555ceb443f632f5ab5b52c759bd6dd971c6989352d812c5fea19069cf5d89215712cc613122c9c02168e935a01e20f5e46d407e1f6ca4edbf7793b8f0c37e76326373c19b2a71f9dcdccc63f94fdab14eb1ab15ff52282ee9ef02ca5b16dd1a2480880b138f7"
+                ),
+            },
+            // HKDF over `R̄ || Z`, compressed ephemeral key and truncated tag
+            {
+                params: Params {
+                    kdf: Kdf::Hkdf,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Compressed,
+                    mac_tag: MacTag::Truncated,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "03346abd196a03555941cfa9cd927d2e5a38a879f22f21ea073b05e9020da8d1270d353e0066492be2009ee84a643036e570a0795988a0c7171d057f8e7ad75ca030988668568629bac7fc510aa8a1da1d134b257b177ebc4cc15afb49726dc944c3a2b44352037ce313409dd27890568fa71034aa10"
+                ),
+            },
+            // Bouncy Castle `IESEngine` layout without a block cipher and with a 128-bit MAC key
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::MacKeyFirst,
+                    mac_key_len: Some(16),
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04346abd196a03555941cfa9cd927d2e5a38a879f22f21ea073b05e9020da8d1270d353e0066492be2009ee84a643036e5456634266f22aaa0db788c833ad931ca89467aacd700002644b6f49f6cb4a92396d5194692ce03555ceb443f632f5ab59726de1b2d9208db784743182dc41fcdb165f438fa7e7038a6d74be10229110301c76948bcf4b00bfa9521a4d7e34b7eabac8ff3acfe9d75e4ff801b6dcd17a1ead0a73b66f83714633abafb1fe92fc089c730d60e4bbc3fc24691620d"
+                ),
+            },
+        ],
+    );
+}
@@ -1,7 +1,8 @@
 use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
 use ed448_goldilocks::{
     Decaf448, DecafPoint, DecafScalar, Ed448, EdwardsPoint, EdwardsScalar, MontgomeryPoint,
-    elliptic_curve::{Generate, group::GroupEncoding},
+    elliptic_curve::{Generate, consts::U5, group::GroupEncoding},
+    wnaf::{JsfBase, JsfScalar, WnafBase, WnafScalar},
 };
 use getrandom::{SysRng, rand_core::TryRng};
 use hash2curve::GroupDigest;
@@ -21,6 +22,45 @@ pub fn ed448(c: &mut Criterion) {
         )
     });
 
+    group.bench_function("double scalar multiplication (wNAF)", |b| {
+        b.iter_batched(
+            || {
+                let point = EdwardsPoint::generate();
+                let s1 = EdwardsScalar::generate();
+                let s2 = EdwardsScalar::generate();
+                (point, s1, s2)
+            },
+            |(point, s1, s2)| {
+                let bases = [
+                    WnafBase::<_, U5>::new(&EdwardsPoint::GENERATOR),
+                    WnafBase::new(&point),
+                ];
+                let scalars = [
+                    WnafScalar::<_, U5>::from_le_bytes(&s1.to_bytes()),
+                    WnafScalar::from_le_bytes(&s2.to_bytes()),
+                ];
+                WnafBase::multiscalar_mul(bases.iter().zip(scalars.iter()))
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("double scalar multiplication (JSF)", |b| {
+        b.iter_batched(
+            || {
+                let point = EdwardsPoint::generate();
+                let s1 = EdwardsScalar::generate();
+                let s2 = EdwardsScalar::generate();
+                (point, s1, s2)
+            },
+            |(point, s1, s2)| {
+                let base = JsfBase::new(&EdwardsPoint::GENERATOR, &point);
+                &base * &JsfScalar::from_le_bytes(&s1.to_bytes(), &s2.to_bytes())
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
     group.bench_function("point addition", |b| {
         b.iter_batched(
             || {
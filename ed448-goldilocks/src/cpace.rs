@@ -0,0 +1,214 @@
+//! CPace password-authenticated key exchange over Decaf448 as described in
+//! [draft-irtf-cfrg-cpace].
+//!
+//! CPace is a balanced PAKE: both parties share a password-related string (PRS), from which they
+//! derive a secret generator by hashing it to the group together with the session ID, and then
+//! run a Diffie-Hellman key exchange using that generator.
+//!
+//! This module implements the `CPACE-DECAF448-SHAKE256` ciphersuite in both the
+//! initiator-responder and the symmetric setting, on top of the generic protocol in
+//! [`hash2curve::Cpace`].
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "cpace", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "cpace", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `cpace` and `getrandom` crate features are enabled
+//! use ed448_goldilocks::{
+//!     cpace::{Cpace, Role},
+//!     elliptic_curve::common::getrandom::SysRng,
+//! };
+//!
+//! // The session ID must be unique, e.g. chosen at random by one party and sent to the other
+//! let sid = b"unique session ID";
+//!
+//! let alice = Cpace::try_new(Role::Initiator, b"password", b"alice bob", sid, b"", &mut SysRng)?;
+//! let bob = Cpace::try_new(Role::Responder, b"password", b"alice bob", sid, b"", &mut SysRng)?;
+//!
+//! // Alice and Bob exchange their messages and compute the intermediate session key
+//! let alice_isk = alice.finish(bob.message().as_bytes(), b"")?;
+//! let bob_isk = bob.finish(alice.message().as_bytes(), b"")?;
+//! assert_eq!(alice_isk.as_bytes(), bob_isk.as_bytes());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [draft-irtf-cfrg-cpace]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-cpace/
+
+use crate::{CompressedDecaf, Decaf448, DecafPoint, decaf::points::DecafPointBytes};
+use elliptic_curve::{array::Array, consts::U64};
+use hash2curve::CpaceParameters;
+use shake::{
+    Shake256,
+    digest::{ExtendableOutput, Update, XofReader},
+};
+
+pub use hash2curve::CpaceRole as Role;
+
+/// State of a party in the CPace protocol, after it has computed its message.
+pub type Cpace<'a> = hash2curve::Cpace<'a, Decaf448>;
+
+/// Intermediate session key (ISK) resulting from a run of the CPace protocol.
+pub type IntermediateSessionKey = hash2curve::IntermediateSessionKey<Decaf448>;
+
+impl CpaceParameters for Decaf448 {
+    const DSI: &'static [u8] = b"CPaceDecaf448";
+    const S_IN_BYTES: usize = 136;
+
+    type Hash = Shake256;
+    type IskSize = U64;
+    type Message = CompressedDecaf;
+    type SharedSecret = CompressedDecaf;
+
+    fn calculate_generator(generator_string: &[&[u8]]) -> DecafPoint {
+        let mut hash = Shake256::default();
+        for chunk in generator_string {
+            hash.update(chunk);
+        }
+
+        let mut bytes = [0; 112];
+        hash.finalize_xof().read(&mut bytes);
+        DecafPoint::from_uniform_bytes(&bytes)
+    }
+
+    fn encode(point: &DecafPoint) -> CompressedDecaf {
+        point.compress()
+    }
+
+    fn decode(message: &[u8]) -> Option<DecafPoint> {
+        let bytes = DecafPointBytes::try_from(message).ok()?;
+        CompressedDecaf(bytes).decompress().into_option()
+    }
+
+    fn shared_secret(point: &DecafPoint) -> CompressedDecaf {
+        point.compress()
+    }
+
+    fn finalize_isk(hash: Shake256) -> Array<u8, U64> {
+        let mut bytes = Array::default();
+        hash.finalize_xof().read(&mut bytes);
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decaf448NonZeroScalar, DecafScalar};
+    use hex_literal::hex;
+
+    const PRS: &[u8] = b"Password";
+    const CI: &[u8] = b"\x0aAinitiator\x0aBresponder";
+    const SID: &[u8] = b"\x5b\x3d\xdb\x57\x3f\x7c\x7d\xd7\x10\x21\x5e\x6e\x2a\x3f\x4d\x8a";
+
+    // Known answers for the inputs of the draft's test vectors, with our own `ya` and `yb`.
+    //
+    // These were computed with an independent Python implementation of the draft, which
+    // reproduces the element derivation test vectors of RFC 9496. The values of the draft's
+    // appendix itself weren't available to check against.
+    const GENERATOR_STRING: [u8; 176] = hex!(
+        "0d435061636544656361663434380850617373776f726470000000000000000000000000000000000000
+         000000000000000000000000000000000000000000000000000000000000000000000000000000000000
+         000000000000000000000000000000000000000000000000000000000000000000000000000000000000
+         00000000000000000000160a41696e69746961746f720a42726573706f6e646572105b3ddb573f7c7dd7
+         10215e6e2a3f4d8a"
+    );
+    const G: [u8; 56] = hex!(
+        "9c97d016a3022c9679911aedb985d531088a94a129cb2e7173ba3373dbaff68c46ae24487e7e24f1850a
+         267251678a0f02fe4effa25671fa"
+    );
+    const YA: [u8; 56] = hex!(
+        "56d13548e2c3a911db9e30385624477bf3692af03720b6964e09787d2df8e09155c04c07e28ea71077c4
+         65861b1132274b9b60b50f867428"
+    );
+    const YA_MESSAGE: [u8; 56] = hex!(
+        "3e0cf9813715c6d5175667e9be7666824ce06e92150f0ab357000c595c81798da90e251fa40a2490e7bd
+         57b321c9c6c931211b3d221f783f"
+    );
+    const YB: [u8; 56] = hex!(
+        "325bca569114d6ea23dd9fcd50b8491c939df8a9d0be5fc749e8a63088e34c6640a6746867a9b7acc2d7
+         b747deb376225db6496575069737"
+    );
+    const YB_MESSAGE: [u8; 56] = hex!(
+        "6eaf15f344018626ca8b8bc084bc96d8fb69fd33b4d6f9f143b380a88e359212450bcedc7fae45ea0233
+         3ef0ed5b0a8be059bce8bc775050"
+    );
+    const K: [u8; 56] = hex!(
+        "6e3706dfe4aa918812a6b41d909ec03082fbb21986d3f6eb4922f07cbb7fb928dae170b49abe2f8d3ea9
+         073ebe42328500db9714e665630e"
+    );
+    const ISK_IR: [u8; 64] = hex!(
+        "2cbdf794626dabe262d8ae15162e74d57e2e4b4f8916f35b6333c315fffe5c40843d0372f213df673191
+         d25afd804821c8626d9e395ca1cbb34d78b761110e8d"
+    );
+    const ISK_OC: [u8; 64] = hex!(
+        "a78cdd0bf9d2deb9f905c429b32336b921d2c3542fc570393e332366c0e3459b350624650910a5484fc6
+         6020e910319c013a7c0d7ecab2776b128dc45fc8225c"
+    );
+
+    fn cpace(role: Role, secret: u64) -> Cpace<'static> {
+        let secret = Decaf448NonZeroScalar::new(DecafScalar::from(secret)).unwrap();
+        Cpace::new_with_secret(role, PRS, CI, SID, b"", secret)
+    }
+
+    fn secret_from_bytes(bytes: [u8; 56]) -> Decaf448NonZeroScalar {
+        let scalar = DecafScalar::from_canonical_bytes(&bytes.into()).unwrap();
+        Decaf448NonZeroScalar::new(scalar).unwrap()
+    }
+
+    #[test]
+    fn known_answer_generator() {
+        let g = Cpace::calculate_generator(PRS, CI, SID);
+        assert_eq!(g, Decaf448::calculate_generator(&[&GENERATOR_STRING[..]]));
+        assert_eq!(Decaf448::encode(&g).as_bytes(), &G);
+    }
+
+    #[test]
+    fn known_answer() {
+        let ya = secret_from_bytes(YA);
+        let yb = secret_from_bytes(YB);
+
+        let k = Decaf448::decode(&YB_MESSAGE).unwrap() * *ya;
+        assert_eq!(Decaf448::shared_secret(&k).as_bytes(), &K);
+
+        for (roles, isk) in [
+            ((Role::Initiator, Role::Responder), ISK_IR),
+            ((Role::Symmetric, Role::Symmetric), ISK_OC),
+        ] {
+            let a = Cpace::new_with_secret(roles.0, PRS, CI, SID, b"ADa", ya);
+            let b = Cpace::new_with_secret(roles.1, PRS, CI, SID, b"ADb", yb);
+            assert_eq!(a.message().as_bytes(), &YA_MESSAGE);
+            assert_eq!(b.message().as_bytes(), &YB_MESSAGE);
+
+            let a_isk = a.finish(&YB_MESSAGE, b"ADb").unwrap();
+            let b_isk = b.finish(&YA_MESSAGE, b"ADa").unwrap();
+            assert_eq!(a_isk.as_bytes(), isk);
+            assert_eq!(b_isk.as_bytes(), isk);
+        }
+    }
+
+    #[test]
+    fn key_exchange() {
+        let a = cpace(Role::Initiator, 1234);
+        let b = cpace(Role::Responder, 5678);
+        let a_isk = a.finish(b.message().as_bytes(), b"").unwrap();
+        let b_isk = b.finish(a.message().as_bytes(), b"").unwrap();
+        assert_eq!(a_isk.as_bytes(), b_isk.as_bytes());
+        assert_eq!(a_isk.as_bytes().len(), 64);
+    }
+
+    #[test]
+    fn rejects_invalid_messages() {
+        let a = cpace(Role::Initiator, 1234);
+
+        // identity
+        assert!(a.finish(CompressedDecaf::IDENTITY.as_bytes(), b"").is_err());
+
+        // wrong length
+        assert!(a.finish(&a.message().as_bytes()[1..], b"").is_err());
+
+        // non-canonical encoding
+        assert!(a.finish(&[0xff; 56], b"").is_err());
+    }
+}
@@ -520,6 +520,12 @@ impl AsRef<DecafPointBytes> for CompressedDecaf {
     }
 }
 
+impl AsRef<[u8]> for CompressedDecaf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serdect::serde::Serialize for CompressedDecaf {
     fn serialize<S: serdect::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
@@ -815,4 +821,19 @@ mod test {
     //         assert_eq!(rhs, expected);
     //     }
     // }
+
+    #[test]
+    fn test_mul_fixed_window() {
+        use elliptic_curve::consts::U4;
+        use wnaf::{FixedWindowScalar, WnafBase};
+
+        let point = DecafPoint::GENERATOR.double();
+        let base = WnafBase::<DecafPoint, U4>::new(&point);
+        let scalar = DecafScalar::from(0x1234_5678u32).invert();
+
+        for scalar in [scalar, -scalar, DecafScalar::ONE, DecafScalar::ZERO] {
+            let fixed_window = FixedWindowScalar::<DecafScalar, U4>::new(&scalar);
+            assert_eq!(&base * &fixed_window, point * scalar);
+        }
+    }
 }
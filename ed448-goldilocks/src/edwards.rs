@@ -12,6 +12,8 @@
 pub(crate) mod affine;
 pub(crate) mod extended;
 mod scalar;
+mod validation;
 pub use affine::{AffinePoint, CompressedEdwardsY};
 pub use extended::EdwardsPoint;
 pub use scalar::{EdwardsScalar, EdwardsScalarBytes, WideEdwardsScalarBytes};
+pub use validation::ValidationError;
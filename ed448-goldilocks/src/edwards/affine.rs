@@ -11,6 +11,9 @@ use elliptic_curve::{
 use rand_core::{TryCryptoRng, TryRng};
 use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// Affine point on untwisted curve
 #[derive(Copy, Clone, Debug)]
 pub struct AffinePoint {
@@ -103,6 +106,82 @@ impl AffinePoint {
         }
     }
 
+    /// Compute the sum of each pair of points in affine coordinates.
+    ///
+    /// Affine addition needs one inversion per point, but the inversions of a batch are shared
+    /// using Montgomery's trick, so adding `n` pairs of points costs a single inversion plus
+    /// `O(n)` multiplications. As the Edwards addition law is complete, there are no exceptional
+    /// cases and the result is computed in constant time.
+    #[cfg(feature = "alloc")]
+    pub fn batch_add(pairs: &[(Self, Self)]) -> Vec<Self> {
+        let fractions: Vec<_> = pairs.iter().map(|(p, q)| p.add_fractions(q)).collect();
+        Fractions::batch_to_affine(&fractions)
+    }
+
+    /// Compute the sum of each pair of points of an array in affine coordinates.
+    ///
+    /// See [`AffinePoint::batch_add`].
+    pub fn batch_add_array<const N: usize>(pairs: &[(Self, Self); N]) -> [Self; N] {
+        let fractions = core::array::from_fn(|i| pairs[i].0.add_fractions(&pairs[i].1));
+        Fractions::batch_to_affine_array(&fractions)
+    }
+
+    /// Double each point in affine coordinates, sharing the inversions using Montgomery's trick.
+    ///
+    /// See [`AffinePoint::batch_add`].
+    #[cfg(feature = "alloc")]
+    pub fn batch_double(points: &[Self]) -> Vec<Self> {
+        let fractions: Vec<_> = points.iter().map(Self::double_fractions).collect();
+        Fractions::batch_to_affine(&fractions)
+    }
+
+    /// Double each point of an array in affine coordinates.
+    ///
+    /// See [`AffinePoint::batch_double`].
+    pub fn batch_double_array<const N: usize>(points: &[Self; N]) -> [Self; N] {
+        let fractions = core::array::from_fn(|i| points[i].double_fractions());
+        Fractions::batch_to_affine_array(&fractions)
+    }
+
+    /// Compute the coordinates of `self + other` as fractions:
+    ///
+    /// ```text
+    /// x = (x1 y2 + y1 x2) / (1 + d x1 x2 y1 y2)
+    /// y = (y1 y2 - x1 x2) / (1 - d x1 x2 y1 y2)
+    /// ```
+    fn add_fractions(&self, other: &Self) -> Fractions {
+        let xx = self.x * other.x;
+        let yy = self.y * other.y;
+        let dxxyy = FieldElement::EDWARDS_D * xx * yy;
+
+        Fractions {
+            x_num: self.x * other.y + self.y * other.x,
+            x_den: FieldElement::ONE + dxxyy,
+            y_num: yy - xx,
+            y_den: FieldElement::ONE - dxxyy,
+        }
+    }
+
+    /// Compute the coordinates of `self + self` as fractions, using the curve equation to
+    /// simplify the denominators of [`AffinePoint::add_fractions`]:
+    ///
+    /// ```text
+    /// x = 2 x y / (x^2 + y^2)
+    /// y = (y^2 - x^2) / (2 - x^2 - y^2)
+    /// ```
+    fn double_fractions(&self) -> Fractions {
+        let xx = self.x.square();
+        let yy = self.y.square();
+        let xx_plus_yy = xx + yy;
+
+        Fractions {
+            x_num: (self.x * self.y).double(),
+            x_den: xx_plus_yy,
+            y_num: yy - xx,
+            y_den: FieldElement::ONE.double() - xx_plus_yy,
+        }
+    }
+
     /// Generate a random [`AffinePoint`].
     ///
     /// Helper method that has `TryRng` bounds so `ProjectivePoint` can call it for its `group`
@@ -122,6 +201,53 @@ impl AffinePoint {
     }
 }
 
+/// Affine point whose coordinates are given as the fractions `x_num / x_den` and `y_num / y_den`.
+///
+/// The denominators of the Edwards addition law are never zero for points on the curve.
+#[derive(Clone, Copy)]
+struct Fractions {
+    x_num: FieldElement,
+    x_den: FieldElement,
+    y_num: FieldElement,
+    y_den: FieldElement,
+}
+
+impl Fractions {
+    /// Common denominator of both coordinates.
+    fn denominator(&self) -> FieldElement {
+        self.x_den * self.y_den
+    }
+
+    /// Convert to an [`AffinePoint`] given the inverse of [`Fractions::denominator`].
+    fn to_affine(self, den_inv: &FieldElement) -> AffinePoint {
+        AffinePoint {
+            x: self.x_num * self.y_den * den_inv,
+            y: self.y_num * self.x_den * den_inv,
+        }
+    }
+
+    /// Convert a batch of fractions to [`AffinePoint`]s using a single inversion.
+    #[cfg(feature = "alloc")]
+    fn batch_to_affine(fractions: &[Self]) -> Vec<AffinePoint> {
+        let mut dens: Vec<_> = fractions.iter().map(Self::denominator).collect();
+        let mut scratch = vec![FieldElement::ZERO; fractions.len()];
+        let _ = primefield::batch_invert(&mut dens, &mut scratch);
+
+        fractions
+            .iter()
+            .zip(&dens)
+            .map(|(fraction, den_inv)| fraction.to_affine(den_inv))
+            .collect()
+    }
+
+    /// Convert an array of fractions to [`AffinePoint`]s using a single inversion.
+    fn batch_to_affine_array<const N: usize>(fractions: &[Self; N]) -> [AffinePoint; N] {
+        let mut dens = fractions.map(|fraction| fraction.denominator());
+        let _ = primefield::batch_invert_array(&mut dens);
+        core::array::from_fn(|i| fractions[i].to_affine(&dens[i]))
+    }
+}
+
 impl AffineCoordinates for AffinePoint {
     type FieldRepr = Ed448FieldBytes;
 
@@ -529,3 +655,37 @@ impl CompressedEdwardsY {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_add_and_double() {
+        let g = EdwardsPoint::GENERATOR.to_affine();
+        let g2 = EdwardsPoint::GENERATOR.double().to_affine();
+        let id = AffinePoint::IDENTITY;
+        let torsion = AffinePoint {
+            x: FieldElement::ZERO,
+            y: -FieldElement::ONE,
+        };
+        let pairs = [(g, g2), (g, g), (g, -g), (id, g), (g2, torsion), (id, id)];
+
+        let sums = AffinePoint::batch_add_array(&pairs);
+        for ((p, q), sum) in pairs.iter().zip(&sums) {
+            assert_eq!(*sum, (p.to_edwards() + q.to_edwards()).to_affine());
+        }
+
+        let points = [g, g2, -g, id, torsion];
+        let doubles = AffinePoint::batch_double_array(&points);
+        for (p, double) in points.iter().zip(&doubles) {
+            assert_eq!(*double, p.to_edwards().double().to_affine());
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(AffinePoint::batch_add(&pairs), sums);
+            assert_eq!(AffinePoint::batch_double(&points), doubles);
+        }
+    }
+}
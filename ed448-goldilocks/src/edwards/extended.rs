@@ -27,7 +27,7 @@ use elliptic_curve::{
     array::Array,
     ctutils,
     group::{Group, GroupEncoding, cofactor::CofactorGroup, prime::PrimeGroup},
-    ops::{BatchInvert, LinearCombination},
+    ops::LinearCombination,
     point::NonIdentity,
 };
 use rand_core::{TryCryptoRng, TryRng};
@@ -808,7 +808,7 @@ fn batch_normalize_generic(
     }
 
     // Zero `zs` (identity) are handled explicitly below, so the `Choice` here is informational only
-    let _ = FieldElement::batch_invert_in_place(zs, scratch);
+    let _ = primefield::batch_invert(zs, scratch);
 
     for i in 0..out.len() {
         out[i] = AffinePoint::conditional_select(
@@ -1173,4 +1173,107 @@ mod tests {
             assert!(bool::from(!point.is_torsion_free()));
         }
     }
+
+    #[property_test]
+    fn fuzz_mul_fixed_window(bytes: [u8; 57]) {
+        use elliptic_curve::consts::U5;
+        use wnaf::{FixedWindowScalar, WnafBase};
+
+        let scalar = EdwardsScalar::from_bytes_mod_order(&bytes.into());
+        let point = EdwardsPoint::GENERATOR * EdwardsScalar::from(3u8);
+        let base = WnafBase::<EdwardsPoint, U5>::new(&point);
+
+        for scalar in [scalar, scalar.double(), EdwardsScalar::ZERO] {
+            let fixed_window = FixedWindowScalar::<EdwardsScalar, U5>::new(&scalar);
+            prop_assert_eq!(&base * &fixed_window, point * scalar);
+        }
+    }
+
+    /// The base has a torsion component, so its order differs from the order of the scalar field.
+    #[property_test]
+    fn fuzz_mul_fixed_window_torsion(bytes: [u8; 57]) {
+        use elliptic_curve::{PrimeField, consts::U5};
+        use wnaf::{FixedWindowScalar, WnafBase};
+
+        // Point of order 2
+        let torsion = AffinePoint {
+            x: FieldElement::ZERO,
+            y: -FieldElement::ONE,
+        }
+        .to_edwards();
+        let point = EdwardsPoint::GENERATOR * EdwardsScalar::from(3u8);
+        let base = WnafBase::<EdwardsPoint, U5>::new(&(point + torsion));
+
+        let scalar = EdwardsScalar::from_bytes_mod_order(&bytes.into());
+        for scalar in [
+            scalar,
+            scalar.double(),
+            EdwardsScalar::ZERO,
+            EdwardsScalar::ONE,
+            -EdwardsScalar::ONE,
+        ] {
+            let fixed_window = FixedWindowScalar::<EdwardsScalar, U5>::new(&scalar);
+            let expected = point * scalar
+                + EdwardsPoint::conditional_select(
+                    &EdwardsPoint::IDENTITY,
+                    &torsion,
+                    scalar.is_odd(),
+                );
+            prop_assert_eq!(&base * &fixed_window, expected);
+        }
+    }
+
+    /// `EdwardsScalar::to_repr` is little endian, so small scalars have their low bytes first.
+    #[test]
+    fn wnaf_little_endian_scalar_repr() {
+        use elliptic_curve::{PrimeField, consts::U5};
+        use wnaf::{WnafBase, WnafScalar};
+
+        let point = EdwardsPoint::GENERATOR.double();
+        let base = WnafBase::<EdwardsPoint, U5>::new(&point);
+
+        for k in [1u64, 2, 0x1234, u64::MAX] {
+            let scalar = EdwardsScalar::from(k);
+            assert_eq!(PrimeField::to_repr(&scalar)[0], k.to_le_bytes()[0]);
+            assert_eq!(&base * &WnafScalar::new(&scalar), point * scalar);
+        }
+    }
+
+    #[property_test]
+    fn fuzz_jsf(bytes1: [u8; 57], bytes2: [u8; 57]) {
+        use wnaf::{JsfBase, JsfScalar};
+
+        let s1 = EdwardsScalar::from_bytes_mod_order(&bytes1.into());
+        let s2 = EdwardsScalar::from_bytes_mod_order(&bytes2.into());
+        let point = EdwardsPoint::GENERATOR * EdwardsScalar::from(3u8);
+
+        let base = JsfBase::new(&EdwardsPoint::GENERATOR, &point);
+        prop_assert_eq!(
+            &base * &JsfScalar::new(&s1, &s2),
+            EdwardsPoint::GENERATOR * s1 + point * s2
+        );
+    }
+
+    /// Bits of the inputs of `JsfScalar::from_le_bytes` above `NUM_BITS` are ignored.
+    #[test]
+    fn jsf_from_le_bytes_ignores_high_bits() {
+        use elliptic_curve::PrimeField;
+        use wnaf::{JsfBase, JsfScalar};
+
+        assert_eq!(EdwardsScalar::NUM_BITS, 446);
+        let bytes = [0xff; 57];
+        let mut truncated = [0xff; 57];
+        truncated[55] = 0x3f;
+        truncated[56] = 0;
+
+        let s1 = EdwardsScalar::from_bytes_mod_order(&truncated.into());
+        let s2 = EdwardsScalar::from(0xffu8);
+        let point = EdwardsPoint::GENERATOR * EdwardsScalar::from(3u8);
+
+        let base = JsfBase::new(&EdwardsPoint::GENERATOR, &point);
+        assert_eq!(
+            &base * &JsfScalar::<EdwardsScalar>::from_le_bytes(&bytes, &bytes[..1]),
+            EdwardsPoint::GENERATOR * s1 + point * s2
+        );
+    }
 }
@@ -0,0 +1,248 @@
+//! Public key validation as described in NIST SP 800-56A Rev. 3 § 5.6.2.3.
+
+use super::{AffinePoint, CompressedEdwardsY, affine::PointBytes};
+use crate::field::FieldElement;
+use core::fmt;
+use elliptic_curve::group::Group;
+
+/// Public key validation errors.
+///
+/// Each variant corresponds to one of the checks of the full public key validation routine of
+/// NIST SP 800-56A Rev. 3 § 5.6.2.3.3, in the order in which they are performed. The variants
+/// are the same as those of `primeorder::ValidationError`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ValidationError {
+    /// Malformed encoding.
+    Encoding,
+
+    /// The point is the identity (step 1).
+    Identity,
+
+    /// A coordinate is not in the range `[0, p - 1]` (step 2).
+    Range,
+
+    /// The point is not on the curve (step 3).
+    NotOnCurve,
+
+    /// The point is not in the subgroup of prime order `n` (step 4).
+    Order,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Encoding => "malformed point encoding",
+            Self::Identity => "point is the identity",
+            Self::Range => "coordinate out of range",
+            Self::NotOnCurve => "point is not on the curve",
+            Self::Order => "point is not in the prime order subgroup",
+        })
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+impl From<ValidationError> for elliptic_curve::Error {
+    fn from(_: ValidationError) -> Self {
+        elliptic_curve::Error
+    }
+}
+
+impl AffinePoint {
+    /// Perform the partial public key validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.4
+    /// on this point.
+    ///
+    /// Checks that the point is not the identity and is on the curve. The coordinates of an
+    /// [`AffinePoint`] are always in range.
+    ///
+    /// Note that partial validation does not check that the point is in the prime order
+    /// subgroup, which is required for keys that are used with this curve, as it has a cofactor
+    /// of 4.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::Identity`] or [`ValidationError::NotOnCurve`] if the respective
+    /// check fails.
+    pub fn validate_partial(&self) -> Result<(), ValidationError> {
+        if self.to_edwards().is_identity().into() {
+            return Err(ValidationError::Identity);
+        }
+
+        if !bool::from(self.is_on_curve()) {
+            return Err(ValidationError::NotOnCurve);
+        }
+
+        Ok(())
+    }
+
+    /// Perform the full public key validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.3 on
+    /// this point.
+    ///
+    /// In addition to the checks of [`AffinePoint::validate_partial`], checks that the point is
+    /// in the prime order subgroup.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    pub fn validate_full(&self) -> Result<(), ValidationError> {
+        self.validate_partial()?;
+
+        if !bool::from(self.to_edwards().is_torsion_free()) {
+            return Err(ValidationError::Order);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a compressed point from a byte slice, performing the partial public key validation
+    /// routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.4.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::Encoding`] if `bytes` is not 57 bytes long, otherwise the
+    /// [`ValidationError`] corresponding to the first check that fails.
+    pub fn from_bytes_partial(bytes: &[u8]) -> Result<Self, ValidationError> {
+        let bytes = PointBytes::try_from(bytes).map_err(|_| ValidationError::Encoding)?;
+        CompressedEdwardsY(bytes).validate_partial()
+    }
+
+    /// Decode a compressed point from a byte slice, performing the full public key validation
+    /// routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.3.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::Encoding`] if `bytes` is not 57 bytes long, otherwise the
+    /// [`ValidationError`] corresponding to the first check that fails.
+    pub fn from_bytes_full(bytes: &[u8]) -> Result<Self, ValidationError> {
+        let bytes = PointBytes::try_from(bytes).map_err(|_| ValidationError::Encoding)?;
+        CompressedEdwardsY(bytes).validate_full()
+    }
+}
+
+impl CompressedEdwardsY {
+    /// Decompress this point, performing the partial public key validation routine of
+    /// NIST SP 800-56A Rev. 3 § 5.6.2.3.4.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails. Setting any of
+    /// the unused bits of the encoding is reported as [`ValidationError::Range`].
+    pub fn validate_partial(&self) -> Result<AffinePoint, ValidationError> {
+        let mut y_bytes = [0u8; 56];
+        y_bytes.copy_from_slice(&self.0[..56]);
+
+        if self.0[56] & 0x7f != 0 || FieldElement::from_repr(&y_bytes).is_none().into() {
+            return Err(ValidationError::Range);
+        }
+
+        let point = self
+            .decompress_unchecked()
+            .into_option()
+            .ok_or(ValidationError::NotOnCurve)?;
+
+        point.validate_partial()?;
+        Ok(point)
+    }
+
+    /// Decompress this point, performing the full public key validation routine of
+    /// NIST SP 800-56A Rev. 3 § 5.6.2.3.3.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    pub fn validate_full(&self) -> Result<AffinePoint, ValidationError> {
+        let point = self.validate_partial()?;
+        point.validate_full()?;
+        Ok(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EdwardsPoint;
+
+    #[test]
+    fn validate_generator() {
+        let point = CompressedEdwardsY::GENERATOR.validate_full().unwrap();
+        assert_eq!(point, EdwardsPoint::GENERATOR.to_affine());
+        assert_eq!(point.validate_full(), Ok(()));
+    }
+
+    #[test]
+    fn validate_encoding() {
+        let bytes = CompressedEdwardsY::GENERATOR.to_bytes();
+        assert_eq!(
+            AffinePoint::from_bytes_full(&bytes),
+            Ok(EdwardsPoint::GENERATOR.to_affine())
+        );
+        assert_eq!(
+            AffinePoint::from_bytes_partial(&bytes[..56]),
+            Err(ValidationError::Encoding)
+        );
+        assert_eq!(
+            AffinePoint::from_bytes_full(&[]),
+            Err(ValidationError::Encoding)
+        );
+    }
+
+    #[test]
+    fn validate_identity() {
+        assert_eq!(
+            AffinePoint::IDENTITY.compress().validate_partial(),
+            Err(ValidationError::Identity)
+        );
+        assert_eq!(
+            AffinePoint::IDENTITY.validate_full(),
+            Err(ValidationError::Identity)
+        );
+    }
+
+    #[test]
+    fn validate_range() {
+        let mut bytes = CompressedEdwardsY::GENERATOR.to_bytes();
+        bytes[56] |= 1;
+        assert_eq!(
+            CompressedEdwardsY(bytes).validate_partial(),
+            Err(ValidationError::Range)
+        );
+
+        assert_eq!(
+            CompressedEdwardsY([0xff; 57]).validate_partial(),
+            Err(ValidationError::Range)
+        );
+    }
+
+    #[test]
+    fn validate_not_on_curve() {
+        let bytes = (2u8..)
+            .map(|y| {
+                let mut bytes = [0; 57];
+                bytes[0] = y;
+                bytes
+            })
+            .find(|bytes| {
+                CompressedEdwardsY(*bytes)
+                    .decompress_unchecked()
+                    .is_none()
+                    .into()
+            })
+            .unwrap();
+
+        assert_eq!(
+            CompressedEdwardsY(bytes).validate_partial(),
+            Err(ValidationError::NotOnCurve)
+        );
+    }
+
+    #[test]
+    fn validate_torsion() {
+        // Add the point of order 2 to the generator
+        let torsion = AffinePoint {
+            x: FieldElement::ZERO,
+            y: -FieldElement::ONE,
+        };
+        let point = (EdwardsPoint::GENERATOR + torsion.to_edwards()).to_affine();
+
+        assert_eq!(point.validate_partial(), Ok(()));
+        assert_eq!(point.validate_full(), Err(ValidationError::Order));
+        assert_eq!(
+            point.compress().validate_full(),
+            Err(ValidationError::Order)
+        );
+    }
+}
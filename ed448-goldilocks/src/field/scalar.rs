@@ -14,7 +14,7 @@ use elliptic_curve::{
         typenum::{Prod, Unsigned},
     },
     bigint::{Limb, U448, U896, Word, modular::Retrieve},
-    consts::U2,
+    consts::{U2, U447},
     ctutils::{self, CtSelect},
     ff::{Field, helpers},
     ops::{
@@ -339,6 +339,10 @@ impl<C: CurveWithScalar> Field for Scalar<C> {
     }
 }
 
+impl<C: CurveWithScalar> wnaf::WnafSize for Scalar<C> {
+    type StorageSize = U447;
+}
+
 impl<C: CurveWithScalar> PrimeField for Scalar<C> {
     type Repr = ScalarBytes<C>;
 
@@ -57,7 +57,10 @@ pub use hash2curve;
 pub use rand_core;
 pub use shake;
 pub use subtle;
+pub use wnaf;
 
+#[cfg(feature = "cpace")]
+pub mod cpace;
 pub(crate) mod curve;
 pub(crate) mod decaf;
 pub(crate) mod edwards;
@@ -74,7 +77,7 @@ pub use decaf::{
 };
 pub use edwards::{
     AffinePoint, CompressedEdwardsY, EdwardsPoint, EdwardsScalar, EdwardsScalarBytes,
-    WideEdwardsScalarBytes,
+    ValidationError, WideEdwardsScalarBytes,
 };
 pub use field::{MODULUS_LIMBS, ORDER, Scalar, WIDE_ORDER};
 pub use montgomery::{MontgomeryPoint, ProjectiveMontgomeryPoint};
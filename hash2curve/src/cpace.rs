@@ -0,0 +1,366 @@
+//! CPace password-authenticated key exchange as described in [draft-irtf-cfrg-cpace].
+//!
+//! [draft-irtf-cfrg-cpace]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-cpace/
+
+use core::fmt;
+use digest::Update;
+use elliptic_curve::{
+    CurveArithmetic, Error, Generate, NonZeroScalar, ProjectivePoint, Result,
+    array::{Array, ArraySize},
+    group::Group,
+    rand_core::{CryptoRng, TryCryptoRng},
+    zeroize::Zeroize,
+};
+
+/// Zero bytes the generator string is padded with.
+const ZERO_PADDING: [u8; 256] = [0; 256];
+
+/// Group and hash function parameters of a CPace ciphersuite.
+pub trait CpaceParameters: CurveArithmetic {
+    /// Domain separation identifier of the ciphersuite.
+    const DSI: &'static [u8];
+
+    /// Input block size of the hash function in bytes, which the generator string is padded to.
+    ///
+    /// It must not exceed 256 bytes.
+    const S_IN_BYTES: usize;
+
+    /// Hash function used to compute the intermediate session key.
+    type Hash: Default + Update;
+
+    /// Size of the intermediate session key in bytes.
+    type IskSize: ArraySize;
+
+    /// Encoding of a group element, as sent to the other party.
+    type Message: AsRef<[u8]> + Clone + fmt::Debug;
+
+    /// Encoding of the shared secret `K` computed by `scalar_mult_vfy`.
+    type SharedSecret: AsRef<[u8]> + Zeroize;
+
+    /// Derive the secret generator from the generator string, given as the concatenation of the
+    /// elements in `generator_string`.
+    fn calculate_generator(generator_string: &[&[u8]]) -> ProjectivePoint<Self>;
+
+    /// Encode a group element.
+    fn encode(point: &ProjectivePoint<Self>) -> Self::Message;
+
+    /// Decode a group element received from the other party, or return `None` if the message
+    /// isn't a valid encoding.
+    fn decode(message: &[u8]) -> Option<ProjectivePoint<Self>>;
+
+    /// Encode the shared secret `K`, which is never the identity.
+    fn shared_secret(point: &ProjectivePoint<Self>) -> Self::SharedSecret;
+
+    /// Finalize the hash into the intermediate session key.
+    fn finalize_isk(hash: Self::Hash) -> Array<u8, Self::IskSize>;
+}
+
+/// Role of a party in the CPace protocol.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum CpaceRole {
+    /// Party A, which sends the first message.
+    Initiator,
+
+    /// Party B, which responds to the message of the initiator.
+    Responder,
+
+    /// Either party in the symmetric setting, where both parties send their messages in parallel
+    /// and the transcript orders them by their encodings.
+    Symmetric,
+}
+
+/// State of a party in the CPace protocol, after it has computed its message.
+pub struct Cpace<'a, C: CpaceParameters> {
+    role: CpaceRole,
+    sid: &'a [u8],
+    ad: &'a [u8],
+    secret: NonZeroScalar<C>,
+    message: C::Message,
+}
+
+impl<'a, C: CpaceParameters> Cpace<'a, C> {
+    /// Start the protocol as the party with the given role.
+    ///
+    /// Both parties must agree on the setting: either one is the [`CpaceRole::Initiator`] and the
+    /// other the [`CpaceRole::Responder`], or both are [`CpaceRole::Symmetric`].
+    ///
+    /// - `prs`: password-related string shared by both parties.
+    /// - `ci`: channel identifier, e.g. the identities of both parties. It may be empty.
+    /// - `sid`: session ID, which must be unique for each run of the protocol. It may be empty if
+    ///   the application can't establish one, at the cost of weaker security guarantees.
+    /// - `ad`: associated data sent to the other party along with the message. It may be empty.
+    #[must_use]
+    pub fn new<R: CryptoRng + ?Sized>(
+        role: CpaceRole,
+        prs: &[u8],
+        ci: &[u8],
+        sid: &'a [u8],
+        ad: &'a [u8],
+        rng: &mut R,
+    ) -> Self {
+        let secret = NonZeroScalar::generate_from_rng(rng);
+        Self::new_with_secret(role, prs, ci, sid, ad, secret)
+    }
+
+    /// Start the protocol as the party with the given role.
+    ///
+    /// See [`Cpace::new`] for a description of the parameters.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_new<R: TryCryptoRng + ?Sized>(
+        role: CpaceRole,
+        prs: &[u8],
+        ci: &[u8],
+        sid: &'a [u8],
+        ad: &'a [u8],
+        rng: &mut R,
+    ) -> core::result::Result<Self, R::Error> {
+        let secret = NonZeroScalar::try_generate_from_rng(rng)?;
+        Ok(Self::new_with_secret(role, prs, ci, sid, ad, secret))
+    }
+
+    /// Start the protocol with the given ephemeral secret scalar (`ya` for the initiator, `yb`
+    /// for the responder).
+    ///
+    /// <div class = "warning">
+    /// <b>Security Warning</b>
+    ///
+    /// The secret scalar MUST be generated uniformly at random and never reused. This is only
+    /// intended for testing against known answers; use [`Cpace::new`] otherwise.
+    /// </div>
+    #[must_use]
+    pub fn new_with_secret(
+        role: CpaceRole,
+        prs: &[u8],
+        ci: &[u8],
+        sid: &'a [u8],
+        ad: &'a [u8],
+        secret: NonZeroScalar<C>,
+    ) -> Self {
+        let message = C::encode(&(Self::calculate_generator(prs, ci, sid) * *secret));
+
+        Self {
+            role,
+            sid,
+            ad,
+            secret,
+            message,
+        }
+    }
+
+    /// Role of this party.
+    #[must_use]
+    pub fn role(&self) -> CpaceRole {
+        self.role
+    }
+
+    /// Message to send to the other party along with the associated data.
+    #[must_use]
+    pub fn message(&self) -> &C::Message {
+        &self.message
+    }
+
+    /// Process the message and associated data received from the other party and compute the
+    /// intermediate session key.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the message isn't a valid encoding of a group element, or it leads to
+    /// the identity.
+    pub fn finish(&self, peer_message: &[u8], peer_ad: &[u8]) -> Result<IntermediateSessionKey<C>> {
+        let peer = C::decode(peer_message).ok_or(Error)?;
+
+        // K = scalar_mult_vfy(y, Y)
+        let k = peer * *self.secret;
+        if k.is_identity().into() {
+            return Err(Error);
+        }
+        let mut k = C::shared_secret(&k);
+
+        // ISK = H(lv_cat(DSI || "_ISK", sid, K) || transcript_ir(Ya, ADa, Yb, ADb))
+        let mut hash = C::Hash::default();
+        hash.update(Leb128::new(C::DSI.len() + 4).as_slice());
+        hash.update(C::DSI);
+        hash.update(b"_ISK");
+        for field in [self.sid, k.as_ref()] {
+            update_lv(&mut hash, field);
+        }
+
+        let own = [self.message.as_ref(), self.ad];
+        let peer = [peer_message, peer_ad];
+        let (first, second) = match self.role {
+            // transcript_ir(Ya, ADa, Yb, ADb)
+            CpaceRole::Initiator => (own, peer),
+            CpaceRole::Responder => (peer, own),
+            // transcript_oc(Ya, ADa, Yb, ADb) = o_cat(lv_cat(Ya, ADa), lv_cat(Yb, ADb))
+            CpaceRole::Symmetric => {
+                hash.update(b"oc");
+                if lv_bytes(&own).gt(lv_bytes(&peer)) {
+                    (own, peer)
+                } else {
+                    (peer, own)
+                }
+            }
+        };
+        for field in first.into_iter().chain(second) {
+            update_lv(&mut hash, field);
+        }
+
+        k.zeroize();
+        Ok(IntermediateSessionKey {
+            bytes: C::finalize_isk(hash),
+        })
+    }
+}
+
+impl<C: CpaceParameters> fmt::Debug for Cpace<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cpace")
+            .field("role", &self.role)
+            .field("message", &self.message)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: CpaceParameters> Drop for Cpace<'_, C> {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// Intermediate session key (ISK) resulting from a run of the CPace protocol.
+///
+/// The ISK isn't authenticated: the application should confirm it, e.g. by exchanging MACs of
+/// the transcript, or derive its session keys from it.
+pub struct IntermediateSessionKey<C: CpaceParameters> {
+    bytes: Array<u8, C::IskSize>,
+}
+
+impl<C: CpaceParameters> IntermediateSessionKey<C> {
+    /// Bytes of the intermediate session key.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<C: CpaceParameters> fmt::Debug for IntermediateSessionKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntermediateSessionKey")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: CpaceParameters> Drop for IntermediateSessionKey<C> {
+    fn drop(&mut self) {
+        self.bytes.as_mut_slice().zeroize();
+    }
+}
+
+impl<C: CpaceParameters> Cpace<'_, C> {
+    /// Derive the secret generator `g` from the password-related string, the channel identifier
+    /// and the session ID.
+    ///
+    /// This is computed by [`Cpace::new`]; it is exposed for testing against known answers.
+    #[must_use]
+    pub fn calculate_generator(prs: &[u8], ci: &[u8], sid: &[u8]) -> ProjectivePoint<C> {
+        const { assert!(C::S_IN_BYTES <= ZERO_PADDING.len()) };
+
+        // generator_string(DSI, PRS, CI, sid, s_in_bytes)
+        //   = lv_cat(DSI, PRS, zero_bytes(len_zpad), CI, sid)
+        let dsi_len = Leb128::new(C::DSI.len());
+        let prs_len = Leb128::new(prs.len());
+        let zpad =
+            C::S_IN_BYTES.saturating_sub(1 + prs_len.len + prs.len() + dsi_len.len + C::DSI.len());
+        let zpad_len = Leb128::new(zpad);
+        let ci_len = Leb128::new(ci.len());
+        let sid_len = Leb128::new(sid.len());
+
+        C::calculate_generator(&[
+            dsi_len.as_slice(),
+            C::DSI,
+            prs_len.as_slice(),
+            prs,
+            zpad_len.as_slice(),
+            &ZERO_PADDING[..zpad],
+            ci_len.as_slice(),
+            ci,
+            sid_len.as_slice(),
+            sid,
+        ])
+    }
+}
+
+/// Update the hash with `prepend_len(field)`.
+fn update_lv(hash: &mut impl Update, field: &[u8]) {
+    hash.update(Leb128::new(field.len()).as_slice());
+    hash.update(field);
+}
+
+/// Bytes of `lv_cat(fields)`, to order them in `transcript_oc` without buffering.
+fn lv_bytes<'a>(fields: &'a [&'a [u8]; 2]) -> impl Iterator<Item = u8> + 'a {
+    fields.iter().flat_map(|field| {
+        let len = Leb128::new(field.len());
+        len.bytes
+            .into_iter()
+            .take(len.len)
+            .chain(field.iter().copied())
+    })
+}
+
+/// Length of a field encoded as an unsigned LEB128 integer, as used by `prepend_len`.
+struct Leb128 {
+    bytes: [u8; 10],
+    len: usize,
+}
+
+impl Leb128 {
+    fn new(mut value: usize) -> Self {
+        let mut bytes = [0; 10];
+        let mut len = 0;
+
+        loop {
+            let byte = value.to_le_bytes()[0] & 0x7f;
+            value >>= 7;
+
+            if value == 0 {
+                bytes[len] = byte;
+                len += 1;
+                break;
+            }
+
+            bytes[len] = byte | 0x80;
+            len += 1;
+        }
+
+        Self { bytes, len }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Leb128, lv_bytes};
+
+    #[test]
+    fn leb128() {
+        assert_eq!(Leb128::new(0).as_slice(), [0]);
+        assert_eq!(Leb128::new(0x7f).as_slice(), [0x7f]);
+        assert_eq!(Leb128::new(0x80).as_slice(), [0x80, 0x01]);
+        assert_eq!(Leb128::new(0x3fff).as_slice(), [0xff, 0x7f]);
+        assert_eq!(Leb128::new(0x4000).as_slice(), [0x80, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn lv_cat_order() {
+        assert!(lv_bytes(&[b"Y", b"AD"]).eq(*b"\x01Y\x02AD"));
+
+        // the length prefix comes first, so a longer `Y` is larger
+        assert!(lv_bytes(&[b"AA", b""]).gt(lv_bytes(&[b"B", b""])));
+        assert!(lv_bytes(&[b"B", b""]).gt(lv_bytes(&[b"A", b"\xff"])));
+        assert!(lv_bytes(&[b"A", b"A"]).gt(lv_bytes(&[b"A", b""])));
+    }
+}
@@ -22,11 +22,13 @@
     unused_must_use
 )]
 
+mod cpace;
 mod group_digest;
 mod hash2field;
 mod map2curve;
 mod oprf;
 
+pub use cpace::*;
 pub use group_digest::*;
 pub use hash2field::*;
 pub use map2curve::*;
@@ -14,6 +14,7 @@ use k256::{
         subtle::ConstantTimeEq,
     },
 };
+use wnaf::{BoxedGlvWnaf, BoxedWnaf};
 
 fn test_scalar_x() -> Scalar {
     Scalar::from_repr(
@@ -91,6 +92,22 @@ fn bench_point_mul_by_generator<M: Measurement>(group: &mut BenchmarkGroup<'_, M
     });
 }
 
+fn bench_point_wnaf<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let p = ProjectivePoint::GENERATOR;
+    let s = test_scalar_x();
+
+    let mut wnaf = BoxedWnaf::new();
+    let mut wnaf_base = wnaf.base(&p, 1);
+    group.bench_function("BoxedWnaf::scalar", |b| {
+        b.iter(|| wnaf_base.scalar::<AffinePoint>(&black_box(s)));
+    });
+
+    let mut glv_wnaf = BoxedGlvWnaf::new(&p, 1);
+    group.bench_function("BoxedGlvWnaf::scalar", |b| {
+        b.iter(|| glv_wnaf.scalar(&black_box(s)));
+    });
+}
+
 fn bench_point_normalize<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
     let p = ProjectivePoint::GENERATOR;
     let points = [p, p];
@@ -110,6 +127,7 @@ fn bench_point(c: &mut Criterion) {
     bench_point_lincomb(&mut group);
     bench_point_mul(&mut group);
     bench_point_mul_by_generator(&mut group);
+    bench_point_wnaf(&mut group);
     bench_point_normalize(&mut group);
 
     group.bench_function("ct_eq", |b| {
@@ -14,6 +14,7 @@ mod hash2curve;
 mod tables;
 
 pub use field::FieldElement;
+pub use mul::GeneratorContext;
 
 use self::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
 use crate::Secp256k1;
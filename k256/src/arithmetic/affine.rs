@@ -7,7 +7,7 @@ use crate::{CompressedPoint, FieldBytes, PublicKey, Scalar, Sec1Point, Secp256k1
 use elliptic_curve::{
     Error, Generate, Result, ctutils,
     ff::PrimeField,
-    group::{CurveAffine, GroupEncoding},
+    group::{CurveAffine, Group, GroupEncoding, UncompressedEncoding},
     ops::{Mul, MulVartime, Neg},
     point::{AffineCoordinates, DecompactPoint, DecompressPoint, NonIdentity},
     rand_core::{TryCryptoRng, TryRng},
@@ -15,6 +15,10 @@ use elliptic_curve::{
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
 };
+use primeorder::{AffinePointValidation, primefield};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
 use serdect::serde::{Deserialize, Serialize, de, ser};
@@ -76,6 +80,137 @@ impl AffinePoint {
         infinity: 0,
     };
 
+    /// Decode a batch of SEC1-encoded points.
+    ///
+    /// The output contains one result per encoding, which is an [`Error`] if that encoding is
+    /// malformed or isn't a point on the curve. Invalid entries don't affect the other entries.
+    /// Like [`FromSec1Point`], the SEC1 identity encoding is accepted.
+    ///
+    /// Decoding affine coordinates involves no field inversions. The square root needed to
+    /// decompress each compressed entry can't be shared between unrelated points, so the cost is
+    /// the same as decoding each entry with [`FromSec1Point`].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn batch_from_sec1<B: AsRef<[u8]>>(encodings: &[B]) -> Vec<Result<Self>> {
+        encodings
+            .iter()
+            .map(|bytes| {
+                let point = Sec1Point::from_bytes(bytes.as_ref()).map_err(|_| Error)?;
+                Self::try_from(&point)
+            })
+            .collect()
+    }
+
+    /// Compute the sum of each pair of points in affine coordinates.
+    ///
+    /// Affine addition needs one inversion per point, but the inversions of a batch are shared
+    /// using Montgomery's trick, so adding `n` pairs of points costs a single inversion plus
+    /// `O(n)` multiplications. This makes it cheaper than adding the points in projective
+    /// coordinates and normalizing the results.
+    ///
+    /// Runs in constant time: pairs of equal points, pairs of opposite points and the identity
+    /// are handled without branching.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn batch_add(pairs: &[(Self, Self)]) -> Vec<Self> {
+        let mut denominators: Vec<_> = pairs
+            .iter()
+            .map(|(p, q)| p.slope(q).1.normalize())
+            .collect();
+        let mut scratch = vec![FieldElement::ZERO; pairs.len()];
+        let _ = primefield::batch_invert(&mut denominators, &mut scratch);
+
+        pairs
+            .iter()
+            .zip(denominators)
+            .map(|((p, q), den_inv)| p.add_with_den_inv(q, &den_inv))
+            .collect()
+    }
+
+    /// Compute the sum of each pair of points of an array in affine coordinates.
+    ///
+    /// See [`AffinePoint::batch_add`].
+    #[must_use]
+    pub fn batch_add_array<const N: usize>(pairs: &[(Self, Self); N]) -> [Self; N] {
+        let mut denominators: [FieldElement; N] =
+            core::array::from_fn(|i| pairs[i].0.slope(&pairs[i].1).1.normalize());
+        let _ = primefield::batch_invert_array(&mut denominators);
+        core::array::from_fn(|i| pairs[i].0.add_with_den_inv(&pairs[i].1, &denominators[i]))
+    }
+
+    /// Double each point in affine coordinates, sharing the inversions using Montgomery's trick.
+    ///
+    /// See [`AffinePoint::batch_add`].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn batch_double(points: &[Self]) -> Vec<Self> {
+        let mut denominators: Vec<_> = points.iter().map(|p| p.y.double().normalize()).collect();
+        let mut scratch = vec![FieldElement::ZERO; points.len()];
+        let _ = primefield::batch_invert(&mut denominators, &mut scratch);
+
+        points
+            .iter()
+            .zip(denominators)
+            .map(|(p, den_inv)| p.double_with_den_inv(&den_inv))
+            .collect()
+    }
+
+    /// Double each point of an array in affine coordinates.
+    ///
+    /// See [`AffinePoint::batch_double`].
+    #[must_use]
+    pub fn batch_double_array<const N: usize>(points: &[Self; N]) -> [Self; N] {
+        let mut denominators: [FieldElement; N] =
+            core::array::from_fn(|i| points[i].y.double().normalize());
+        let _ = primefield::batch_invert_array(&mut denominators);
+        core::array::from_fn(|i| points[i].double_with_den_inv(&denominators[i]))
+    }
+
+    /// Compute the numerator and denominator of the slope of the line through `self` and
+    /// `other`, or of the tangent if the points are equal.
+    ///
+    /// The denominator is zero iff the points have the same x-coordinate but aren't equal, in
+    /// which case their sum is the identity. Both have magnitude 1.
+    fn slope(&self, other: &Self) -> (FieldElement, FieldElement) {
+        let dx = (other.x + &self.x.negate(1)).normalize_weak();
+        let dy = (other.y + &self.y.negate(1)).normalize_weak();
+        let is_double = dx.normalizes_to_zero() & dy.normalizes_to_zero();
+
+        // a = 0, so the slope of the tangent is 3x² / 2y
+        let xx = self.x.square();
+        let num =
+            FieldElement::conditional_select(&dy, &(xx.double() + &xx).normalize_weak(), is_double);
+        let den =
+            FieldElement::conditional_select(&dx, &self.y.double().normalize_weak(), is_double);
+        (num, den)
+    }
+
+    /// Compute `self + other` given the inverse of the denominator of their slope, as computed
+    /// by [`AffinePoint::slope`].
+    fn add_with_den_inv(&self, other: &Self, den_inv: &FieldElement) -> Self {
+        let (num, den) = self.slope(other);
+        let lambda = num * den_inv;
+        let x = (lambda.square() + &self.x.negate(1) + &other.x.negate(1)).normalize();
+        let y = (lambda * &(self.x + &x.negate(1)) + &self.y.negate(1)).normalize();
+
+        let mut sum =
+            Self::conditional_select(&Self::new(x, y), &Self::IDENTITY, den.normalizes_to_zero());
+        sum.conditional_assign(self, other.is_identity());
+        sum.conditional_assign(other, self.is_identity());
+        sum
+    }
+
+    /// Compute `self + self` given the inverse of `2y`.
+    fn double_with_den_inv(&self, den_inv: &FieldElement) -> Self {
+        let xx = self.x.square();
+        let lambda = (xx.double() + &xx) * den_inv;
+        let x = (lambda.square() + &self.x.double().negate(2)).normalize();
+        let y = (lambda * &(self.x + &x.negate(1)) + &self.y.negate(1)).normalize();
+
+        // As the curve has prime order, `y` is only zero for the identity
+        Self::conditional_select(&Self::new(x, y), self, self.is_identity())
+    }
+
     /// Generate a random [`AffinePoint`].
     ///
     /// This internal method avoids the `TryCryptoRng` bounds so it can be used in `group` impls for
@@ -319,6 +454,40 @@ impl GroupEncoding for AffinePoint {
     }
 }
 
+impl UncompressedEncoding for AffinePoint {
+    type Uncompressed = sec1::UncompressedPoint<Secp256k1>;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Sec1Point::from_bytes(bytes)
+            .map_or_else(
+                |_| {
+                    // The identity is encoded as all zeroes, as with `GroupEncoding`
+                    let is_identity = ctutils::CtEq::ct_eq(
+                        bytes.as_slice(),
+                        Self::Uncompressed::default().as_slice(),
+                    );
+
+                    ctutils::CtOption::new(Sec1Point::identity(), is_identity)
+                },
+                ctutils::CtOption::some,
+            )
+            .and_then(|point| Self::from_sec1_point(&point))
+            .into()
+    }
+
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        // The curve has prime order, so every point on the curve is in the subgroup
+        Self::from_uncompressed(bytes)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        let encoded = self.to_sec1_point(false);
+        let mut result = Self::Uncompressed::default();
+        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
+        result
+    }
+}
+
 impl FromSec1Point<Secp256k1> for AffinePoint {
     /// Attempts to parse the given [`Sec1Point`] as an SEC1-encoded [`AffinePoint`].
     ///
@@ -347,6 +516,21 @@ impl ToSec1Point<Secp256k1> for AffinePoint {
     }
 }
 
+/// NIST SP 800-56A Rev. 3 § 5.6.2.3 public key validation, which provides the
+/// [`PublicKeyValidation`][`primeorder::PublicKeyValidation`] impl for [`PublicKey`].
+impl AffinePointValidation<Secp256k1> for AffinePoint {
+    fn is_in_range(coordinate: &FieldBytes) -> Choice {
+        FieldElement::from_bytes(coordinate).is_some()
+    }
+
+    fn is_on_curve(&self) -> Choice {
+        // Check that y² = x³ + 7
+        let lhs = (self.y * &self.y).negate(1);
+        let rhs = self.x * &self.x * &self.x + &CURVE_EQUATION_B;
+        (lhs + &rhs).normalizes_to_zero()
+    }
+}
+
 impl TryFrom<Sec1Point> for AffinePoint {
     type Error = Error;
 
@@ -443,7 +627,7 @@ impl<'de> Deserialize<'de> for AffinePoint {
 #[cfg(test)]
 mod tests {
     use super::AffinePoint;
-    use crate::Sec1Point;
+    use crate::{ProjectivePoint, Sec1Point};
     use elliptic_curve::{
         group::{CurveAffine, GroupEncoding},
         sec1::{FromSec1Point, ToSec1Point},
@@ -515,4 +699,102 @@ mod tests {
                 .is_identity()
         ));
     }
+
+    #[test]
+    fn sp800_56a_validation() {
+        use crate::{PublicKey, PublicKeyValidation, ValidationError};
+
+        let public_key = PublicKey::from_sec1_bytes_full(UNCOMPRESSED_BASEPOINT).unwrap();
+        assert_eq!(public_key.as_affine(), &AffinePoint::GENERATOR);
+        assert_eq!(public_key.validate_full(), Ok(()));
+        assert_eq!(
+            PublicKey::from_sec1_bytes_partial(COMPRESSED_BASEPOINT),
+            Ok(public_key)
+        );
+
+        let mut not_on_curve = [0; 65];
+        not_on_curve.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+        not_on_curve[64] ^= 1;
+
+        let mut y_out_of_range = [0xff; 65];
+        y_out_of_range[..33].copy_from_slice(&UNCOMPRESSED_BASEPOINT[..33]);
+
+        let mut x_out_of_range = [0xff; 33];
+        x_out_of_range[0] = 0x02;
+
+        let invalid: [(&[u8], ValidationError); 5] = [
+            (&[0x04, 0x79], ValidationError::Encoding),
+            (&[0x00], ValidationError::Identity),
+            (&y_out_of_range, ValidationError::Range),
+            (&x_out_of_range, ValidationError::Range),
+            (&not_on_curve, ValidationError::NotOnCurve),
+        ];
+
+        for (bytes, err) in invalid {
+            assert_eq!(PublicKey::from_sec1_bytes_partial(bytes), Err(err));
+            assert_eq!(PublicKey::from_sec1_bytes_full(bytes), Err(err));
+        }
+    }
+
+    #[test]
+    fn batch_add_and_double() {
+        let g = AffinePoint::GENERATOR;
+        let g2 = ProjectivePoint::GENERATOR.double().to_affine();
+        let id = AffinePoint::IDENTITY;
+        let pairs = [
+            (g, g2),
+            (g2, g),
+            (g, g),
+            (g, -g),
+            (id, g),
+            (g2, id),
+            (id, id),
+        ];
+
+        let sums = AffinePoint::batch_add_array(&pairs);
+        for ((p, q), sum) in pairs.iter().zip(&sums) {
+            assert_eq!(*sum, (ProjectivePoint::from(*p) + q).to_affine());
+        }
+
+        let points = [g, g2, -g, id];
+        let doubles = AffinePoint::batch_double_array(&points);
+        for (p, double) in points.iter().zip(&doubles) {
+            assert_eq!(*double, ProjectivePoint::from(*p).double().to_affine());
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(AffinePoint::batch_add(&pairs), sums);
+            assert_eq!(AffinePoint::batch_double(&points), doubles);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_from_sec1() {
+        let mut not_on_curve = [0; 65];
+        not_on_curve.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+        not_on_curve[64] ^= 1;
+
+        let mut x_out_of_range = [0xff; 33];
+        x_out_of_range[0] = 0x02;
+
+        let encodings: [&[u8]; 6] = [
+            COMPRESSED_BASEPOINT,
+            &not_on_curve,
+            UNCOMPRESSED_BASEPOINT,
+            &x_out_of_range,
+            &[0x00],
+            &[0x02, 0x79],
+        ];
+
+        let points = AffinePoint::batch_from_sec1(&encodings);
+        assert_eq!(points.len(), encodings.len());
+        assert_eq!(points[0].as_ref().ok(), Some(&AffinePoint::GENERATOR));
+        assert!(points[1].is_err());
+        assert_eq!(points[2].as_ref().ok(), Some(&AffinePoint::GENERATOR));
+        assert!(points[3].is_err());
+        assert_eq!(points[4].as_ref().ok(), Some(&AffinePoint::IDENTITY));
+        assert!(points[5].is_err());
+    }
 }
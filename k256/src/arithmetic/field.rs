@@ -35,6 +35,8 @@ use elliptic_curve::{
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
 };
+use primeorder::{FieldExt, PrimeFieldExt};
+
 #[cfg(test)]
 use num_bigint::{BigUint, ToBigUint};
 
@@ -383,6 +385,13 @@ impl PrimeField for FieldElement {
     }
 }
 
+impl FieldExt for FieldElement {
+    fn invert_vartime(&self) -> CtOption<Self> {
+        Self::invert_vartime(self)
+    }
+}
+impl PrimeFieldExt for FieldElement {}
+
 impl Retrieve for FieldElement {
     type Output = U256;
 
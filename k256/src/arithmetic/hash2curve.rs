@@ -3,8 +3,9 @@ use elliptic_curve::array::Array;
 use elliptic_curve::bigint::{ArrayEncoding, U256};
 use elliptic_curve::consts::{U4, U16, U48};
 use elliptic_curve::ops::Reduce;
-use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use hash2curve::MapToCurve;
+use primeorder::elligator_squared::ElligatorSquared;
 use primeorder::osswu::{OsswuMap, OsswuMapParams, Sgn0};
 
 use crate::{AffinePoint, ProjectivePoint, Scalar, Secp256k1};
@@ -128,6 +129,42 @@ impl OsswuMap for FieldElement {
         y.conditional_assign(&-y, self.sgn0() ^ y.sgn0());
         (x, y)
     }
+
+    fn osswu_preimage(x: &Self, y: &Self, branch: u8) -> CtOption<Self> {
+        let is_x2 = Choice::from(branch & 1);
+        let is_neg = Choice::from((branch >> 1) & 1);
+        let two = FieldElement::from(2);
+
+        // x1 branch: Z * u^2 = (-1 ± sqrt((A*x - 3*B)/(A*x + B)))/2
+        // x2 branch: Z * u^2 = (k - 2 ± sqrt(k^2 - 4))/2 where k = 1 - A*x/B
+        let ax = Self::PARAMS.map_a * x;
+        let k = (Self::ONE + (ax * Self::PARAMS.map_b.invert().unwrap()).negate(1)).normalize();
+        let num1 = (ax + FieldElement::from(3 * 0x6eb).negate(1)).normalize();
+        let num2 = (k.square() + FieldElement::from(4).negate(1)).normalize();
+        let div1 = (ax + Self::PARAMS.map_b).normalize();
+
+        let num = Self::conditional_select(&num1, &num2, is_x2);
+        let div = Self::conditional_select(&div1, &Self::ONE, is_x2);
+        let (is_square, r) = Self::sqrt_ratio(&num, &div);
+        let r = r.normalize();
+        let r = Self::conditional_select(&r, &r.negate(1).normalize(), is_neg);
+
+        let c1 = Self::ONE.negate(1).normalize();
+        let c2 = (k + two.negate(1)).normalize();
+        let c = Self::conditional_select(&c1, &c2, is_x2);
+        let t = (c + r) * two.invert().unwrap();
+
+        let (is_u_square, u) = Self::sqrt_ratio(&t, &Self::PARAMS.z);
+        let u = u.normalize();
+        let u = Self::conditional_select(&u, &u.negate(1).normalize(), u.sgn0() ^ y.sgn0());
+
+        let (x2, y2) = u.osswu();
+        let is_preimage = is_square
+            & is_u_square
+            & x2.normalize().ct_eq(&x.normalize())
+            & y2.normalize().ct_eq(&y.normalize());
+        CtOption::new(u, is_preimage)
+    }
 }
 
 impl MapToCurve for Secp256k1 {
@@ -148,6 +185,23 @@ impl MapToCurve for Secp256k1 {
     }
 }
 
+impl ElligatorSquared for ProjectivePoint {
+    type FieldElement = FieldElement;
+    type UniformBytes = Array<u8, U48>;
+
+    fn map_to_curve(u: &FieldElement) -> Self {
+        Secp256k1::map_to_curve(*u)
+    }
+
+    fn map_preimage(&self, branch: u8) -> CtOption<FieldElement> {
+        // The preimage is found on the isogenous curve, so map the point back through the isogeny
+        let point = self.to_affine();
+        let (x, y) = isogeny_inverse(&point);
+        FieldElement::osswu_preimage(&x, &y, branch)
+            .and_then(|u| CtOption::new(u, !Choice::from(point.infinity)))
+    }
+}
+
 impl Reduce<Array<u8, U48>> for Scalar {
     fn reduce(value: &Array<u8, U48>) -> Self {
         const F_2_192: Scalar = Scalar(U256::from_be_hex(
@@ -266,6 +320,47 @@ fn isogeny(x: FieldElement, y: FieldElement) -> (FieldElement, FieldElement) {
     (x_num * x_den, y_num * y_den)
 }
 
+/// Inverse of [`isogeny`] for points on secp256k1, which is a bijection as both curves have
+/// prime order `n`.
+///
+/// The dual isogeny `φ'` is computed using Vélu's formulas with the kernel generated by a
+/// point with `x0^3 = -28`, chosen so that `isogeny(φ'(P)) = [3]P`. The inverse is then
+/// `φ'([3^-1 mod n]P)`.
+///
+/// The result is undefined for the identity.
+fn isogeny_inverse(point: &AffinePoint) -> (FieldElement, FieldElement) {
+    // 0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa9d1c9e899ca306ad27fe1945de0242b81
+    const THREE_INV: Scalar = Scalar(U256::from_be_hex(
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa9d1c9e899ca306ad27fe1945de0242b81",
+    ));
+    // 0x7cf24bd3b5eb4f5a53f61dce2f38b063567891e6053a6049f631b90fdc6cbb93
+    const X0: FieldElement = FieldElement::from_bytes_unchecked(&[
+        0x7c, 0xf2, 0x4b, 0xd3, 0xb5, 0xeb, 0x4f, 0x5a, 0x53, 0xf6, 0x1d, 0xce, 0x2f, 0x38, 0xb0,
+        0x63, 0x56, 0x78, 0x91, 0xe6, 0x05, 0x3a, 0x60, 0x49, 0xf6, 0x31, 0xb9, 0x0f, 0xdc, 0x6c,
+        0xbb, 0x93,
+    ]);
+    // 6 * x0^2 = 0x8ce4f610d3b8610713178887cfced5e90488d5b8d7503053bfef24d8fabf2312
+    const V: FieldElement = FieldElement::from_bytes_unchecked(&[
+        0x8c, 0xe4, 0xf6, 0x10, 0xd3, 0xb8, 0x61, 0x07, 0x13, 0x17, 0x88, 0x87, 0xcf, 0xce, 0xd5,
+        0xe9, 0x04, 0x88, 0xd5, 0xb8, 0xd7, 0x50, 0x30, 0x53, 0xbf, 0xef, 0x24, 0xd8, 0xfa, 0xbf,
+        0x23, 0x12,
+    ]);
+    // 4 * (x0^3 + 7) = -84
+    let w = FieldElement::from(84);
+
+    let p = (ProjectivePoint::from(*point) * THREE_INV).to_affine();
+
+    // x' = x + v/(x - x0) - 84/(x - x0)^2
+    // y' = y * (1 - v/(x - x0)^2 + 168/(x - x0)^3)
+    let d = (p.x + X0.negate(1)).invert().unwrap();
+    let d2 = d.square();
+    let d3 = d2 * d;
+    let x = p.x + V * d + (w * d2).negate(1);
+    let y = p.y * (FieldElement::ONE + (V * d2).negate(1) + w.double() * d3);
+
+    (x.normalize(), y.normalize())
+}
+
 fn compute_iso(xxs: &[FieldElement], k: &[FieldElement]) -> FieldElement {
     let mut xx = FieldElement::ZERO;
     for (xi, ki) in xxs.iter().zip(k.iter()) {
@@ -276,7 +371,10 @@ fn compute_iso(xxs: &[FieldElement], k: &[FieldElement]) -> FieldElement {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Scalar, Secp256k1, U256, arithmetic::field::FieldElement};
+    use super::{isogeny, isogeny_inverse};
+    use crate::{
+        AffinePoint, ProjectivePoint, Scalar, Secp256k1, U256, arithmetic::field::FieldElement,
+    };
     use elliptic_curve::{
         Curve,
         array::Array,
@@ -287,6 +385,7 @@ mod tests {
     };
     use hash2curve::MapToCurve;
     use hex_literal::hex;
+    use primeorder::osswu::OsswuMap;
     use proptest::{num::u64::ANY, prelude::ProptestConfig, proptest};
 
     #[test]
@@ -435,4 +534,46 @@ mod tests {
             assert_eq!(from_okm, simple_from_okm);
         });
     }
+
+    #[test]
+    fn osswu_preimage() {
+        proptest!(ProptestConfig::with_cases(100), |(b0 in ANY, b1 in ANY, b2 in ANY, b3 in ANY)| {
+            let mut data = Array::<u8, U48>::default();
+            data[16..24].copy_from_slice(&b0.to_be_bytes());
+            data[24..32].copy_from_slice(&b1.to_be_bytes());
+            data[32..40].copy_from_slice(&b2.to_be_bytes());
+            data[40..].copy_from_slice(&b3.to_be_bytes());
+
+            let u = FieldElement::reduce(&data);
+            let (x, y) = u.osswu();
+            let mut found = false;
+
+            for branch in 0..4 {
+                if let Some(v) = FieldElement::osswu_preimage(&x, &y, branch).into_option() {
+                    let (vx, vy) = v.osswu();
+                    assert_eq!(vx.normalize(), x.normalize());
+                    assert_eq!(vy.normalize(), y.normalize());
+                    found |= v.normalize() == u.normalize();
+                }
+            }
+
+            assert!(found);
+        });
+    }
+
+    #[test]
+    fn isogeny_inverse_roundtrip() {
+        let mut point = ProjectivePoint::GENERATOR;
+
+        for _ in 0..10 {
+            let affine = point.to_affine();
+            let (x, y) = isogeny_inverse(&affine);
+            let (x, y) = isogeny(x, y);
+            assert_eq!(AffinePoint::new(x.normalize(), y.normalize()), affine);
+            point += ProjectivePoint::GENERATOR.double();
+        }
+    }
+
+    #[cfg(feature = "getrandom")]
+    primeorder::test_elligator_squared!(ProjectivePoint, 32 + 16);
 }
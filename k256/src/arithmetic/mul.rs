@@ -34,17 +34,22 @@
 //! (Note that 'd' is also equal to the curve order here because `[a1,b1]` and `[a2,b2]` are found
 //! as outputs of the Extended Euclidean Algorithm on inputs 'order' and 'lambda').
 
+mod context;
 mod glv;
 
-use super::{ProjectivePoint, scalar::Scalar};
+pub use context::GeneratorContext;
+
+use super::{FieldElement, ProjectivePoint, scalar::Scalar};
 use core::array;
 use elliptic_curve::{
     array::sizes::{U5, U33},
+    ff::Field,
     ops::{LinearCombination, Mul, MulAssign, MulByGeneratorVartime, MulVartime},
+    rand_core::{CryptoRng, TryCryptoRng},
     scalar::IsHigh,
     subtle::ConditionallySelectable,
 };
-use primeorder::Radix16Decomposition;
+use primeorder::{FieldExt, Radix16Decomposition};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -57,11 +62,11 @@ type LookupTable = primeorder::LookupTable<ProjectivePoint>;
 /// w-NAF window size to use by default.
 type WnafWindowSize = U5;
 
-/// `WnafBase` specialized for `k256`.
-type WnafBase = wnaf::WnafBase<ProjectivePoint, WnafWindowSize>;
+/// `GlvWnafBase` specialized for `k256`.
+type GlvWnafBase = wnaf::GlvWnafBase<ProjectivePoint, WnafWindowSize>;
 
-/// `WnafScalar` specialized for `k256`.
-type WnafScalar = wnaf::WnafScalar<Scalar, WnafWindowSize>;
+/// `GlvWnafScalar` specialized for `k256`.
+type GlvWnafScalar = wnaf::GlvWnafScalar<ProjectivePoint, WnafWindowSize>;
 
 impl<const N: usize> LinearCombination<[(ProjectivePoint, Scalar); N]> for ProjectivePoint {
     fn lincomb(points_and_scalars: &[(ProjectivePoint, Scalar); N]) -> Self {
@@ -74,7 +79,7 @@ impl<const N: usize> LinearCombination<[(ProjectivePoint, Scalar); N]> for Proje
     fn lincomb_vartime(points_and_scalars: &[(ProjectivePoint, Scalar); N]) -> Self {
         let decomposed: [_; N] = array::from_fn(|i| {
             let (x, k) = &points_and_scalars[i];
-            glv::decompose_wnaf(x, k)
+            (GlvWnafBase::new(x), GlvWnafScalar::new(k))
         });
 
         lincomb_vartime_glv_wnaf(&decomposed)
@@ -101,7 +106,7 @@ impl LinearCombination<[(ProjectivePoint, Scalar)]> for ProjectivePoint {
     fn lincomb_vartime(points_and_scalars: &[(ProjectivePoint, Scalar)]) -> Self {
         let decomposed: Vec<_> = points_and_scalars
             .iter()
-            .map(|(x, k)| glv::decompose_wnaf(x, k))
+            .map(|(x, k)| (GlvWnafBase::new(x), GlvWnafScalar::new(k)))
             .collect();
 
         lincomb_vartime_glv_wnaf(&decomposed)
@@ -163,15 +168,9 @@ fn lincomb(
 }
 
 /// Linear combination / multiscalar multiplication using inputs decomposed for the GLV endomorphism
-/// (using `glv::decompose_wnaf`) in combination with w-NAF scalar multiplication.
-fn lincomb_vartime_glv_wnaf(
-    decomposed_xks: &[([WnafBase; 2], [WnafScalar; 2])],
-) -> ProjectivePoint {
-    let terms = decomposed_xks
-        .iter()
-        .flat_map(|(bases, scalars)| bases.iter().zip(scalars.iter()));
-
-    WnafBase::multiscalar_mul(terms)
+/// in combination with w-NAF scalar multiplication.
+fn lincomb_vartime_glv_wnaf(decomposed_xks: &[(GlvWnafBase, GlvWnafScalar)]) -> ProjectivePoint {
+    GlvWnafBase::multiscalar_mul(decomposed_xks.iter().map(|(x, k)| (x, k)))
 }
 
 impl ProjectivePoint {
@@ -180,20 +179,7 @@ impl ProjectivePoint {
     pub fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
         #[cfg(feature = "precomputed-tables")]
         {
-            let digits = Radix16Decomposition::<U65>::new(k);
-            let table = *BASEPOINT_TABLE;
-            let mut acc = table[32].select(digits[64]);
-            let mut acc2 = ProjectivePoint::IDENTITY;
-            for i in (0..32).rev() {
-                acc2 += &table[i].select(digits[i * 2 + 1]);
-                acc += &table[i].select(digits[i * 2]);
-            }
-            // This is the price of halving the precomputed table size (from 60kb to 30kb)
-            // The performance hit is minor, about 3%.
-            for _ in 0..4 {
-                acc2.double_in_place();
-            }
-            acc + acc2
+            mul_by_generator_table(k, None)
         }
 
         #[cfg(not(feature = "precomputed-tables"))]
@@ -202,6 +188,78 @@ impl ProjectivePoint {
         }
     }
 
+    /// Calculates `k * G`, where `G` is the generator, using randomness from `rng` to blind the
+    /// computation.
+    ///
+    /// See [`ProjectivePoint::mul_blinded`] for more information, and [`GeneratorContext`] for an
+    /// alternative which amortizes the cost of blinding over many multiplications.
+    #[must_use]
+    pub fn mul_by_generator_blinded<R: CryptoRng + ?Sized>(k: &Scalar, rng: &mut R) -> Self {
+        let Ok(point) = Self::try_mul_by_generator_blinded(k, rng);
+        point
+    }
+
+    /// Calculates `k * G`, where `G` is the generator, using randomness from `rng` to blind the
+    /// computation.
+    ///
+    /// See [`ProjectivePoint::mul_blinded`] for more information.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_mul_by_generator_blinded<R: TryCryptoRng + ?Sized>(
+        k: &Scalar,
+        rng: &mut R,
+    ) -> Result<Self, R::Error> {
+        #[cfg(feature = "precomputed-tables")]
+        {
+            let r = Scalar::try_random(rng)?;
+            let lambda = FieldElement::try_random_nonzero(rng)?;
+            Ok(mul_by_generator_table(&(*k - r), Some(&lambda))
+                + mul_by_generator_table(&r, Some(&lambda)))
+        }
+
+        #[cfg(not(feature = "precomputed-tables"))]
+        {
+            ProjectivePoint::GENERATOR.try_mul_blinded(k, rng)
+        }
+    }
+
+    /// Calculates `k * self`, using randomness from `rng` to blind the computation.
+    ///
+    /// This is a countermeasure against side-channel attacks such as differential power or EM
+    /// analysis. The scalar is split into the random shares `k - r` and `r`, and the projective
+    /// coordinates of the point are multiplied by a random nonzero field element, so neither the
+    /// selected table entries nor the intermediate coordinates can be predicted from `k`.
+    ///
+    /// This is about twice as slow as regular constant-time scalar multiplication.
+    ///
+    /// `ecdsa::SigningKey` computes `[k]G` with [`Group::mul_by_generator`], which is blinded
+    /// using the system RNG when the `blinding` crate feature is enabled.
+    ///
+    /// [`Group::mul_by_generator`]: elliptic_curve::group::Group::mul_by_generator
+    #[must_use]
+    pub fn mul_blinded<R: CryptoRng + ?Sized>(&self, k: &Scalar, rng: &mut R) -> Self {
+        let Ok(point) = self.try_mul_blinded(k, rng);
+        point
+    }
+
+    /// Calculates `k * self`, using randomness from `rng` to blind the computation.
+    ///
+    /// See [`ProjectivePoint::mul_blinded`] for more information.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_mul_blinded<R: TryCryptoRng + ?Sized>(
+        &self,
+        k: &Scalar,
+        rng: &mut R,
+    ) -> Result<Self, R::Error> {
+        let r = Scalar::try_random(rng)?;
+        let lambda = FieldElement::try_random_nonzero(rng)?;
+        let point = self.rescale(&lambda);
+        Ok(ProjectivePoint::lincomb(&[(point, *k - r), (point, r)]))
+    }
+
     /// Calculates `k * G` in variable-time, where `G` is the generator.
     #[must_use]
     pub fn mul_by_generator_vartime(k: &Scalar) -> ProjectivePoint {
@@ -232,6 +290,35 @@ impl ProjectivePoint {
     }
 }
 
+/// Calculates `k * G` using the precomputed basepoint table.
+///
+/// If `lambda` is provided, the projective coordinates of the accumulators are multiplied by it,
+/// which randomizes all intermediate coordinates when `lambda` is random. The entries of the
+/// basepoint table are public constants and are not randomized.
+#[cfg(feature = "precomputed-tables")]
+fn mul_by_generator_table(k: &Scalar, lambda: Option<&FieldElement>) -> ProjectivePoint {
+    let digits = Radix16Decomposition::<U65>::new(k);
+    let table = *BASEPOINT_TABLE;
+    let mut acc = table[32].select(digits[64]);
+    let mut acc2 = ProjectivePoint::IDENTITY;
+
+    if let Some(lambda) = lambda {
+        acc = acc.rescale(lambda);
+        acc2 = acc2.rescale(lambda);
+    }
+
+    for i in (0..32).rev() {
+        acc2 += &table[i].select(digits[i * 2 + 1]);
+        acc += &table[i].select(digits[i * 2]);
+    }
+    // This is the price of halving the precomputed table size (from 60kb to 30kb)
+    // The performance hit is minor, about 3%.
+    for _ in 0..4 {
+        acc2.double_in_place();
+    }
+    acc + acc2
+}
+
 #[inline]
 fn mul(x: &ProjectivePoint, k: &Scalar) -> ProjectivePoint {
     ProjectivePoint::lincomb(&[(*x, *k)])
@@ -240,10 +327,7 @@ fn mul(x: &ProjectivePoint, k: &Scalar) -> ProjectivePoint {
 /// Variable-time `k * self` using width-5 wNAF + GLV endomorphism.
 #[inline]
 fn mul_vartime(x: &ProjectivePoint, k: &Scalar) -> ProjectivePoint {
-    let mut bases = [WnafBase::default(), WnafBase::default()];
-    let mut scalars = [WnafScalar::default(), WnafScalar::default()];
-    glv::decompose_wnaf_into(x, k, &mut bases, &mut scalars);
-    WnafBase::multiscalar_mul([(&bases[0], &scalars[0]), (&bases[1], &scalars[1])].into_iter())
+    &GlvWnafBase::new(x) * &GlvWnafScalar::new(k)
 }
 
 impl Mul<Scalar> for ProjectivePoint {
@@ -302,8 +386,11 @@ impl MulByGeneratorVartime for ProjectivePoint {
 
     fn mul_by_generator_and_mul_add_vartime(a: &Self::Scalar, b: &Self::Scalar, p: &Self) -> Self {
         let decomposed = [
-            glv::decompose_wnaf(&ProjectivePoint::GENERATOR, a),
-            glv::decompose_wnaf(p, b),
+            (
+                GlvWnafBase::new(&ProjectivePoint::GENERATOR),
+                GlvWnafScalar::new(a),
+            ),
+            (GlvWnafBase::new(p), GlvWnafScalar::new(b)),
         ];
 
         lincomb_vartime_glv_wnaf(&decomposed)
@@ -328,7 +415,7 @@ mod tests {
     use crate::arithmetic::{ProjectivePoint, Scalar};
 
     #[cfg(feature = "getrandom")]
-    use elliptic_curve::Generate;
+    use elliptic_curve::{Generate, common::getrandom::SysRng};
 
     #[test]
     #[cfg(feature = "getrandom")]
@@ -352,6 +439,37 @@ mod tests {
         assert_eq!(reference, test);
     }
 
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn test_mul_blinded() {
+        let x = ProjectivePoint::generate();
+        let k = Scalar::generate();
+        let reference = x * k;
+        let test = x.try_mul_blinded(&k, &mut SysRng).unwrap();
+        assert_eq!(reference, test);
+    }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn test_mul_by_generator_blinded() {
+        let k = Scalar::generate();
+        let reference = ProjectivePoint::GENERATOR * k;
+        let test = ProjectivePoint::try_mul_by_generator_blinded(&k, &mut SysRng).unwrap();
+        assert_eq!(reference, test);
+    }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn test_generator_context() {
+        let k = Scalar::generate();
+        let reference = ProjectivePoint::GENERATOR * k;
+        let mut context = GeneratorContext::try_new(&mut SysRng).unwrap();
+        assert_eq!(reference, context.mul(&k));
+
+        context.try_randomize(&mut SysRng).unwrap();
+        assert_eq!(reference, context.mul(&k));
+    }
+
     #[test]
     fn test_mul_vartime() {
         let p = ProjectivePoint::GENERATOR;
@@ -364,6 +482,32 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn test_glv_wnaf() {
+        let x = ProjectivePoint::generate();
+        let k = Scalar::generate();
+        let reference = x * k;
+
+        let test = &GlvWnafBase::new(&x) * &GlvWnafScalar::new(&k);
+        assert_eq!(reference, test);
+
+        let test = &GlvWnafBase::new(&x) * &GlvWnafScalar::new(&-k);
+        assert_eq!(-reference, test);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "getrandom"))]
+    fn test_boxed_glv_wnaf() {
+        let x = ProjectivePoint::generate();
+        let scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::generate()];
+
+        let mut wnaf = wnaf::BoxedGlvWnaf::new(&x, scalars.len());
+        for k in scalars {
+            assert_eq!(x * k, wnaf.scalar(&k));
+        }
+    }
+
     #[cfg(all(feature = "alloc", feature = "getrandom"))]
     #[test]
     fn test_lincomb_slice() {
@@ -0,0 +1,92 @@
+//! Randomized context for multiplication by the generator.
+
+use super::{FieldElement, ProjectivePoint, Scalar};
+use core::fmt::{self, Debug};
+use elliptic_curve::{
+    ff::Field,
+    rand_core::{CryptoRng, TryCryptoRng},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
+use primeorder::FieldExt;
+
+/// Randomized context for multiplication by the generator, similar to the context randomization
+/// of `libsecp256k1`.
+///
+/// The context contains a secret random scalar `b` along with the point `B = -b * G`, and
+/// computes `k * G` as `(k + b) * G + B`. The scalar used to select entries from the basepoint
+/// table is therefore independent of `k`, and the coordinates of `B` are randomized as well.
+///
+/// Unlike [`ProjectivePoint::mul_by_generator_blinded`], which draws fresh randomness for every
+/// multiplication, the cost of blinding is only paid when the context is created or
+/// [re-randomized][`GeneratorContext::randomize`], which should happen periodically, e.g. before
+/// each signature.
+#[derive(Clone)]
+pub struct GeneratorContext {
+    /// Secret blinding scalar `b`.
+    blind: Scalar,
+
+    /// Precomputed `-b * G`.
+    offset: ProjectivePoint,
+}
+
+impl GeneratorContext {
+    /// Create a new randomized context.
+    #[must_use]
+    pub fn new<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let Ok(context) = Self::try_new(rng);
+        context
+    }
+
+    /// Create a new randomized context.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_new<R: TryCryptoRng + ?Sized>(rng: &mut R) -> Result<Self, R::Error> {
+        let mut context = Self {
+            blind: Scalar::ZERO,
+            offset: ProjectivePoint::IDENTITY,
+        };
+        context.try_randomize(rng)?;
+        Ok(context)
+    }
+
+    /// Replace the blinding values of this context with fresh random ones.
+    pub fn randomize<R: CryptoRng + ?Sized>(&mut self, rng: &mut R) {
+        let Ok(()) = self.try_randomize(rng);
+    }
+
+    /// Replace the blinding values of this context with fresh random ones.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_randomize<R: TryCryptoRng + ?Sized>(&mut self, rng: &mut R) -> Result<(), R::Error> {
+        let blind = Scalar::try_random(rng)?;
+        let lambda = FieldElement::try_random_nonzero(rng)?;
+
+        // The previous blinding values are used to blind the computation of the new offset
+        self.offset = self.mul(&-blind).rescale(&lambda);
+        self.blind = blind;
+        Ok(())
+    }
+
+    /// Calculates `k * G`, where `G` is the generator, blinded by this context.
+    #[must_use]
+    pub fn mul(&self, k: &Scalar) -> ProjectivePoint {
+        ProjectivePoint::mul_by_generator(&(*k + self.blind)) + self.offset
+    }
+}
+
+impl Debug for GeneratorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeneratorContext").finish_non_exhaustive()
+    }
+}
+
+impl Drop for GeneratorContext {
+    fn drop(&mut self) {
+        self.blind.zeroize();
+        self.offset.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for GeneratorContext {}
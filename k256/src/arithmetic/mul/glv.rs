@@ -1,10 +1,9 @@
 //! Support for using secp256k1's GLV (Gallant-Lambert-Vanstone) endomorphism for accelerating
 //! scalar multiplication by decomposing scalars into smaller sub-scalars.
 
-use super::{WnafBase, WnafScalar};
 use crate::{ProjectivePoint, Scalar, arithmetic::scalar::WideScalar};
 use elliptic_curve::scalar::IsHigh;
-use primeorder::PrimeFieldExt;
+use wnaf::Endomorphism;
 
 /// Negative endomorphism eigenvalue used for GLV scalar decomposition.
 const MINUS_LAMBDA: Scalar = Scalar::from_bytes_unchecked(&[
@@ -36,10 +35,6 @@ const G2: Scalar = Scalar::from_bytes_unchecked(&[
     0x22, 0x12, 0x08, 0xac, 0x9d, 0xf5, 0x06, 0xc6, 0x15, 0x71, 0xb4, 0xae, 0x8a, 0xc4, 0x7f, 0x71,
 ]);
 
-/// Number of little-endian bytes to feed into `WnafScalar::from_le_bytes` for a GLV half-scalar.
-/// GLV guarantees magnitude < 2^128 (16 bytes).
-const GLV_LE_BYTES: usize = 16;
-
 /*
  * Proof for decompose_scalar's bounds.
  *
@@ -155,35 +150,21 @@ pub(super) fn decompose_scalar(k: &Scalar) -> (Scalar, Scalar) {
     (r1, r2)
 }
 
-/// Perform GLV decomposition and return the resulting w-NAF bases and scalars.
-#[inline]
-pub(super) fn decompose_wnaf(x: &ProjectivePoint, k: &Scalar) -> ([WnafBase; 2], [WnafScalar; 2]) {
-    let mut bases = [WnafBase::default(), WnafBase::default()];
-    let mut scalars = [WnafScalar::default(), WnafScalar::default()];
-    decompose_wnaf_into(x, k, &mut bases, &mut scalars);
-    (bases, scalars)
-}
+impl Endomorphism for ProjectivePoint {
+    /// See the proof of `decompose_scalar`'s bounds above.
+    const SUB_SCALAR_BITS: u32 = 128;
 
-/// GLV-decompose `k` for `x`: two `(WnafBase, WnafScalar)` pairs representing `r1 * self_signed`
-/// and `r2 * endomorphism(self_signed)`, with signs folded into the points.
-#[inline]
-pub(super) fn decompose_wnaf_into(
-    x: &ProjectivePoint,
-    k: &Scalar,
-    bases: &mut [WnafBase; 2],
-    scalars: &mut [WnafScalar; 2],
-) {
-    let (r1, r2) = decompose_scalar(k);
-    let r1_neg = bool::from(r1.is_high());
-    let r2_neg = bool::from(r2.is_high());
-    let r1 = if r1_neg { -r1 } else { r1 };
-    let r2 = if r2_neg { -r2 } else { r2 };
-    scalars[0].init_from_le_bytes(&r1.to_le_repr()[..GLV_LE_BYTES]);
-    scalars[1].init_from_le_bytes(&r2.to_le_repr()[..GLV_LE_BYTES]);
+    #[inline]
+    fn endomorphism(&self) -> Self {
+        ProjectivePoint::endomorphism(self)
+    }
 
-    let p1 = if r1_neg { -*x } else { *x };
-    let p_beta = x.endomorphism();
-    let p2 = if r2_neg { -p_beta } else { p_beta };
-    bases[0].init_from_base(&p1);
-    bases[1].init_from_base(&p2);
+    #[inline]
+    fn decompose_scalar(k: &Scalar) -> [(Scalar, bool); 2] {
+        let (r1, r2) = decompose_scalar(k);
+        [r1, r2].map(|r| {
+            let negative = bool::from(r.is_high());
+            (if negative { -r } else { r }, negative)
+        })
+    }
 }
@@ -17,16 +17,20 @@ use elliptic_curve::{
         cofactor::CofactorGroup,
         prime::{PrimeCurve, PrimeGroup},
     },
-    ops::{BatchInvert, Double},
+    ops::Double,
     point::NonIdentity,
     rand_core::{TryCryptoRng, TryRng},
     sec1::{FromSec1Point, ToSec1Point},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
 };
+use primeorder::primefield;
+use wnaf::WnafGroup;
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "blinding")]
+use elliptic_curve::common::getrandom::SysRng;
 
 #[rustfmt::skip]
 const ENDOMORPHISM_BETA: FieldElement = FieldElement::from_bytes_unchecked(&[
@@ -246,6 +250,17 @@ impl ProjectivePoint {
         }
     }
 
+    /// Returns a representation of the same point with its projective coordinates multiplied by
+    /// `lambda`, which must be nonzero.
+    #[inline]
+    pub(super) fn rescale(&self, lambda: &FieldElement) -> Self {
+        Self {
+            x: self.x * lambda,
+            y: self.y * lambda,
+            z: self.z * lambda,
+        }
+    }
+
     /// Check whether `self` is equal to an affine point.
     ///
     /// This is a lot faster than first converting `self` to an `AffinePoint` and then doing the
@@ -374,12 +389,13 @@ fn batch_normalize(
     debug_assert_eq!(points.len(), scratch.len());
     debug_assert_eq!(points.len(), out.len());
 
+    // `primefield` batch inversion expects normalized elements to detect zeros
     for (z, point) in zs.iter_mut().zip(points) {
-        *z = point.z;
+        *z = point.z.normalize();
     }
 
     // Zero `zs` (identity) are handled explicitly below, so the `Choice` here is informational only
-    let _ = FieldElement::batch_invert_in_place(zs, scratch);
+    let _ = primefield::batch_invert(zs, scratch);
 
     for i in 0..out.len() {
         out[i] = AffinePoint::conditional_select(
@@ -400,12 +416,13 @@ fn batch_normalize_vartime(
     debug_assert_eq!(points.len(), scratch.len());
     debug_assert_eq!(points.len(), out.len());
 
+    // `primefield` batch inversion expects normalized elements to detect zeros
     for (z, point) in zs.iter_mut().zip(points) {
-        *z = point.z;
+        *z = point.z.normalize();
     }
 
     // Zero `zs` (identity) are handled explicitly below, so the `Choice` here is informational only
-    let _ = FieldElement::batch_invert_in_place_vartime(zs, scratch);
+    let _ = primefield::batch_invert_vartime(zs, scratch);
 
     for i in 0..out.len() {
         out[i] = if bool::from(points[i].z.normalizes_to_zero()) {
@@ -591,9 +608,23 @@ impl Group for ProjectivePoint {
         Self::double(self)
     }
 
+    /// Multiply the generator by `k`.
+    ///
+    /// When the `blinding` crate feature is enabled this is computed with
+    /// [`ProjectivePoint::try_mul_by_generator_blinded`] using the system RNG. If the system RNG
+    /// fails, the unblinded multiplication is used instead.
     #[inline]
     fn mul_by_generator(k: &Scalar) -> Self {
-        Self::mul_by_generator(k)
+        #[cfg(feature = "blinding")]
+        {
+            Self::try_mul_by_generator_blinded(k, &mut SysRng)
+                .unwrap_or_else(|_| Self::mul_by_generator(k))
+        }
+
+        #[cfg(not(feature = "blinding"))]
+        {
+            Self::mul_by_generator(k)
+        }
     }
 }
 
@@ -617,6 +648,12 @@ impl GroupEncoding for ProjectivePoint {
 impl PrimeCurve for ProjectivePoint {}
 impl PrimeGroup for ProjectivePoint {}
 
+impl WnafGroup for ProjectivePoint {
+    fn recommended_wnaf_for_num_scalars(_num_scalars: usize) -> usize {
+        5
+    }
+}
+
 //
 // `core::ops` trait impls
 //
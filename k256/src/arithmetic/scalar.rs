@@ -324,7 +324,11 @@ impl PrimeField for Scalar {
     }
 }
 
-impl FieldExt for Scalar {}
+impl FieldExt for Scalar {
+    fn invert_vartime(&self) -> CtOption<Self> {
+        Self::invert_vartime(self)
+    }
+}
 impl PrimeFieldExt for Scalar {}
 
 impl From<u32> for Scalar {
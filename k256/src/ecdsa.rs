@@ -106,6 +106,11 @@ impl EcdsaCurve for Secp256k1 {
 }
 
 /// ECDSA/secp256k1 signing key
+///
+/// When the `blinding` crate feature is enabled, the computation of `[k]G` is blinded with
+/// randomness from the system RNG, see [`ProjectivePoint::mul_blinded`].
+///
+/// [`ProjectivePoint::mul_blinded`]: crate::ProjectivePoint::mul_blinded
 #[cfg(feature = "ecdsa")]
 pub type SigningKey = ecdsa_core::SigningKey<Secp256k1>;
 
@@ -0,0 +1,123 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES) as described in [SEC 1 v2 § 5.1].
+//!
+//! See [`primeorder::ecies`] for the supported options. The hash function defaults to SHA-256.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "ecies", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "ecies", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `ecies` and `getrandom` crate features are enabled
+//! use k256::{
+//!     ecies::DecryptingKey,
+//!     elliptic_curve::{Generate, common::getrandom::SysRng},
+//! };
+//!
+//! // Recipient
+//! let decrypting_key: DecryptingKey = DecryptingKey::generate();
+//! let encrypting_key = decrypting_key.encrypting_key();
+//!
+//! // Sender
+//! let ciphertext = encrypting_key.encrypt(&mut SysRng, b"hello world")?;
+//!
+//! // Recipient
+//! let plaintext = decrypting_key.decrypt(&ciphertext)?;
+//! assert_eq!(plaintext, b"hello world");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [SEC 1 v2 § 5.1]: https://www.secg.org/sec1-v2.pdf#page=47
+
+pub use primeorder::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params, PointEncoding};
+
+use crate::Secp256k1;
+use sha2::Sha256;
+
+/// ECIES encrypting key for secp256k1, using the hash function `D`.
+pub type EncryptingKey<D = Sha256> = primeorder::ecies::EncryptingKey<Secp256k1, D>;
+
+/// ECIES decrypting key for secp256k1, using the hash function `D`.
+pub type DecryptingKey<D = Sha256> = primeorder::ecies::DecryptingKey<Secp256k1, D>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    primeorder::test_ecies!(
+        crate::Secp256k1,
+        Sha256,
+        secret_key: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+        public_key: hex!(
+            "042c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae64564b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085"
+        ),
+        ephemeral_key: hex!("2b5e1c7a0d4f3e8d9c6b5a4f3e2d1c0b0a99887766554433221100ffeeddccbb"),
+        test_vectors: [
+            // SEC 1 `ecies-with-x9-63-kdf`
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: false,
+                ciphertext: hex!(
+                    "0492fd1e1ebe5bfefb05f2393871246e5d13508880675a2ec6ee2fc5881d4ae519a749003595445f142029a87c2b1fb6f410bd5be6bcec740e95fa8541fa82e8bf96f950384ae3e5f86892629f1b49d9a1007de98d8609901b87f9be859baebb92a3653dee45313b7a5e2f6bf71fe375cc3ae9777bca49925894f48beb4834e19b8ae2570788958de68b6bbbf58c"
+                ),
+            },
+            // SEC 1 with shared information
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "0492fd1e1ebe5bfefb05f2393871246e5d13508880675a2ec6ee2fc5881d4ae519a749003595445f142029a87c2b1fb6f410bd5be6bcec740e95fa8541fa82e8bff93fccd447d7bcb5b6755666c2716f53d0eee6233e36de31fa6a133d198587e9b9e0b1400321b8977d406d68d811428db86fb49da0a3e1b04aa6cb78570ba9d573647f107c05ab86cf71faa3cf"
+                ),
+            },
+            // HKDF over `R̄ || Z`, compressed ephemeral key and truncated tag
+            {
+                params: Params {
+                    kdf: Kdf::Hkdf,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Compressed,
+                    mac_tag: MacTag::Truncated,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "0392fd1e1ebe5bfefb05f2393871246e5d13508880675a2ec6ee2fc5881d4ae519aaf70ff43508836224298e791e2b6cbe605ada2610b4e2f6d4300b8ee0e4b2b7333e4b8335a6ce08ccdd82a0f34da4a5b7f67542a2568e2be4d37e06f1"
+                ),
+            },
+            // Bouncy Castle `IESEngine` layout without a block cipher and with a 128-bit MAC key
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::MacKeyFirst,
+                    mac_key_len: Some(16),
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "0492fd1e1ebe5bfefb05f2393871246e5d13508880675a2ec6ee2fc5881d4ae519a749003595445f142029a87c2b1fb6f410bd5be6bcec740e95fa8541fa82e8bf340d6f2012326b9c0098662579dce69258a479c0350ada98f1c7081bc57125d8d9c9e426ca3ba06b8966a4a65b5351d23a5d5cf692a99d103a83c0584947e0f2acba2c0587737b2b0c3a2741dd"
+                ),
+            },
+        ],
+    );
+}
@@ -43,21 +43,31 @@ pub mod ecdh;
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
+#[cfg(feature = "ecies")]
+pub mod ecies;
+
 #[cfg(feature = "schnorr")]
 pub mod schnorr;
 
 #[cfg(any(feature = "test-vectors", test))]
 pub mod test_vectors;
 
+#[cfg(feature = "vrf")]
+pub mod vrf;
+
 #[cfg(feature = "hash2curve")]
 pub use hash2curve;
 
 pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "arithmetic")]
-pub use arithmetic::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
+pub use arithmetic::{
+    GeneratorContext, affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar,
+};
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
+#[cfg(feature = "arithmetic")]
+pub use primeorder::{PublicKeyValidation, ValidationError};
 #[cfg(feature = "sha2")]
 pub use sha2;
 
@@ -0,0 +1,215 @@
+//! Elliptic Curve Verifiable Random Function (ECVRF) over secp256k1.
+//!
+//! A VRF is the public-key version of a keyed hash: only the holder of the [`ProvingKey`] can
+//! compute the output `beta` for an input `alpha`, but anyone can check with the
+//! [`VerifyingKey`] and a [`Proof`] that `beta` is the correct output for `alpha`.
+//!
+//! [RFC 9381] doesn't define ciphersuites for secp256k1. Two ciphersuites are supported:
+//!
+//! - [`Tai`]: `SECP256K1_SHA256_TAI` with suite string `0xFE`, as deployed by the [`vrf`] crate
+//!   of Witnet. It uses the construction of [draft-irtf-cfrg-vrf-05] with SHA-256 and the
+//!   try-and-increment method, and is interoperable with it.
+//! - [`Sswu`]: `ECVRF-SECP256K1-SHA256-SSWU` with suite string `0xFF`, which uses the
+//!   construction of RFC 9381 with the same parameters as its P-256 ciphersuites, and the
+//!   [`GroupDigest`] implementation of secp256k1, i.e. the `encode_to_curve` function of
+//!   [RFC 9380].
+//!
+//! The scope of [`Sswu`] is limited: it isn't standardized, its suite string isn't registered
+//! anywhere, and no deployed implementation of it is known, so its proofs aren't interoperable
+//! with any other library and its test vectors were computed with an independent
+//! implementation of RFC 9381. Use [`Tai`] to interoperate with existing deployments.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "vrf", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "vrf", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `vrf` and `getrandom` crate features are enabled
+//! use k256::{
+//!     SecretKey,
+//!     elliptic_curve::Generate,
+//!     vrf::{Proof, ProvingKey, Tai, VerifyingKey},
+//! };
+//!
+//! // Prover
+//! let proving_key = ProvingKey::<Tai>::from(&SecretKey::generate());
+//! let proof = proving_key.prove(b"alpha")?;
+//! let beta = proof.proof_to_hash();
+//! let proof_bytes = proof.to_bytes();
+//!
+//! // Verifier
+//! let verifying_key = proving_key.verifying_key();
+//! let proof = Proof::<Tai>::from_bytes(&proof_bytes)?;
+//! assert_eq!(verifying_key.verify(b"alpha", &proof)?, beta);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+//! [RFC 9381]: https://www.rfc-editor.org/rfc/rfc9381
+//! [draft-irtf-cfrg-vrf-05]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-vrf-05
+//! [`vrf`]: https://github.com/witnet/vrf-rs
+
+use crate::{ProjectivePoint, Secp256k1};
+use elliptic_curve::{Error, Result, consts::U81};
+use hash2curve::GroupDigest;
+use primeorder::vrf::{Version, encode_to_curve_tai};
+use sha2::Sha256;
+
+pub use primeorder::vrf::{Proof, ProvingKey, Suite, VerifyingKey};
+
+/// `SECP256K1_SHA256_TAI` ciphersuite of [draft-irtf-cfrg-vrf-05], which encodes to the curve
+/// with the try-and-increment method.
+///
+/// [draft-irtf-cfrg-vrf-05]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-vrf-05#section-5.4.1.1
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tai;
+
+impl Suite for Tai {
+    type Curve = Secp256k1;
+    type Hash = Sha256;
+    type ProofSize = U81;
+
+    const SUITE_STRING: u8 = 0xFE;
+    const VERSION: Version = Version::Draft05;
+
+    /// Returns [`Error`] in the negligibly unlikely case that none of the 256 candidates is the
+    /// x-coordinate of a point on the curve.
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> Result<ProjectivePoint> {
+        encode_to_curve_tai::<Self>(salt, alpha)
+    }
+}
+
+/// `ECVRF-SECP256K1-SHA256-SSWU` ciphersuite, which encodes to the curve with the
+/// `secp256k1_XMD:SHA-256_SSWU_NU_` suite of [RFC 9380] as described in [RFC 9381 § 5.4.1.2].
+///
+/// <div class = "warning">
+/// <b>Interoperability</b>
+///
+/// This suite isn't standardized and no other implementation of it is known: see the
+/// [module documentation](self).
+/// </div>
+///
+/// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+/// [RFC 9381 § 5.4.1.2]: https://www.rfc-editor.org/rfc/rfc9381#section-5.4.1.2
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Sswu;
+
+impl Suite for Sswu {
+    type Curve = Secp256k1;
+    type Hash = Sha256;
+    type ProofSize = U81;
+
+    const SUITE_STRING: u8 = 0xFF;
+
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> Result<ProjectivePoint> {
+        // DST = "ECVRF_" || h2c_suite_ID_string || suite_string
+        Secp256k1::encode_from_bytes(
+            &[salt, alpha],
+            &[
+                b"ECVRF_",
+                Secp256k1::ENCODE_TO_CURVE_ID,
+                &[Self::SUITE_STRING],
+            ],
+        )
+        .map_err(|_| Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    const SK: [u8; 32] = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+    const PK: [u8; 33] = hex!("032c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645");
+
+    /// Test vectors of the `vrf` crate v0.2.4 (`src/openssl/mod.rs`), for `alpha = "sample"`.
+    const TAI_PROVE_PI: [u8; 81] = hex!(
+        "031f4dbca087a1972d04a07a779b7df1caa99e0f5db2aa21f3aecc4f9e10e85d08748c9fbe6b95d17359707bfb8e8ab0c93ba0c515333adcb8b64f372c535e115ccf66ebf5abe6fadb01b5efb37c0a0ec9"
+    );
+    const TAI_VERIFY_PI: [u8; 81] = hex!(
+        "031f4dbca087a1972d04a07a779b7df1caa99e0f5db2aa21f3aecc4f9e10e85d0814faa89697b482daa377fb6b4a8b0191a65d34a6d90a8a2461e5db9205d4cf0bb4b2c31b5ef6997a585a9f1a72517b6f"
+    );
+    const TAI_BETA: [u8; 32] =
+        hex!("612065e309e937ef46c2ef04d5886b9c6efd2991ac484ec64a9b014366fc5d81");
+
+    /// Known-answer tests of RFC 9381-style proofs, computed with an independent implementation
+    /// of the construction as no other implementation of this suite is known.
+    struct TestVector {
+        sk: [u8; 32],
+        pk: [u8; 33],
+        alpha: &'static [u8],
+        pi: [u8; 81],
+        beta: [u8; 32],
+    }
+
+    const SSWU_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            sk: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+            pk: hex!("032c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645"),
+            alpha: b"sample",
+            pi: hex!(
+                "0285ddb907ae972ee8c1b0dc4e590cf57e9e8bdfd0c487ef3ee4717c45dc1d828360dbb78b347d1a6999cce98d29d9a76c57b9982e8f0dd2e0886a8fac46335219a014f1be2663ea63a1e68a87c2b08a61"
+            ),
+            beta: hex!("dd32d8227593601723a4d652367fc975685ae944aa80ea303a024f16dbc6fe31"),
+        },
+        TestVector {
+            sk: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+            pk: hex!("032c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645"),
+            alpha: b"test",
+            pi: hex!(
+                "02b25b1b50df6e2cff0c5150ba7e0bd5a1c87bcde8a7c27cab56f7759caf02f42c2ecb68b80dc5bd49b224e7e804bd42036758be5b8dfaa8f423dc0a9330929bca83d99b8e9e2d4a890f64935ec1345c24"
+            ),
+            beta: hex!("7591f93598a7ac9e3a454357377d1623e16dd2e0c793a618bc8c8b4845bd9692"),
+        },
+        TestVector {
+            sk: hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+            pk: hex!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+            alpha: b"",
+            pi: hex!(
+                "02f4c7308cd807cb71a7c1e24c6a864379998d3a0b0421265905388e21e5615c43a4f25a046f6ba6d4ebe82aed770524f5a182fb6efc0f611d968b2d3e79e373621818d84efb004d77df284ba1d58ff484"
+            ),
+            beta: hex!("c7ff9d5ee2f6e12d4b000ce5623e2f49faa1b22cf6ef3df1be662eb550c8a677"),
+        },
+    ];
+
+    #[test]
+    fn tai_test_vectors() {
+        let proving_key = ProvingKey::<Tai>::from_bytes(&SK.into()).unwrap();
+        assert_eq!(proving_key.verifying_key().to_sec1_point().as_bytes(), PK);
+
+        let proof = proving_key.prove(b"sample").unwrap();
+        assert_eq!(proof.to_bytes().as_slice(), TAI_PROVE_PI);
+        assert_eq!(proof.proof_to_hash().as_slice(), TAI_BETA);
+
+        // the verification test of the `vrf` crate uses a proof with another nonce
+        let verifying_key = VerifyingKey::<Tai>::from_sec1_bytes(&PK).unwrap();
+        for pi in [TAI_PROVE_PI, TAI_VERIFY_PI] {
+            let proof = Proof::<Tai>::from_bytes(&pi).unwrap();
+            let beta = verifying_key.verify(b"sample", &proof).unwrap();
+            assert_eq!(beta.as_slice(), TAI_BETA);
+            assert!(verifying_key.verify(b"notsample", &proof).is_err());
+        }
+
+        assert!(Proof::<Tai>::from_bytes(&[0; 16]).is_err());
+    }
+
+    #[test]
+    fn sswu_test_vectors() {
+        for vector in SSWU_TEST_VECTORS {
+            let proving_key = ProvingKey::<Sswu>::from_bytes(&vector.sk.into()).unwrap();
+            let verifying_key = proving_key.verifying_key();
+            assert_eq!(verifying_key.to_sec1_point().as_bytes(), vector.pk);
+
+            let proof = proving_key.prove(vector.alpha).unwrap();
+            assert_eq!(proof.to_bytes().as_slice(), vector.pi);
+            assert_eq!(proof.proof_to_hash().as_slice(), vector.beta);
+
+            let verifying_key = VerifyingKey::<Sswu>::from_sec1_bytes(&vector.pk).unwrap();
+            let proof = Proof::<Sswu>::from_bytes(&vector.pi).unwrap();
+            let beta = verifying_key.verify(vector.alpha, &proof).unwrap();
+            assert_eq!(beta.as_slice(), vector.beta);
+        }
+    }
+}
@@ -27,7 +27,9 @@ pub mod test_vectors;
 pub use elliptic_curve;
 
 #[cfg(feature = "arithmetic")]
-pub use arithmetic::{AffinePoint, ProjectivePoint, scalar::Scalar};
+pub use arithmetic::{AffinePoint, NonZeroScalar, ProjectivePoint, PublicKey, scalar::Scalar};
+#[cfg(feature = "arithmetic")]
+pub use primeorder::{PublicKeyValidation, ValidationError};
 
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
@@ -79,9 +81,5 @@ pub type Sec1Point = elliptic_curve::sec1::Sec1Point<NistP192>;
 /// scalar).
 pub type FieldBytes = elliptic_curve::FieldBytes<NistP192>;
 
-/// Non-zero NIST P-192 scalar field element.
-#[cfg(feature = "arithmetic")]
-pub type NonZeroScalar = elliptic_curve::NonZeroScalar<NistP192>;
-
 #[cfg(not(feature = "arithmetic"))]
 impl elliptic_curve::sec1::ValidatePublicKey for NistP192 {}
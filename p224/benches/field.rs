@@ -0,0 +1,95 @@
+//! secp224r1 field element benchmarks
+
+#![allow(missing_docs, clippy::unwrap_used, reason = "benchmark")]
+
+use criterion::{
+    BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::Measurement,
+};
+use elliptic_curve::{Curve, Field, array::Array, bigint::cpubits, hazmat::FieldArithmetic};
+use hex_literal::hex;
+use p224::NistP224;
+
+type FieldElement = <NistP224 as FieldArithmetic>::FieldElement;
+type Uint = <NistP224 as Curve>::Uint;
+
+const MODULUS_HEX: &str = {
+    cpubits! {
+        32 => { "ffffffffffffffffffffffffffffffff000000000000000000000001" }
+        64 => { "00000000ffffffffffffffffffffffffffffffff000000000000000000000001" }
+    }
+};
+
+// Same modulus as `FieldElement`, but using the generic constant-time Tonelli-Shanks square root
+// instead of the precomputed table, for comparison.
+primefield::monty_field_params! {
+    name: GenericSqrtParams,
+    modulus: MODULUS_HEX,
+    uint: Uint,
+    byte_order: primefield::ByteOrder::BigEndian,
+    multiplicative_generator: 22,
+    doc: "P-224 field modulus without a square root table"
+}
+
+type GenericSqrtFieldElement = primefield::MontyFieldElement<GenericSqrtParams, { Uint::LIMBS }>;
+
+const X: [u8; 28] = hex!("b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21");
+const Y: [u8; 28] = hex!("bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34");
+
+fn test_field_element_x() -> FieldElement {
+    FieldElement::from_bytes(&Array(X)).unwrap()
+}
+
+fn test_field_element_y() -> FieldElement {
+    FieldElement::from_bytes(&Array(Y)).unwrap()
+}
+
+fn bench_field_element_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_field_element_x();
+    let y = test_field_element_y();
+    group.bench_function("mul", |b| b.iter(|| x * y));
+}
+
+fn bench_field_element_square<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_field_element_x();
+    group.bench_function("square", |b| b.iter(|| x.square()));
+}
+
+fn bench_field_element_sqrt<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_field_element_x();
+    group.bench_function("sqrt", |b| b.iter(|| x.sqrt()));
+
+    let x = GenericSqrtFieldElement::from_bytes(&Array(X)).unwrap();
+    group.bench_function("sqrt (generic)", |b| b.iter(|| x.sqrt()));
+}
+
+fn bench_field_element_sqrt_ratio<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_field_element_x();
+    let y = test_field_element_y();
+    group.bench_function("sqrt_ratio", |b| {
+        b.iter(|| FieldElement::sqrt_ratio(&x, &y))
+    });
+
+    let x = GenericSqrtFieldElement::from_bytes(&Array(X)).unwrap();
+    let y = GenericSqrtFieldElement::from_bytes(&Array(Y)).unwrap();
+    group.bench_function("sqrt_ratio (generic)", |b| {
+        b.iter(|| GenericSqrtFieldElement::sqrt_ratio(&x, &y))
+    });
+}
+
+fn bench_field_element_invert<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_field_element_x();
+    group.bench_function("invert", |b| b.iter(|| x.invert()));
+}
+
+fn bench_field_element(c: &mut Criterion) {
+    let mut group = c.benchmark_group("field element operations");
+    bench_field_element_mul(&mut group);
+    bench_field_element_square(&mut group);
+    bench_field_element_invert(&mut group);
+    bench_field_element_sqrt(&mut group);
+    bench_field_element_sqrt_ratio(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_field_element);
+criterion_main!(benches);
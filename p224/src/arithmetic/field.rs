@@ -64,7 +64,8 @@ primefield::monty_field_params! {
     uint: Uint,
     byte_order: primefield::ByteOrder::BigEndian,
     multiplicative_generator: 22,
-    doc: "Montgomery parameters for the NIST P-224 field modulus: `p = 2^{224} − 2^{96} + 1`."
+    doc: "Montgomery parameters for the NIST P-224 field modulus: `p = 2^{224} − 2^{96} + 1`.",
+    sqrt: table
 }
 
 primefield::monty_field_element! {
@@ -10,12 +10,11 @@
 //! Apache License (Version 2.0), and the BSD 1-Clause License;
 //! users may pick which license to apply.
 
-use crate::{FieldBytes, NistP224, ORDER_HEX, Uint};
+use crate::{NistP224, ORDER_HEX, Uint};
 use elliptic_curve::{
     Curve as _,
     bigint::{Limb, cpubits},
     ff::PrimeField,
-    field,
     ops::Reduce,
     scalar::{FromUintUnchecked, IsHigh},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
@@ -138,13 +137,6 @@ impl Reduce<Uint> for Scalar {
     }
 }
 
-impl Reduce<FieldBytes> for Scalar {
-    #[inline]
-    fn reduce(bytes: &FieldBytes) -> Self {
-        Self::reduce(&field::bytes_to_uint::<NistP224>(bytes))
-    }
-}
-
 #[cfg(feature = "serde")]
 impl Serialize for Scalar {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -31,6 +31,8 @@ pub use elliptic_curve;
 
 #[cfg(feature = "arithmetic")]
 pub use arithmetic::{AffinePoint, ProjectivePoint, scalar::Scalar};
+#[cfg(feature = "arithmetic")]
+pub use primeorder::{PublicKeyValidation, ValidationError};
 
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
@@ -0,0 +1,77 @@
+//! secp256r1 ECDH benchmarks
+
+#![allow(missing_docs, clippy::unwrap_used, reason = "benchmark")]
+
+use core::hint::black_box;
+use criterion::{
+    BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::Measurement,
+};
+use hex_literal::hex;
+use p256::{
+    NonZeroScalar, PublicKey, Scalar,
+    ecdh::{diffie_hellman, diffie_hellman_x_only},
+    elliptic_curve::{
+        PrimeField, ecdh::diffie_hellman as diffie_hellman_projective, sec1::ToSec1Point,
+    },
+};
+
+fn test_secret_key() -> NonZeroScalar {
+    NonZeroScalar::new(
+        Scalar::from_repr(
+            hex!("519b423d715f8b581f4fa8ee59f4771a5b44c8130b4e3eacca54a56dda72b464").into(),
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn test_public_key() -> PublicKey {
+    let secret = NonZeroScalar::new(
+        Scalar::from_repr(
+            hex!("0f56db78ca460b055c500064824bed999a25aaf48ebb519ac201537b85479813").into(),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    PublicKey::from_secret_scalar(&secret)
+}
+
+fn bench_diffie_hellman<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let secret = test_secret_key();
+    let public = test_public_key();
+
+    group.bench_function("diffie_hellman (projective)", |b| {
+        b.iter(|| diffie_hellman_projective(black_box(&secret), public.as_affine()));
+    });
+
+    group.bench_function("diffie_hellman (x-only)", |b| {
+        b.iter(|| diffie_hellman(black_box(&secret), public.as_affine()));
+    });
+}
+
+fn bench_diffie_hellman_compressed<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let secret = test_secret_key();
+    let compressed = test_public_key().as_affine().to_sec1_point(true);
+
+    group.bench_function("diffie_hellman compressed (projective)", |b| {
+        b.iter(|| {
+            let public = PublicKey::from_sec1_bytes(compressed.as_bytes()).unwrap();
+            diffie_hellman_projective(black_box(&secret), public.as_affine())
+        });
+    });
+
+    group.bench_function("diffie_hellman compressed (x-only)", |b| {
+        b.iter(|| diffie_hellman_x_only(black_box(&secret), compressed.as_bytes()));
+    });
+}
+
+fn bench_ecdh(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ECDH");
+    bench_diffie_hellman(&mut group);
+    bench_diffie_hellman_compressed(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_ecdh);
+criterion_main!(benches);
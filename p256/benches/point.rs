@@ -11,6 +11,7 @@ use p256::{
     AffinePoint, ProjectivePoint, Scalar,
     elliptic_curve::{
         BatchNormalize, Group, PrimeField,
+        consts::U5,
         ops::{LinearCombination, MulByGeneratorVartime, MulVartime},
         subtle::ConstantTimeEq,
     },
@@ -59,6 +60,62 @@ fn bench_point_mul<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
     });
 }
 
+fn bench_point_vartime_formulas<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    type WnafBase = primeorder::wnaf::WnafBase<ProjectivePoint, U5>;
+    type WnafScalar = primeorder::wnaf::WnafScalar<Scalar, U5>;
+
+    let p = ProjectivePoint::GENERATOR.double();
+    let x = test_scalar_x();
+    let y = test_scalar_y();
+
+    group.bench_function("mul_vartime (Jacobian)", |b| {
+        b.iter(|| black_box(p).mul_vartime(&black_box(x)));
+    });
+    group.bench_function("mul_vartime (complete projective)", |b| {
+        b.iter(|| WnafBase::new(&black_box(p)) * WnafScalar::new(&black_box(x)));
+    });
+    group.bench_function("lincomb_vartime (2-term, Jacobian)", |b| {
+        b.iter(|| ProjectivePoint::lincomb_vartime(&black_box([(p, x), (p.double(), y)])));
+    });
+    group.bench_function("lincomb_vartime (2-term, complete projective)", |b| {
+        b.iter(|| {
+            let [(p1, s1), (p2, s2)] = black_box([(p, x), (p.double(), y)]);
+            let bases = [WnafBase::new(&p1), WnafBase::new(&p2)];
+            let scalars = [WnafScalar::new(&s1), WnafScalar::new(&s2)];
+            WnafBase::multiscalar_mul(bases.iter().zip(scalars.iter()))
+        });
+    });
+}
+
+fn bench_point_jsf<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    type WnafBase = primeorder::wnaf::WnafBase<ProjectivePoint, U5>;
+    type WnafScalar = primeorder::wnaf::WnafScalar<Scalar, U5>;
+    type JsfBase = primeorder::wnaf::JsfBase<ProjectivePoint>;
+    type JsfScalar = primeorder::wnaf::JsfScalar<Scalar>;
+
+    let p = ProjectivePoint::GENERATOR.double();
+    let x = test_scalar_x();
+    let y = test_scalar_y();
+
+    group.bench_function("verify lincomb (wNAF)", |b| {
+        b.iter(|| {
+            let (q, u1, u2) = black_box((p, x, y));
+            let bases = [
+                WnafBase::new(&ProjectivePoint::GENERATOR),
+                WnafBase::new(&q),
+            ];
+            let scalars = [WnafScalar::new(&u1), WnafScalar::new(&u2)];
+            WnafBase::multiscalar_mul(bases.iter().zip(scalars.iter()))
+        });
+    });
+    group.bench_function("verify lincomb (JSF)", |b| {
+        b.iter(|| {
+            let (q, u1, u2) = black_box((p, x, y));
+            &JsfBase::new(&ProjectivePoint::GENERATOR, &q) * &JsfScalar::new(&u1, &u2)
+        });
+    });
+}
+
 fn bench_point_mul_by_generator<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
     let x = test_scalar_x();
     let y = test_scalar_y();
@@ -101,6 +158,8 @@ fn bench_point(c: &mut Criterion) {
     bench_point_lincomb(&mut group);
     bench_point_mul(&mut group);
     bench_point_mul_by_generator(&mut group);
+    bench_point_vartime_formulas(&mut group);
+    bench_point_jsf(&mut group);
     bench_point_normalize(&mut group);
 
     group.bench_function("ct_eq", |b| {
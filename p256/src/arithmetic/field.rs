@@ -174,6 +174,7 @@ mod tests {
     }
 
     primefield::test_primefield!(FieldElement, U256);
+    primefield::test_field_ext!(FieldElement);
 
     /// Ensures the legacy `R2` constant is computed the same way as the `crypto-bigint`
     /// implementation.
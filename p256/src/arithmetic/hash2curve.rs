@@ -1,5 +1,5 @@
 use super::FieldElement;
-use crate::{AffinePoint, FieldBytes, NistP256, ProjectivePoint, Scalar};
+use crate::{AffinePoint, NistP256, ProjectivePoint, Scalar};
 use elliptic_curve::{
     array::Array,
     bigint::{ArrayEncoding, U256},
@@ -18,24 +18,6 @@ impl hash2curve::GroupDigest for NistP256 {
     type ExpandMsg = hash2curve::ExpandMsgXmd<sha2::Sha256>;
 }
 
-impl Reduce<Array<u8, U48>> for FieldElement {
-    fn reduce(value: &Array<u8, U48>) -> Self {
-        const F_2_192: FieldElement = FieldElement::from_montgomery(U256::from_be_hex(
-            "00000000000000030000000200000000fffffffffffffffefffffffeffffffff",
-        ));
-
-        let mut d0_bytes = FieldBytes::default();
-        d0_bytes[8..].copy_from_slice(&value[..24]);
-        let d0 = FieldElement::from_uint_unchecked(U256::from_be_byte_array(d0_bytes));
-
-        let mut d1_bytes = FieldBytes::default();
-        d1_bytes[8..].copy_from_slice(&value[24..]);
-        let d1 = FieldElement::from_uint_unchecked(U256::from_be_byte_array(d1_bytes));
-
-        d0 * F_2_192 + d1
-    }
-}
-
 impl Sgn0 for FieldElement {
     fn sgn0(&self) -> Choice {
         self.is_odd()
@@ -92,7 +74,7 @@ impl Reduce<Array<u8, U48>> for Scalar {
 #[cfg(test)]
 mod tests {
     use super::FieldElement;
-    use crate::{NistP256, Scalar, U256, arithmetic::field::FieldParams};
+    use crate::{AffinePoint, NistP256, Scalar, U256, arithmetic::field::FieldParams};
     use elliptic_curve::{
         Curve, Field,
         array::Array,
@@ -103,7 +85,7 @@ mod tests {
     use hash2curve::{self, ExpandMsgXmd, MapToCurve};
     use hex_literal::hex;
     use primefield::bigint::Reduce;
-    use primeorder::osswu::OsswuMap;
+    use primeorder::osswu::{AffineOsswuMap, OsswuMap};
     use proptest::{num::u64::ANY, prelude::ProptestConfig, proptest};
     use sha2::Sha256;
 
@@ -341,4 +323,31 @@ mod tests {
             assert_eq!(from_okm, simple_from_okm);
         });
     }
+
+    #[test]
+    fn osswu_preimage() {
+        proptest!(ProptestConfig::with_cases(100), |(b0 in ANY, b1 in ANY, b2 in ANY, b3 in ANY)| {
+            let mut data = Array::<u8, U48>::default();
+            data[16..24].copy_from_slice(&b0.to_be_bytes());
+            data[24..32].copy_from_slice(&b1.to_be_bytes());
+            data[32..40].copy_from_slice(&b2.to_be_bytes());
+            data[40..].copy_from_slice(&b3.to_be_bytes());
+
+            let u = FieldElement::reduce(&data);
+            let point = AffinePoint::osswu(&u);
+            let mut found = false;
+
+            for branch in 0..4 {
+                if let Some(v) = point.osswu_preimage(branch).into_option() {
+                    assert_eq!(AffinePoint::osswu(&v), point);
+                    found |= v == u;
+                }
+            }
+
+            assert!(found);
+        });
+    }
+
+    #[cfg(feature = "getrandom")]
+    primeorder::test_elligator_squared!(crate::ProjectivePoint, 32 + 16);
 }
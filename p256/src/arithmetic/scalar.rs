@@ -320,7 +320,11 @@ impl PrimeField for Scalar {
     }
 }
 
-impl FieldExt for Scalar {}
+impl FieldExt for Scalar {
+    fn invert_vartime(&self) -> CtOption<Self> {
+        Self::invert_vartime(self)
+    }
+}
 impl PrimeFieldExt for Scalar {}
 
 wnaf::impl_wnaf_size_for_scalar!(Scalar);
@@ -24,6 +24,7 @@ impl PrimeCurveWithBasepointTable<WINDOW_SIZE> for NistP256 {
 pub(crate) mod backend {
     use super::BASEPOINT_TABLE;
     use crate::{NistP256, ProjectivePoint, Scalar};
+    use elliptic_curve::rand_core::TryCryptoRng;
     use primeorder::MulBackend;
 
     /// Backend based on precomputed tables.
@@ -40,6 +41,14 @@ pub(crate) mod backend {
         fn mul_by_generator_vartime(k: &Scalar) -> ProjectivePoint {
             BASEPOINT_TABLE.mul_vartime(k)
         }
+
+        #[inline]
+        fn try_mul_by_generator_blinded<R: TryCryptoRng + ?Sized>(
+            k: &Scalar,
+            rng: &mut R,
+        ) -> Result<ProjectivePoint, R::Error> {
+            BASEPOINT_TABLE.try_mul_blinded(k, rng)
+        }
     }
 }
 
@@ -0,0 +1,206 @@
+//! CPace password-authenticated key exchange as described in [draft-irtf-cfrg-cpace].
+//!
+//! CPace is a balanced PAKE: both parties share a password-related string (PRS), from which they
+//! derive a secret generator by hashing it to the curve together with the session ID, and then
+//! run a Diffie-Hellman key exchange using that generator.
+//!
+//! This module implements the `CPACE-P256_XMD:SHA-256_SSWU_NU_-SHA256` ciphersuite in both the
+//! initiator-responder and the symmetric setting, on top of the generic protocol in
+//! [`hash2curve::Cpace`].
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "cpace", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "cpace", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `cpace` and `getrandom` crate features are enabled
+//! use p256::{
+//!     cpace::{Cpace, Role},
+//!     elliptic_curve::common::getrandom::SysRng,
+//! };
+//!
+//! // The session ID must be unique, e.g. chosen at random by one party and sent to the other
+//! let sid = b"unique session ID";
+//!
+//! let alice = Cpace::try_new(Role::Initiator, b"password", b"alice bob", sid, b"", &mut SysRng)?;
+//! let bob = Cpace::try_new(Role::Responder, b"password", b"alice bob", sid, b"", &mut SysRng)?;
+//!
+//! // Alice and Bob exchange their messages and compute the intermediate session key
+//! let alice_isk = alice.finish(bob.message().as_bytes(), b"")?;
+//! let bob_isk = bob.finish(alice.message().as_bytes(), b"")?;
+//! assert_eq!(alice_isk.as_bytes(), bob_isk.as_bytes());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [draft-irtf-cfrg-cpace]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-cpace/
+
+use crate::{AffinePoint, FieldBytes, NistP256, ProjectivePoint, Sec1Point};
+use elliptic_curve::{
+    array::Array,
+    consts::U32,
+    point::AffineCoordinates,
+    sec1::{FromSec1Point, ToSec1Point},
+};
+use hash2curve::{CpaceParameters, GroupDigest};
+use sha2::{Digest, Sha256};
+
+pub use hash2curve::CpaceRole as Role;
+
+/// State of a party in the CPace protocol, after it has computed its message.
+pub type Cpace<'a> = hash2curve::Cpace<'a, NistP256>;
+
+/// Intermediate session key (ISK) resulting from a run of the CPace protocol.
+pub type IntermediateSessionKey = hash2curve::IntermediateSessionKey<NistP256>;
+
+impl CpaceParameters for NistP256 {
+    const DSI: &'static [u8] = b"CPaceP256_XMD:SHA-256_SSWU_NU_";
+    const S_IN_BYTES: usize = 64;
+
+    type Hash = Sha256;
+    type IskSize = U32;
+    type Message = Sec1Point;
+    type SharedSecret = FieldBytes;
+
+    fn calculate_generator(generator_string: &[&[u8]]) -> ProjectivePoint {
+        Self::encode_from_bytes(generator_string, &[Self::DSI, b"_DST"])
+            .expect("CPace DST is valid")
+    }
+
+    fn encode(point: &ProjectivePoint) -> Sec1Point {
+        point.to_affine().to_sec1_point(false)
+    }
+
+    fn decode(message: &[u8]) -> Option<ProjectivePoint> {
+        let point = Sec1Point::from_bytes(message).ok()?;
+        AffinePoint::from_sec1_point(&point)
+            .into_option()
+            .map(ProjectivePoint::from)
+    }
+
+    fn shared_secret(point: &ProjectivePoint) -> FieldBytes {
+        // K is the x-coordinate of y * Y
+        point.to_affine().x()
+    }
+
+    fn finalize_isk(hash: Sha256) -> Array<u8, U32> {
+        hash.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NonZeroScalar, Scalar};
+    use elliptic_curve::ff::PrimeField;
+    use hex_literal::hex;
+
+    const PRS: &[u8] = b"Password";
+    const CI: &[u8] = b"\x0aAinitiator\x0aBresponder";
+    const SID: &[u8] = b"\x7e\x4b\x47\x91\xd6\xa8\xef\x01\x9b\x93\x6c\x79\xfb\x7f\x2c\x57";
+
+    fn secret(value: u64) -> NonZeroScalar {
+        NonZeroScalar::new(Scalar::from(value)).unwrap()
+    }
+
+    fn secret_from_bytes(bytes: [u8; 32]) -> NonZeroScalar {
+        NonZeroScalar::new(Scalar::from_repr(bytes.into()).unwrap()).unwrap()
+    }
+
+    // Known answers for the inputs of the draft's test vectors, with our own `ya` and `yb`.
+    //
+    // These were computed with an independent Python implementation of the draft, which
+    // reproduces the `P256_XMD:SHA-256_SSWU_NU_` test vectors of RFC 9380. The values of the
+    // draft's appendix itself weren't available to check against.
+    const GENERATOR_STRING: [u8; 104] = hex!(
+        "1e4350616365503235365f584d443a5348412d3235365f535357555f4e555f0850617373776f726417
+         0000000000000000000000000000000000000000000000160a41696e69746961746f720a4272657370
+         6f6e646572107e4b4791d6a8ef019b936c79fb7f2c57"
+    );
+    const G: [u8; 65] = hex!(
+        "048dda57081bd0b0eca3935a254af4c60425d50c9a62632d9933d1fb5b6ec19879
+         525029fb6ea534f720c499e95164f7e2454cbd5801bdf8e7c306a1057557c371"
+    );
+    const YA: [u8; 32] = hex!("576e66e98766ab4920344403815d322f31da2ba510305609755044b11d61d210");
+    const YA_MESSAGE: [u8; 65] = hex!(
+        "045e2744cd7bbc1778710b80ccbb7aee9c00b69395d82cdda92cf13f2c39f1f08c
+         fbe51aea33f856c79bc60d07b2f4db81afbb33f429fd0699a5f0eb2bfe0e9fcd"
+    );
+    const YB: [u8; 32] = hex!("fb0109e9de69f3a9e3672b82762cabfd7d1580ad0e675d434ed1eee5eff81d66");
+    const YB_MESSAGE: [u8; 65] = hex!(
+        "04f6e3e1178d2f56e4892862351a3e561bad63b5e452475457dd0693f398b64580
+         b12da5e28b436b0c3e693394f7284783da53d870ef777a227ef7d614bc1dd2d6"
+    );
+    const K: [u8; 32] = hex!("e869dc8df43151212085a996834a8d03b92f788a3ebebda243beada42c8abdab");
+    const ISK_IR: [u8; 32] =
+        hex!("baa888a246d4382070fed634b0e7908c760a3c97ebd6707b0735b64a3ee6e04f");
+    const ISK_OC: [u8; 32] =
+        hex!("07348bac18ddbc31aff3113386a25fa2211b668805e98c14748194c91d6b733c");
+
+    #[test]
+    fn known_answer_generator() {
+        let g = Cpace::calculate_generator(PRS, CI, SID);
+        assert_eq!(g, NistP256::calculate_generator(&[&GENERATOR_STRING[..]]));
+        assert_eq!(NistP256::encode(&g).as_bytes(), G);
+    }
+
+    #[test]
+    fn known_answer() {
+        let ya = secret_from_bytes(YA);
+        let yb = secret_from_bytes(YB);
+
+        let k = NistP256::decode(&YB_MESSAGE).unwrap() * *ya;
+        assert_eq!(NistP256::shared_secret(&k).as_slice(), K);
+
+        for (roles, isk) in [
+            ((Role::Initiator, Role::Responder), ISK_IR),
+            ((Role::Symmetric, Role::Symmetric), ISK_OC),
+        ] {
+            let a = Cpace::new_with_secret(roles.0, PRS, CI, SID, b"ADa", ya);
+            let b = Cpace::new_with_secret(roles.1, PRS, CI, SID, b"ADb", yb);
+            assert_eq!(a.message().as_bytes(), YA_MESSAGE);
+            assert_eq!(b.message().as_bytes(), YB_MESSAGE);
+
+            let a_isk = a.finish(&YB_MESSAGE, b"ADb").unwrap();
+            let b_isk = b.finish(&YA_MESSAGE, b"ADa").unwrap();
+            assert_eq!(a_isk.as_bytes(), isk);
+            assert_eq!(b_isk.as_bytes(), isk);
+        }
+    }
+
+    #[test]
+    fn key_exchange() {
+        let a = Cpace::new_with_secret(Role::Initiator, PRS, CI, SID, b"ADa", secret(1234));
+        let b = Cpace::new_with_secret(Role::Responder, PRS, CI, SID, b"ADb", secret(5678));
+        let a_isk = a.finish(b.message().as_bytes(), b"ADb").unwrap();
+        let b_isk = b.finish(a.message().as_bytes(), b"ADa").unwrap();
+        assert_eq!(a_isk.as_bytes(), b_isk.as_bytes());
+
+        for b_isk in [
+            // the associated data is bound to the ISK
+            b.finish(a.message().as_bytes(), b""),
+            Cpace::new_with_secret(Role::Responder, b"wrong", CI, SID, b"ADb", secret(5678))
+                .finish(a.message().as_bytes(), b"ADa"),
+            Cpace::new_with_secret(Role::Responder, PRS, b"wrong", SID, b"ADb", secret(5678))
+                .finish(a.message().as_bytes(), b"ADa"),
+            Cpace::new_with_secret(Role::Responder, PRS, CI, b"wrong", b"ADb", secret(5678))
+                .finish(a.message().as_bytes(), b"ADa"),
+        ] {
+            assert_ne!(a_isk.as_bytes(), b_isk.unwrap().as_bytes());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_messages() {
+        let a = Cpace::new_with_secret(Role::Initiator, PRS, CI, SID, b"", secret(1234));
+
+        // identity
+        assert!(a.finish(&[0], b"").is_err());
+
+        // not on the curve
+        let mut message = [0; 65];
+        message.copy_from_slice(a.message().as_bytes());
+        message[64] ^= 1;
+        assert!(a.finish(&message, b"").is_err());
+    }
+}
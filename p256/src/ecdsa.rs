@@ -61,6 +61,11 @@ impl EcdsaCurve for NistP256 {
 }
 
 /// ECDSA/P-256 signing key
+///
+/// When the `blinding` crate feature is enabled, the computation of `[k]G` is blinded with
+/// randomness from the system RNG, see [`ProjectivePoint::mul_blinded`].
+///
+/// [`ProjectivePoint::mul_blinded`]: crate::ProjectivePoint::mul_blinded
 #[cfg(feature = "ecdsa")]
 pub type SigningKey = ecdsa_core::SigningKey<NistP256>;
 
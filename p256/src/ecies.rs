@@ -0,0 +1,155 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES) as described in [SEC 1 v2 § 5.1].
+//!
+//! See [`primeorder::ecies`] for the supported options. The hash function defaults to SHA-256.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "ecies", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "ecies", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `ecies` and `getrandom` crate features are enabled
+//! use p256::{
+//!     ecies::DecryptingKey,
+//!     elliptic_curve::{Generate, common::getrandom::SysRng},
+//! };
+//!
+//! // Recipient
+//! let decrypting_key: DecryptingKey = DecryptingKey::generate();
+//! let encrypting_key = decrypting_key.encrypting_key();
+//!
+//! // Sender
+//! let ciphertext = encrypting_key.encrypt(&mut SysRng, b"hello world")?;
+//!
+//! // Recipient
+//! let plaintext = decrypting_key.decrypt(&ciphertext)?;
+//! assert_eq!(plaintext, b"hello world");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [SEC 1 v2 § 5.1]: https://www.secg.org/sec1-v2.pdf#page=47
+
+pub use primeorder::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params, PointEncoding};
+
+use crate::NistP256;
+use sha2::Sha256;
+
+/// ECIES encrypting key for P-256, using the hash function `D`.
+pub type EncryptingKey<D = Sha256> = primeorder::ecies::EncryptingKey<NistP256, D>;
+
+/// ECIES decrypting key for P-256, using the hash function `D`.
+pub type DecryptingKey<D = Sha256> = primeorder::ecies::DecryptingKey<NistP256, D>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    primeorder::test_ecies!(
+        crate::NistP256,
+        Sha256,
+        secret_key: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+        public_key: hex!(
+            "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299"
+        ),
+        ephemeral_key: hex!("2b5e1c7a0d4f3e8d9c6b5a4f3e2d1c0b0a99887766554433221100ffeeddccbb"),
+        test_vectors: [
+            // SEC 1 `ecies-with-x9-63-kdf`
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: false,
+                ciphertext: hex!(
+                    "04d64ae9e11da5d5f44ca9cdfff6d9a7827bb71ab1b6377b54470d01d100e996a51de8110c297a0fe4bff5bd605994d3d0f7816109e3666f29ad3c7c29a211e4ca0575c5a5ca8c6eb35a046c1e2b36d70acc7cc1b6f69a6c288dc99f4560c6f283f08a7ed53ca406a5ebffdf425cac1a25b2bf0e535b2528fc6a7c24dc1344ff5e15eb7ab48c5623c57a0bb5369c"
+                ),
+            },
+            // SEC 1 with shared information
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04d64ae9e11da5d5f44ca9cdfff6d9a7827bb71ab1b6377b54470d01d100e996a51de8110c297a0fe4bff5bd605994d3d0f7816109e3666f29ad3c7c29a211e4caae97c2f9967f61bea20369947b75e48bcd32339c795107543b0b6eb6e3856b24dd71836914f108ffec5c191d817f8caef2d74ded21418599ec83a862ea60d8907619c5629f2e4fb67c8b8dedfe"
+                ),
+            },
+            // KDF over `R̄ || Z` as in ISO 18033-2
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04d64ae9e11da5d5f44ca9cdfff6d9a7827bb71ab1b6377b54470d01d100e996a51de8110c297a0fe4bff5bd605994d3d0f7816109e3666f29ad3c7c29a211e4ca74cfde56adfa67e1ffe7ab5c1ceb4cc98de3fd1aac540f90b30388cc6c3dfcb13d2fca90c983fc8023e95c76ad585dd4f1f4c9f6c41a58f37f5d54b62c2812faba365f502114916d9043fad23b"
+                ),
+            },
+            // HKDF over `R̄ || Z`, compressed ephemeral key and truncated tag
+            {
+                params: Params {
+                    kdf: Kdf::Hkdf,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Compressed,
+                    mac_tag: MacTag::Truncated,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "02d64ae9e11da5d5f44ca9cdfff6d9a7827bb71ab1b6377b54470d01d100e996a5d03877284ac1583a7d1f7cfe4418cee1d10aff8da3e588e1d74a4971530d270d9426e3485cbe485f0b5599672b7474962d5c0f65a8d518fc1a202a1d5f"
+                ),
+            },
+            // Length of the second shared information appended to the MAC input
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04d64ae9e11da5d5f44ca9cdfff6d9a7827bb71ab1b6377b54470d01d100e996a51de8110c297a0fe4bff5bd605994d3d0f7816109e3666f29ad3c7c29a211e4ca74cfde56adfa67e1ffe7ab5c1ceb4cc98de3fd1aac540f90b30388cc6c3dfcb13d2fca90c983fc8023e95c76ad4a37269903022428ee0a38313d63aac39fb4f0f1df59b10d2adfc0848953a13d"
+                ),
+            },
+            // Bouncy Castle `IESEngine` layout without a block cipher and with a 128-bit MAC key
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::MacKeyFirst,
+                    mac_key_len: Some(16),
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04d64ae9e11da5d5f44ca9cdfff6d9a7827bb71ab1b6377b54470d01d100e996a51de8110c297a0fe4bff5bd605994d3d0f7816109e3666f29ad3c7c29a211e4ca9fe4e01ab7540b83ab0385c86638b2e5332592dcc28ce8d034a65421e974a66b5ffc4da5638ac2b4ffec336bf81c8cda9262aa16db9e1ab60ae5b5231edc736f0ecd0735d782ede91f8618c866"
+                ),
+            },
+        ],
+    );
+}
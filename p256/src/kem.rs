@@ -0,0 +1,103 @@
+//! Diffie-Hellman based key encapsulation mechanism `DHKEM(P-256, HKDF-SHA256)` as described
+//! in [RFC 9180 § 4.1], for use with HPKE.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "kem", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "kem", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `kem` and `getrandom` crate features are enabled
+//! use p256::{
+//!     elliptic_curve::{
+//!         Generate,
+//!         common::getrandom::{SysRng, rand_core::UnwrapErr},
+//!     },
+//!     kem::{DecapsulationKey, Encapsulate, TryDecapsulate},
+//! };
+//!
+//! // Recipient
+//! let decapsulation_key = DecapsulationKey::generate();
+//! let encapsulation_key = decapsulation_key.encapsulation_key();
+//!
+//! // Sender
+//! let (enc, sender_secret) = encapsulation_key.encapsulate_with_rng(&mut UnwrapErr(SysRng));
+//!
+//! // Recipient
+//! let recipient_secret = decapsulation_key.try_decapsulate(&enc)?;
+//! assert_eq!(sender_secret, recipient_secret);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 9180 § 4.1]: https://www.rfc-editor.org/rfc/rfc9180#section-4.1
+
+pub use primeorder::dhkem::{
+    Decapsulator, DhKemParams, Encapsulate, Kem, KeyExport, TryDecapsulate, TryKeyInit,
+};
+
+use crate::NistP256;
+use sha2::Sha256;
+
+/// `DHKEM(P-256, HKDF-SHA256)`.
+pub type DhKem = primeorder::dhkem::DhKem<NistP256>;
+
+/// `DHKEM(P-256, HKDF-SHA256)` decapsulation key.
+pub type DecapsulationKey = primeorder::dhkem::DecapsulationKey<NistP256>;
+
+/// `DHKEM(P-256, HKDF-SHA256)` encapsulation key.
+pub type EncapsulationKey = primeorder::dhkem::EncapsulationKey<NistP256>;
+
+/// `DHKEM(P-256, HKDF-SHA256)` shared secret.
+pub type SharedSecret = primeorder::dhkem::SharedSecret<NistP256>;
+
+impl DhKemParams for NistP256 {
+    type Hash = Sha256;
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9180#section-7.1>.
+    const KEM_ID: u16 = 0x0010;
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9180#section-7.1.3>.
+    const BITMASK: u8 = 0xff;
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    primeorder::test_dhkem!(
+        crate::NistP256,
+        // RFC 9180 Appendix A.3.1
+        base: {
+            ikm_e: hex!("4270e54ffd08d79d5928020af4686d8f6b7d35dbe470265f1f5aa22816ce860e"),
+            sk_e: hex!("4995788ef4b9d6132b249ce59a77281493eb39af373d236a1fe415cb0c2d7beb"),
+            pk_e: hex!(
+                "04a92719c6195d5085104f469a8b9814d5838ff72b60501e2c4466e5e67b325ac98536d7b61a1af4b78e5b7f951c0900be863c403ce65c9bfcb9382657222d18c4"
+            ),
+            ikm_r: hex!("668b37171f1072f3cf12ea8a236a45df23fc13b82af3609ad1e354f6ef817550"),
+            sk_r: hex!("f3ce7fdae57e1a310d87f1ebbde6f328be0a99cdbcadf4d6589cf29de4b8ffd2"),
+            pk_r: hex!(
+                "04fe8c19ce0905191ebc298a9245792531f26f0cece2460639e8bc39cb7f706a826a779b4cf969b8a0e539c7f62fb3d30ad6aa8f80e30f1d128aafd68a2ce72ea0"
+            ),
+            shared_secret: hex!("c0d26aeab536609a572b07695d933b589dcf363ff9d93c93adea537aeabb8cb8"),
+        },
+        // RFC 9180 Appendix A.3.3
+        auth: {
+            ikm_e: hex!("798d82a8d9ea19dbc7f2c6dfa54e8a6706f7cdc119db0813dacf8440ab37c857"),
+            sk_e: hex!("6b8de0873aed0c1b2d09b8c7ed54cbf24fdf1dfc7a47fa501f918810642d7b91"),
+            pk_e: hex!(
+                "042224f3ea800f7ec55c03f29fc9865f6ee27004f818fcbdc6dc68932c1e52e15b79e264a98f2c535ef06745f3d308624414153b22c7332bc1e691cb4af4d53454"
+            ),
+            ikm_r: hex!("7bc93bde8890d1fb55220e7f3b0c107ae7e6eda35ca4040bb6651284bf0747ee"),
+            sk_r: hex!("d929ab4be2e59f6954d6bedd93e638f02d4046cef21115b00cdda2acb2a4440e"),
+            pk_r: hex!(
+                "04423e363e1cd54ce7b7573110ac121399acbc9ed815fae03b72ffbd4c18b01836835c5a09513f28fc971b7266cfde2e96afe84bb0f266920e82c4f53b36e1a78d"
+            ),
+            ikm_s: hex!("874baa0dcf93595a24a45a7f042e0d22d368747daaa7e19f80a802af19204ba8"),
+            sk_s: hex!("1120ac99fb1fccc1e8230502d245719d1b217fe20505c7648795139d177f0de9"),
+            pk_s: hex!(
+                "04a817a0902bf28e036d66add5d544cc3a0457eab150f104285df1e293b5c10eef8651213e43d9cd9086c80b309df22cf37609f58c1127f7607e85f210b2804f73"
+            ),
+            shared_secret: hex!("d4aea336439aadf68f9348880aa358086f1480e7c167b6ef15453ba69b94b44f"),
+        },
+    );
+}
@@ -18,12 +18,22 @@
 //!
 //! Please see type-specific documentation for more information.
 
+#[cfg(feature = "cpace")]
+pub mod cpace;
 #[cfg(feature = "ecdh")]
 pub mod ecdh;
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
+#[cfg(feature = "ecies")]
+pub mod ecies;
+#[cfg(feature = "kem")]
+pub mod kem;
+#[cfg(feature = "spake2")]
+pub mod spake2;
 #[cfg(any(feature = "test-vectors", test))]
 pub mod test_vectors;
+#[cfg(feature = "vrf")]
+pub mod vrf;
 
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
@@ -36,6 +46,8 @@ pub use arithmetic::{AffinePoint, ProjectivePoint, scalar::Scalar};
 pub use elliptic_curve::pkcs8;
 #[cfg(feature = "hash2curve")]
 pub use hash2curve;
+#[cfg(feature = "arithmetic")]
+pub use primeorder::{PublicKeyValidation, ValidationError};
 
 use elliptic_curve::{array::Array, bigint::Odd, consts::U33};
 
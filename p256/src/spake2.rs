@@ -0,0 +1,243 @@
+//! SPAKE2 password-authenticated key exchange as described in [RFC 9382].
+//!
+//! [`Spake2`] implements the `SPAKE2-P256-SHA256-HKDF-HMAC` ciphersuite by default, and
+//! `SPAKE2-P256-SHA512-HKDF-HMAC` with a different hash function.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "spake2", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "spake2", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `spake2` and `getrandom` crate features are enabled
+//! use p256::{
+//!     Scalar,
+//!     elliptic_curve::{Generate, common::getrandom::SysRng},
+//!     spake2::{Role, Spake2},
+//! };
+//!
+//! // Both parties derive `w` from the password with a memory-hard function
+//! let w = Scalar::generate();
+//!
+//! let alice = <Spake2>::try_new(Role::A, &w, b"alice", b"bob", &mut SysRng)?;
+//! let bob = <Spake2>::try_new(Role::B, &w, b"alice", b"bob", &mut SysRng)?;
+//!
+//! // Alice and Bob exchange their messages and compute the shared keys
+//! let alice_keys = alice.finish(bob.message().as_bytes(), b"")?;
+//! let bob_keys = bob.finish(alice.message().as_bytes(), b"")?;
+//!
+//! // Alice and Bob exchange their key confirmation messages and check them
+//! alice_keys.verify_confirmation(bob_keys.confirmation())?;
+//! bob_keys.verify_confirmation(alice_keys.confirmation())?;
+//! assert_eq!(alice_keys.shared_key(), bob_keys.shared_key());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 9382]: https://www.rfc-editor.org/rfc/rfc9382
+
+pub use primeorder::spake2::{Role, Spake2Keys, Spake2Params};
+
+use crate::{NistP256, arithmetic::field::FieldElement};
+use sha2::Sha256;
+
+/// SPAKE2 protocol state for NIST P-256.
+pub type Spake2<'a, D = Sha256> = primeorder::spake2::Spake2<'a, NistP256, D>;
+
+impl Spake2Params for NistP256 {
+    /// See <https://www.rfc-editor.org/rfc/rfc9382#section-6>.
+    const M: (FieldElement, FieldElement) = (
+        FieldElement::from_hex_vartime(
+            "886e2f97ace46e55ba9dd7242579f2993b64e16ef3dcab95afd497333d8fa12f",
+        ),
+        FieldElement::from_hex_vartime(
+            "5ff355163e43ce224e0b0e65ff02ac8e5c7be09419c785e0ca547d55a12e2d20",
+        ),
+    );
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9382#section-6>.
+    const N: (FieldElement, FieldElement) = (
+        FieldElement::from_hex_vartime(
+            "d8bbd6c639c62937b04d997f38c3770719c629d7014d49a24b4f98baa1292b49",
+        ),
+        FieldElement::from_hex_vartime(
+            "07d60aa6bfade45008a636337f5168c64d9bd36034808cd564490b1e656edbe7",
+        ),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AffinePoint, NonZeroScalar, ProjectivePoint, Scalar};
+    use elliptic_curve::{
+        ff::PrimeField,
+        point::AffineCoordinates,
+        sec1::{FromSec1Point, Sec1Point, ToSec1Point},
+    };
+    use hex_literal::hex;
+
+    /// SPAKE2 test vector in the format of RFC 9382 Appendix B.
+    struct TestVector {
+        id_a: &'static [u8],
+        id_b: &'static [u8],
+        w: [u8; 32],
+        x: [u8; 32],
+        p_a: [u8; 65],
+        y: [u8; 32],
+        p_b: [u8; 65],
+        ke: [u8; 16],
+        conf_a: [u8; 32],
+        conf_b: [u8; 32],
+    }
+
+    const TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            id_a: b"server",
+            id_b: b"client",
+            w: hex!("2ee57912099d31560b3a44b1184b9b4866e904c49d12ac5042c97dca461b1a5f"),
+            x: hex!("43dd0fd7215bdcb482879fca3220c6a968e66d70b1356cac18bb26c84a78d729"),
+            p_a: hex!(
+                "04a56fa807caaa53a4d28dbb9853b9815c61a411118a6fe516a8798434751470f9010153ac33d0d5f2047ffdb1a3e42c9b4e6be662766e1eeb4116988ede5f912c"
+            ),
+            y: hex!("dcb60106f276b02606d8ef0a328c02e4b629f84f89786af5befb0bc75b6e66be"),
+            p_b: hex!(
+                "0406557e482bd03097ad0cbaa5df82115460d951e3451962f1eaf4367a420676d09857ccbc522686c83d1852abfa8ed6e4a1155cf8f1543ceca528afb591a1e0b7"
+            ),
+            ke: hex!("0e0672dc86f8e45565d338b0540abe69"),
+            conf_a: hex!("58ad4aa88e0b60d5061eb6b5dd93e80d9c4f00d127c65b3b35b1b5281fee38f0"),
+            conf_b: hex!("d3e2e547f1ae04f2dbdbf0fc4b79f8ecff2dff314b5d32fe9fcef2fb26dc459b"),
+        },
+        TestVector {
+            id_a: b"",
+            id_b: b"client",
+            w: hex!("0548d8729f730589e579b0475a582c1608138ddf7054b73b5381c7e883e2efae"),
+            x: hex!("403abbe3b1b4b9ba17e3032849759d723939a27a27b9d921c500edde18ed654b"),
+            p_a: hex!(
+                "04a897b769e681c62ac1c2357319a3d363f610839c4477720d24cbe32f5fd85f44fb92ba966578c1b712be6962498834078262caa5b441ecfa9d4a9485720e918a"
+            ),
+            y: hex!("903023b6598908936ea7c929bd761af6039577a9c3f9581064187c3049d87065"),
+            p_b: hex!(
+                "04e0f816fd1c35e22065d5556215c097e799390d16661c386e0ecc84593974a61b881a8c82327687d0501862970c64565560cb5671f696048050ca66ca5f8cc7fc"
+            ),
+            ke: hex!("642f05c473c2cd79909f9a841e2f30a7"),
+            conf_a: hex!("47d29e6666af1b7dd450d571233085d7a9866e4d49d2645e2df975489521232b"),
+            conf_b: hex!("3313c5cefc361d27fb16847a91c2a73b766ffa90a4839122a9b70a2f6bd1d6df"),
+        },
+        // The remaining vectors cover the identity combinations of the last two vectors of
+        // RFC 9382 Appendix B, with `w`, `x` and `y` of our own choosing. They were computed with
+        // an independent Python implementation of RFC 9382 § 4, which reproduces the two vectors
+        // above.
+        TestVector {
+            id_a: b"server",
+            id_b: b"",
+            w: hex!("686a46d4f219dc2845e31f99fffd7fa18e2f53d9a1dc0255e87ae56f16afe062"),
+            x: hex!("76db50440b1575fc0ec4bcea9709c3aa9e10306d883d478b03ded8d0f2c8a93e"),
+            p_a: hex!(
+                "045352562985aa16691faf4f14a84002dc0990b534a2f159631277a8e459a3ca50a88b50020c2bbc7df3f633e4180624428a8a8579bbe5ea50d05106190f1dcf1b"
+            ),
+            y: hex!("fc3e11157629bbfb8654a100e4ef3c5b4ec29019dbdb0a6d98a8295a89b69be6"),
+            p_b: hex!(
+                "04ad9f33073db2b912fedae16bf5f3b4981dbe8cad53c201af66bc795a0ddb3c93ce8a26ca5db50294a077fce0554fdd8003659b22b2bc49d7f12ac5cbe9fa8516"
+            ),
+            ke: hex!("bb6a4685f08d77d583a4f9f4ccde5a3a"),
+            conf_a: hex!("61ccfdea580cb518b431cce65513f2de9872e7651a46eea11c1376cb78a92753"),
+            conf_b: hex!("5e87e0339836b5ed44c687245281190d847394c2d758344ca68acbf4968a31be"),
+        },
+        TestVector {
+            id_a: b"",
+            id_b: b"",
+            w: hex!("ece3f468f31472c3169327a406ce5794ecd71254ed735b0a13520bb91cd015ec"),
+            x: hex!("ed26c7263d4d7c3dd3254ab9649677cff7b86fcc45e011049b01dce9b65da4b4"),
+            p_a: hex!(
+                "0479517b298195249aeaf34777477a79fa39032ca5cba1e297eceb49a2b36b41754745a367e7fe9b30696e155819fe3476ba225a4bc6c4aac16e3ac9fe588492e0"
+            ),
+            y: hex!("2db973cc26d712184fec4583e78baa4abed659d132d1867a4d3025143374ef4f"),
+            p_b: hex!(
+                "04c2eb22522cce5a8a374512e27480745ec75b2d027d07ce0d2c2e38d3cf9ea2ce5ad764d619f82ff9992b52c2148a5a062398637112cd414c82e6aca4277add34"
+            ),
+            ke: hex!("9d5e942c4d2229ac1500fa16830d2d63"),
+            conf_a: hex!("23d1130049ec94fed4b49c4e8b4620155de617bc7fcd582231993838d8416543"),
+            conf_b: hex!("8d719b2c02271f885b8b1ab12aab314bbb40058058fd698469c6536aac2f7c75"),
+        },
+    ];
+
+    fn scalar(bytes: &[u8; 32]) -> Scalar {
+        Scalar::from_repr((*bytes).into()).unwrap()
+    }
+
+    /// Compressed SEC1 encodings of `M` and `N` given in RFC 9382 § 6.
+    const RFC9382_M: [u8; 33] =
+        hex!("02886e2f97ace46e55ba9dd7242579f2993b64e16ef3dcab95afd497333d8fa12f");
+    const RFC9382_N: [u8; 33] =
+        hex!("03d8bbd6c639c62937b04d997f38c3770719c629d7014d49a24b4f98baa1292b49");
+
+    #[test]
+    fn constants_match_rfc9382() {
+        for (constant, encoded) in [(NistP256::M, RFC9382_M), (NistP256::N, RFC9382_N)] {
+            let point = Sec1Point::<NistP256>::from_bytes(encoded).unwrap();
+            let point = AffinePoint::from_sec1_point(&point).unwrap();
+            assert_eq!(point.x(), constant.0.to_repr());
+            assert_eq!(point.y(), constant.1.to_repr());
+        }
+    }
+
+    #[test]
+    fn rfc9382_test_vectors() {
+        for vector in TEST_VECTORS {
+            let w = scalar(&vector.w);
+            let x = NonZeroScalar::new(scalar(&vector.x)).unwrap();
+            let y = NonZeroScalar::new(scalar(&vector.y)).unwrap();
+
+            let a = <Spake2>::new_with_secret(Role::A, &w, x, vector.id_a, vector.id_b);
+            let b = <Spake2>::new_with_secret(Role::B, &w, y, vector.id_a, vector.id_b);
+            assert_eq!(a.message().as_bytes(), vector.p_a);
+            assert_eq!(b.message().as_bytes(), vector.p_b);
+
+            let a_keys = a.finish(&vector.p_b, b"").unwrap();
+            let b_keys = b.finish(&vector.p_a, b"").unwrap();
+            assert_eq!(a_keys.shared_key(), vector.ke);
+            assert_eq!(b_keys.shared_key(), vector.ke);
+            assert_eq!(a_keys.confirmation(), vector.conf_a);
+            assert_eq!(b_keys.confirmation(), vector.conf_b);
+            assert!(a_keys.verify_confirmation(&vector.conf_b).is_ok());
+            assert!(b_keys.verify_confirmation(&vector.conf_a).is_ok());
+        }
+    }
+
+    #[test]
+    fn wrong_password() {
+        let vector = &TEST_VECTORS[0];
+        let x = NonZeroScalar::new(scalar(&vector.x)).unwrap();
+        let y = NonZeroScalar::new(scalar(&vector.y)).unwrap();
+
+        let a = <Spake2>::new_with_secret(Role::A, &scalar(&vector.w), x, b"", b"");
+        let b = <Spake2>::new_with_secret(Role::B, &Scalar::ONE, y, b"", b"");
+        let a_keys = a.finish(b.message().as_bytes(), b"").unwrap();
+        let b_keys = b.finish(a.message().as_bytes(), b"").unwrap();
+
+        assert_ne!(a_keys.shared_key(), b_keys.shared_key());
+        assert!(a_keys.verify_confirmation(b_keys.confirmation()).is_err());
+        assert!(b_keys.verify_confirmation(a_keys.confirmation()).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_messages() {
+        let vector = &TEST_VECTORS[0];
+        let x = NonZeroScalar::new(scalar(&vector.x)).unwrap();
+        let a = <Spake2>::new_with_secret(Role::A, &scalar(&vector.w), x, b"", b"");
+
+        // identity
+        assert!(a.finish(&[0], b"").is_err());
+
+        // not on the curve
+        let mut p_b = vector.p_b;
+        p_b[64] ^= 1;
+        assert!(a.finish(&p_b, b"").is_err());
+
+        // w * N, which leads to K being the identity
+        let n = Sec1Point::<NistP256>::from_bytes(RFC9382_N).unwrap();
+        let n = ProjectivePoint::from(AffinePoint::from_sec1_point(&n).unwrap());
+        let w_n = (n * scalar(&vector.w)).to_affine();
+        assert!(a.finish(w_n.to_sec1_point(false).as_bytes(), b"").is_err());
+    }
+}
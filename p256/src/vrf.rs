@@ -0,0 +1,248 @@
+//! Elliptic Curve Verifiable Random Function (ECVRF) as described in [RFC 9381].
+//!
+//! A VRF is the public-key version of a keyed hash: only the holder of the [`ProvingKey`] can
+//! compute the output `beta` for an input `alpha`, but anyone can check with the
+//! [`VerifyingKey`] and a [`Proof`] that `beta` is the correct output for `alpha`.
+//!
+//! Two ciphersuites are supported, which only differ in the way `alpha` is encoded to the curve:
+//!
+//! - [`Tai`]: `ECVRF-P256-SHA256-TAI`, using the try-and-increment method.
+//! - [`Sswu`]: `ECVRF-P256-SHA256-SSWU`, using the `encode_to_curve` function of [RFC 9380].
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "vrf", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "vrf", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `vrf` and `getrandom` crate features are enabled
+//! use p256::{
+//!     SecretKey,
+//!     elliptic_curve::Generate,
+//!     vrf::{Proof, ProvingKey, Sswu, VerifyingKey},
+//! };
+//!
+//! // Prover
+//! let proving_key = ProvingKey::<Sswu>::from(&SecretKey::generate());
+//! let proof = proving_key.prove(b"alpha")?;
+//! let beta = proof.proof_to_hash();
+//! let proof_bytes = proof.to_bytes();
+//!
+//! // Verifier
+//! let verifying_key = proving_key.verifying_key();
+//! let proof = Proof::<Sswu>::from_bytes(&proof_bytes)?;
+//! assert_eq!(verifying_key.verify(b"alpha", &proof)?, beta);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+//! [RFC 9381]: https://www.rfc-editor.org/rfc/rfc9381
+
+use crate::{NistP256, ProjectivePoint};
+use elliptic_curve::{Error, Result, consts::U81};
+use hash2curve::GroupDigest;
+use primeorder::vrf::encode_to_curve_tai;
+use sha2::Sha256;
+
+pub use primeorder::vrf::{Proof, ProvingKey, Suite, VerifyingKey};
+
+/// `ECVRF-P256-SHA256-TAI` ciphersuite, which encodes to the curve with the try-and-increment
+/// method described in [RFC 9381 § 5.4.1.1].
+///
+/// [RFC 9381 § 5.4.1.1]: https://www.rfc-editor.org/rfc/rfc9381#section-5.4.1.1
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tai;
+
+impl Suite for Tai {
+    type Curve = NistP256;
+    type Hash = Sha256;
+    type ProofSize = U81;
+
+    const SUITE_STRING: u8 = 0x01;
+
+    /// Returns [`Error`] in the negligibly unlikely case that none of the 256 candidates is the
+    /// x-coordinate of a point on the curve.
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> Result<ProjectivePoint> {
+        encode_to_curve_tai::<Self>(salt, alpha)
+    }
+}
+
+/// `ECVRF-P256-SHA256-SSWU` ciphersuite, which encodes to the curve with the
+/// `P256_XMD:SHA-256_SSWU_NU_` suite of [RFC 9380] as described in [RFC 9381 § 5.4.1.2].
+///
+/// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+/// [RFC 9381 § 5.4.1.2]: https://www.rfc-editor.org/rfc/rfc9381#section-5.4.1.2
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Sswu;
+
+impl Suite for Sswu {
+    type Curve = NistP256;
+    type Hash = Sha256;
+    type ProofSize = U81;
+
+    const SUITE_STRING: u8 = 0x02;
+
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> Result<ProjectivePoint> {
+        // DST = "ECVRF_" || h2c_suite_ID_string || suite_string
+        NistP256::encode_from_bytes(
+            &[salt, alpha],
+            &[
+                b"ECVRF_",
+                NistP256::ENCODE_TO_CURVE_ID,
+                &[Self::SUITE_STRING],
+            ],
+        )
+        .map_err(|_| Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    const PT_LEN: usize = 33;
+    const C_LEN: usize = 16;
+    const PROOF_SIZE: usize = 81;
+
+    /// Test vector from RFC 9381 Appendix B.
+    struct TestVector {
+        sk: [u8; 32],
+        pk: [u8; PT_LEN],
+        alpha: &'static [u8],
+        pi: [u8; PROOF_SIZE],
+        beta: [u8; 32],
+    }
+
+    /// Examples 10 to 12.
+    const TAI_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            sk: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+            pk: hex!("0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"),
+            alpha: b"sample",
+            pi: hex!(
+                "035b5c726e8c0e2c488a107c600578ee75cb702343c153cb1eb8dec77f4b5071b4a53f0a46f018bc2c56e58d383f2305e0975972c26feea0eb122fe7893c15af376b33edf7de17c6ea056d4d82de6bc02f"
+            ),
+            beta: hex!("a3ad7b0ef73d8fc6655053ea22f9bede8c743f08bbed3d38821f0e16474b505e"),
+        },
+        TestVector {
+            sk: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+            pk: hex!("0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"),
+            alpha: b"test",
+            pi: hex!(
+                "034dac60aba508ba0c01aa9be80377ebd7562c4a52d74722e0abae7dc3080ddb56c19e067b15a8a8174905b13617804534214f935b94c2287f797e393eb0816969d864f37625b443f30f1a5a33f2b3c854"
+            ),
+            beta: hex!("a284f94ceec2ff4b3794629da7cbafa49121972671b466cab4ce170aa365f26d"),
+        },
+        TestVector {
+            sk: hex!("2ca1411a41b17b24cc8c3b089cfd033f1920202a6c0de8abb97df1498d50d2c8"),
+            pk: hex!("03596375e6ce57e0f20294fc46bdfcfd19a39f8161b58695b3ec5b3d16427c274d"),
+            alpha: b"Example using ECDSA key from Appendix L.4.2 of ANSI.X9-62-2005",
+            pi: hex!(
+                "03d03398bf53aa23831d7d1b2937e005fb0062cbefa06796579f2a1fc7e7b8c667d091c00b0f5c3619d10ecea44363b5a599cadc5b2957e223fec62e81f7b4825fc799a771a3d7334b9186bdbee87316b1"
+            ),
+            beta: hex!("90871e06da5caa39a3c61578ebb844de8635e27ac0b13e829997d0d95dd98c19"),
+        },
+    ];
+
+    /// Examples 13 to 15.
+    const SSWU_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            sk: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+            pk: hex!("0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"),
+            alpha: b"sample",
+            pi: hex!(
+                "0331d984ca8fece9cbb9a144c0d53df3c4c7a33080c1e02ddb1a96a365394c7888782fffde7b842c38c20c08de6ec6c2e7027a97000f2c9fa4425d5c03e639fb48fde58114d755985498d7eb234cf4aed9"
+            ),
+            beta: hex!("21e66dc9747430f17ed9efeda054cf4a264b097b9e8956a1787526ed00dc664b"),
+        },
+        TestVector {
+            sk: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+            pk: hex!("0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"),
+            alpha: b"test",
+            pi: hex!(
+                "03f814c0455d32dbc75ad3aea08c7e2db31748e12802db23640203aebf1fa8db2743aad348a3006dc1caad7da28687320740bf7dd78fe13c298867321ce3b36b79ec3093b7083ac5e4daf3465f9f43c627"
+            ),
+            beta: hex!("8e7185d2b420e4f4681f44ce313a26d05613323837da09a69f00491a83ad25dd"),
+        },
+        TestVector {
+            sk: hex!("2ca1411a41b17b24cc8c3b089cfd033f1920202a6c0de8abb97df1498d50d2c8"),
+            pk: hex!("03596375e6ce57e0f20294fc46bdfcfd19a39f8161b58695b3ec5b3d16427c274d"),
+            alpha: b"Example using ECDSA key from Appendix L.4.2 of ANSI.X9-62-2005",
+            pi: hex!(
+                "039f8d9cdc162c89be2871cbcb1435144739431db7fab437ab7bc4e2651a9e99d5488405a11a6c7fc8defddd9e1573a563b7333aab4effe73ae9803274174c659269fd39b53e133dcd9e0d24f01288de9a"
+            ),
+            beta: hex!("4fbadf33b42a5f42f23a6f89952d2e634a6e3810f15878b46ef1bb85a04fe95a"),
+        },
+    ];
+
+    fn check_test_vectors<S: Suite<Curve = NistP256>>(vectors: &[TestVector]) {
+        for vector in vectors {
+            let proving_key = ProvingKey::<S>::from_bytes(&vector.sk.into()).unwrap();
+            let verifying_key = proving_key.verifying_key();
+            assert_eq!(verifying_key.to_sec1_point().as_bytes(), vector.pk);
+
+            let proof = proving_key.prove(vector.alpha).unwrap();
+            assert_eq!(proof.to_bytes().as_slice(), vector.pi);
+            assert_eq!(proof.proof_to_hash().as_slice(), vector.beta);
+
+            let verifying_key = VerifyingKey::<S>::from_sec1_bytes(&vector.pk).unwrap();
+            let proof = Proof::<S>::from_bytes(&vector.pi).unwrap();
+            let beta = verifying_key.verify(vector.alpha, &proof).unwrap();
+            assert_eq!(beta.as_slice(), vector.beta);
+        }
+    }
+
+    #[test]
+    fn tai_test_vectors() {
+        check_test_vectors::<Tai>(TAI_TEST_VECTORS);
+    }
+
+    #[test]
+    fn sswu_test_vectors() {
+        check_test_vectors::<Sswu>(SSWU_TEST_VECTORS);
+    }
+
+    #[test]
+    fn rejects_invalid_proofs() {
+        let vector = &SSWU_TEST_VECTORS[0];
+        let verifying_key = VerifyingKey::<Sswu>::from_sec1_bytes(&vector.pk).unwrap();
+        let proof = Proof::<Sswu>::from_bytes(&vector.pi).unwrap();
+
+        // wrong input
+        assert!(verifying_key.verify(b"wrong", &proof).is_err());
+
+        // wrong key
+        let other_key = VerifyingKey::<Sswu>::from_sec1_bytes(&SSWU_TEST_VECTORS[2].pk).unwrap();
+        assert!(other_key.verify(vector.alpha, &proof).is_err());
+
+        // wrong suite
+        let tai_key = VerifyingKey::<Tai>::from_sec1_bytes(&vector.pk).unwrap();
+        let tai_proof = Proof::<Tai>::from_bytes(&vector.pi).unwrap();
+        assert!(tai_key.verify(vector.alpha, &tai_proof).is_err());
+
+        // tampered `c` and `s`
+        for i in [PT_LEN, PROOF_SIZE - 1] {
+            let mut pi = vector.pi;
+            pi[i] ^= 1;
+            let proof = Proof::<Sswu>::from_bytes(&pi).unwrap();
+            assert!(verifying_key.verify(vector.alpha, &proof).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_proofs() {
+        let vector = &SSWU_TEST_VECTORS[0];
+        assert!(Proof::<Sswu>::from_bytes(&vector.pi[1..]).is_err());
+
+        // `Gamma` isn't a compressed point
+        let mut pi = vector.pi;
+        pi[0] = 0x04;
+        assert!(Proof::<Sswu>::from_bytes(&pi).is_err());
+
+        // `s` isn't smaller than the order
+        let mut pi = vector.pi;
+        pi[PT_LEN + C_LEN..].fill(0xff);
+        assert!(Proof::<Sswu>::from_bytes(&pi).is_err());
+    }
+}
@@ -134,3 +134,69 @@ fn noncompatible_is_none() {
         .to_bool();
     assert!(!is_compactable);
 }
+
+#[test]
+fn sp800_56a_validation() {
+    use p256::{PublicKey, PublicKeyValidation, ValidationError};
+
+    let public_key = PublicKey::from_sec1_bytes_full(UNCOMPRESSED_BASEPOINT).unwrap();
+    assert_eq!(public_key.as_affine(), &AffinePoint::generator());
+    assert_eq!(public_key.validate_full(), Ok(()));
+    assert_eq!(
+        PublicKey::from_sec1_bytes_partial(COMPRESSED_BASEPOINT),
+        Ok(public_key)
+    );
+
+    let mut not_on_curve = [0; 65];
+    not_on_curve.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    not_on_curve[64] ^= 1;
+
+    let mut y_out_of_range = [0xff; 65];
+    y_out_of_range[..33].copy_from_slice(&UNCOMPRESSED_BASEPOINT[..33]);
+
+    let invalid: [(&[u8], ValidationError); 4] = [
+        (&[0x04, 0x6B], ValidationError::Encoding),
+        (&[0x00], ValidationError::Identity),
+        (&y_out_of_range, ValidationError::Range),
+        (&not_on_curve, ValidationError::NotOnCurve),
+    ];
+
+    for (bytes, err) in invalid {
+        assert_eq!(PublicKey::from_sec1_bytes_partial(bytes), Err(err));
+        assert_eq!(PublicKey::from_sec1_bytes_full(bytes), Err(err));
+    }
+
+    assert_eq!(
+        AffinePoint::IDENTITY.validate_full(),
+        Err(ValidationError::Identity)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn batch_from_sec1() {
+    let mut not_on_curve = [0; 65];
+    not_on_curve.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    not_on_curve[64] ^= 1;
+
+    let mut x_out_of_range = [0xff; 33];
+    x_out_of_range[0] = 0x03;
+
+    let encodings: [&[u8]; 6] = [
+        COMPRESSED_BASEPOINT,
+        &not_on_curve,
+        UNCOMPRESSED_BASEPOINT,
+        &x_out_of_range,
+        &[0x00],
+        &[0x03, 0x6B],
+    ];
+
+    let points = AffinePoint::batch_from_sec1(&encodings);
+    assert_eq!(points.len(), encodings.len());
+    assert_eq!(points[0].as_ref().ok(), Some(&AffinePoint::GENERATOR));
+    assert!(points[1].is_err());
+    assert_eq!(points[2].as_ref().ok(), Some(&AffinePoint::GENERATOR));
+    assert!(points[3].is_err());
+    assert_eq!(points[4].as_ref().ok(), Some(&AffinePoint::IDENTITY));
+    assert!(points[5].is_err());
+}
@@ -0,0 +1,110 @@
+//! ECDH tests.
+
+#![cfg(feature = "ecdh")]
+
+use elliptic_curve::{
+    ff::PrimeField,
+    ops::ReduceNonZero,
+    point::{AffineCoordinates, DecompressPoint},
+    sec1::ToSec1Point,
+};
+use p256::{
+    AffinePoint, FieldBytes, NistP256, NonZeroScalar, ProjectivePoint, PublicKey, Scalar,
+    ecdh::{diffie_hellman, diffie_hellman_x_only},
+};
+use primeorder::{FieldArithmetic, x_only_mul};
+use proptest::prelude::*;
+
+type FieldElement = <NistP256 as FieldArithmetic>::FieldElement;
+
+prop_compose! {
+    fn nonzero_scalar()(bytes in any::<[u8; 32]>()) -> NonZeroScalar {
+        NonZeroScalar::reduce_nonzero(&FieldBytes::from(bytes))
+    }
+}
+
+proptest! {
+    #[test]
+    fn diffie_hellman_matches_projective(a in nonzero_scalar(), b in nonzero_scalar()) {
+        let public = PublicKey::from_secret_scalar(&b);
+        let expected = elliptic_curve::ecdh::diffie_hellman(a, public.as_affine());
+        let shared = diffie_hellman(a, public.as_affine());
+        prop_assert_eq!(expected.raw_secret_bytes(), shared.raw_secret_bytes());
+    }
+
+    #[test]
+    fn diffie_hellman_x_only_encodings(a in nonzero_scalar(), b in nonzero_scalar()) {
+        let public = PublicKey::from_secret_scalar(&b);
+        let expected = elliptic_curve::ecdh::diffie_hellman(a, public.as_affine());
+
+        let compressed = public.as_affine().to_sec1_point(true);
+        let uncompressed = public.as_affine().to_sec1_point(false);
+        let x = public.as_affine().x();
+
+        for bytes in [compressed.as_bytes(), uncompressed.as_bytes(), x.as_slice()] {
+            let shared = diffie_hellman_x_only(a, bytes).unwrap();
+            prop_assert_eq!(expected.raw_secret_bytes(), shared.raw_secret_bytes());
+        }
+    }
+}
+
+#[test]
+fn x_only_mul_edge_cases() {
+    let g = AffinePoint::GENERATOR;
+    let x = FieldElement::from_repr(g.x()).unwrap();
+    let one = Scalar::ONE;
+    let g2 = (ProjectivePoint::GENERATOR + g).to_affine();
+
+    assert!(bool::from(
+        x_only_mul::<NistP256>(&x, &Scalar::ZERO).is_none()
+    ));
+    for k in [one, -one] {
+        assert_eq!(x_only_mul::<NistP256>(&x, &k).unwrap().to_repr(), g.x());
+    }
+    for k in [one.double(), -one.double()] {
+        assert_eq!(x_only_mul::<NistP256>(&x, &k).unwrap().to_repr(), g2.x());
+    }
+}
+
+#[test]
+fn diffie_hellman_x_only_rejects_invalid_keys() {
+    let secret = NonZeroScalar::new(Scalar::ONE).unwrap();
+
+    // first x-coordinate which is on the quadratic twist rather than the curve
+    let twist_x = (0u64..)
+        .map(|i| {
+            let mut x = FieldBytes::default();
+            x[24..].copy_from_slice(&i.to_be_bytes());
+            x
+        })
+        .find(|x| AffinePoint::decompress(x, 0.into()).is_none().into())
+        .unwrap();
+
+    assert!(diffie_hellman_x_only(secret, &twist_x).is_err());
+    assert!(diffie_hellman_x_only(secret, &[0]).is_err());
+    assert!(diffie_hellman_x_only(secret, &twist_x[1..]).is_err());
+}
+
+#[test]
+#[cfg(feature = "getrandom")]
+fn ephemeral_secret_agreement() {
+    use elliptic_curve::Generate;
+    use p256::ecdh::EphemeralSecret;
+
+    let alice = EphemeralSecret::generate();
+    let bob = EphemeralSecret::generate();
+
+    let alice_shared = alice.diffie_hellman(&bob.public_key());
+    let bob_shared = bob.diffie_hellman(&alice.public_key());
+    assert_eq!(
+        alice_shared.raw_secret_bytes(),
+        bob_shared.raw_secret_bytes()
+    );
+
+    let compressed = alice.public_key().as_affine().to_sec1_point(true);
+    let bob_shared = bob.diffie_hellman_x_only(compressed.as_bytes()).unwrap();
+    assert_eq!(
+        alice_shared.raw_secret_bytes(),
+        bob_shared.raw_secret_bytes()
+    );
+}
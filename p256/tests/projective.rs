@@ -2,22 +2,36 @@
 
 #![cfg(all(feature = "arithmetic", feature = "test-vectors"))]
 
+#[cfg(feature = "getrandom")]
+use elliptic_curve::common::getrandom::SysRng;
 use elliptic_curve::{
     BatchNormalize, Group,
     array::Array,
-    consts::U32,
+    consts::{U5, U32},
     group::{GroupEncoding, ff::PrimeField},
     ops::{LinearCombination, MulByGeneratorVartime, Reduce, ReduceNonZero},
     point::NonIdentity,
     sec1::{self, ToSec1Point},
 };
 use p256::{
-    AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, Scalar,
+    AffinePoint, FieldBytes, NistP256, NonZeroScalar, ProjectivePoint, Scalar,
     test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
 };
-use primeorder::test_projective_arithmetic;
+#[cfg(feature = "alloc")]
+use primeorder::{
+    FixedBaseTable,
+    wnaf::{BoxedWnaf, Digit},
+};
+use primeorder::{
+    test_projective_arithmetic,
+    wnaf::{JsfBase, JsfScalar, WnafBase, WnafScalar},
+};
 use proptest::{prelude::any, prop_compose, proptest};
 
+/// Owned `BoxedWnaf` base context.
+#[cfg(feature = "alloc")]
+type BoxedWnafBase = BoxedWnaf<usize, Vec<AffinePoint>, Vec<Digit>>;
+
 test_projective_arithmetic!(
     AffinePoint,
     ProjectivePoint,
@@ -79,6 +93,39 @@ proptest! {
         }
     }
 
+    #[test]
+    fn batch_add(
+        a in projective(),
+        b in projective(),
+    ) {
+        let (a, b) = (a.to_affine(), b.to_affine());
+        let pairs = [(a, b), (a, a), (a, -a), (b, AffinePoint::IDENTITY)];
+        let sums = AffinePoint::batch_add_array(&pairs);
+
+        for ((p, q), sum) in pairs.iter().zip(sums) {
+            assert_eq!(sum, (ProjectivePoint::from(*p) + q).to_affine());
+        }
+
+        let doubles = AffinePoint::batch_double_array(&[a, b]);
+        assert_eq!(doubles[0], ProjectivePoint::from(a).double().to_affine());
+        assert_eq!(doubles[1], ProjectivePoint::from(b).double().to_affine());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn batch_add_alloc(
+        a in projective(),
+        b in projective(),
+    ) {
+        let (a, b) = (a.to_affine(), b.to_affine());
+        let pairs = vec![(a, b), (AffinePoint::IDENTITY, a)];
+        let sums = AffinePoint::batch_add(&pairs);
+        assert_eq!(sums, AffinePoint::batch_add_array(&[(a, b), (AffinePoint::IDENTITY, a)]));
+
+        let doubles = AffinePoint::batch_double(&[a, b]);
+        assert_eq!(doubles, AffinePoint::batch_double_array(&[a, b]));
+    }
+
     #[test]
     fn lincomb(
         p1 in projective(),
@@ -123,6 +170,18 @@ proptest! {
         assert_eq!(reference, test);
     }
 
+    #[test]
+    fn jsf(
+        p1 in projective(),
+        s1 in scalar(),
+        p2 in projective(),
+        s2 in scalar(),
+    ) {
+        let reference = p1 * s1 + p2 * s2;
+        let test = &JsfBase::new(&p1, &p2) * &JsfScalar::new(&s1, &s2);
+        assert_eq!(reference, test);
+    }
+
     #[test]
     fn mul_by_generator(s1 in scalar()) {
         let reference = ProjectivePoint::GENERATOR * s1;
@@ -146,4 +205,166 @@ proptest! {
         let test = p1.mul_vartime(&s1);
         assert_eq!(reference, test);
     }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn mul_blinded(
+        p1 in projective(),
+        s1 in scalar()
+    ) {
+        let reference = p1 * s1;
+        let test = p1.try_mul_blinded(&s1, &mut SysRng).unwrap();
+        assert_eq!(reference, test);
+    }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn mul_by_generator_blinded(s1 in scalar()) {
+        let reference = ProjectivePoint::GENERATOR * s1;
+        let test = ProjectivePoint::try_mul_by_generator_blinded(&s1, &mut SysRng).unwrap();
+        assert_eq!(reference, test);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fixed_base_table(
+        p1 in projective(),
+        s1 in scalar()
+    ) {
+        let table = FixedBaseTable::new(&p1);
+        let reference = p1 * s1;
+        assert_eq!(reference, table.mul(&s1));
+        assert_eq!(reference, table.mul_vartime(&s1));
+
+        #[cfg(feature = "getrandom")]
+        assert_eq!(reference, table.try_mul_blinded(&s1, &mut SysRng).unwrap());
+
+        let table = FixedBaseTable::from_bytes(&table.to_bytes()).unwrap();
+        assert_eq!(reference, table.mul(&s1));
+    }
+
+    #[test]
+    fn wnaf_base_bytes(
+        p1 in projective(),
+        s1 in scalar()
+    ) {
+        type Base = WnafBase<ProjectivePoint, U5>;
+
+        let mut bytes = [0; 33 * 8];
+        Base::new(&p1).write_bytes(&mut bytes).unwrap();
+        let base = Base::from_bytes(&bytes).unwrap();
+        assert_eq!(p1 * s1, &base * &WnafScalar::new(&s1));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn boxed_wnaf_base_bytes(
+        p1 in projective(),
+        s1 in scalar()
+    ) {
+        let mut wnaf = BoxedWnaf::new();
+        let bytes = wnaf.base(&p1, 1).to_bytes();
+        let mut wnaf = BoxedWnafBase::from_bytes(&bytes).unwrap();
+        assert_eq!(p1 * s1, wnaf.scalar(&s1));
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn fixed_base_table_identity() {
+    let table = FixedBaseTable::<NistP256>::new(&ProjectivePoint::IDENTITY);
+    let bytes = table.to_bytes();
+    assert_eq!(bytes, [0; 33 * 8]);
+
+    let table = FixedBaseTable::<NistP256>::from_bytes(&bytes).unwrap();
+    assert_eq!(table.mul(&Scalar::ONE), ProjectivePoint::IDENTITY);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn fixed_base_table_rejects_malformed_bytes() {
+    let bytes = FixedBaseTable::new(&ProjectivePoint::GENERATOR).to_bytes();
+    assert!(FixedBaseTable::<NistP256>::from_bytes(&bytes[..bytes.len() - 65]).is_err());
+    assert!(FixedBaseTable::<NistP256>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    let mut invalid = bytes.clone();
+    invalid[1] ^= 1;
+    assert!(FixedBaseTable::<NistP256>::from_bytes(&invalid).is_err());
+}
+
+/// `Scalar::to_repr` is big endian, so small scalars have their low bytes at the end.
+#[test]
+fn wnaf_big_endian_scalar_repr() {
+    let point = ProjectivePoint::GENERATOR.double();
+    let base = WnafBase::<ProjectivePoint, U5>::new(&point);
+
+    for k in [1u64, 2, 0x1234, u64::MAX] {
+        let scalar = Scalar::from(k);
+        assert_eq!(scalar.to_repr()[31], k.to_le_bytes()[0]);
+        assert_eq!(&base * &WnafScalar::new(&scalar), point * scalar);
+    }
+}
+
+#[test]
+fn wnaf_base_rejects_malformed_bytes() {
+    type Base = WnafBase<ProjectivePoint, U5>;
+
+    assert_eq!(Base::encoded_len(), 33 * 8);
+    let mut bytes = [0; 33 * 8];
+    Base::new(&ProjectivePoint::GENERATOR)
+        .write_bytes(&mut bytes)
+        .unwrap();
+    assert!(
+        Base::new(&ProjectivePoint::GENERATOR)
+            .write_bytes(&mut [0; 33 * 7])
+            .is_err()
+    );
+    assert!(Base::from_bytes(&bytes[..bytes.len() - 33]).is_err());
+    assert!(Base::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // Uncompressed tag
+    let mut invalid = bytes;
+    invalid[0] = 4;
+    assert!(Base::from_bytes(&invalid).is_err());
+
+    // x-coordinate not reduced modulo p
+    let mut invalid = bytes;
+    invalid[1..33].fill(0xff);
+    assert!(Base::from_bytes(&invalid).is_err());
+
+    #[cfg(feature = "alloc")]
+    {
+        assert!(BoxedWnafBase::from_bytes(&bytes[..33 * 3]).is_err());
+        assert!(BoxedWnafBase::from_bytes(&[]).is_err());
+        assert!(BoxedWnafBase::from_bytes(&invalid).is_err());
+    }
+}
+
+/// Tables of valid points which aren't the odd multiples `[P, 3P, 5P, ...]` are rejected.
+#[test]
+fn wnaf_base_rejects_wrong_multiples() {
+    type Base = WnafBase<ProjectivePoint, U5>;
+
+    let mut bytes = [0; 33 * 8];
+    Base::new(&ProjectivePoint::GENERATOR)
+        .write_bytes(&mut bytes)
+        .unwrap();
+
+    // `3P` and `5P` swapped
+    let mut swapped = bytes;
+    swapped[33..66].copy_from_slice(&bytes[66..99]);
+    swapped[66..99].copy_from_slice(&bytes[33..66]);
+    assert!(Base::from_bytes(&swapped).is_err());
+
+    // Last entry negated
+    let mut negated = bytes;
+    negated[33 * 7] ^= 1;
+    assert!(Base::from_bytes(&negated).is_err());
+
+    #[cfg(feature = "alloc")]
+    {
+        assert!(BoxedWnafBase::from_bytes(&bytes).is_ok());
+        assert!(BoxedWnafBase::from_bytes(&swapped).is_err());
+        assert!(BoxedWnafBase::from_bytes(&negated).is_err());
+    }
 }
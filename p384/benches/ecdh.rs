@@ -0,0 +1,77 @@
+//! secp384r1 ECDH benchmarks
+
+#![allow(missing_docs, clippy::unwrap_used, reason = "benchmark")]
+
+use core::hint::black_box;
+use criterion::{
+    BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::Measurement,
+};
+use hex_literal::hex;
+use p384::{
+    NonZeroScalar, PublicKey, Scalar,
+    ecdh::{diffie_hellman, diffie_hellman_x_only},
+    elliptic_curve::{
+        PrimeField, ecdh::diffie_hellman as diffie_hellman_projective, sec1::ToSec1Point,
+    },
+};
+
+fn test_secret_key() -> NonZeroScalar {
+    NonZeroScalar::new(
+        Scalar::from_repr(
+            hex!("201b432d8df14324182d6261db3e4b3f46a8284482d52e370da41e6cbdf45ec2952f5db7ccbce3bc29449f4fb080ac97").into(),
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn test_public_key() -> PublicKey {
+    let secret = NonZeroScalar::new(
+        Scalar::from_repr(
+            hex!("23d9f4ea6d87b7d6163d64256e3449255db14786401a51daa7847161bf56d494325ad2ac8ba928394e01061d882c3528").into(),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    PublicKey::from_secret_scalar(&secret)
+}
+
+fn bench_diffie_hellman<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let secret = test_secret_key();
+    let public = test_public_key();
+
+    group.bench_function("diffie_hellman (projective)", |b| {
+        b.iter(|| diffie_hellman_projective(black_box(&secret), public.as_affine()));
+    });
+
+    group.bench_function("diffie_hellman (x-only)", |b| {
+        b.iter(|| diffie_hellman(black_box(&secret), public.as_affine()));
+    });
+}
+
+fn bench_diffie_hellman_compressed<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let secret = test_secret_key();
+    let compressed = test_public_key().as_affine().to_sec1_point(true);
+
+    group.bench_function("diffie_hellman compressed (projective)", |b| {
+        b.iter(|| {
+            let public = PublicKey::from_sec1_bytes(compressed.as_bytes()).unwrap();
+            diffie_hellman_projective(black_box(&secret), public.as_affine())
+        });
+    });
+
+    group.bench_function("diffie_hellman compressed (x-only)", |b| {
+        b.iter(|| diffie_hellman_x_only(black_box(&secret), compressed.as_bytes()));
+    });
+}
+
+fn bench_ecdh(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ECDH");
+    bench_diffie_hellman(&mut group);
+    bench_diffie_hellman_compressed(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_ecdh);
+criterion_main!(benches);
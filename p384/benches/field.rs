@@ -9,6 +9,7 @@ use criterion::{
 use elliptic_curve::{ff::Field, hazmat::FieldArithmetic};
 use hex_literal::hex;
 use p384::NistP384;
+use primeorder::FieldExt;
 
 type FieldElement = <NistP384 as FieldArithmetic>::FieldElement;
 
@@ -32,6 +33,22 @@ fn bench_field_element_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>
     group.bench_function("mul", |b| b.iter(|| x * y));
 }
 
+fn bench_field_element_sum_of_products<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_field_element_x();
+    let y = test_field_element_y();
+    let a = [x, y];
+    let b = [y, x.square()];
+    group.bench_function("sum_of_products (2 terms)", |bench| {
+        bench.iter(|| FieldExt::sum_of_products(black_box(&a), black_box(&b)));
+    });
+    group.bench_function("mul + mul + add", |bench| {
+        bench.iter(|| {
+            let (a, b) = black_box((&a, &b));
+            a[0] * b[0] + a[1] * b[1]
+        });
+    });
+}
+
 fn bench_field_element_square<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_field_element_x();
     group.bench_function("square", |b| b.iter(|| x.square()));
@@ -51,6 +68,7 @@ fn bench_field_element(c: &mut Criterion) {
     let mut group = c.benchmark_group("field element operations");
     bench_field_element_mul(&mut group);
     bench_field_element_square(&mut group);
+    bench_field_element_sum_of_products(&mut group);
     bench_field_element_invert(&mut group);
     bench_field_element_sqrt(&mut group);
     group.finish();
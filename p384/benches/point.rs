@@ -0,0 +1,32 @@
+//! secp384r1 `ProjectivePoint` benchmarks
+
+#![allow(missing_docs, reason = "benchmark")]
+
+use core::hint::black_box;
+use criterion::{
+    BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::Measurement,
+};
+use p384::{AffinePoint, ProjectivePoint, elliptic_curve::group::Group};
+
+fn bench_point_add<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let p = ProjectivePoint::GENERATOR.double();
+    let q = ProjectivePoint::GENERATOR;
+    let a = AffinePoint::GENERATOR;
+    group.bench_function("add", |b| b.iter(|| black_box(p) + black_box(q)));
+    group.bench_function("add_mixed", |b| b.iter(|| black_box(p) + black_box(a)));
+}
+
+fn bench_point_double<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let p = ProjectivePoint::GENERATOR;
+    group.bench_function("double", |b| b.iter(|| black_box(p).double()));
+}
+
+fn bench_point(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ProjectivePoint operations");
+    bench_point_add(&mut group);
+    bench_point_double(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_point);
+criterion_main!(benches);
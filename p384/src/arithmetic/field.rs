@@ -88,6 +88,7 @@ mod tests {
     };
 
     primefield::test_primefield!(FieldElement, U384);
+    primefield::test_field_ext!(FieldElement);
 
     #[cfg(p384_backend = "fiat")]
     primefield::test_fiat_monty_field_arithmetic!(
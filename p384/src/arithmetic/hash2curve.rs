@@ -1,10 +1,7 @@
 use super::FieldElement;
-use crate::{AffinePoint, FieldBytes, NistP384, ProjectivePoint, Scalar};
+use crate::{AffinePoint, NistP384, ProjectivePoint};
 use elliptic_curve::{
-    array::Array,
-    bigint::{ArrayEncoding, U384},
     consts::{U24, U72},
-    ops::Reduce,
     subtle::Choice,
 };
 use hash2curve::MapToCurve;
@@ -18,24 +15,6 @@ impl hash2curve::GroupDigest for NistP384 {
     type ExpandMsg = hash2curve::ExpandMsgXmd<sha2::Sha384>;
 }
 
-impl Reduce<Array<u8, U72>> for FieldElement {
-    fn reduce(value: &Array<u8, U72>) -> Self {
-        const F_2_288: FieldElement = FieldElement::from_hex_vartime(
-            "000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000",
-        );
-
-        let mut d0 = FieldBytes::default();
-        d0[12..].copy_from_slice(&value[0..36]);
-        let d0 = FieldElement::from_uint_unchecked(U384::from_be_byte_array(d0));
-
-        let mut d1 = FieldBytes::default();
-        d1[12..].copy_from_slice(&value[36..]);
-        let d1 = FieldElement::from_uint_unchecked(U384::from_be_byte_array(d1));
-
-        d0 * F_2_288 + d1
-    }
-}
-
 impl Sgn0 for FieldElement {
     fn sgn0(&self) -> Choice {
         self.is_odd()
@@ -73,27 +52,9 @@ impl MapToCurve for NistP384 {
     }
 }
 
-impl Reduce<Array<u8, U72>> for Scalar {
-    fn reduce(value: &Array<u8, U72>) -> Self {
-        const F_2_288: Scalar = Scalar::from_hex_vartime(
-            "000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000",
-        );
-
-        let mut d0 = FieldBytes::default();
-        d0[12..].copy_from_slice(&value[0..36]);
-        let d0 = Scalar::reduce(&U384::from_be_byte_array(d0));
-
-        let mut d1 = FieldBytes::default();
-        d1[12..].copy_from_slice(&value[36..]);
-        let d1 = Scalar::reduce(&U384::from_be_byte_array(d1));
-
-        d0 * F_2_288 + d1
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::{NistP384, Scalar, arithmetic::field::FieldElement};
+    use crate::{AffinePoint, NistP384, Scalar, arithmetic::field::FieldElement};
     use elliptic_curve::{
         Curve,
         array::Array,
@@ -106,7 +67,7 @@ mod tests {
     };
     use hash2curve::{self, ExpandMsgXmd, MapToCurve};
     use hex_literal::hex;
-    use primeorder::osswu::OsswuMap;
+    use primeorder::osswu::{AffineOsswuMap, OsswuMap};
     use proptest::{num::u64::ANY, prelude::ProptestConfig, proptest};
     use sha2::Sha384;
 
@@ -351,4 +312,25 @@ mod tests {
             assert_eq!(from_okm, simple_from_okm);
         });
     }
+
+    #[test]
+    fn osswu_preimage() {
+        proptest!(ProptestConfig::with_cases(100), |(b0 in ANY, b1 in ANY, b2 in ANY)| {
+            let u = FieldElement::from(b0) * FieldElement::from(b1) + FieldElement::from(b2);
+            let point = AffinePoint::osswu(&u);
+            let mut found = false;
+
+            for branch in 0..4 {
+                if let Some(v) = point.osswu_preimage(branch).into_option() {
+                    assert_eq!(AffinePoint::osswu(&v), point);
+                    found |= v == u;
+                }
+            }
+
+            assert!(found);
+        });
+    }
+
+    #[cfg(feature = "getrandom")]
+    primeorder::test_elligator_squared!(crate::ProjectivePoint, 48 + 16);
 }
@@ -117,13 +117,6 @@ impl Reduce<U384> for Scalar {
     }
 }
 
-impl Reduce<FieldBytes> for Scalar {
-    #[inline]
-    fn reduce(bytes: &FieldBytes) -> Self {
-        Self::reduce(&U384::from_be_byte_array(*bytes))
-    }
-}
-
 impl ReduceNonZero<U384> for Scalar {
     fn reduce_nonzero(w: &U384) -> Self {
         const ORDER_MINUS_ONE: U384 = NistP384::ORDER.as_ref().wrapping_sub(&U384::ONE);
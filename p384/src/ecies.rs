@@ -0,0 +1,127 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES) as described in [SEC 1 v2 § 5.1].
+//!
+//! See [`primeorder::ecies`] for the supported options. The hash function defaults to SHA-384.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "ecies", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "ecies", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `ecies` and `getrandom` crate features are enabled
+//! use p384::{
+//!     ecies::DecryptingKey,
+//!     elliptic_curve::{Generate, common::getrandom::SysRng},
+//! };
+//!
+//! // Recipient
+//! let decrypting_key: DecryptingKey = DecryptingKey::generate();
+//! let encrypting_key = decrypting_key.encrypting_key();
+//!
+//! // Sender
+//! let ciphertext = encrypting_key.encrypt(&mut SysRng, b"hello world")?;
+//!
+//! // Recipient
+//! let plaintext = decrypting_key.decrypt(&ciphertext)?;
+//! assert_eq!(plaintext, b"hello world");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [SEC 1 v2 § 5.1]: https://www.secg.org/sec1-v2.pdf#page=47
+
+pub use primeorder::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params, PointEncoding};
+
+use crate::NistP384;
+use sha2::Sha384;
+
+/// ECIES encrypting key for P-384, using the hash function `D`.
+pub type EncryptingKey<D = Sha384> = primeorder::ecies::EncryptingKey<NistP384, D>;
+
+/// ECIES decrypting key for P-384, using the hash function `D`.
+pub type DecryptingKey<D = Sha384> = primeorder::ecies::DecryptingKey<NistP384, D>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    primeorder::test_ecies!(
+        crate::NistP384,
+        Sha384,
+        secret_key: hex!(
+            "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5"
+        ),
+        public_key: hex!(
+            "04ec3a4e415b4e19a4568618029f427fa5da9a8bc4ae92e02e06aae5286b300c64def8f0ea9055866064a254515480bc138015d9b72d7d57244ea8ef9ac0c621896708a59367f9dfb9f54ca84b3f1c9db1288b231c3ae0d4fe7344fd2533264720"
+        ),
+        ephemeral_key: hex!(
+            "00000000000000000000000000002b5e47d829c94bdcdaf8f6ba987c5a3826a49310eeccaa8866442310efddbb98ccbb"
+        ),
+        test_vectors: [
+            // SEC 1 `ecies-with-x9-63-kdf`
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: false,
+                ciphertext: hex!(
+                    "04e7e72bdfbe06c98eb2167a7b800973db35f9963738d8a6e735e720197b37c09091a231a6312c06a506e7ea9164a6ce45917d30c8a64b1e879427c2cd267c1f21c09fc9f0f3f4e6cf62ac99f71471563ca0a50b081e557eedb39a2fdd192be0494ed2936413e2e29ef641f7186c01a47482a977859e4f989f253f9c26e8f885514d8f9a2809a612bd6bee18505e040793c0a511a3eee93f89e5cc851ea763a7ea893e94b2212aab8f7d0dfa03328bd777ce3d120c9a308e9062e991141d"
+                ),
+            },
+            // SEC 1 with shared information
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04e7e72bdfbe06c98eb2167a7b800973db35f9963738d8a6e735e720197b37c09091a231a6312c06a506e7ea9164a6ce45917d30c8a64b1e879427c2cd267c1f21c09fc9f0f3f4e6cf62ac99f71471563ca0a50b081e557eedb39a2fdd192be04987287dcee1f13d1a731f60c1e0e3d73f8e7339420eb48fb5c5f69709f88cee688e23b3b34132efe79d56f9f5f2a9ac7cafe79c5ecdb00a6dbb499f34da33637e43fec18b2a772d38ae47ce64fda2039d32e721731e6a0892fe99c485a8"
+                ),
+            },
+            // HKDF over `R̄ || Z`, compressed ephemeral key and truncated tag
+            {
+                params: Params {
+                    kdf: Kdf::Hkdf,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Compressed,
+                    mac_tag: MacTag::Truncated,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "03e7e72bdfbe06c98eb2167a7b800973db35f9963738d8a6e735e720197b37c09091a231a6312c06a506e7ea9164a6ce45cf1d52b5a839dd04cc3971fadf4024321fd21a4ddc30943c99bf5776b862d7a73697aef893a6b599bc89924c5e111950ef77609790ad8173612f629b63314c5b0267f2ec2c"
+                ),
+            },
+            // Bouncy Castle `IESEngine` layout without a block cipher and with a 128-bit MAC key
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::MacKeyFirst,
+                    mac_key_len: Some(16),
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "04e7e72bdfbe06c98eb2167a7b800973db35f9963738d8a6e735e720197b37c09091a231a6312c06a506e7ea9164a6ce45917d30c8a64b1e879427c2cd267c1f21c09fc9f0f3f4e6cf62ac99f71471563ca0a50b081e557eedb39a2fdd192be049bbe31200e8502364cd1e2d4faab13733726121529c3b3b229e30e9d6a152368c8a72b8a8a8f2303f3f7fdfad44abd3b9252d1116f4f879da3b86987eb2a024c456b63ac36b872309ca4fc2b606896fd16e349760454409bb82dd06c91f"
+                ),
+            },
+        ],
+    );
+}
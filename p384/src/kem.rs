@@ -0,0 +1,192 @@
+//! Diffie-Hellman based key encapsulation mechanism `DHKEM(P-384, HKDF-SHA384)` as described
+//! in [RFC 9180 § 4.1], for use with HPKE.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "kem", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "kem", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `kem` and `getrandom` crate features are enabled
+//! use p384::{
+//!     elliptic_curve::{
+//!         Generate,
+//!         common::getrandom::{SysRng, rand_core::UnwrapErr},
+//!     },
+//!     kem::{DecapsulationKey, Encapsulate, TryDecapsulate},
+//! };
+//!
+//! // Recipient
+//! let decapsulation_key = DecapsulationKey::generate();
+//! let encapsulation_key = decapsulation_key.encapsulation_key();
+//!
+//! // Sender
+//! let (enc, sender_secret) = encapsulation_key.encapsulate_with_rng(&mut UnwrapErr(SysRng));
+//!
+//! // Recipient
+//! let recipient_secret = decapsulation_key.try_decapsulate(&enc)?;
+//! assert_eq!(sender_secret, recipient_secret);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 9180 § 4.1]: https://www.rfc-editor.org/rfc/rfc9180#section-4.1
+
+pub use primeorder::dhkem::{
+    Decapsulator, DhKemParams, Encapsulate, Kem, KeyExport, TryDecapsulate, TryKeyInit,
+};
+
+use crate::NistP384;
+use sha2::Sha384;
+
+/// `DHKEM(P-384, HKDF-SHA384)`.
+pub type DhKem = primeorder::dhkem::DhKem<NistP384>;
+
+/// `DHKEM(P-384, HKDF-SHA384)` decapsulation key.
+pub type DecapsulationKey = primeorder::dhkem::DecapsulationKey<NistP384>;
+
+/// `DHKEM(P-384, HKDF-SHA384)` encapsulation key.
+pub type EncapsulationKey = primeorder::dhkem::EncapsulationKey<NistP384>;
+
+/// `DHKEM(P-384, HKDF-SHA384)` shared secret.
+pub type SharedSecret = primeorder::dhkem::SharedSecret<NistP384>;
+
+impl DhKemParams for NistP384 {
+    type Hash = Sha384;
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9180#section-7.1>.
+    const KEM_ID: u16 = 0x0011;
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9180#section-7.1.3>.
+    const BITMASK: u8 = 0xff;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elliptic_curve::sec1::ToSec1Point;
+    use hex_literal::hex;
+
+    /// Base mode test vector from the test vectors of the HPKE post-quantum extension
+    /// (`hpkewg/hpke-pq` at commit `6433c8f`), since RFC 9180 Appendix A has none for P-384.
+    ///
+    /// It doesn't include the ephemeral secret key, which is derived from `ikm_e` instead.
+    struct TestVector {
+        ikm_e: [u8; 48],
+        ikm_r: [u8; 48],
+        sk_r: [u8; 48],
+        pk_r: [u8; 97],
+        enc: [u8; 97],
+        shared_secret: [u8; 48],
+    }
+
+    const TEST_VECTOR: TestVector = TestVector {
+        ikm_e: hex!(
+            "253bec4623463f09f36b496c419fd8830fa68cc2981e720becde042c865ae9b6348d1d2795be4e23d65c19523fb99f21"
+        ),
+        ikm_r: hex!(
+            "65fca3ea3b6db29a62bff28ec53c08710fab10b3798e59b678d3224296d5883f039123471784ce57b0d85a17cd521196"
+        ),
+        sk_r: hex!(
+            "679172205e04663f40fda1018cd46c18ebaa876ede6998ba86b051614ca4d5e4bfbea34b720617a4b958cc80f6305244"
+        ),
+        pk_r: hex!(
+            "04a5f53da8564364255bc36850df793672782a5c9e4a7fb5fb2e2146eb12e4d8477ab1f326a361dfd1e41212109510e813380547c68c0964c1908f16f67b902a061be27b2f8b43f1fab1bf0dbf89f5167ce80aca2c210b8fc0f040699db9ee1229"
+        ),
+        enc: hex!(
+            "049f1da943827d165268869c842962c1feba1fb46402fd3fac50c002cf44bb103c1aa8fb15a848f9908554624b0eac4573ec258788335421dcbfa625bfc9136cfa0e335f0de018e4f9517ae0a8863f1b3631343c49c67fd240213f86af1b235ba4"
+        ),
+        shared_secret: hex!(
+            "f609b68f1e65f077d9cca41ad41d45dd66284adfb8341b9ebdd0ff39c90917a1af423d5b70d6a917ebf469e093023850"
+        ),
+    };
+
+    #[test]
+    fn derive_key_pair() {
+        let key = DecapsulationKey::derive(&TEST_VECTOR.ikm_r).unwrap();
+        assert_eq!(key.to_bytes().as_slice(), TEST_VECTOR.sk_r);
+        assert_eq!(
+            key.encapsulation_key().to_sec1_point().as_bytes(),
+            TEST_VECTOR.pk_r
+        );
+
+        let key = DecapsulationKey::derive(&TEST_VECTOR.ikm_e).unwrap();
+        assert_eq!(
+            key.encapsulation_key().to_sec1_point().as_bytes(),
+            TEST_VECTOR.enc
+        );
+    }
+
+    #[test]
+    fn encapsulate_and_decapsulate() {
+        let ephemeral = DecapsulationKey::derive(&TEST_VECTOR.ikm_e).unwrap();
+        let recipient = DecapsulationKey::from_bytes(&TEST_VECTOR.sk_r.into()).unwrap();
+        let encapsulation_key = EncapsulationKey::from_sec1_bytes(&TEST_VECTOR.pk_r).unwrap();
+
+        let (enc, shared_secret) = encapsulation_key.encapsulate_with_ephemeral(&ephemeral);
+        assert_eq!(enc.as_bytes(), TEST_VECTOR.enc);
+        assert_eq!(shared_secret.as_bytes(), TEST_VECTOR.shared_secret);
+
+        let shared_secret = recipient.decapsulate(&TEST_VECTOR.enc).unwrap();
+        assert_eq!(shared_secret.as_bytes(), TEST_VECTOR.shared_secret);
+    }
+
+    /// No known answer is available for the authenticated mode, so only check that both sides
+    /// agree, and that the shared secret depends on the sender.
+    #[test]
+    fn auth_encapsulate_and_decapsulate() {
+        let ephemeral = DecapsulationKey::derive(&TEST_VECTOR.ikm_e).unwrap();
+        let recipient = DecapsulationKey::from_bytes(&TEST_VECTOR.sk_r.into()).unwrap();
+        let sender = DecapsulationKey::derive(&[0x42; 48]).unwrap();
+
+        let (enc, sender_secret) = recipient
+            .encapsulation_key()
+            .auth_encapsulate_with_ephemeral(&sender, &ephemeral);
+        assert_eq!(enc.as_bytes(), TEST_VECTOR.enc);
+        assert_ne!(sender_secret.as_bytes(), TEST_VECTOR.shared_secret);
+
+        let recipient_secret = recipient
+            .auth_decapsulate(&TEST_VECTOR.enc, sender.encapsulation_key())
+            .unwrap();
+        assert_eq!(recipient_secret.as_bytes(), sender_secret.as_bytes());
+
+        let recipient_secret = recipient
+            .auth_decapsulate(&TEST_VECTOR.enc, ephemeral.encapsulation_key())
+            .unwrap();
+        assert_ne!(recipient_secret.as_bytes(), sender_secret.as_bytes());
+    }
+
+    #[test]
+    fn kem_traits() {
+        let recipient = DecapsulationKey::from_bytes(&TEST_VECTOR.sk_r.into()).unwrap();
+        let encapsulation_key = EncapsulationKey::new(&TEST_VECTOR.pk_r.into()).unwrap();
+        assert_eq!(recipient.encapsulation_key(), &encapsulation_key);
+        assert_eq!(encapsulation_key.to_bytes().as_slice(), TEST_VECTOR.pk_r);
+
+        let shared_secret = recipient.try_decapsulate(&TEST_VECTOR.enc.into()).unwrap();
+        assert_eq!(shared_secret.as_slice(), TEST_VECTOR.shared_secret);
+        assert!(
+            recipient
+                .try_decapsulate_slice(&TEST_VECTOR.enc[1..])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_encapsulated_keys() {
+        let recipient = DecapsulationKey::from_bytes(&TEST_VECTOR.sk_r.into()).unwrap();
+
+        // identity
+        assert!(recipient.decapsulate(&[0]).is_err());
+
+        // compressed
+        let point = EncapsulationKey::from_sec1_bytes(&TEST_VECTOR.enc).unwrap();
+        let compressed = point.as_affine().to_sec1_point(true);
+        assert!(recipient.decapsulate(compressed.as_bytes()).is_err());
+
+        // not on the curve
+        let mut enc = TEST_VECTOR.enc;
+        enc[96] ^= 1;
+        assert!(recipient.decapsulate(&enc).is_err());
+        assert!(EncapsulationKey::new(&enc.into()).is_err());
+    }
+}
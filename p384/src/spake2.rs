@@ -0,0 +1,135 @@
+//! SPAKE2 password-authenticated key exchange as described in [RFC 9382].
+//!
+//! [`Spake2`] implements the `SPAKE2-P384-SHA256-HKDF-HMAC` ciphersuite by default, and
+//! `SPAKE2-P384-SHA512-HKDF-HMAC` with a different hash function.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "spake2", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "spake2", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `spake2` and `getrandom` crate features are enabled
+//! use p384::{
+//!     Scalar,
+//!     elliptic_curve::{Generate, common::getrandom::SysRng},
+//!     spake2::{Role, Spake2},
+//! };
+//!
+//! // Both parties derive `w` from the password with a memory-hard function
+//! let w = Scalar::generate();
+//!
+//! let alice = <Spake2>::try_new(Role::A, &w, b"alice", b"bob", &mut SysRng)?;
+//! let bob = <Spake2>::try_new(Role::B, &w, b"alice", b"bob", &mut SysRng)?;
+//!
+//! // Alice and Bob exchange their messages and compute the shared keys
+//! let alice_keys = alice.finish(bob.message().as_bytes(), b"")?;
+//! let bob_keys = bob.finish(alice.message().as_bytes(), b"")?;
+//!
+//! // Alice and Bob exchange their key confirmation messages and check them
+//! alice_keys.verify_confirmation(bob_keys.confirmation())?;
+//! bob_keys.verify_confirmation(alice_keys.confirmation())?;
+//! assert_eq!(alice_keys.shared_key(), bob_keys.shared_key());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 9382]: https://www.rfc-editor.org/rfc/rfc9382
+
+pub use primeorder::spake2::{Role, Spake2Keys, Spake2Params};
+
+use crate::{NistP384, arithmetic::field::FieldElement};
+use sha2::Sha256;
+
+/// SPAKE2 protocol state for NIST P-384.
+pub type Spake2<'a, D = Sha256> = primeorder::spake2::Spake2<'a, NistP384, D>;
+
+impl Spake2Params for NistP384 {
+    /// See <https://www.rfc-editor.org/rfc/rfc9382#section-6>.
+    const M: (FieldElement, FieldElement) = (
+        FieldElement::from_hex_vartime(
+            "0ff0895ae5ebf6187080a82d82b42e2765e3b2f8749c7e05eba366434b363d3dc36f15314739074d2eb8613fceec2853",
+        ),
+        FieldElement::from_hex_vartime(
+            "97592c55797cdd77c0715cb7df2150220a0119866486af4234f390aad1f6addde5930909adc67a1fc0c99ba3d52dc5dd",
+        ),
+    );
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9382#section-6>.
+    const N: (FieldElement, FieldElement) = (
+        FieldElement::from_hex_vartime(
+            "c72cf2e390853a1c1c4ad816a62fd15824f56078918f43f922ca21518f9c543bb252c5490214cf9aa3f0baab4b665c10",
+        ),
+        FieldElement::from_hex_vartime(
+            "c38b7d7f4e7f320317cd717315a797c7e02933aef68b364cbf84ebc619bedbe21ff5c69ea0f1fed5d7e3200418073f40",
+        ),
+    );
+}
+
+// RFC 9382 Appendix B only has test vectors for P-256, and there are no other published known
+// answers for P-384, so these tests only check the protocol against itself. The code shared with
+// P-256 is covered by its test vectors.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AffinePoint, NonZeroScalar, Scalar};
+    use elliptic_curve::{
+        ff::PrimeField,
+        point::AffineCoordinates,
+        sec1::{FromSec1Point, Sec1Point},
+    };
+    use hex_literal::hex;
+
+    /// Compressed SEC1 encodings of `M` and `N` given in RFC 9382 § 6.
+    const RFC9382_M: [u8; 49] = hex!(
+        "030ff0895ae5ebf6187080a82d82b42e2765e3b2f8749c7e05eba366434b363d3dc36f15314739074d2eb8613fceec2853"
+    );
+    const RFC9382_N: [u8; 49] = hex!(
+        "02c72cf2e390853a1c1c4ad816a62fd15824f56078918f43f922ca21518f9c543bb252c5490214cf9aa3f0baab4b665c10"
+    );
+
+    #[test]
+    fn constants_match_rfc9382() {
+        for (constant, encoded) in [(NistP384::M, RFC9382_M), (NistP384::N, RFC9382_N)] {
+            let point = Sec1Point::<NistP384>::from_bytes(encoded).unwrap();
+            let point = AffinePoint::from_sec1_point(&point).unwrap();
+            assert_eq!(point.x(), constant.0.to_repr());
+            assert_eq!(point.y(), constant.1.to_repr());
+        }
+    }
+
+    #[test]
+    fn key_exchange() {
+        let w = Scalar::from(0x1234_5678_u64);
+        let x = NonZeroScalar::new(Scalar::from(0x9abc_def0_u64)).unwrap();
+        let y = NonZeroScalar::new(Scalar::from(0x0fed_cba9_u64)).unwrap();
+
+        let a = <Spake2>::new_with_secret(Role::A, &w, x, b"server", b"client");
+        let b = <Spake2>::new_with_secret(Role::B, &w, y, b"server", b"client");
+        let a_keys = a.finish(b.message().as_bytes(), b"aad").unwrap();
+        let b_keys = b.finish(a.message().as_bytes(), b"aad").unwrap();
+
+        assert_eq!(a_keys.shared_key(), b_keys.shared_key());
+        assert!(a_keys.verify_confirmation(b_keys.confirmation()).is_ok());
+        assert!(b_keys.verify_confirmation(a_keys.confirmation()).is_ok());
+
+        // different associated data only changes the confirmation keys
+        let b_keys = b.finish(a.message().as_bytes(), b"").unwrap();
+        assert_eq!(a_keys.shared_key(), b_keys.shared_key());
+        assert!(a_keys.verify_confirmation(b_keys.confirmation()).is_err());
+    }
+
+    #[test]
+    fn wrong_password() {
+        let x = NonZeroScalar::new(Scalar::from(0x9abc_def0_u64)).unwrap();
+        let y = NonZeroScalar::new(Scalar::from(0x0fed_cba9_u64)).unwrap();
+
+        let a = <Spake2>::new_with_secret(Role::A, &Scalar::from(1234_u64), x, b"", b"");
+        let b = <Spake2>::new_with_secret(Role::B, &Scalar::from(4321_u64), y, b"", b"");
+        let a_keys = a.finish(b.message().as_bytes(), b"").unwrap();
+        let b_keys = b.finish(a.message().as_bytes(), b"").unwrap();
+
+        assert_ne!(a_keys.shared_key(), b_keys.shared_key());
+        assert!(a_keys.verify_confirmation(b_keys.confirmation()).is_err());
+        assert!(b_keys.verify_confirmation(a_keys.confirmation()).is_err());
+    }
+}
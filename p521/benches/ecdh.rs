@@ -0,0 +1,77 @@
+//! secp521r1 ECDH benchmarks
+
+#![allow(missing_docs, clippy::unwrap_used, reason = "benchmark")]
+
+use core::hint::black_box;
+use criterion::{
+    BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::Measurement,
+};
+use hex_literal::hex;
+use p521::{
+    NonZeroScalar, PublicKey, Scalar,
+    ecdh::{diffie_hellman, diffie_hellman_x_only},
+    elliptic_curve::{
+        PrimeField, ecdh::diffie_hellman as diffie_hellman_projective, sec1::ToSec1Point,
+    },
+};
+
+fn test_secret_key() -> NonZeroScalar {
+    NonZeroScalar::new(
+        Scalar::from_repr(
+            hex!("01d7bb864c5b5ecae019296cf9b5c63a166f5f1113942819b1933d889a96d12245777a99428f93de4fc9a18d709bf91889d7f8dddd522b4c364aeae13c983e9fae46").into(),
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn test_public_key() -> PublicKey {
+    let secret = NonZeroScalar::new(
+        Scalar::from_repr(
+            hex!("017e49b8ea8f9d1b7c0378e378a7a42e68e12cf78779ed41dcd29a090ae7e0f883b0d0f2cbc8f0473c0ad6732bea40d371a7f363bc6537d075bd1a4c23e558b0bc73").into(),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    PublicKey::from_secret_scalar(&secret)
+}
+
+fn bench_diffie_hellman<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let secret = test_secret_key();
+    let public = test_public_key();
+
+    group.bench_function("diffie_hellman (projective)", |b| {
+        b.iter(|| diffie_hellman_projective(black_box(&secret), public.as_affine()));
+    });
+
+    group.bench_function("diffie_hellman (x-only)", |b| {
+        b.iter(|| diffie_hellman(black_box(&secret), public.as_affine()));
+    });
+}
+
+fn bench_diffie_hellman_compressed<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    let secret = test_secret_key();
+    let compressed = test_public_key().as_affine().to_sec1_point(true);
+
+    group.bench_function("diffie_hellman compressed (projective)", |b| {
+        b.iter(|| {
+            let public = PublicKey::from_sec1_bytes(compressed.as_bytes()).unwrap();
+            diffie_hellman_projective(black_box(&secret), public.as_affine())
+        });
+    });
+
+    group.bench_function("diffie_hellman compressed (x-only)", |b| {
+        b.iter(|| diffie_hellman_x_only(black_box(&secret), compressed.as_bytes()));
+    });
+}
+
+fn bench_ecdh(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ECDH");
+    bench_diffie_hellman(&mut group);
+    bench_diffie_hellman_compressed(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_ecdh);
+criterion_main!(benches);
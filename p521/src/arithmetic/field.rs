@@ -543,7 +543,16 @@ impl Field for FieldElement {
     }
 }
 
-impl FieldExt for FieldElement {}
+impl FieldExt for FieldElement {
+    fn add_and_mul(&self, add: &Self, mul: &Self) -> Self {
+        // defer the carry of the sum to the multiplication
+        self.add_loose(add).multiply(&mul.relax())
+    }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        Self::invert_vartime(self)
+    }
+}
 impl PrimeFieldExt for FieldElement {}
 
 impl Generate for FieldElement {
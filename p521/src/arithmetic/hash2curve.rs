@@ -1,8 +1,8 @@
 use super::FieldElement;
-use crate::{AffinePoint, NistP521, ProjectivePoint, Scalar};
+use crate::{AffinePoint, NistP521, ProjectivePoint};
 use elliptic_curve::{
     array::Array,
-    consts::{U32, U98},
+    consts::{U32, U82, U98},
     field,
     ops::Reduce,
     subtle::Choice,
@@ -38,6 +38,16 @@ impl Reduce<Array<u8, U98>> for FieldElement {
     }
 }
 
+/// Reduce the uniformly random encodings of Elligator Squared, which are 16 bytes longer than
+/// [`FieldBytes`](crate::FieldBytes).
+impl Reduce<Array<u8, U82>> for FieldElement {
+    fn reduce(value: &Array<u8, U82>) -> Self {
+        let mut wide = Array::<u8, U98>::default();
+        wide[16..].copy_from_slice(value);
+        Self::reduce(&wide)
+    }
+}
+
 impl Sgn0 for FieldElement {
     fn sgn0(&self) -> Choice {
         self.is_odd()
@@ -76,29 +86,9 @@ impl MapToCurve for NistP521 {
     }
 }
 
-impl Reduce<Array<u8, U98>> for Scalar {
-    fn reduce(value: &Array<u8, U98>) -> Self {
-        const F_2_392: Scalar = Scalar::from_hex_vartime(
-            "000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
-        );
-
-        let mut d0 = Array::default();
-        d0[17..].copy_from_slice(&value[0..49]);
-        let u0 = field::bytes_to_uint::<NistP521>(&d0);
-        let d0 = Scalar::reduce(&u0);
-
-        let mut d1 = Array::default();
-        d1[17..].copy_from_slice(&value[49..]);
-        let u1 = field::bytes_to_uint::<NistP521>(&d1);
-        let d1 = Scalar::reduce(&u1);
-
-        d0 * F_2_392 + d1
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::{NistP521, Scalar, Uint, arithmetic::field::FieldElement};
+    use crate::{AffinePoint, NistP521, Scalar, Uint, arithmetic::field::FieldElement};
     use elliptic_curve::{
         Curve,
         array::Array,
@@ -110,6 +100,7 @@ mod tests {
     };
     use hash2curve::{self, ExpandMsgXmd, MapToCurve};
     use hex_literal::hex;
+    use primeorder::osswu::AffineOsswuMap;
     use proptest::{num, prelude::ProptestConfig, proptest};
     use sha2::Sha512;
 
@@ -394,4 +385,25 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn osswu_preimage() {
+        proptest!(ProptestConfig::with_cases(100), |(b0 in num::u64::ANY, b1 in num::u64::ANY, b2 in num::u64::ANY)| {
+            let u = FieldElement::from(b0) * FieldElement::from(b1) + FieldElement::from(b2);
+            let point = AffinePoint::osswu(&u);
+            let mut found = false;
+
+            for branch in 0..4 {
+                if let Some(v) = point.osswu_preimage(branch).into_option() {
+                    assert_eq!(AffinePoint::osswu(&v), point);
+                    found |= v == u;
+                }
+            }
+
+            assert!(found);
+        });
+    }
+
+    #[cfg(feature = "getrandom")]
+    primeorder::test_elligator_squared!(crate::ProjectivePoint, 66 + 16);
 }
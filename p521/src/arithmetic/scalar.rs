@@ -2,12 +2,11 @@
 //!
 //! Arithmetic implementations are provided by `primefield` and `crypto-bigint`.
 
-use crate::{FieldBytes, NistP521, ORDER_HEX, Uint};
+use crate::{NistP521, ORDER_HEX, Uint};
 use elliptic_curve::{
     Curve as _,
     bigint::Limb,
     ff::PrimeField,
-    field,
     ops::Reduce,
     scalar::{FromUintUnchecked, IsHigh},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
@@ -78,13 +77,6 @@ impl Reduce<Uint> for Scalar {
     }
 }
 
-impl Reduce<FieldBytes> for Scalar {
-    #[inline]
-    fn reduce(bytes: &FieldBytes) -> Self {
-        <Self as Reduce<Uint>>::reduce(&field::bytes_to_uint::<NistP521>(bytes))
-    }
-}
-
 #[cfg(feature = "serde")]
 impl Serialize for Scalar {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -24,6 +24,7 @@ impl PrimeCurveWithBasepointTable<WINDOW_SIZE> for NistP521 {
 pub(crate) mod backend {
     use super::BASEPOINT_TABLE;
     use crate::{NistP521, ProjectivePoint, Scalar};
+    use elliptic_curve::rand_core::TryCryptoRng;
     use primeorder::MulBackend;
 
     /// Backend based on precomputed tables.
@@ -40,5 +41,13 @@ pub(crate) mod backend {
         fn mul_by_generator_vartime(k: &Scalar) -> ProjectivePoint {
             BASEPOINT_TABLE.mul_vartime(k)
         }
+
+        #[inline]
+        fn try_mul_by_generator_blinded<R: TryCryptoRng + ?Sized>(
+            k: &Scalar,
+            rng: &mut R,
+        ) -> Result<ProjectivePoint, R::Error> {
+            BASEPOINT_TABLE.try_mul_blinded(k, rng)
+        }
     }
 }
@@ -43,12 +43,12 @@
 //! # }
 //! ```
 
-pub use elliptic_curve::ecdh::diffie_hellman;
+pub use primeorder::ecdh::{diffie_hellman, diffie_hellman_x_only};
 
 use crate::NistP521;
 
 /// NIST P-521 Ephemeral Diffie-Hellman Secret.
-pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<NistP521>;
+pub type EphemeralSecret = primeorder::ecdh::EphemeralSecret<NistP521>;
 
 /// Shared secret value computed via ECDH key agreement.
 pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<NistP521>;
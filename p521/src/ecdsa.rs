@@ -58,6 +58,11 @@ impl EcdsaCurve for NistP521 {
 }
 
 /// ECDSA/P-521 signing key
+///
+/// When the `blinding` crate feature is enabled, the computation of `[k]G` is blinded with
+/// randomness from the system RNG, see [`ProjectivePoint::mul_blinded`].
+///
+/// [`ProjectivePoint::mul_blinded`]: crate::ProjectivePoint::mul_blinded
 #[cfg(feature = "ecdsa")]
 pub type SigningKey = ecdsa_core::SigningKey<NistP521>;
 
@@ -0,0 +1,127 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES) as described in [SEC 1 v2 § 5.1].
+//!
+//! See [`primeorder::ecies`] for the supported options. The hash function defaults to SHA-512.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "ecies", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "ecies", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `ecies` and `getrandom` crate features are enabled
+//! use p521::{
+//!     ecies::DecryptingKey,
+//!     elliptic_curve::{Generate, common::getrandom::SysRng},
+//! };
+//!
+//! // Recipient
+//! let decrypting_key: DecryptingKey = DecryptingKey::generate();
+//! let encrypting_key = decrypting_key.encrypting_key();
+//!
+//! // Sender
+//! let ciphertext = encrypting_key.encrypt(&mut SysRng, b"hello world")?;
+//!
+//! // Recipient
+//! let plaintext = decrypting_key.decrypt(&ciphertext)?;
+//! assert_eq!(plaintext, b"hello world");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [SEC 1 v2 § 5.1]: https://www.secg.org/sec1-v2.pdf#page=47
+
+pub use primeorder::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params, PointEncoding};
+
+use crate::NistP521;
+use sha2::Sha512;
+
+/// ECIES encrypting key for P-521, using the hash function `D`.
+pub type EncryptingKey<D = Sha512> = primeorder::ecies::EncryptingKey<NistP521, D>;
+
+/// ECIES decrypting key for P-521, using the hash function `D`.
+pub type DecryptingKey<D = Sha512> = primeorder::ecies::DecryptingKey<NistP521, D>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    primeorder::test_ecies!(
+        crate::NistP521,
+        Sha512,
+        secret_key: hex!(
+            "00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b83538"
+        ),
+        public_key: hex!(
+            "0401894550d0785932e00eaa23b694f213f8c3121f86dc97a04e5a7167db4e5bcd371123d46e45db6b5d5370a7f20fb633155d38ffa16d2bd761dcac474b9a2f5023a400493101c962cd4d2fddf782285e64584139c2f91b47f87ff82354d6630f746a28a0db25741b5b34a828008b22acc23f924faafbd4d33f81ea66956dfeaa2bfdfcf5"
+        ),
+        ephemeral_key: hex!(
+            "000000000000000000000000000000000000000000000000000000000000002b5e1ca56b6bb89aeba9e7eba9876b4937b59381ffddbb9977553421ffdecca9ddccbb"
+        ),
+        test_vectors: [
+            // SEC 1 `ecies-with-x9-63-kdf`
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: false,
+                ciphertext: hex!(
+                    "040007a4ed9ea4b0d2fbc9e260ef401d26175d56a0c537180b178dfa3f5b6e5bb73bec6b1402f9fa50ae697d7d0ba46abdb27bbb601c0506400adbb2d8418f7c6aed150037a57bf1b773550f81459fc73dc24f8e2d681090273854722b94129390303d042e09d3bf90ff662275cb5535b30fb22082be08cde297be993bf1ab71b2a492bdca26e679ed05f6859a119a7effb00288972447681ab15ac1c005e83bdcf455f1ef2edc5468332550c979895243eb56deb4119285483d7ad64de68986e6d72d30443cc2975d7e94646e7dde07e360b3e0e45cf7d45d1a433d47a1e9eff61fbdc55462f163706af2e4e5beb73711db"
+                ),
+            },
+            // SEC 1 with shared information
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::SharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "040007a4ed9ea4b0d2fbc9e260ef401d26175d56a0c537180b178dfa3f5b6e5bb73bec6b1402f9fa50ae697d7d0ba46abdb27bbb601c0506400adbb2d8418f7c6aed150037a57bf1b773550f81459fc73dc24f8e2d681090273854722b94129390303d042e09d3bf90ff662275cb5535b30fb22082be08cde297be993bf1ab71b2a492bdca11f77a17e0c44cc019de3ee6f3d0ffd07b7793da3dadc1232841681afd2880640c556a16c5f7715396c4fa5339ea7595851d00fb89daf3890236c4a0d841f68239308fbfb46786175375ddaf89b1f3b2babfc54fbaee8040d681b9d214cc56077b3641a99f85eec07abb0ac4fd"
+                ),
+            },
+            // HKDF over `R̄ || Z`, compressed ephemeral key and truncated tag
+            {
+                params: Params {
+                    kdf: Kdf::Hkdf,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Compressed,
+                    mac_tag: MacTag::Truncated,
+                    mac_input: MacInput::SharedInfo,
+                    key_order: KeyOrder::EncryptionKeyFirst,
+                    mac_key_len: None,
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "020007a4ed9ea4b0d2fbc9e260ef401d26175d56a0c537180b178dfa3f5b6e5bb73bec6b1402f9fa50ae697d7d0ba46abdb27bbb601c0506400adbb2d8418f7c6aed150650236fad36d724a7712cb2bc093a6a8ade42661dead2bc4aac7586c5b4b6ee548a7c3ce99705ae9fcf018fc201adeb82d961df45e7254dc552875d6b7cb1bcb339027c462b7e488e5d6df1dc"
+                ),
+            },
+            // Bouncy Castle `IESEngine` layout without a block cipher and with a 128-bit MAC key
+            {
+                params: Params {
+                    kdf: Kdf::AnsiX963,
+                    kdf_input: KdfInput::EphemeralKeyAndSharedSecret,
+                    point_encoding: PointEncoding::Uncompressed,
+                    mac_tag: MacTag::Full,
+                    mac_input: MacInput::SharedInfoAndLength,
+                    key_order: KeyOrder::MacKeyFirst,
+                    mac_key_len: Some(16),
+                },
+                shared_info: true,
+                ciphertext: hex!(
+                    "040007a4ed9ea4b0d2fbc9e260ef401d26175d56a0c537180b178dfa3f5b6e5bb73bec6b1402f9fa50ae697d7d0ba46abdb27bbb601c0506400adbb2d8418f7c6aed150037a57bf1b773550f81459fc73dc24f8e2d681090273854722b94129390303d042e09d3bf90ff662275cb5535b30fb22082be08cde297be993bf1ab71b2a492bdca32b1597f5dc3d32f3e76cc5dd26a8c786dbb94dcd7d6fbb0f187260081552cda759835ebc932ad31e1864243016a4989e608bae1df51b652cd49c37cebdc4977ec83ba12d972b7f9cd0d10efec67c96c931e441d05a6e49116f1313bca623bf50958e054df6787c9a553df5784"
+                ),
+            },
+        ],
+    );
+}
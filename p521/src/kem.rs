@@ -0,0 +1,127 @@
+//! Diffie-Hellman based key encapsulation mechanism `DHKEM(P-521, HKDF-SHA512)` as described
+//! in [RFC 9180 § 4.1], for use with HPKE.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "kem", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "kem", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `kem` and `getrandom` crate features are enabled
+//! use p521::{
+//!     elliptic_curve::{
+//!         Generate,
+//!         common::getrandom::{SysRng, rand_core::UnwrapErr},
+//!     },
+//!     kem::{DecapsulationKey, Encapsulate, TryDecapsulate},
+//! };
+//!
+//! // Recipient
+//! let decapsulation_key = DecapsulationKey::generate();
+//! let encapsulation_key = decapsulation_key.encapsulation_key();
+//!
+//! // Sender
+//! let (enc, sender_secret) = encapsulation_key.encapsulate_with_rng(&mut UnwrapErr(SysRng));
+//!
+//! // Recipient
+//! let recipient_secret = decapsulation_key.try_decapsulate(&enc)?;
+//! assert_eq!(sender_secret, recipient_secret);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 9180 § 4.1]: https://www.rfc-editor.org/rfc/rfc9180#section-4.1
+
+pub use primeorder::dhkem::{
+    Decapsulator, DhKemParams, Encapsulate, Kem, KeyExport, TryDecapsulate, TryKeyInit,
+};
+
+use crate::NistP521;
+use sha2::Sha512;
+
+/// `DHKEM(P-521, HKDF-SHA512)`.
+pub type DhKem = primeorder::dhkem::DhKem<NistP521>;
+
+/// `DHKEM(P-521, HKDF-SHA512)` decapsulation key.
+pub type DecapsulationKey = primeorder::dhkem::DecapsulationKey<NistP521>;
+
+/// `DHKEM(P-521, HKDF-SHA512)` encapsulation key.
+pub type EncapsulationKey = primeorder::dhkem::EncapsulationKey<NistP521>;
+
+/// `DHKEM(P-521, HKDF-SHA512)` shared secret.
+pub type SharedSecret = primeorder::dhkem::SharedSecret<NistP521>;
+
+impl DhKemParams for NistP521 {
+    type Hash = Sha512;
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9180#section-7.1>.
+    const KEM_ID: u16 = 0x0012;
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9180#section-7.1.3>.
+    const BITMASK: u8 = 0x01;
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    primeorder::test_dhkem!(
+        crate::NistP521,
+        // RFC 9180 Appendix A.6.1
+        base: {
+            ikm_e: hex!(
+                "7f06ab8215105fc46aceeb2e3dc5028b44364f960426eb0d8e4026c2f8b5d7e7a986688f1591abf5ab753c357a5d6f0440414b4ed4ede71317772ac98d9239f70904"
+            ),
+            sk_e: hex!(
+                "014784c692da35df6ecde98ee43ac425dbdd0969c0c72b42f2e708ab9d535415a8569bdacfcc0a114c85b8e3f26acf4d68115f8c91a66178cdbd03b7bcc5291e374b"
+            ),
+            pk_e: hex!(
+                "040138b385ca16bb0d5fa0c0665fbbd7e69e3ee29f63991d3e9b5fa740aab8900aaeed46ed73a49055758425a0ce36507c54b29cc5b85a5cee6bae0cf1c21f2731ece2013dc3fb7c8d21654bb161b463962ca19e8c654ff24c94dd2898de12051f1ed0692237fb02b2f8d1dc1c73e9b366b529eb436e98a996ee522aef863dd5739d2f29b0"
+            ),
+            ikm_r: hex!(
+                "2ad954bbe39b7122529f7dde780bff626cd97f850d0784a432784e69d86eccaade43b6c10a8ffdb94bf943c6da479db137914ec835a7e715e36e45e29b587bab3bf1"
+            ),
+            sk_r: hex!(
+                "01462680369ae375e4b3791070a7458ed527842f6a98a79ff5e0d4cbde83c27196a3916956655523a6a2556a7af62c5cadabe2ef9da3760bb21e005202f7b2462847"
+            ),
+            pk_r: hex!(
+                "0401b45498c1714e2dce167d3caf162e45e0642afc7ed435df7902ccae0e84ba0f7d373f646b7738bbbdca11ed91bdeae3cdcba3301f2457be452f271fa6837580e661012af49583a62e48d44bed350c7118c0d8dc861c238c72a2bda17f64704f464b57338e7f40b60959480c0e58e6559b190d81663ed816e523b6b6a418f66d2451ec64"
+            ),
+            shared_secret: hex!(
+                "776ab421302f6eff7d7cb5cb1adaea0cd50872c71c2d63c30c4f1d5e43653336fef33b103c67e7a98add2d3b66e2fda95b5b2a667aa9dac7e59cc1d46d30e818"
+            ),
+        },
+        // RFC 9180 Appendix A.6.3
+        auth: {
+            ikm_e: hex!(
+                "fe1c589c2a05893895a537f38c7cb4300b5a7e8fef3d6ccb8f07a498029c61e90262e009dc254c7f6235f9c6b2fd6aeff0a714db131b09258c16e217b7bd2aa619b0"
+            ),
+            sk_e: hex!(
+                "0185f03560de87bb2c543ef03607f3c33ac09980000de25eabe3b224312946330d2e65d192d3b4aa46ca92fc5ca50736b624402d95f6a80dc04d1f10ae9517137261"
+            ),
+            pk_e: hex!(
+                "04017de12ede7f72cb101dab36a111265c97b3654816dcd6183f809d4b3d111fe759497f8aefdc5dbb40d3e6d21db15bdc60f15f2a420761bcaeef73b891c2b117e9cf01e29320b799bbc86afdc5ea97d941ea1c5bd5ebeeac7a784b3bab524746f3e640ec26ee1bd91255f9330d974f845084637ee0e6fe9f505c5b87c86a4e1a6c3096dd"
+            ),
+            ikm_r: hex!(
+                "8feea0438481fc0ecd470d6adfcda334a759c6b8650452c5a5dd9b2dd2cc9be33d2bb7ee64605fc07ab4664a58bb9a8de80defe510b6c97d2daf85b92cd4bb0a66bf"
+            ),
+            sk_r: hex!(
+                "013ef326940998544a899e15e1726548ff43bbdb23a8587aa3bef9d1b857338d87287df5667037b519d6a14661e9503cfc95a154d93566d8c84e95ce93ad05293a0b"
+            ),
+            pk_r: hex!(
+                "04007d419b8834e7513d0e7cc66424a136ec5e11395ab353da324e3586673ee73d53ab34f30a0b42a92d054d0db321b80f6217e655e304f72793767c4231785c4a4a6e008f31b93b7a4f2b8cd12e5fe5a0523dc71353c66cbdad51c86b9e0bdfcd9a45698f2dab1809ab1b0f88f54227232c858accc44d9a8d41775ac026341564a2d749f4"
+            ),
+            ikm_s: hex!(
+                "2f66a68b85ef04822b054ef521838c00c64f8b6226935593b69e13a1a2461a4f1a74c10c836e87eed150c0db85d4e4f506cbb746149befac6f5c07dc48a615ef92db"
+            ),
+            sk_s: hex!(
+                "001018584599625ff9953b9305849850d5e34bd789d4b81101139662fbea8b6508ddb9d019b0d692e737f66beae3f1f783e744202aaf6fea01506c27287e359fe776"
+            ),
+            pk_s: hex!(
+                "04015cc3636632ea9a3879e43240beae5d15a44fba819282fac26a19c989fafdd0f330b8521dff7dc393101b018c1e65b07be9f5fc9a28a1f450d6a541ee0d76221133001e8f0f6a05ab79f9b9bb9ccce142a453d59c5abebb5674839d935a3ca1a3fbc328539a60b3bc3c05fed22838584a726b9c176796cad0169ba4093332cbd2dc3a9f"
+            ),
+            shared_secret: hex!(
+                "26648fa2a2deb0bfc56349a590fd4cb7108a51797b634694fc02061e8d91b3576ac736a68bf848fe2a58dfb1956d266e68209a4d631e513badf8f4dcfc00f30a"
+            ),
+        },
+    );
+}
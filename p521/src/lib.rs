@@ -21,6 +21,12 @@
 pub mod ecdh;
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
+#[cfg(feature = "ecies")]
+pub mod ecies;
+#[cfg(feature = "kem")]
+pub mod kem;
+#[cfg(feature = "spake2")]
+pub mod spake2;
 #[cfg(any(feature = "test-vectors", test))]
 pub mod test_vectors;
 
@@ -35,6 +41,8 @@ pub use arithmetic::{AffinePoint, ProjectivePoint, scalar::Scalar};
 pub use elliptic_curve::pkcs8;
 #[cfg(feature = "hash2curve")]
 pub use hash2curve;
+#[cfg(feature = "arithmetic")]
+pub use primeorder::{PublicKeyValidation, ValidationError};
 
 use elliptic_curve::{
     array::Array,
@@ -0,0 +1,134 @@
+//! SPAKE2 password-authenticated key exchange as described in [RFC 9382].
+//!
+//! [`Spake2`] implements the `SPAKE2-P521-SHA512-HKDF-HMAC` ciphersuite.
+//!
+//! # Usage
+//!
+#![cfg_attr(all(feature = "spake2", feature = "getrandom"), doc = "```")]
+#![cfg_attr(not(all(feature = "spake2", feature = "getrandom")), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // NOTE: requires the `spake2` and `getrandom` crate features are enabled
+//! use p521::{
+//!     Scalar,
+//!     elliptic_curve::{Generate, common::getrandom::SysRng},
+//!     spake2::{Role, Spake2},
+//! };
+//!
+//! // Both parties derive `w` from the password with a memory-hard function
+//! let w = Scalar::generate();
+//!
+//! let alice = <Spake2>::try_new(Role::A, &w, b"alice", b"bob", &mut SysRng)?;
+//! let bob = <Spake2>::try_new(Role::B, &w, b"alice", b"bob", &mut SysRng)?;
+//!
+//! // Alice and Bob exchange their messages and compute the shared keys
+//! let alice_keys = alice.finish(bob.message().as_bytes(), b"")?;
+//! let bob_keys = bob.finish(alice.message().as_bytes(), b"")?;
+//!
+//! // Alice and Bob exchange their key confirmation messages and check them
+//! alice_keys.verify_confirmation(bob_keys.confirmation())?;
+//! bob_keys.verify_confirmation(alice_keys.confirmation())?;
+//! assert_eq!(alice_keys.shared_key(), bob_keys.shared_key());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC 9382]: https://www.rfc-editor.org/rfc/rfc9382
+
+pub use primeorder::spake2::{Role, Spake2Keys, Spake2Params};
+
+use crate::{NistP521, arithmetic::field::FieldElement};
+use sha2::Sha512;
+
+/// SPAKE2 protocol state for NIST P-521.
+pub type Spake2<'a, D = Sha512> = primeorder::spake2::Spake2<'a, NistP521, D>;
+
+impl Spake2Params for NistP521 {
+    /// See <https://www.rfc-editor.org/rfc/rfc9382#section-6>.
+    const M: (FieldElement, FieldElement) = (
+        FieldElement::from_hex(
+            "003f06f38131b2ba2600791e82488e8d20ab889af753a41806c5db18d37d85608cfae06b82e4a72cd744c719193562a653ea1f119eef9356907edc9b56979962d7aa",
+        ),
+        FieldElement::from_hex(
+            "01bdd179a3d547610892e9b96dea1eab10bdd7ac5ae0cf75aa0f853bfd185cf782f894301998b11d1898ede2701dca37a2bb50b4f519c3d89a7d054b51fb84912192",
+        ),
+    );
+
+    /// See <https://www.rfc-editor.org/rfc/rfc9382#section-6>.
+    const N: (FieldElement, FieldElement) = (
+        FieldElement::from_hex(
+            "00c7924b9ec017f3094562894336a53c50167ba8c5963876880542bc669e494b2532d76c5b53dfb349fdf69154b9e0048c58a42e8ed04cef052a3bc349d95575cd25",
+        ),
+        FieldElement::from_hex(
+            "01c62bee650c9287a651bb75c7f39a2006873347b769840d261d17760b107e29f091d556a82a2e4cde0c40b84b95b878db2489ef760206424b3fe7968aa8e0b1f334",
+        ),
+    );
+}
+
+// RFC 9382 Appendix B only has test vectors for P-256, and there are no other published known
+// answers for P-521, so these tests only check the protocol against itself. The code shared with
+// P-256 is covered by its test vectors.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AffinePoint, NonZeroScalar, Scalar};
+    use elliptic_curve::{
+        ff::PrimeField,
+        point::AffineCoordinates,
+        sec1::{FromSec1Point, Sec1Point},
+    };
+    use hex_literal::hex;
+
+    /// Compressed SEC1 encodings of `M` and `N` given in RFC 9382 § 6.
+    const RFC9382_M: [u8; 67] = hex!(
+        "02003f06f38131b2ba2600791e82488e8d20ab889af753a41806c5db18d37d85608cfae06b82e4a72cd744c719193562a653ea1f119eef9356907edc9b56979962d7aa"
+    );
+    const RFC9382_N: [u8; 67] = hex!(
+        "0200c7924b9ec017f3094562894336a53c50167ba8c5963876880542bc669e494b2532d76c5b53dfb349fdf69154b9e0048c58a42e8ed04cef052a3bc349d95575cd25"
+    );
+
+    #[test]
+    fn constants_match_rfc9382() {
+        for (constant, encoded) in [(NistP521::M, RFC9382_M), (NistP521::N, RFC9382_N)] {
+            let point = Sec1Point::<NistP521>::from_bytes(encoded).unwrap();
+            let point = AffinePoint::from_sec1_point(&point).unwrap();
+            assert_eq!(point.x(), constant.0.to_repr());
+            assert_eq!(point.y(), constant.1.to_repr());
+        }
+    }
+
+    #[test]
+    fn key_exchange() {
+        let w = Scalar::from(0x1234_5678_u64);
+        let x = NonZeroScalar::new(Scalar::from(0x9abc_def0_u64)).unwrap();
+        let y = NonZeroScalar::new(Scalar::from(0x0fed_cba9_u64)).unwrap();
+
+        let a = <Spake2>::new_with_secret(Role::A, &w, x, b"server", b"client");
+        let b = <Spake2>::new_with_secret(Role::B, &w, y, b"server", b"client");
+        let a_keys = a.finish(b.message().as_bytes(), b"aad").unwrap();
+        let b_keys = b.finish(a.message().as_bytes(), b"aad").unwrap();
+
+        assert_eq!(a_keys.shared_key(), b_keys.shared_key());
+        assert!(a_keys.verify_confirmation(b_keys.confirmation()).is_ok());
+        assert!(b_keys.verify_confirmation(a_keys.confirmation()).is_ok());
+
+        // different associated data only changes the confirmation keys
+        let b_keys = b.finish(a.message().as_bytes(), b"").unwrap();
+        assert_eq!(a_keys.shared_key(), b_keys.shared_key());
+        assert!(a_keys.verify_confirmation(b_keys.confirmation()).is_err());
+    }
+
+    #[test]
+    fn wrong_password() {
+        let x = NonZeroScalar::new(Scalar::from(0x9abc_def0_u64)).unwrap();
+        let y = NonZeroScalar::new(Scalar::from(0x0fed_cba9_u64)).unwrap();
+
+        let a = <Spake2>::new_with_secret(Role::A, &Scalar::from(1234_u64), x, b"", b"");
+        let b = <Spake2>::new_with_secret(Role::B, &Scalar::from(4321_u64), y, b"", b"");
+        let a_keys = a.finish(b.message().as_bytes(), b"").unwrap();
+        let b_keys = b.finish(a.message().as_bytes(), b"").unwrap();
+
+        assert_ne!(a_keys.shared_key(), b_keys.shared_key());
+        assert!(a_keys.verify_confirmation(b_keys.confirmation()).is_err());
+        assert!(b_keys.verify_confirmation(a_keys.confirmation()).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! Batch inversion of field elements using Montgomery's trick.
+//!
+//! Inverting `n` elements costs a single field inversion plus `3(n - 1)` multiplications. Zero
+//! elements are skipped: they are left as zero and do not affect the inverses of the others.
+
+use crate::FieldExt;
+use ff::Field;
+use subtle::Choice;
+
+/// Invert every element of `elements` in place in constant time, using `scratch` as working
+/// space.
+///
+/// Zero elements are left unchanged. Returns a truthy [`Choice`] if every element was nonzero.
+///
+/// # Panics
+/// If `elements` and `scratch` have different lengths.
+pub fn batch_invert<F: Field>(elements: &mut [F], scratch: &mut [F]) -> Choice {
+    assert_eq!(elements.len(), scratch.len(), "scratch length mismatch");
+    let mut all_nonzero = Choice::from(1);
+    let mut acc = F::ONE;
+
+    for (element, prefix) in elements.iter().zip(scratch.iter_mut()) {
+        *prefix = acc;
+        let is_zero = element.is_zero();
+        all_nonzero &= !is_zero;
+        acc.conditional_assign(&(acc * element), !is_zero);
+    }
+
+    // `acc` is a product of nonzero elements and therefore always invertible
+    acc = acc.invert().unwrap_or(F::ZERO);
+
+    for (element, prefix) in elements.iter_mut().zip(scratch.iter()).rev() {
+        let is_zero = element.is_zero();
+        let inverse = *prefix * acc;
+        acc.conditional_assign(&(acc * *element), !is_zero);
+        element.conditional_assign(&inverse, !is_zero);
+    }
+
+    all_nonzero
+}
+
+/// Invert every element of `elements` in place in variable time, using `scratch` as working
+/// space.
+///
+/// Zero elements are left unchanged. Returns a truthy [`Choice`] if every element was nonzero.
+///
+/// # Panics
+/// If `elements` and `scratch` have different lengths.
+pub fn batch_invert_vartime<F>(elements: &mut [F], scratch: &mut [F]) -> Choice
+where
+    F: FieldExt,
+{
+    assert_eq!(elements.len(), scratch.len(), "scratch length mismatch");
+    let mut all_nonzero = true;
+    let mut acc = F::ONE;
+
+    for (element, prefix) in elements.iter().zip(scratch.iter_mut()) {
+        *prefix = acc;
+
+        if bool::from(element.is_zero()) {
+            all_nonzero = false;
+        } else {
+            acc *= element;
+        }
+    }
+
+    // `acc` is a product of nonzero elements and therefore always invertible
+    acc = acc.invert_vartime().unwrap_or(F::ZERO);
+
+    for (element, prefix) in elements.iter_mut().zip(scratch.iter()).rev() {
+        if !bool::from(element.is_zero()) {
+            let inverse = *prefix * acc;
+            acc *= *element;
+            *element = inverse;
+        }
+    }
+
+    Choice::from(u8::from(all_nonzero))
+}
+
+/// Invert every element of a fixed-size array in place in constant time.
+///
+/// Zero elements are left unchanged. Returns a truthy [`Choice`] if every element was nonzero.
+pub fn batch_invert_array<F: Field, const N: usize>(elements: &mut [F; N]) -> Choice {
+    let mut scratch = [F::ONE; N];
+    batch_invert(elements, &mut scratch)
+}
+
+/// Invert every element of a fixed-size array in place in variable time.
+///
+/// Zero elements are left unchanged. Returns a truthy [`Choice`] if every element was nonzero.
+pub fn batch_invert_array_vartime<F, const N: usize>(elements: &mut [F; N]) -> Choice
+where
+    F: FieldExt,
+{
+    let mut scratch = [F::ONE; N];
+    batch_invert_vartime(elements, &mut scratch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        batch_invert, batch_invert_array, batch_invert_array_vartime, batch_invert_vartime,
+    };
+    use crate::{ByteOrder, MontyFieldElement, monty_field_params};
+    use bigint::U256;
+
+    monty_field_params!(
+        name: P256Params,
+        modulus: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        uint: U256,
+        byte_order: ByteOrder::BigEndian,
+        multiplicative_generator: 6,
+        doc: "P-256 field modulus"
+    );
+
+    type FieldElement = MontyFieldElement<P256Params, { U256::LIMBS }>;
+
+    fn inputs() -> [FieldElement; 5] {
+        [2u64, 0, 3, 0xdead_beef, 0].map(FieldElement::from_u64)
+    }
+
+    fn expected() -> [FieldElement; 5] {
+        inputs().map(|fe| fe.invert().unwrap_or(FieldElement::ZERO))
+    }
+
+    #[test]
+    fn slice() {
+        let mut elements = inputs();
+        let mut scratch = [FieldElement::ZERO; 5];
+        assert!(!bool::from(batch_invert(&mut elements, &mut scratch)));
+        assert_eq!(elements, expected());
+
+        let mut elements = inputs();
+        assert!(!bool::from(batch_invert_vartime(
+            &mut elements,
+            &mut scratch
+        )));
+        assert_eq!(elements, expected());
+    }
+
+    #[test]
+    fn array() {
+        let mut elements = inputs();
+        assert!(!bool::from(batch_invert_array(&mut elements)));
+        assert_eq!(elements, expected());
+
+        let mut elements = inputs();
+        assert!(!bool::from(batch_invert_array_vartime(&mut elements)));
+        assert_eq!(elements, expected());
+    }
+
+    #[test]
+    fn all_nonzero() {
+        let mut elements = [1u64, 7, 11].map(FieldElement::from_u64);
+        let expected = elements.map(|fe| fe.invert().unwrap_or(FieldElement::ZERO));
+        assert!(bool::from(batch_invert_array(&mut elements)));
+        assert_eq!(elements, expected);
+    }
+
+    #[test]
+    fn empty() {
+        let mut elements: [FieldElement; 0] = [];
+        assert!(bool::from(batch_invert_array(&mut elements)));
+        assert!(bool::from(batch_invert_array_vartime(&mut elements)));
+    }
+}
@@ -188,3 +188,28 @@ macro_rules! test_field_sqrt {
         }
     };
 }
+
+/// Implement tests for the fused operations of the `FieldExt` trait, comparing them against
+/// separate multiplications and additions.
+#[macro_export]
+macro_rules! test_field_ext {
+    ($fe:tt) => {
+        #[test]
+        fn sum_of_products() {
+            use $crate::FieldExt as _;
+
+            let max = -$fe::ONE;
+            let a = [max, max, $fe::from(3u64), max, $fe::ONE, max];
+            let b = [max, $fe::from(7u64), max, max, $fe::ZERO, max];
+
+            let expected = a
+                .iter()
+                .zip(&b)
+                .fold($fe::ZERO, |acc, (a_i, b_i)| acc + *a_i * b_i);
+
+            assert_eq!($fe::sum_of_products(&a, &b), expected);
+            assert_eq!($fe::sum_of_products(&[max], &[max]), $fe::ONE);
+            assert_eq!($fe::sum_of_products::<0>(&[], &[]), $fe::ZERO);
+        }
+    };
+}
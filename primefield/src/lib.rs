@@ -7,15 +7,19 @@
 )]
 #![forbid(unsafe_code)]
 
+mod batch;
 mod dev;
 mod error;
 mod macros;
 mod monty;
+mod sqrt;
 mod traits;
 
 pub use crate::{
+    batch::{batch_invert, batch_invert_array, batch_invert_array_vartime, batch_invert_vartime},
     error::{Error, Result},
     monty::{MontyFieldBytes, MontyFieldElement, MontyFieldParams, compute_t},
+    sqrt::{SqrtTable, sqrt_table_windows},
     traits::{FieldExt, PrimeFieldExt},
 };
 pub use bigint;
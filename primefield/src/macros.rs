@@ -12,6 +12,9 @@ mod fiat;
 /// - number of bytes in an encoded field element
 /// - byte order to use when encoding/decoding field elements
 /// - documentation string for the field modulus type
+/// - optionally, `sqrt: table` to compute square roots in constant time using a precomputed
+///   [`SqrtTable`][`crate::SqrtTable`], which is considerably faster than the default for fields
+///   with high 2-adicity (i.e. where `p - 1` is divisible by a large power of two)
 ///
 /// ```
 /// use primefield::{ByteOrder, bigint::U256, consts::U32};
@@ -34,6 +37,80 @@ macro_rules! monty_field_params {
         byte_order: $byte_order:expr,
         multiplicative_generator: $multiplicative_generator:expr,
         doc: $doc:expr
+    ) => {
+        $crate::monty_field_params!(
+            @impl
+            name: $name,
+            modulus: $modulus_hex,
+            uint: $uint,
+            byte_order: $byte_order,
+            multiplicative_generator: $multiplicative_generator,
+            doc: $doc,
+            items: {}
+        );
+    };
+    (
+        name: $name:ident,
+        modulus: $modulus_hex:expr,
+        uint: $uint:ty,
+        byte_order: $byte_order:expr,
+        multiplicative_generator: $multiplicative_generator:expr,
+        doc: $doc:expr,
+        sqrt: table
+    ) => {
+        $crate::monty_field_params!(
+            @impl
+            name: $name,
+            modulus: $modulus_hex,
+            uint: $uint,
+            byte_order: $byte_order,
+            multiplicative_generator: $multiplicative_generator,
+            doc: $doc,
+            items: {
+                fn sqrt(
+                    elem: &$crate::MontyFieldElement<Self, { <$uint>::LIMBS }>,
+                ) -> $crate::subtle::CtOption<$crate::MontyFieldElement<Self, { <$uint>::LIMBS }>> {
+                    $name::sqrt_table().sqrt(elem)
+                }
+
+                fn sqrt_ratio(
+                    num: &$crate::MontyFieldElement<Self, { <$uint>::LIMBS }>,
+                    div: &$crate::MontyFieldElement<Self, { <$uint>::LIMBS }>,
+                ) -> (
+                    $crate::subtle::Choice,
+                    $crate::MontyFieldElement<Self, { <$uint>::LIMBS }>,
+                ) {
+                    $name::sqrt_table().sqrt_ratio(num, div)
+                }
+            }
+        );
+
+        impl $name {
+            /// Precomputed table used to compute square roots in constant time.
+            fn sqrt_table() -> &'static $crate::SqrtTable<
+                $name,
+                { <$uint>::LIMBS },
+                { $crate::sqrt_table_windows::<$name, { <$uint>::LIMBS }>() },
+            > {
+                static TABLE: $crate::SqrtTable<
+                    $name,
+                    { <$uint>::LIMBS },
+                    { $crate::sqrt_table_windows::<$name, { <$uint>::LIMBS }>() },
+                > = $crate::SqrtTable::new();
+
+                &TABLE
+            }
+        }
+    };
+    (
+        @impl
+        name: $name:ident,
+        modulus: $modulus_hex:expr,
+        uint: $uint:ty,
+        byte_order: $byte_order:expr,
+        multiplicative_generator: $multiplicative_generator:expr,
+        doc: $doc:expr,
+        items: { $($items:tt)* }
     ) => {
         use $crate::bigint::modular::ConstMontyParams;
 
@@ -52,6 +129,8 @@ macro_rules! monty_field_params {
             const BYTE_ORDER: $crate::ByteOrder = $byte_order;
             const MODULUS_HEX: &'static str = $modulus_hex;
             const T: $uint = $crate::compute_t($name::PARAMS.modulus().as_ref());
+
+            $($items)*
         }
     };
 }
@@ -73,6 +152,7 @@ macro_rules! monty_field_params {
 /// - `pub fn to_canonical`
 /// - `pub fn is_odd`
 /// - `pub fn is_zero`
+/// - `pub fn is_square`
 /// - `pub fn double`
 ///
 /// # Trait impls
@@ -138,6 +218,18 @@ macro_rules! monty_field_element {
                     .map(Self)
             }
 
+            /// Reduce a uniformly random byte string of up to twice the size of [`
+            #[doc = stringify!($uint)]
+            /// `] to a [`
+            #[doc = stringify!($fe)]
+            /// `] using the field's configured byte order.
+            ///
+            /// # Panics
+            /// If `bytes` is too long.
+            pub fn from_uniform_bytes(bytes: &[u8]) -> Self {
+                Self($crate::MontyFieldElement::<$params, { <$params>::LIMBS }>::from_uniform_bytes(bytes))
+            }
+
             /// Decode a [`
             #[doc = stringify!($fe)]
             /// `] from hex-encoded bytes using the field's configured byte order.
@@ -242,6 +334,17 @@ macro_rules! monty_field_element {
                 self.0.is_zero()
             }
 
+            /// Determine if this [`
+            #[doc = stringify!($fe)]
+            /// `] is a square, i.e. a quadratic residue. Zero is considered a square.
+            ///
+            /// # Returns
+            ///
+            /// If square, return `Choice(1)`.  Otherwise, return `Choice(0)`.
+            pub fn is_square(&self) -> $crate::subtle::Choice {
+                self.0.is_square()
+            }
+
             /// Returns `self^exp`, where `exp` is a little-endian integer exponent.
             ///
             /// **This operation is variable time with respect to the exponent `exp`.**
@@ -310,7 +413,8 @@ macro_rules! monty_field_element {
             }
 
             fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
-                $crate::ff::helpers::sqrt_ratio_generic(num, div)
+                let (is_square, root) = $crate::ff::Field::sqrt_ratio(&num.0, &div.0);
+                (is_square, Self(root))
             }
         }
 
@@ -352,7 +456,18 @@ macro_rules! monty_field_element {
             }
         }
 
-        impl $crate::FieldExt for $fe {}
+        impl $crate::FieldExt for $fe {
+            fn sum_of_products<const N: usize>(a: &[Self; N], b: &[Self; N]) -> Self {
+                Self($crate::MontyFieldElement::sum_of_products(
+                    &a.map(|a_i| a_i.0),
+                    &b.map(|b_i| b_i.0),
+                ))
+            }
+
+            fn invert_vartime(&self) -> $crate::subtle::CtOption<Self> {
+                self.0.invert_vartime().map(Self)
+            }
+        }
 
         impl $crate::PrimeFieldExt for $fe {
             const REPR_ENDIANNESS: $crate::ByteOrder =
@@ -472,6 +587,18 @@ macro_rules! monty_field_element {
             }
         }
 
+        impl<L> $crate::bigint::Reduce<$crate::array::Array<u8, L>> for $fe
+        where
+            L: $crate::array::ArraySize,
+            $crate::MontyFieldElement<$params, { <$params>::LIMBS }>:
+                $crate::bigint::Reduce<$crate::array::Array<u8, L>>,
+        {
+            #[inline]
+            fn reduce(bytes: &$crate::array::Array<u8, L>) -> Self {
+                Self($crate::bigint::Reduce::reduce(bytes))
+            }
+        }
+
         impl From<$crate::MontyFieldElement::<$params, { <$params>::LIMBS }>> for $fe {
             fn from(fe: $crate::MontyFieldElement::<$params, { <$params>::LIMBS }>) -> $fe {
                 $fe(fe)
@@ -720,8 +847,10 @@ macro_rules! monty_field_arithmetic {
     };
 }
 
-/// Write `Reduce` impls for a particular field implementation which delegate to
+/// Write a `Reduce` impl for a particular field implementation's `Uint` type which delegates to
 /// `MontyFieldElement`.
+///
+/// Reductions of byte strings are provided by [`monty_field_element!`].
 #[macro_export]
 macro_rules! monty_field_reduce {
     (
@@ -734,15 +863,6 @@ macro_rules! monty_field_reduce {
                 Self($crate::MontyFieldElement::<$params, { <$params>::LIMBS }>::reduce(w))
             }
         }
-
-        impl $crate::bigint::Reduce<$crate::MontyFieldBytes<$params, { <$params>::LIMBS }>>
-            for $fe
-        {
-            #[inline]
-            fn reduce(bytes: &$crate::MontyFieldBytes<$params, { <$params>::LIMBS }>) -> Self {
-                Self($crate::MontyFieldElement::<$params, { <$params>::LIMBS }>::reduce(bytes))
-            }
-        }
     };
 }
 
@@ -1,10 +1,13 @@
 //! Field elements which use an internal Montgomery form representation, implemented using
 //! `crypto-bigint`'s [`MontyForm`].
 
-use crate::ByteOrder;
+use crate::{ByteOrder, FieldExt};
 use bigint::{
-    ArrayEncoding, ByteArray, Invert, Reduce, Uint, Word, ctutils,
-    hybrid_array::{Array, ArraySize, typenum::Unsigned},
+    ArrayEncoding, ByteArray, Invert, Reduce, Uint, WideWord, Word, ctutils,
+    hybrid_array::{
+        Array, ArraySize,
+        typenum::{IsLessOrEqual, Prod, True, U2, Unsigned},
+    },
     modular::{
         ConstMontyForm as MontyForm, ConstMontyParams, ConstPrimeMontyParams, FixedMontyParams,
         Retrieve,
@@ -38,6 +41,33 @@ pub trait MontyFieldParams<const LIMBS: usize>: ConstPrimeMontyParams<LIMBS> {
 
     /// `T = (modulus - 1) >> S`, where `S = (modulus - 1).trailing_zeros()`
     const T: Uint<LIMBS>;
+
+    /// Compute the square root of a field element.
+    ///
+    /// The default implementation is provided by `crypto-bigint`. Fields with high 2-adicity can
+    /// override it with a [`SqrtTable`][`crate::SqrtTable`], e.g. using the `sqrt: table` option of
+    /// [`monty_field_params!`][`crate::monty_field_params`].
+    #[must_use]
+    fn sqrt(elem: &MontyFieldElement<Self, LIMBS>) -> CtOption<MontyFieldElement<Self, LIMBS>> {
+        elem.inner
+            .sqrt()
+            .map(|inner| MontyFieldElement { inner })
+            .into()
+    }
+
+    /// Compute the square root of `num / div`, with the semantics of [`Field::sqrt_ratio`].
+    ///
+    /// The default implementation uses [`ff::helpers::sqrt_ratio_generic`].
+    #[must_use]
+    fn sqrt_ratio(
+        num: &MontyFieldElement<Self, LIMBS>,
+        div: &MontyFieldElement<Self, LIMBS>,
+    ) -> (Choice, MontyFieldElement<Self, LIMBS>)
+    where
+        MontyFieldElement<Self, LIMBS>: PrimeField,
+    {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
 }
 
 /// Serialized representation of a field element.
@@ -67,6 +97,9 @@ where
         inner: MontyForm::ONE,
     };
 
+    /// `-p^-1 mod 2^Word::BITS`, used for Montgomery reduction.
+    const MOD_NEG_INV: Word = mod_neg_inv(MOD::PARAMS.modulus().as_ref().as_words()[0]);
+
     /// Number of limbs used by the internal integer representation.
     pub const LIMBS: usize = LIMBS;
 
@@ -112,6 +145,64 @@ where
         Self::from_bytes(&array).into()
     }
 
+    /// Reduce a uniformly random byte string, e.g. the output of a hash function or XOF, to a
+    /// field element, interpreting it using the field's configured byte order.
+    ///
+    /// The input may be up to twice as long as the internal integer representation. To make the
+    /// bias of the result negligible, the input should be at least `k` bits longer than the
+    /// modulus for a security level of `k` bits, which is what `hash_to_field` from RFC 9380 does.
+    ///
+    /// # Panics
+    /// If `bytes` is more than twice as long as the internal integer representation.
+    #[must_use]
+    pub fn from_uniform_bytes(bytes: &[u8]) -> Self
+    where
+        Uint<LIMBS>: ArrayEncoding,
+    {
+        let uint_size = <Uint<LIMBS> as ArrayEncoding>::ByteSize::USIZE;
+        assert!(
+            bytes.len() <= 2 * uint_size,
+            "input is too long to be reduced"
+        );
+
+        // Split the input as `hi * 2^(8 * uint_size) + lo`
+        let (hi, lo) = match MOD::BYTE_ORDER {
+            ByteOrder::BigEndian => bytes.split_at(bytes.len().saturating_sub(uint_size)),
+            ByteOrder::LittleEndian => {
+                let (lo, hi) = bytes.split_at(bytes.len().min(uint_size));
+                (hi, lo)
+            }
+        };
+
+        // `2^(8 * uint_size)` is the Montgomery radix `R`, so `hi * R` can be computed by
+        // multiplying by the Montgomery representation of one.
+        let r = Self::from_uint_reduced(Self::ONE.as_montgomery());
+        let hi = Self::from_uint_reduced(&Self::uint_from_slice(hi));
+        let lo = Self::from_uint_reduced(&Self::uint_from_slice(lo));
+        hi.multiply(&r).add(&lo)
+    }
+
+    /// Decode a [`Uint`] from a slice no longer than its serialized size, using the field's
+    /// configured byte order.
+    fn uint_from_slice(slice: &[u8]) -> Uint<LIMBS>
+    where
+        Uint<LIMBS>: ArrayEncoding,
+    {
+        let mut byte_array = ByteArray::<Uint<LIMBS>>::default();
+        let offset = byte_array.len() - slice.len();
+
+        match MOD::BYTE_ORDER {
+            ByteOrder::BigEndian => {
+                byte_array[offset..].copy_from_slice(slice);
+                Uint::from_be_byte_array(byte_array)
+            }
+            ByteOrder::LittleEndian => {
+                byte_array[..slice.len()].copy_from_slice(slice);
+                Uint::from_le_byte_array(byte_array)
+            }
+        }
+    }
+
     /// Decode a field element from hex-encoded bytes.
     ///
     /// This is primarily intended for defining constants using hex literals.
@@ -306,6 +397,15 @@ where
         self.ct_eq(&Self::ZERO)
     }
 
+    /// Determine if this field element is a square (i.e. a quadratic residue) by computing its
+    /// Jacobi symbol in constant time.
+    ///
+    /// Zero is considered a square.
+    #[must_use]
+    pub fn is_square(&self) -> Choice {
+        !Choice::from(self.inner.jacobi_symbol().is_minus_one())
+    }
+
     /// Translate field element out of the Montgomery domain, returning a [`Uint`] in canonical form.
     #[inline]
     #[must_use]
@@ -367,6 +467,65 @@ where
         }
     }
 
+    /// Compute the sum of products `a[0] * b[0] + a[1] * b[1] + ... + a[N - 1] * b[N - 1]`.
+    ///
+    /// The double-width products are accumulated before performing a single Montgomery reduction,
+    /// rather than reducing each product individually.
+    #[must_use]
+    pub fn sum_of_products<const N: usize>(a: &[Self; N], b: &[Self; N]) -> Self {
+        // T = t[0] + t[1] * R + top * R^2 where R = 2^(Word::BITS * LIMBS), with T < N * p^2
+        let mut t = [[0; LIMBS]; 2];
+        let mut top: Word = 0;
+
+        for (x, y) in a.iter().zip(b) {
+            let product = widening_mul(x.as_montgomery().as_words(), y.as_montgomery().as_words());
+            let mut carry = 0;
+
+            for (acc, word) in t.iter_mut().flatten().zip(product.iter().flatten()) {
+                (*acc, carry) = adc(*acc, *word, carry);
+            }
+
+            top = top.wrapping_add(carry);
+        }
+
+        // Montgomery reduction: add multiples of `p` to clear the low half, then divide by R
+        let modulus = MOD::PARAMS.modulus().as_ref().as_words();
+        for i in 0..LIMBS {
+            let m = t[0][i].wrapping_mul(Self::MOD_NEG_INV);
+            let mut carry = 0;
+
+            for k in i..(2 * LIMBS) {
+                let word = &mut t[k / LIMBS][k % LIMBS];
+                let p = if k < i + LIMBS { modulus[k - i] } else { 0 };
+                (*word, carry) = mac(*word, m, p, carry);
+            }
+
+            top = top.wrapping_add(carry);
+        }
+
+        // The result `(T + m * p) / R` is less than `(N + 1) * p`, so at most `N` subtractions of
+        // the modulus are needed to fully reduce it
+        let [_, mut ret] = t;
+        for _ in 0..N {
+            let mut diff = [0; LIMBS];
+            let mut borrow = 0;
+
+            for (d, (word, p)) in diff.iter_mut().zip(ret.iter().zip(modulus)) {
+                (*d, borrow) = sbb(*word, *p, borrow);
+            }
+
+            let (top_diff, underflow) = top.overflowing_sub(borrow);
+            let choice = !Choice::from(u8::from(underflow));
+            top.conditional_assign(&top_diff, choice);
+
+            for (word, d) in ret.iter_mut().zip(&diff) {
+                word.conditional_assign(d, choice);
+            }
+        }
+
+        Self::from_montgomery_words(ret)
+    }
+
     /// Compute field inversion: `1 / self`.
     #[inline]
     #[must_use]
@@ -465,11 +624,11 @@ where
     }
 
     fn sqrt(&self) -> CtOption<Self> {
-        self.inner.sqrt().map(|inner| Self { inner }).into()
+        MOD::sqrt(self)
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
-        ff::helpers::sqrt_ratio_generic(num, div)
+        MOD::sqrt_ratio(num, div)
     }
 }
 
@@ -504,6 +663,21 @@ where
     }
 }
 
+impl<MOD, const LIMBS: usize> FieldExt for MontyFieldElement<MOD, LIMBS>
+where
+    MOD: MontyFieldParams<LIMBS>,
+    MontyFieldBytes<MOD, LIMBS>: Copy,
+    Uint<LIMBS>: ArrayEncoding,
+{
+    fn sum_of_products<const N: usize>(a: &[Self; N], b: &[Self; N]) -> Self {
+        Self::sum_of_products(a, b)
+    }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        Self::invert_vartime(self)
+    }
+}
+
 //
 // Arithmetic trait impls
 //
@@ -675,7 +849,7 @@ impl<'a, MOD: MontyFieldParams<LIMBS>, const LIMBS: usize>
 impl<MOD, const LIMBS: usize> Invert for MontyFieldElement<MOD, LIMBS>
 where
     MOD: MontyFieldParams<LIMBS>,
-    MontyForm<MOD, LIMBS>: Invert<Output = CtOption<MontyForm<MOD, LIMBS>>>,
+    MontyForm<MOD, LIMBS>: Invert<Output = ctutils::CtOption<MontyForm<MOD, LIMBS>>>,
 {
     type Output = CtOption<Self>;
 
@@ -698,19 +872,20 @@ where
     }
 }
 
-impl<MOD, const LIMBS: usize> Reduce<MontyFieldBytes<MOD, LIMBS>> for MontyFieldElement<MOD, LIMBS>
+/// Reduce a byte string of up to twice the size of the internal integer representation, using
+/// the field's configured byte order. This includes [`MontyFieldBytes`] as well as the wide
+/// outputs of `hash_to_field`. Longer byte strings are rejected at compile time.
+///
+/// See [`MontyFieldElement::from_uniform_bytes`].
+impl<MOD, const LIMBS: usize, L> Reduce<Array<u8, L>> for MontyFieldElement<MOD, LIMBS>
 where
     MOD: MontyFieldParams<LIMBS>,
-    Uint<LIMBS>: ArrayEncoding<ByteSize = MOD::ByteSize>,
+    Uint<LIMBS>: ArrayEncoding<ByteSize: Mul<U2>>,
+    L: ArraySize + IsLessOrEqual<Prod<<Uint<LIMBS> as ArrayEncoding>::ByteSize, U2>, Output = True>,
 {
     #[inline]
-    fn reduce(bytes: &MontyFieldBytes<MOD, LIMBS>) -> Self {
-        let uint = match MOD::BYTE_ORDER {
-            ByteOrder::BigEndian => Uint::<LIMBS>::from_be_byte_array(bytes.clone()),
-            ByteOrder::LittleEndian => Uint::<LIMBS>::from_le_byte_array(bytes.clone()),
-        };
-
-        Self::reduce(&uint)
+    fn reduce(bytes: &Array<u8, L>) -> Self {
+        Self::from_uniform_bytes(bytes)
     }
 }
 
@@ -996,6 +1171,65 @@ pub const fn compute_t<const LIMBS: usize>(modulus: &Uint<LIMBS>) -> Uint<LIMBS>
     modulus.wrapping_sub(&Uint::ONE).unbounded_shr(s)
 }
 
+/// Compute `-p0^-1 mod 2^Word::BITS` for odd `p0`.
+const fn mod_neg_inv(p0: Word) -> Word {
+    // Each Newton iteration doubles the number of correct low bits, and `p0 * p0 = 1 mod 8`
+    let two: Word = 2;
+    let mut inv = p0;
+    let mut bits = 3;
+
+    while bits < Word::BITS {
+        inv = inv.wrapping_mul(two.wrapping_sub(p0.wrapping_mul(inv)));
+        bits *= 2;
+    }
+
+    inv.wrapping_neg()
+}
+
+/// Compute the double-width product of `x` and `y` as `[lo, hi]`.
+fn widening_mul<const LIMBS: usize>(x: &[Word; LIMBS], y: &[Word; LIMBS]) -> [[Word; LIMBS]; 2] {
+    let mut ret = [[0; LIMBS]; 2];
+
+    for i in 0..LIMBS {
+        let mut carry = 0;
+
+        for j in 0..LIMBS {
+            let k = i + j;
+            let word = &mut ret[k / LIMBS][k % LIMBS];
+            (*word, carry) = mac(*word, x[i], y[j], carry);
+        }
+
+        // this word hasn't been written to by any of the previous rows
+        ret[1][i] = carry;
+    }
+
+    ret
+}
+
+/// Compute `a + b * c + carry`, returning the result along with the new carry.
+#[inline(always)]
+#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+const fn mac(a: Word, b: Word, c: Word, carry: Word) -> (Word, Word) {
+    let ret = (a as WideWord) + (b as WideWord) * (c as WideWord) + (carry as WideWord);
+    (ret as Word, (ret >> Word::BITS) as Word)
+}
+
+/// Compute `a + b + carry`, returning the result along with the new carry.
+#[inline(always)]
+#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+const fn adc(a: Word, b: Word, carry: Word) -> (Word, Word) {
+    let ret = (a as WideWord) + (b as WideWord) + (carry as WideWord);
+    (ret as Word, (ret >> Word::BITS) as Word)
+}
+
+/// Compute `a - (b + borrow)`, returning the result along with the new borrow (0 or 1).
+#[inline(always)]
+#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+const fn sbb(a: Word, b: Word, borrow: Word) -> (Word, Word) {
+    let ret = (a as WideWord).wrapping_sub((b as WideWord) + (borrow as WideWord));
+    (ret as Word, (ret >> (2 * Word::BITS - 1)) as Word)
+}
+
 #[cfg(test)]
 mod tests {
     use super::MontyFieldElement;
@@ -1032,6 +1266,60 @@ mod tests {
         assert_eq!(FieldElement::DELTA, FieldElement::from_u64(36));
     }
 
+    #[test]
+    fn from_uniform_bytes() {
+        // 2^256 = (2^256 - 1) + 1
+        let mut bytes = [0u8; 48];
+        bytes[15] = 1;
+        let expected = FieldElement::from_uint_reduced(&U256::MAX).add(&FieldElement::ONE);
+        assert_eq!(FieldElement::from_uniform_bytes(&bytes), expected);
+
+        let expected = FieldElement::from_uint_reduced(&U256::MAX);
+        assert_eq!(FieldElement::from_uniform_bytes(&[0xff; 32]), expected);
+        assert_eq!(
+            FieldElement::from_uniform_bytes(&[0x01, 0x02]),
+            FieldElement::from_u64(0x0102)
+        );
+    }
+
+    #[test]
+    fn sum_of_products() {
+        let max = FieldElement::ZERO.sub(&FieldElement::ONE);
+        let a = [
+            max,
+            max,
+            FieldElement::from_u64(3),
+            max,
+            FieldElement::ONE,
+            max,
+        ];
+        let b = [
+            max,
+            FieldElement::from_u64(7),
+            max,
+            max,
+            FieldElement::ZERO,
+            max,
+        ];
+
+        let expected = a
+            .iter()
+            .zip(&b)
+            .fold(FieldElement::ZERO, |acc, (a_i, b_i)| {
+                acc.add(&a_i.multiply(b_i))
+            });
+
+        assert_eq!(FieldElement::sum_of_products(&a, &b), expected);
+        assert_eq!(
+            FieldElement::sum_of_products(&[max], &[max]),
+            FieldElement::ONE
+        );
+        assert_eq!(
+            FieldElement::sum_of_products::<0>(&[], &[]),
+            FieldElement::ZERO
+        );
+    }
+
     // Regression test for little-endian byte order fix
     // Verifies that from_bytes/to_bytes correctly handle little-endian encoding
     mod little_endian {
@@ -1086,5 +1374,20 @@ mod tests {
         fn computed_delta_constant() {
             assert_eq!(BignP256Element::DELTA, BignP256Element::from_u64(4));
         }
+
+        #[test]
+        fn from_uniform_bytes() {
+            // 2^256 mod p = 189
+            let mut bytes = [0u8; 48];
+            bytes[32] = 1;
+            assert_eq!(
+                BignP256Element::from_uniform_bytes(&bytes),
+                BignP256Element::from_u64(189)
+            );
+            assert_eq!(
+                BignP256Element::from_uniform_bytes(&[0x01, 0x02]),
+                BignP256Element::from_u64(0x0201)
+            );
+        }
     }
 }
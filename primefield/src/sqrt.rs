@@ -0,0 +1,312 @@
+//! Constant-time square roots for fields with high 2-adicity.
+//!
+//! Writing `p - 1 = 2^S * T` with `T` odd, square roots are computed using a table-based variant
+//! of the Tonelli–Shanks algorithm: a single exponentiation by `(T - 1) / 2` reduces the problem
+//! to a discrete logarithm in the subgroup of order `2^S`, which is then solved a few bits at a
+//! time using precomputed powers of the `2^S`-th root of unity.
+//!
+//! The cost of the discrete logarithm step is still quadratic in `S`: the classic constant-time
+//! Tonelli–Shanks algorithm needs about `S^2 / 2` squarings, whereas recovering several bits per
+//! step reduces this by a constant factor to about `S^2 / 8` squarings (plus a linear number of
+//! constant-time table lookups). This matters for fields with a large `S` such as P-224's
+//! (`S = 96`), and is negligible for fields where `S` is small.
+//!
+//! See also: Sarkar, "Computing Square Roots Faster than the Tonelli-Shanks/Bernstein Algorithm"
+//! <https://eprint.iacr.org/2020/1407>
+
+use crate::{MontyFieldElement, MontyFieldParams};
+use bigint::Uint;
+use core::fmt;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// Maximum number of bits of the discrete logarithm recovered per table lookup.
+const WINDOW_BITS: u32 = 4;
+
+/// Number of entries in each table.
+const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+
+/// Number of windows needed by the [`SqrtTable`] for the field with parameters `MOD`.
+#[must_use]
+pub const fn sqrt_table_windows<MOD, const LIMBS: usize>() -> usize
+where
+    MOD: MontyFieldParams<LIMBS>,
+{
+    (MOD::PRIME_PARAMS.s().get() as usize).div_ceil(WINDOW_BITS as usize)
+}
+
+/// Precomputed powers of the `2^S`-th root of unity `z` used to compute square roots in constant
+/// time with the table-based Tonelli–Shanks algorithm.
+///
+/// `WINDOWS` must be equal to [`sqrt_table_windows`].
+#[derive(Clone)]
+pub struct SqrtTable<MOD, const LIMBS: usize, const WINDOWS: usize>
+where
+    MOD: MontyFieldParams<LIMBS>,
+{
+    /// `roots[j] = z^(j * 2^(S - w))`, i.e. the `2^w`-th roots of unity.
+    roots: [MontyFieldElement<MOD, LIMBS>; WINDOW_SIZE],
+
+    /// `inverse_powers[i][j] = z^(-j * 2^(i * w))`.
+    inverse_powers: [[MontyFieldElement<MOD, LIMBS>; WINDOW_SIZE]; WINDOWS],
+}
+
+impl<MOD, const LIMBS: usize, const WINDOWS: usize> SqrtTable<MOD, LIMBS, WINDOWS>
+where
+    MOD: MontyFieldParams<LIMBS>,
+{
+    /// 2-adicity of the field.
+    const S: u32 = MOD::PRIME_PARAMS.s().get();
+
+    /// Number of bits per window: `w = min(S, 4)`.
+    const W: u32 = if Self::S < WINDOW_BITS {
+        Self::S
+    } else {
+        WINDOW_BITS
+    };
+
+    /// `(T - 1) / 2`, where `T` is odd.
+    const T_MINUS_ONE_DIV_TWO: Uint<LIMBS> = MOD::T.shr_vartime(1);
+
+    /// Compute the table.
+    ///
+    /// This is intended to be evaluated at compile time, i.e. to initialize a `static`.
+    ///
+    /// # Panics
+    /// If `WINDOWS` isn't equal to [`sqrt_table_windows`], or if `p ≡ 3 mod 4` (i.e. `S = 1`)
+    /// in which case a single exponentiation is already optimal.
+    #[must_use]
+    pub const fn new() -> Self {
+        assert!(
+            WINDOWS == sqrt_table_windows::<MOD, LIMBS>(),
+            "wrong number of windows"
+        );
+        assert!(Self::S > 1, "sqrt table is only useful for p ≡ 1 mod 4");
+
+        let generator = MontyFieldElement::from_u32(MOD::PRIME_PARAMS.generator().get());
+        let root_of_unity = generator.pow_vartime(&MOD::T);
+        let root_of_unity_inv = root_of_unity.const_invert();
+
+        let mut roots = [MontyFieldElement::ONE; WINDOW_SIZE];
+        let window_root = root_of_unity.sqn_vartime((Self::S - Self::W) as usize);
+        let mut j = 1;
+        while j < WINDOW_SIZE {
+            roots[j] = roots[j - 1].multiply(&window_root);
+            j += 1;
+        }
+
+        let mut inverse_powers = [[MontyFieldElement::ONE; WINDOW_SIZE]; WINDOWS];
+        let mut base = root_of_unity_inv;
+        let mut i = 0;
+        while i < WINDOWS {
+            let mut j = 1;
+            while j < WINDOW_SIZE {
+                inverse_powers[i][j] = inverse_powers[i][j - 1].multiply(&base);
+                j += 1;
+            }
+
+            base = base.sqn_vartime(Self::W as usize);
+            i += 1;
+        }
+
+        Self {
+            roots,
+            inverse_powers,
+        }
+    }
+
+    /// Compute the square root of `elem` in constant time.
+    ///
+    /// Returns none if `elem` is not a square.
+    #[must_use]
+    pub fn sqrt(
+        &self,
+        elem: &MontyFieldElement<MOD, LIMBS>,
+    ) -> CtOption<MontyFieldElement<MOD, LIMBS>> {
+        // w = a^((T - 1) / 2), so that (a * w)^2 = a * a^T
+        let w = elem.pow_vartime(&Self::T_MINUS_ONE_DIV_TWO);
+        let root = w.multiply(elem);
+        let y = root.multiply(&w);
+        let (is_square, root) = self.finish(root, y);
+        CtOption::new(root, is_square)
+    }
+
+    /// Compute the square root of `num / div` in constant time, without inverting `div`.
+    ///
+    /// Follows the semantics of [`ff::Field::sqrt_ratio`]:
+    /// - `(true, sqrt(num / div))` if `num` and `div` are nonzero and `num / div` is a square.
+    /// - `(true, 0)` if `num` is zero.
+    /// - `(false, 0)` if `num` is nonzero and `div` is zero.
+    /// - `(false, sqrt(G_S * num / div))` if `num / div` is nonzero and not a square, where `G_S`
+    ///   is the `2^S`-th root of unity.
+    #[must_use]
+    pub fn sqrt_ratio(
+        &self,
+        num: &MontyFieldElement<MOD, LIMBS>,
+        div: &MontyFieldElement<MOD, LIMBS>,
+    ) -> (Choice, MontyFieldElement<MOD, LIMBS>) {
+        // d = div^(2^(S - 1) - 1) and v = div^(2^S - 1) = d^2 * div
+        let mut d = MontyFieldElement::ONE;
+        for _ in 1..Self::S {
+            d = d.square().multiply(div);
+        }
+        let v = d.square().multiply(div);
+
+        // With u = num * v and w = u^((T - 1) / 2), the candidate root r = w * num * d satisfies
+        // r^2 = (num / div) * u^T, where u^T lies in the subgroup of order 2^S
+        let u = num.multiply(&v);
+        let w = u.pow_vartime(&Self::T_MINUS_ONE_DIV_TWO);
+        let root = w.multiply(num).multiply(&d);
+        let y = w.square().multiply(&u);
+        let (is_square, root) = self.finish(root, y);
+
+        (is_square & !(div.is_zero() & !num.is_zero()), root)
+    }
+
+    /// Given `root` and an element `y` of the subgroup of order `2^S` with `root^2 = a * y`, solve
+    /// `y = z^e` and return `(e is even, root * z^(-floor(e / 2)))`.
+    ///
+    /// If `e` is even the result is a square root of `a`, otherwise it is a square root of `z * a`.
+    fn finish(
+        &self,
+        mut root: MontyFieldElement<MOD, LIMBS>,
+        mut y: MontyFieldElement<MOD, LIMBS>,
+    ) -> (Choice, MontyFieldElement<MOD, LIMBS>) {
+        let s = Self::S as usize;
+        let w = Self::W as usize;
+        let mut digits = [0u8; WINDOWS];
+
+        // Recover e one window at a time, starting from the least significant bits: after
+        // removing the known low bits, y^(2^(S - (i + 1) * w)) is the 2^w-th root of unity
+        // indexed by the current window
+        for (i, digit) in digits.iter_mut().enumerate() {
+            let end = (i + 1) * w;
+            let t = y.sqn_vartime(s.saturating_sub(end));
+
+            let mut index = 0u8;
+            for (j, unity) in (0u8..).zip(&self.roots[..1 << w]) {
+                index.conditional_assign(&j, unity.ct_eq(&t));
+            }
+
+            // the last window may be narrower than `w` bits
+            *digit = index >> end.saturating_sub(s);
+            y = y.multiply(&lookup(&self.inverse_powers[i], *digit));
+        }
+
+        let is_square = Choice::from(!digits[0] & 1);
+
+        for i in 0..WINDOWS {
+            let next = digits.get(i + 1).copied().unwrap_or(0);
+            let half = (digits[i] >> 1) | ((next & 1) << (w - 1));
+            root = root.multiply(&lookup(&self.inverse_powers[i], half));
+        }
+
+        (is_square, root)
+    }
+}
+
+impl<MOD, const LIMBS: usize, const WINDOWS: usize> Default for SqrtTable<MOD, LIMBS, WINDOWS>
+where
+    MOD: MontyFieldParams<LIMBS>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<MOD, const LIMBS: usize, const WINDOWS: usize> fmt::Debug for SqrtTable<MOD, LIMBS, WINDOWS>
+where
+    MOD: MontyFieldParams<LIMBS>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SqrtTable").finish_non_exhaustive()
+    }
+}
+
+/// Select `table[index]` in constant time.
+fn lookup<MOD, const LIMBS: usize>(
+    table: &[MontyFieldElement<MOD, LIMBS>; WINDOW_SIZE],
+    index: u8,
+) -> MontyFieldElement<MOD, LIMBS>
+where
+    MOD: MontyFieldParams<LIMBS>,
+{
+    let mut ret = MontyFieldElement::ONE;
+    for (j, elem) in (0u8..).zip(table) {
+        ret.conditional_assign(elem, j.ct_eq(&index));
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ByteOrder, MontyFieldElement, monty_field_params, test_primefield};
+    use bigint::U256;
+    use ff::{Field, PrimeField};
+
+    // P-224 base field: p = 2^224 - 2^96 + 1, which has 2-adicity S = 96
+    monty_field_params!(
+        name: P224Params,
+        modulus: "00000000ffffffffffffffffffffffffffffffff000000000000000000000001",
+        uint: U256,
+        byte_order: ByteOrder::BigEndian,
+        multiplicative_generator: 22,
+        doc: "P-224 field modulus",
+        sqrt: table
+    );
+
+    type FieldElement = MontyFieldElement<P224Params, { U256::LIMBS }>;
+
+    test_primefield!(FieldElement, U256);
+
+    /// Squares and non-squares with discrete logarithms spread over the whole 2^S subgroup.
+    fn test_vectors() -> impl Iterator<Item = FieldElement> {
+        let mut x = FieldElement::from_u64(0x1234_5678);
+        (0..128).map(move |i| {
+            x = x.square() + FieldElement::from_u64(i);
+            x
+        })
+    }
+
+    #[test]
+    fn sqrt_table() {
+        assert_eq!(FieldElement::ZERO.sqrt().unwrap(), FieldElement::ZERO);
+        assert!(bool::from(
+            FieldElement::MULTIPLICATIVE_GENERATOR.sqrt().is_none()
+        ));
+        assert!(bool::from(FieldElement::ROOT_OF_UNITY.sqrt().is_none()));
+
+        for a in test_vectors() {
+            let root = a.sqrt();
+            assert_eq!(bool::from(root.is_some()), bool::from(a.is_square()));
+
+            if let Some(root) = root.into_option() {
+                assert_eq!(root.square(), a);
+            }
+
+            let square = a.square();
+            assert_eq!(square.sqrt().unwrap().square(), square);
+        }
+    }
+
+    #[test]
+    fn sqrt_ratio_table() {
+        let one = FieldElement::ONE;
+        let zero = FieldElement::ZERO;
+        assert!(bool::from(FieldElement::sqrt_ratio(&zero, &one).0));
+        assert!(bool::from(FieldElement::sqrt_ratio(&zero, &zero).0));
+        assert!(!bool::from(FieldElement::sqrt_ratio(&one, &zero).0));
+        assert_eq!(FieldElement::sqrt_ratio(&one, &zero).1, zero);
+
+        for (num, div) in test_vectors().zip(test_vectors().skip(1)) {
+            let (is_square, root) = FieldElement::sqrt_ratio(&num, &div);
+            let ratio = num * div.invert().unwrap();
+            assert_eq!(bool::from(is_square), bool::from(ratio.is_square()));
+
+            if bool::from(is_square) {
+                assert_eq!(root.square(), ratio);
+            } else {
+                assert_eq!(root.square(), ratio * FieldElement::ROOT_OF_UNITY);
+            }
+        }
+    }
+}
@@ -1,12 +1,57 @@
 use crate::ByteOrder;
+use rand_core::TryRng;
+use subtle::CtOption;
 
 /// Extension trait for [`ff::Field`], intended as a place to put optimizable arithmetic operations.
 ///
-/// The idea is future versions of this crate can add provided methods which are useful in dense
-/// field arithmetic like `primeorder`'s RCB implementation, and `primefield` can potentially
-/// automatically plug in optimized implementations when available.
-// TODO(tarcieri): add some methods to this trait, e.g. `add_and_mul`, `mul_and_add`
-pub trait FieldExt: ff::Field {}
+/// The provided methods are useful in dense field arithmetic like `primeorder`'s RCB
+/// implementation. Their default implementations are expressed in terms of [`ff::Field`], and
+/// field implementations can override them with optimized versions, e.g. ones which defer
+/// reductions.
+pub trait FieldExt: ff::Field {
+    /// Compute `(self + add) * mul`.
+    #[must_use]
+    fn add_and_mul(&self, add: &Self, mul: &Self) -> Self {
+        (*self + add) * mul
+    }
+
+    /// Compute `self * mul + add`.
+    #[must_use]
+    fn mul_and_add(&self, mul: &Self, add: &Self) -> Self {
+        *self * mul + add
+    }
+
+    /// Compute the sum of products `a[0] * b[0] + a[1] * b[1] + ... + a[N - 1] * b[N - 1]`.
+    #[must_use]
+    fn sum_of_products<const N: usize>(a: &[Self; N], b: &[Self; N]) -> Self {
+        a.iter()
+            .zip(b)
+            .map(|(a_i, b_i)| *a_i * b_i)
+            .reduce(|acc, product| acc + product)
+            .unwrap_or(Self::ZERO)
+    }
+
+    /// Compute the multiplicative inverse of this element in variable time, returning none if it
+    /// is zero.
+    ///
+    /// The default implementation falls back to the constant-time [`ff::Field::invert`].
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    /// Sample a random nonzero element, e.g. for randomizing projective coordinates.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    fn try_random_nonzero<R: TryRng + ?Sized>(rng: &mut R) -> Result<Self, R::Error> {
+        loop {
+            let element = Self::try_random(rng)?;
+            if !bool::from(element.is_zero()) {
+                return Ok(element);
+            }
+        }
+    }
+}
 
 /// Extension trait for [`ff::PrimeField`] which enables specifying the endianness in which
 /// [`ff::PrimeField::Repr`] is encoded.
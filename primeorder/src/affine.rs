@@ -9,15 +9,21 @@ use elliptic_curve::{
     bigint::modular::Retrieve,
     ctutils::{self, CtGt as _, CtSelect as _},
     ff::{Field, PrimeField},
-    group::{CurveAffine, GroupEncoding},
+    group::{CurveAffine, GroupEncoding, UncompressedEncoding},
     ops::{Double, Mul, MulVartime, Neg},
     point::{AffineCoordinates, DecompactPoint, DecompressPoint, NonIdentity},
     rand_core::{TryCryptoRng, TryRng},
-    sec1::{self, CompressedPoint, FromSec1Point, Sec1Point, ToCompactSec1Point, ToSec1Point},
+    sec1::{
+        self, CompressedPoint, FromSec1Point, Sec1Point, ToCompactSec1Point, ToSec1Point,
+        UncompressedPoint,
+    },
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "serde")]
 use serdect::serde::{Deserialize, Serialize, de, ser};
 
@@ -72,6 +78,134 @@ where
         }
     }
 
+    /// Decode a batch of SEC1-encoded points.
+    ///
+    /// The output contains one result per encoding, which is an [`Error`] if that encoding is
+    /// malformed or isn't a point on the curve. Invalid entries don't affect the other entries.
+    /// Like [`FromSec1Point`], the SEC1 identity encoding is accepted.
+    ///
+    /// Decoding affine coordinates involves no field inversions. The square root needed to
+    /// decompress each compressed entry can't be shared between unrelated points, so the cost is
+    /// the same as decoding each entry with [`FromSec1Point`].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn batch_from_sec1<B: AsRef<[u8]>>(encodings: &[B]) -> Vec<Result<Self>> {
+        encodings
+            .iter()
+            .map(|bytes| {
+                let point = Sec1Point::<C>::from_bytes(bytes.as_ref()).map_err(|_| Error)?;
+                Self::try_from(&point)
+            })
+            .collect()
+    }
+
+    /// Compute the sum of each pair of points in affine coordinates.
+    ///
+    /// Affine addition needs one inversion per point, but the inversions of a batch are shared
+    /// using Montgomery's trick, so adding `n` pairs of points costs a single inversion plus
+    /// `O(n)` multiplications. This makes it cheaper than adding the points in projective
+    /// coordinates and normalizing the results.
+    ///
+    /// Runs in constant time: pairs of equal points, pairs of opposite points and the identity
+    /// are handled without branching.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn batch_add(pairs: &[(Self, Self)]) -> Vec<Self> {
+        let mut denominators: Vec<_> = pairs.iter().map(|(p, q)| p.slope(q).1).collect();
+        let mut scratch = denominators.clone();
+        let _ = primefield::batch_invert(&mut denominators, &mut scratch);
+
+        pairs
+            .iter()
+            .zip(denominators)
+            .map(|((p, q), den_inv)| p.add_with_den_inv(q, &den_inv))
+            .collect()
+    }
+
+    /// Compute the sum of each pair of points of an array in affine coordinates.
+    ///
+    /// See [`AffinePoint::batch_add`].
+    #[must_use]
+    pub fn batch_add_array<const N: usize>(pairs: &[(Self, Self); N]) -> [Self; N] {
+        let mut denominators: [C::FieldElement; N] =
+            core::array::from_fn(|i| pairs[i].0.slope(&pairs[i].1).1);
+        let _ = primefield::batch_invert_array(&mut denominators);
+        core::array::from_fn(|i| pairs[i].0.add_with_den_inv(&pairs[i].1, &denominators[i]))
+    }
+
+    /// Double each point in affine coordinates, sharing the inversions using Montgomery's trick.
+    ///
+    /// See [`AffinePoint::batch_add`].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn batch_double(points: &[Self]) -> Vec<Self> {
+        let mut denominators: Vec<_> = points.iter().map(|p| p.y.double()).collect();
+        let mut scratch = denominators.clone();
+        let _ = primefield::batch_invert(&mut denominators, &mut scratch);
+
+        points
+            .iter()
+            .zip(denominators)
+            .map(|(p, den_inv)| p.double_with_den_inv(&den_inv))
+            .collect()
+    }
+
+    /// Double each point of an array in affine coordinates.
+    ///
+    /// See [`AffinePoint::batch_double`].
+    #[must_use]
+    pub fn batch_double_array<const N: usize>(points: &[Self; N]) -> [Self; N] {
+        let mut denominators: [C::FieldElement; N] = core::array::from_fn(|i| points[i].y.double());
+        let _ = primefield::batch_invert_array(&mut denominators);
+        core::array::from_fn(|i| points[i].double_with_den_inv(&denominators[i]))
+    }
+
+    /// Compute the numerator and denominator of the slope of the line through `self` and
+    /// `other`, or of the tangent if the points are equal.
+    ///
+    /// The denominator is zero iff the points have the same x-coordinate but aren't equal, in
+    /// which case their sum is the identity.
+    fn slope(&self, other: &Self) -> (C::FieldElement, C::FieldElement) {
+        let dx = other.x - self.x;
+        let dy = other.y - self.y;
+        let is_double = dx.is_zero() & dy.is_zero();
+
+        let xx = self.x.square();
+        let num = C::FieldElement::conditional_select(
+            &dy,
+            &(xx.double() + xx + C::EQUATION_A),
+            is_double,
+        );
+        let den = C::FieldElement::conditional_select(&dx, &self.y.double(), is_double);
+        (num, den)
+    }
+
+    /// Compute `self + other` given the inverse of the denominator of their slope, as computed
+    /// by [`AffinePoint::slope`].
+    fn add_with_den_inv(&self, other: &Self, den_inv: &C::FieldElement) -> Self {
+        let (num, den) = self.slope(other);
+        let lambda = num * den_inv;
+        let x = lambda.square() - self.x - other.x;
+        let y = lambda * (self.x - x) - self.y;
+
+        let mut sum =
+            Self::conditional_select(&Self { x, y, infinity: 0 }, &Self::IDENTITY, den.is_zero());
+        sum.conditional_assign(self, other.is_identity());
+        sum.conditional_assign(other, self.is_identity());
+        sum
+    }
+
+    /// Compute `self + self` given the inverse of `2y`.
+    fn double_with_den_inv(&self, den_inv: &C::FieldElement) -> Self {
+        let xx = self.x.square();
+        let lambda = (xx.double() + xx + C::EQUATION_A) * den_inv;
+        let x = lambda.square() - self.x.double();
+        let y = lambda * (self.x - x) - self.y;
+
+        // As the curve has prime order, `y` is only zero for the identity
+        Self::conditional_select(&Self { x, y, infinity: 0 }, self, self.is_identity())
+    }
+
     /// Internal RNG that avoids a `TryCryptoRng` bound so we can use it with `group`.
     ///
     /// TODO(tarcieri): find some way to avoid this?
@@ -332,6 +466,43 @@ where
     }
 }
 
+impl<C> UncompressedEncoding for AffinePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    type Uncompressed = UncompressedPoint<C>;
+
+    /// NOTE: not constant-time with respect to identity point
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Sec1Point::<C>::from_bytes(bytes)
+            .map_or_else(
+                |_| {
+                    // The identity is encoded as all zeroes, as with `GroupEncoding`
+                    let is_identity = ctutils::CtEq::ct_eq(
+                        bytes.as_slice(),
+                        Self::Uncompressed::default().as_slice(),
+                    );
+                    ctutils::CtOption::new(Sec1Point::<C>::identity(), is_identity)
+                },
+                ctutils::CtOption::some,
+            )
+            .and_then(|point| Self::from_sec1_point(&point))
+            .into()
+    }
+
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        // The curve has prime order, so every point on the curve is in the subgroup
+        Self::from_uncompressed(bytes)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        let encoded = self.to_sec1_point(false);
+        let mut result = UncompressedPoint::<C>::default();
+        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
+        result
+    }
+}
+
 impl<C> PartialEq for AffinePoint<C>
 where
     C: PrimeCurveParams,
@@ -0,0 +1,86 @@
+//! Randomness for blinding scalar multiplications.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+use elliptic_curve::{
+    array::{Array, ArraySize, typenum::Unsigned},
+    rand_core::{Infallible, TryCryptoRng, TryRng, utils},
+    zeroize::Zeroize,
+};
+
+/// RNG which draws its output from a deterministic generator of secret blocks, such as the
+/// RFC6979 or `bign-genk` `k` generator, for use with [`ProjectivePoint::mul_blinded`].
+///
+/// Continuing the output of the generator which produced `k` yields values which are
+/// unpredictable without the secret key, so deterministic signers can blind `[k]G` without
+/// needing an external RNG.
+///
+/// [`ProjectivePoint::mul_blinded`]: crate::ProjectivePoint::mul_blinded
+pub struct BlindingRng<N, F>
+where
+    N: ArraySize,
+    F: FnMut(&mut Array<u8, N>),
+{
+    fill_block: F,
+    block_size: PhantomData<N>,
+}
+
+impl<N, F> BlindingRng<N, F>
+where
+    N: ArraySize,
+    F: FnMut(&mut Array<u8, N>),
+{
+    /// Create a new [`BlindingRng`] which obtains blocks of output from `fill_block`.
+    #[must_use]
+    pub fn new(fill_block: F) -> Self {
+        Self {
+            fill_block,
+            block_size: PhantomData,
+        }
+    }
+}
+
+impl<N, F> Debug for BlindingRng<N, F>
+where
+    N: ArraySize,
+    F: FnMut(&mut Array<u8, N>),
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlindingRng").finish_non_exhaustive()
+    }
+}
+
+impl<N, F> TryRng for BlindingRng<N, F>
+where
+    N: ArraySize,
+    F: FnMut(&mut Array<u8, N>),
+{
+    type Error = Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+        utils::next_word_via_fill(self)
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+        utils::next_word_via_fill(self)
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+        let mut block = Array::<u8, N>::default();
+        for chunk in dst.chunks_mut(N::USIZE) {
+            (self.fill_block)(&mut block);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        block.zeroize();
+        Ok(())
+    }
+}
+
+impl<N, F> TryCryptoRng for BlindingRng<N, F>
+where
+    N: ArraySize,
+    F: FnMut(&mut Array<u8, N>),
+{
+}
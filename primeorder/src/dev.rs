@@ -148,5 +148,484 @@ macro_rules! test_projective_arithmetic {
                 assert_point_eq!(p, coords);
             }
         }
+
+        #[test]
+        fn test_vector_scalar_mult_vartime() {
+            let generator = $projective::GENERATOR;
+
+            for (k, coords) in $add_vectors
+                .iter()
+                .enumerate()
+                .map(|(k, coords)| (<$scalar>::from(k as u64 + 1), *coords))
+                .chain(
+                    $mul_vectors
+                        .iter()
+                        .cloned()
+                        .map(|(k, x, y)| (<$scalar>::from_repr(k.into()).unwrap(), (x, y))),
+                )
+            {
+                let p = generator.mul_vartime(&k);
+                assert_point_eq!(p, coords);
+            }
+        }
+
+        #[test]
+        fn scalar_mult_vartime_exceptional_cases() {
+            use $crate::{Field, elliptic_curve::ops::LinearCombination};
+
+            let generator = $projective::GENERATOR;
+            let identity = $projective::IDENTITY;
+            let k = <$scalar>::from(3u64);
+
+            assert_eq!(generator.mul_vartime(&<$scalar>::ZERO), identity);
+            assert_eq!(identity.mul_vartime(&k), identity);
+            assert_eq!(generator.mul_vartime(&-k), -(generator * &k));
+            assert_eq!(
+                $projective::lincomb_vartime(&[(generator, k), (generator, -k)]),
+                identity
+            );
+            assert_eq!(
+                $projective::lincomb_vartime(&[(generator, k), (generator, k)]),
+                generator * &(k + k)
+            );
+            assert_eq!(
+                $projective::lincomb_vartime(&[(generator, k), (identity, k)]),
+                generator * &k
+            );
+        }
+    };
+}
+
+/// Implement Elligator Squared round-trip tests, where `$len` is the length of each half of the
+/// encoding.
+///
+/// Requires the `getrandom` feature of `elliptic-curve`.
+#[macro_export]
+macro_rules! test_elligator_squared {
+    ($projective:ty, $len:expr) => {
+        #[test]
+        fn elligator_squared() {
+            use $crate::elligator_squared::ElligatorSquared;
+            use $crate::elliptic_curve::{Generate, common::getrandom::SysRng};
+
+            let mut high_bits = 0;
+
+            for _ in 0..25 {
+                let point = <$projective>::try_generate_from_rng(&mut SysRng).unwrap();
+                let repr = point.try_encode_uniform(&mut SysRng).unwrap();
+                assert_eq!(repr[0].len(), $len);
+                assert_eq!(<$projective>::decode_uniform(&repr), point);
+                high_bits |= repr[0][0] | repr[1][0];
+            }
+
+            // The leading bits of the encodings are random, not determined by the field modulus
+            assert_eq!(high_bits & 0x80, 0x80);
+        }
+    };
+}
+
+/// Implement DHKEM tests from the RFC 9180 Appendix A vectors of the base mode (`Encap`/`Decap`)
+/// and the authenticated mode (`AuthEncap`/`AuthDecap`) of `$curve`.
+///
+/// The key pairs of the ephemeral (`e`), recipient (`r`) and sender (`s`) keys are given by the
+/// `ikm` input of `DeriveKeyPair` and the serialized `sk`/`pk` outputs.
+#[macro_export]
+macro_rules! test_dhkem {
+    (
+        $curve:ty,
+        base: {
+            ikm_e: $base_ikm_e:expr,
+            sk_e: $base_sk_e:expr,
+            pk_e: $base_pk_e:expr,
+            ikm_r: $base_ikm_r:expr,
+            sk_r: $base_sk_r:expr,
+            pk_r: $base_pk_r:expr,
+            shared_secret: $base_shared_secret:expr $(,)?
+        },
+        auth: {
+            ikm_e: $auth_ikm_e:expr,
+            sk_e: $auth_sk_e:expr,
+            pk_e: $auth_pk_e:expr,
+            ikm_r: $auth_ikm_r:expr,
+            sk_r: $auth_sk_r:expr,
+            pk_r: $auth_pk_r:expr,
+            ikm_s: $auth_ikm_s:expr,
+            sk_s: $auth_sk_s:expr,
+            pk_s: $auth_pk_s:expr,
+            shared_secret: $auth_shared_secret:expr $(,)?
+        } $(,)?
+    ) => {
+        /// Key pair derived with `DeriveKeyPair`.
+        struct KeyPair {
+            ikm: &'static [u8],
+            sk: &'static [u8],
+            pk: &'static [u8],
+        }
+
+        /// Test vector from RFC 9180 Appendix A.
+        struct TestVector {
+            e: KeyPair,
+            r: KeyPair,
+            /// Key pair of the sender in the authenticated mode.
+            s: Option<KeyPair>,
+            shared_secret: &'static [u8],
+        }
+
+        const TEST_VECTORS: &[TestVector] = &[
+            TestVector {
+                e: KeyPair {
+                    ikm: &$base_ikm_e,
+                    sk: &$base_sk_e,
+                    pk: &$base_pk_e,
+                },
+                r: KeyPair {
+                    ikm: &$base_ikm_r,
+                    sk: &$base_sk_r,
+                    pk: &$base_pk_r,
+                },
+                s: None,
+                shared_secret: &$base_shared_secret,
+            },
+            TestVector {
+                e: KeyPair {
+                    ikm: &$auth_ikm_e,
+                    sk: &$auth_sk_e,
+                    pk: &$auth_pk_e,
+                },
+                r: KeyPair {
+                    ikm: &$auth_ikm_r,
+                    sk: &$auth_sk_r,
+                    pk: &$auth_pk_r,
+                },
+                s: Some(KeyPair {
+                    ikm: &$auth_ikm_s,
+                    sk: &$auth_sk_s,
+                    pk: &$auth_pk_s,
+                }),
+                shared_secret: &$auth_shared_secret,
+            },
+        ];
+
+        type DecapsulationKey = $crate::dhkem::DecapsulationKey<$curve>;
+        type EncapsulationKey = $crate::dhkem::EncapsulationKey<$curve>;
+
+        fn decapsulation_key(key_pair: &KeyPair) -> DecapsulationKey {
+            DecapsulationKey::from_bytes(&key_pair.sk.try_into().unwrap()).unwrap()
+        }
+
+        #[test]
+        fn derive_key_pair() {
+            for vector in TEST_VECTORS {
+                for key_pair in [Some(&vector.e), Some(&vector.r), vector.s.as_ref()]
+                    .into_iter()
+                    .flatten()
+                {
+                    let key = DecapsulationKey::derive(key_pair.ikm).unwrap();
+                    assert_eq!(key.to_bytes().as_slice(), key_pair.sk);
+                    assert_eq!(
+                        key.encapsulation_key().to_sec1_point().as_bytes(),
+                        key_pair.pk
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn encapsulate_and_decapsulate() {
+            for vector in TEST_VECTORS {
+                let ephemeral = decapsulation_key(&vector.e);
+                let recipient = decapsulation_key(&vector.r);
+                let encapsulation_key = EncapsulationKey::from_sec1_bytes(vector.r.pk).unwrap();
+
+                let (enc, shared_secret) = match &vector.s {
+                    None => encapsulation_key.encapsulate_with_ephemeral(&ephemeral),
+                    Some(s) => encapsulation_key
+                        .auth_encapsulate_with_ephemeral(&decapsulation_key(s), &ephemeral),
+                };
+                assert_eq!(enc.as_bytes(), vector.e.pk);
+                assert_eq!(shared_secret.as_bytes(), vector.shared_secret);
+
+                let shared_secret = match &vector.s {
+                    None => recipient.decapsulate(vector.e.pk).unwrap(),
+                    Some(s) => {
+                        let sender = EncapsulationKey::from_sec1_bytes(s.pk).unwrap();
+                        recipient.auth_decapsulate(vector.e.pk, &sender).unwrap()
+                    }
+                };
+                assert_eq!(shared_secret.as_bytes(), vector.shared_secret);
+            }
+        }
+
+        #[test]
+        fn auth_decapsulate_with_wrong_sender() {
+            let vector = &TEST_VECTORS[1];
+            let recipient = decapsulation_key(&vector.r);
+            let sender = EncapsulationKey::from_sec1_bytes(vector.e.pk).unwrap();
+            let shared_secret = recipient.auth_decapsulate(vector.e.pk, &sender).unwrap();
+            assert_ne!(shared_secret.as_bytes(), vector.shared_secret);
+        }
+
+        #[test]
+        fn kem_traits() {
+            use $crate::dhkem::{KeyExport, TryDecapsulate, TryKeyInit};
+
+            let vector = &TEST_VECTORS[0];
+            let recipient = decapsulation_key(&vector.r);
+            let encapsulation_key =
+                EncapsulationKey::new(&vector.r.pk.try_into().unwrap()).unwrap();
+            assert_eq!(recipient.encapsulation_key(), &encapsulation_key);
+            assert_eq!(encapsulation_key.to_bytes().as_slice(), vector.r.pk);
+
+            let shared_secret = recipient
+                .try_decapsulate(&vector.e.pk.try_into().unwrap())
+                .unwrap();
+            assert_eq!(shared_secret.as_slice(), vector.shared_secret);
+            assert!(recipient.try_decapsulate_slice(&vector.e.pk[1..]).is_err());
+        }
+
+        #[test]
+        fn rejects_invalid_encapsulated_keys() {
+            use $crate::dhkem::{KeyExport, TryKeyInit};
+            use $crate::elliptic_curve::sec1::ToSec1Point;
+
+            let vector = &TEST_VECTORS[0];
+            let recipient = decapsulation_key(&vector.r);
+
+            // identity
+            assert!(recipient.decapsulate(&[0]).is_err());
+
+            // compressed
+            let point = EncapsulationKey::from_sec1_bytes(vector.e.pk).unwrap();
+            let compressed = point.as_affine().to_sec1_point(true);
+            assert!(recipient.decapsulate(compressed.as_bytes()).is_err());
+
+            // not on the curve
+            let mut enc = point.to_bytes();
+            let last = enc.len() - 1;
+            enc[last] ^= 1;
+            assert!(recipient.decapsulate(&enc).is_err());
+            assert!(EncapsulationKey::new(&enc).is_err());
+        }
+    };
+}
+
+/// Implement ECIES tests from known answers of `$curve` with the hash function `$hash`.
+///
+/// Each test vector is the encryption of a fixed message with the given secret key of the
+/// recipient and ephemeral secret key, with or without a fixed pair of shared information. The
+/// first test vector must use the default `Params` without shared information.
+#[macro_export]
+macro_rules! test_ecies {
+    (
+        $curve:ty,
+        $hash:ty,
+        secret_key: $secret_key:expr,
+        public_key: $public_key:expr,
+        ephemeral_key: $ephemeral_key:expr,
+        test_vectors: [
+            $({
+                params: $params:expr,
+                shared_info: $shared_info:expr,
+                ciphertext: $ciphertext:expr $(,)?
+            }),+ $(,)?
+        ] $(,)?
+    ) => {
+        const SECRET_KEY: &[u8] = &$secret_key;
+        const PUBLIC_KEY: &[u8] = &$public_key;
+        const EPHEMERAL_KEY: &[u8] = &$ephemeral_key;
+        const MSG: &[u8] = b"the quick brown fox jumps over the lazy dog!!";
+        const SHARED_INFO1: &[u8] = b"shared info 1";
+        const SHARED_INFO2: &[u8] = b"shared info 2";
+
+        /// Known answer for the given options.
+        struct TestVector {
+            params: $crate::ecies::Params,
+            shared_info: bool,
+            ciphertext: &'static [u8],
+        }
+
+        const TEST_VECTORS: &[TestVector] = &[$(TestVector {
+            params: $params,
+            shared_info: $shared_info,
+            ciphertext: &$ciphertext,
+        }),+];
+
+        type DecryptingKey = $crate::ecies::DecryptingKey<$curve, $hash>;
+        type NonZeroScalar = $crate::elliptic_curve::NonZeroScalar<$curve>;
+
+        fn scalar(bytes: &[u8]) -> NonZeroScalar {
+            NonZeroScalar::from_repr(bytes.try_into().unwrap()).unwrap()
+        }
+
+        fn shared_info(vector: &TestVector) -> (&'static [u8], &'static [u8]) {
+            if vector.shared_info {
+                (SHARED_INFO1, SHARED_INFO2)
+            } else {
+                (&[], &[])
+            }
+        }
+
+        fn decrypting_key(params: $crate::ecies::Params) -> DecryptingKey {
+            DecryptingKey::new_with_params(scalar(SECRET_KEY), params)
+        }
+
+        #[test]
+        fn encrypt() {
+            use $crate::elliptic_curve::sec1::ToSec1Point;
+
+            let ephemeral = scalar(EPHEMERAL_KEY);
+
+            for vector in TEST_VECTORS {
+                let (shared_info1, shared_info2) = shared_info(vector);
+                let key = decrypting_key(vector.params);
+                let encrypting_key = key.encrypting_key();
+                assert_eq!(
+                    encrypting_key
+                        .as_public_key()
+                        .to_sec1_point(false)
+                        .as_bytes(),
+                    PUBLIC_KEY
+                );
+
+                let ciphertext = encrypting_key
+                    .encrypt_with_ephemeral(&ephemeral, MSG, shared_info1, shared_info2)
+                    .unwrap();
+                assert_eq!(ciphertext, vector.ciphertext);
+            }
+        }
+
+        #[test]
+        fn decrypt() {
+            for vector in TEST_VECTORS {
+                let (shared_info1, shared_info2) = shared_info(vector);
+                let key = decrypting_key(vector.params);
+
+                let plaintext = key
+                    .decrypt_with_shared_info(vector.ciphertext, shared_info1, shared_info2)
+                    .unwrap();
+                assert_eq!(plaintext, MSG);
+            }
+        }
+
+        #[test]
+        fn decrypt_with_wrong_shared_info() {
+            for vector in TEST_VECTORS.iter().filter(|vector| vector.shared_info) {
+                let key = decrypting_key(vector.params);
+
+                assert!(key.decrypt(vector.ciphertext).is_err());
+                assert!(
+                    key.decrypt_with_shared_info(vector.ciphertext, SHARED_INFO2, SHARED_INFO2)
+                        .is_err()
+                );
+                assert!(
+                    key.decrypt_with_shared_info(vector.ciphertext, SHARED_INFO1, SHARED_INFO1)
+                        .is_err()
+                );
+            }
+        }
+
+        #[test]
+        fn decrypt_with_wrong_params() {
+            use $crate::ecies::{Kdf, KdfInput, KeyOrder, MacInput, MacTag, Params};
+
+            for vector in TEST_VECTORS {
+                let (shared_info1, shared_info2) = shared_info(vector);
+                let params = vector.params;
+
+                // Every option except the point encoding, which is detected when decrypting.
+                for params in [
+                    Params {
+                        kdf: match params.kdf {
+                            Kdf::AnsiX963 => Kdf::Hkdf,
+                            Kdf::Hkdf => Kdf::AnsiX963,
+                        },
+                        ..params
+                    },
+                    Params {
+                        kdf_input: match params.kdf_input {
+                            KdfInput::SharedSecret => KdfInput::EphemeralKeyAndSharedSecret,
+                            KdfInput::EphemeralKeyAndSharedSecret => KdfInput::SharedSecret,
+                        },
+                        ..params
+                    },
+                    Params {
+                        mac_tag: match params.mac_tag {
+                            MacTag::Full => MacTag::Truncated,
+                            MacTag::Truncated => MacTag::Full,
+                        },
+                        ..params
+                    },
+                    Params {
+                        mac_input: match params.mac_input {
+                            MacInput::SharedInfo => MacInput::SharedInfoAndLength,
+                            MacInput::SharedInfoAndLength => MacInput::SharedInfo,
+                        },
+                        ..params
+                    },
+                    Params {
+                        key_order: match params.key_order {
+                            KeyOrder::EncryptionKeyFirst => KeyOrder::MacKeyFirst,
+                            KeyOrder::MacKeyFirst => KeyOrder::EncryptionKeyFirst,
+                        },
+                        ..params
+                    },
+                    Params {
+                        mac_key_len: match params.mac_key_len {
+                            None => Some(16),
+                            Some(_) => None,
+                        },
+                        ..params
+                    },
+                ] {
+                    assert!(
+                        decrypting_key(params)
+                            .decrypt_with_shared_info(vector.ciphertext, shared_info1, shared_info2)
+                            .is_err()
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn rejects_invalid_ciphertexts() {
+            let vector = &TEST_VECTORS[0];
+            let key = decrypting_key(vector.params);
+            let point_len = PUBLIC_KEY.len();
+            let tag_len = vector.ciphertext.len() - point_len - MSG.len();
+
+            for i in [0, 1, point_len - 1, point_len, vector.ciphertext.len() - 1] {
+                let mut ciphertext = vector.ciphertext.to_vec();
+                ciphertext[i] ^= 1;
+                assert!(key.decrypt(&ciphertext).is_err());
+            }
+
+            // Truncated ciphertexts, including one without the encrypted message.
+            for len in [
+                0,
+                1,
+                point_len,
+                point_len + tag_len - 1,
+                vector.ciphertext.len() - 1,
+            ] {
+                assert!(key.decrypt(&vector.ciphertext[..len]).is_err());
+            }
+
+            // Identity as the ephemeral public key.
+            let ciphertext = [&[0][..], &vector.ciphertext[point_len..]].concat();
+            assert!(key.decrypt(&ciphertext).is_err());
+        }
+
+        #[test]
+        fn decrypt_empty_message() {
+            let vector = &TEST_VECTORS[0];
+            let key = decrypting_key(vector.params);
+
+            let ciphertext = key
+                .encrypting_key()
+                .encrypt_with_ephemeral(&scalar(EPHEMERAL_KEY), &[], &[], &[])
+                .unwrap();
+            assert_eq!(ciphertext.len(), vector.ciphertext.len() - MSG.len());
+            assert!(key.decrypt(&ciphertext).unwrap().is_empty());
+        }
     };
 }
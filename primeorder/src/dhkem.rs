@@ -0,0 +1,498 @@
+//! Diffie-Hellman based key encapsulation mechanism (DHKEM) as described in [RFC 9180 § 4.1].
+//!
+//! DHKEM is the KEM used by Hybrid Public Key Encryption (HPKE) for elliptic curves. The sender
+//! encapsulates a fresh shared secret to the [`EncapsulationKey`] of the recipient, and the
+//! recipient recovers it from the encapsulated key `enc` with its [`DecapsulationKey`].
+//!
+//! Both the base mode (`Encap`/`Decap`) and the authenticated mode (`AuthEncap`/`AuthDecap`),
+//! which additionally proves that the sender holds a given static key, are supported. The base
+//! mode is also available through the [`Encapsulate`] and [`TryDecapsulate`] traits of the
+//! [`kem`] crate, with [`DhKem`] as the [`Kem`] type.
+//!
+//! [RFC 9180 § 4.1]: https://www.rfc-editor.org/rfc/rfc9180#section-4.1
+
+pub use kem::{
+    self, Ciphertext, Decapsulator, Encapsulate, InvalidKey, Kem, Key, KeyExport, KeySizeUser,
+    SharedKey, TryDecapsulate, TryKeyInit,
+};
+
+use crate::{AffinePoint, PrimeCurveParams, ProjectivePoint, ecdh::diffie_hellman};
+use core::{fmt, marker::PhantomData};
+use elliptic_curve::{
+    Error, FieldBytes, Generate, NonZeroScalar, PublicKey, Result, SecretKey,
+    ff::PrimeField,
+    rand_core::{CryptoRng, TryCryptoRng},
+    sec1::{FromSec1Point, Sec1Point, ToSec1Point, UncompressedPoint, UncompressedPointSize},
+    zeroize::Zeroize,
+};
+use hkdf::Hkdf;
+use hmac::{
+    Hmac, KeyInit, Mac,
+    digest::{Output, OutputSizeUser, block_api::EagerHash},
+};
+
+/// Curves which have a DHKEM defined in [RFC 9180 § 7.1].
+///
+/// [RFC 9180 § 7.1]: https://www.rfc-editor.org/rfc/rfc9180#section-7.1
+pub trait DhKemParams: PrimeCurveParams {
+    /// Hash function used with HKDF, e.g. SHA-256 for `DHKEM(P-256, HKDF-SHA256)`. The size of
+    /// the shared secret (`Nsecret`) is the size of its output.
+    type Hash: EagerHash;
+
+    /// Identifier of the KEM.
+    const KEM_ID: u16;
+
+    /// Mask applied to the first byte of candidate secret keys by `DeriveKeyPair`, which clears
+    /// the bits above the order of the curve.
+    const BITMASK: u8;
+}
+
+/// DHKEM over the curve `C`, which ties its [`DecapsulationKey`] and [`EncapsulationKey`]
+/// together as a [`Kem`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DhKem<C: DhKemParams>(PhantomData<C>);
+
+impl<C: DhKemParams> Kem for DhKem<C> {
+    type DecapsulationKey = DecapsulationKey<C>;
+    type EncapsulationKey = EncapsulationKey<C>;
+
+    /// `Nsecret`, which is the output size of the hash function.
+    type SharedKeySize = <C::Hash as OutputSizeUser>::OutputSize;
+
+    /// `Nenc`, which is the size of an uncompressed SEC1 point.
+    type CiphertextSize = UncompressedPointSize<C>;
+}
+
+/// Secret key of the recipient, which decapsulates shared secrets.
+pub struct DecapsulationKey<C: DhKemParams> {
+    secret: NonZeroScalar<C>,
+    encapsulation_key: EncapsulationKey<C>,
+}
+
+impl<C: DhKemParams> DecapsulationKey<C> {
+    /// Deterministically derive a key pair from the input keying material `ikm` with the
+    /// `DeriveKeyPair` function of [RFC 9180 § 7.1.3], which uses rejection sampling.
+    ///
+    /// `ikm` must have at least as many bytes of entropy as the secret key.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if none of the 256 candidates is a valid secret key, which only happens
+    /// with negligible probability.
+    ///
+    /// [RFC 9180 § 7.1.3]: https://www.rfc-editor.org/rfc/rfc9180#section-7.1.3
+    pub fn derive(ikm: &[u8]) -> Result<Self> {
+        let prk = labeled_extract::<C>(b"", b"dkp_prk", [ikm, &[]]);
+
+        for counter in 0..=u8::MAX {
+            let mut bytes = FieldBytes::<C>::default();
+            labeled_expand::<C>(&prk, b"candidate", [&[counter], &[], &[]], &mut bytes);
+            bytes[0] &= C::BITMASK;
+
+            let secret = NonZeroScalar::<C>::from_repr(bytes);
+            bytes.zeroize();
+
+            if let Some(secret) = secret.into_option() {
+                return Ok(Self::from(secret));
+            }
+        }
+
+        Err(Error)
+    }
+
+    /// Deserialize a secret key encoded as a big-endian integer, as described in
+    /// [RFC 9180 § 7.1.2].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the secret key is zero or isn't smaller than the order of the curve.
+    ///
+    /// [RFC 9180 § 7.1.2]: https://www.rfc-editor.org/rfc/rfc9180#section-7.1.2
+    pub fn from_bytes(bytes: &FieldBytes<C>) -> Result<Self> {
+        NonZeroScalar::<C>::from_repr(*bytes)
+            .into_option()
+            .map(Self::from)
+            .ok_or(Error)
+    }
+
+    /// Serialize the secret key as a big-endian integer.
+    #[must_use]
+    pub fn to_bytes(&self) -> FieldBytes<C> {
+        self.secret.to_repr()
+    }
+
+    /// Encapsulation key which corresponds to this decapsulation key.
+    #[must_use]
+    pub fn encapsulation_key(&self) -> &EncapsulationKey<C> {
+        &self.encapsulation_key
+    }
+
+    /// Recover the shared secret from the encapsulated key `enc` (`Decap`).
+    ///
+    /// # Errors
+    /// Returns [`Error`] if `enc` isn't an uncompressed SEC1 point on the curve.
+    pub fn decapsulate(&self, enc: &[u8]) -> Result<SharedSecret<C>> {
+        let ephemeral = EncapsulationKey::<C>::from_sec1_bytes(enc)?;
+        let dh = diffie_hellman(self.secret, ephemeral.point);
+        let enc = ephemeral.to_sec1_point();
+        let pk_r = self.encapsulation_key.to_sec1_point();
+
+        Ok(extract_and_expand::<C>(
+            [dh.raw_secret_bytes(), &[]],
+            [enc.as_bytes(), pk_r.as_bytes(), &[]],
+        ))
+    }
+
+    /// Recover the shared secret from the encapsulated key `enc`, and authenticate that it was
+    /// encapsulated by the holder of the decapsulation key which corresponds to `sender`
+    /// (`AuthDecap`).
+    ///
+    /// # Errors
+    /// Returns [`Error`] if `enc` isn't an uncompressed SEC1 point on the curve.
+    pub fn auth_decapsulate(
+        &self,
+        enc: &[u8],
+        sender: &EncapsulationKey<C>,
+    ) -> Result<SharedSecret<C>> {
+        let ephemeral = EncapsulationKey::<C>::from_sec1_bytes(enc)?;
+        let dh_e = diffie_hellman(self.secret, ephemeral.point);
+        let dh_s = diffie_hellman(self.secret, sender.point);
+        let enc = ephemeral.to_sec1_point();
+        let pk_r = self.encapsulation_key.to_sec1_point();
+        let pk_s = sender.to_sec1_point();
+
+        Ok(extract_and_expand::<C>(
+            [dh_e.raw_secret_bytes(), dh_s.raw_secret_bytes()],
+            [enc.as_bytes(), pk_r.as_bytes(), pk_s.as_bytes()],
+        ))
+    }
+}
+
+impl<C: DhKemParams> From<NonZeroScalar<C>> for DecapsulationKey<C> {
+    fn from(secret: NonZeroScalar<C>) -> Self {
+        let point = (ProjectivePoint::<C>::GENERATOR * *secret).to_affine();
+
+        Self {
+            secret,
+            encapsulation_key: EncapsulationKey { point },
+        }
+    }
+}
+
+impl<C: DhKemParams> From<&SecretKey<C>> for DecapsulationKey<C> {
+    fn from(secret_key: &SecretKey<C>) -> Self {
+        Self::from(secret_key.to_nonzero_scalar())
+    }
+}
+
+impl<C: DhKemParams> Generate for DecapsulationKey<C> {
+    fn try_generate_from_rng<R: TryCryptoRng + ?Sized>(
+        rng: &mut R,
+    ) -> core::result::Result<Self, R::Error> {
+        NonZeroScalar::try_generate_from_rng(rng).map(Self::from)
+    }
+}
+
+impl<C: DhKemParams> Decapsulator for DecapsulationKey<C> {
+    type Kem = DhKem<C>;
+
+    fn encapsulation_key(&self) -> &EncapsulationKey<C> {
+        &self.encapsulation_key
+    }
+}
+
+impl<C: DhKemParams> TryDecapsulate for DecapsulationKey<C> {
+    type Error = Error;
+
+    fn try_decapsulate(&self, enc: &Ciphertext<DhKem<C>>) -> Result<SharedKey<DhKem<C>>> {
+        self.decapsulate(enc)
+            .map(|shared_secret| shared_secret.bytes.clone())
+    }
+}
+
+impl<C: DhKemParams> fmt::Debug for DecapsulationKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecapsulationKey")
+            .field("encapsulation_key", &self.encapsulation_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: DhKemParams> Drop for DecapsulationKey<C> {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// Public key of the recipient, to which shared secrets are encapsulated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EncapsulationKey<C: DhKemParams> {
+    point: AffinePoint<C>,
+}
+
+impl<C: DhKemParams> EncapsulationKey<C> {
+    /// Deserialize an encapsulation key encoded as an uncompressed SEC1 point, as described in
+    /// [RFC 9180 § 7.1.1].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the encoding isn't an uncompressed SEC1 point on the curve, or it is
+    /// the identity.
+    ///
+    /// [RFC 9180 § 7.1.1]: https://www.rfc-editor.org/rfc/rfc9180#section-7.1.1
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        let point = Sec1Point::<C>::from_bytes(bytes).map_err(|_| Error)?;
+        if point.is_compressed() {
+            return Err(Error);
+        }
+
+        AffinePoint::<C>::from_sec1_point(&point)
+            .into_option()
+            .filter(|point| !bool::from(point.is_identity()))
+            .map(|point| Self { point })
+            .ok_or(Error)
+    }
+
+    /// Serialize the encapsulation key as an uncompressed SEC1 point.
+    #[must_use]
+    pub fn to_sec1_point(&self) -> Sec1Point<C> {
+        self.point.to_sec1_point(false)
+    }
+
+    /// Borrow the inner [`AffinePoint`].
+    #[must_use]
+    pub fn as_affine(&self) -> &AffinePoint<C> {
+        &self.point
+    }
+
+    /// Encapsulate a fresh shared secret to this key (`Encap`).
+    ///
+    /// Returns the encapsulated key `enc` to send to the recipient, and the shared secret. See
+    /// also [`Encapsulate::encapsulate_with_rng`] for infallible RNGs.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_encapsulate<R: TryCryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> core::result::Result<(Sec1Point<C>, SharedSecret<C>), R::Error> {
+        let ephemeral = DecapsulationKey::try_generate_from_rng(rng)?;
+        Ok(self.encapsulate_with_ephemeral(&ephemeral))
+    }
+
+    /// Encapsulate a shared secret to this key with the given ephemeral key pair.
+    ///
+    /// <div class = "warning">
+    /// <b>Security Warning</b>
+    ///
+    /// The ephemeral key MUST be generated uniformly at random and never reused. This is only
+    /// intended for testing against known answers; use [`EncapsulationKey::try_encapsulate`]
+    /// otherwise.
+    /// </div>
+    #[must_use]
+    pub fn encapsulate_with_ephemeral(
+        &self,
+        ephemeral: &DecapsulationKey<C>,
+    ) -> (Sec1Point<C>, SharedSecret<C>) {
+        let dh = diffie_hellman(ephemeral.secret, self.point);
+        let enc = ephemeral.encapsulation_key.to_sec1_point();
+        let pk_r = self.to_sec1_point();
+
+        let shared_secret = extract_and_expand::<C>(
+            [dh.raw_secret_bytes(), &[]],
+            [enc.as_bytes(), pk_r.as_bytes(), &[]],
+        );
+        (enc, shared_secret)
+    }
+
+    /// Encapsulate a fresh shared secret to this key, authenticated with the decapsulation key
+    /// of the sender (`AuthEncap`).
+    ///
+    /// Returns the encapsulated key `enc` to send to the recipient, and the shared secret.
+    #[must_use]
+    pub fn auth_encapsulate<R: CryptoRng + ?Sized>(
+        &self,
+        sender: &DecapsulationKey<C>,
+        rng: &mut R,
+    ) -> (Sec1Point<C>, SharedSecret<C>) {
+        self.auth_encapsulate_with_ephemeral(sender, &DecapsulationKey::generate_from_rng(rng))
+    }
+
+    /// Encapsulate a fresh shared secret to this key, authenticated with the decapsulation key
+    /// of the sender (`AuthEncap`).
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_auth_encapsulate<R: TryCryptoRng + ?Sized>(
+        &self,
+        sender: &DecapsulationKey<C>,
+        rng: &mut R,
+    ) -> core::result::Result<(Sec1Point<C>, SharedSecret<C>), R::Error> {
+        let ephemeral = DecapsulationKey::try_generate_from_rng(rng)?;
+        Ok(self.auth_encapsulate_with_ephemeral(sender, &ephemeral))
+    }
+
+    /// Encapsulate a shared secret to this key with the given ephemeral key pair, authenticated
+    /// with the decapsulation key of the sender.
+    ///
+    /// <div class = "warning">
+    /// <b>Security Warning</b>
+    ///
+    /// The ephemeral key MUST be generated uniformly at random and never reused. This is only
+    /// intended for testing against known answers; use [`EncapsulationKey::auth_encapsulate`]
+    /// otherwise.
+    /// </div>
+    #[must_use]
+    pub fn auth_encapsulate_with_ephemeral(
+        &self,
+        sender: &DecapsulationKey<C>,
+        ephemeral: &DecapsulationKey<C>,
+    ) -> (Sec1Point<C>, SharedSecret<C>) {
+        let dh_e = diffie_hellman(ephemeral.secret, self.point);
+        let dh_s = diffie_hellman(sender.secret, self.point);
+        let enc = ephemeral.encapsulation_key.to_sec1_point();
+        let pk_r = self.to_sec1_point();
+        let pk_s = sender.encapsulation_key.to_sec1_point();
+
+        let shared_secret = extract_and_expand::<C>(
+            [dh_e.raw_secret_bytes(), dh_s.raw_secret_bytes()],
+            [enc.as_bytes(), pk_r.as_bytes(), pk_s.as_bytes()],
+        );
+        (enc, shared_secret)
+    }
+}
+
+impl<C: DhKemParams> Encapsulate for EncapsulationKey<C> {
+    type Kem = DhKem<C>;
+
+    fn encapsulate_with_rng<R: CryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> (Ciphertext<DhKem<C>>, SharedKey<DhKem<C>>) {
+        let ephemeral = DecapsulationKey::generate_from_rng(rng);
+        let (enc, shared_secret) = self.encapsulate_with_ephemeral(&ephemeral);
+        (to_uncompressed::<C>(&enc), shared_secret.bytes.clone())
+    }
+}
+
+impl<C: DhKemParams> KeySizeUser for EncapsulationKey<C> {
+    type KeySize = UncompressedPointSize<C>;
+}
+
+impl<C: DhKemParams> TryKeyInit for EncapsulationKey<C> {
+    fn new(key: &Key<Self>) -> core::result::Result<Self, InvalidKey> {
+        Self::from_sec1_bytes(key).map_err(|_| InvalidKey)
+    }
+}
+
+impl<C: DhKemParams> KeyExport for EncapsulationKey<C> {
+    fn to_bytes(&self) -> Key<Self> {
+        to_uncompressed::<C>(&self.to_sec1_point())
+    }
+}
+
+impl<C: DhKemParams> From<PublicKey<C>> for EncapsulationKey<C> {
+    fn from(public_key: PublicKey<C>) -> Self {
+        Self::from(&public_key)
+    }
+}
+
+impl<C: DhKemParams> From<&PublicKey<C>> for EncapsulationKey<C> {
+    fn from(public_key: &PublicKey<C>) -> Self {
+        Self {
+            point: *public_key.as_affine(),
+        }
+    }
+}
+
+/// Shared secret resulting from encapsulation or decapsulation.
+pub struct SharedSecret<C: DhKemParams> {
+    bytes: Output<C::Hash>,
+}
+
+impl<C: DhKemParams> SharedSecret<C> {
+    /// Shared secret bytes, which are `Nsecret` bytes long.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<C: DhKemParams> fmt::Debug for SharedSecret<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
+
+impl<C: DhKemParams> Drop for SharedSecret<C> {
+    fn drop(&mut self) {
+        self.bytes.as_mut_slice().zeroize();
+    }
+}
+
+/// Copy an uncompressed SEC1 point into a fixed-size array.
+fn to_uncompressed<C: DhKemParams>(point: &Sec1Point<C>) -> UncompressedPoint<C> {
+    let mut bytes = UncompressedPoint::<C>::default();
+    bytes.copy_from_slice(point.as_bytes());
+    bytes
+}
+
+/// `ExtractAndExpand`, where the Diffie-Hellman output and the KEM context are the
+/// concatenation of the given slices.
+fn extract_and_expand<C: DhKemParams>(dh: [&[u8]; 2], kem_context: [&[u8]; 3]) -> SharedSecret<C> {
+    let eae_prk = labeled_extract::<C>(b"", b"eae_prk", dh);
+    let mut bytes = Output::<C::Hash>::default();
+    labeled_expand::<C>(&eae_prk, b"shared_secret", kem_context, &mut bytes);
+    SharedSecret { bytes }
+}
+
+/// `LabeledExtract` as described in [RFC 9180 § 4], where `ikm` is the concatenation of the given
+/// slices.
+///
+/// [RFC 9180 § 4]: https://www.rfc-editor.org/rfc/rfc9180#section-4
+fn labeled_extract<C: DhKemParams>(salt: &[u8], label: &[u8], ikm: [&[u8]; 2]) -> Hkdf<C::Hash> {
+    let mut mac = Hmac::<C::Hash>::new_from_slice(salt).expect("HMAC accepts keys of any size");
+    for bytes in [
+        b"HPKE-v1".as_slice(),
+        &suite_id::<C>(),
+        label,
+        ikm[0],
+        ikm[1],
+    ] {
+        mac.update(bytes);
+    }
+
+    let mut prk = mac.finalize().into_bytes();
+    let hkdf = Hkdf::<C::Hash>::from_prk(&prk).expect("PRK has the size of the hash output");
+    prk.as_mut_slice().zeroize();
+    hkdf
+}
+
+/// `LabeledExpand` as described in [RFC 9180 § 4], where `info` is the concatenation of the given
+/// slices.
+///
+/// [RFC 9180 § 4]: https://www.rfc-editor.org/rfc/rfc9180#section-4
+fn labeled_expand<C: DhKemParams>(
+    prk: &Hkdf<C::Hash>,
+    label: &[u8],
+    info: [&[u8]; 3],
+    okm: &mut [u8],
+) {
+    let len = u16::try_from(okm.len()).expect("output size fits in two bytes");
+    prk.expand_multi_info(
+        &[
+            &len.to_be_bytes(),
+            b"HPKE-v1",
+            &suite_id::<C>(),
+            label,
+            info[0],
+            info[1],
+            info[2],
+        ],
+        okm,
+    )
+    .expect("output size is at most the size of the hash output");
+}
+
+/// `suite_id` of the KEM, which is `"KEM" || I2OSP(kem_id, 2)`.
+fn suite_id<C: DhKemParams>() -> [u8; 5] {
+    let [hi, lo] = C::KEM_ID.to_be_bytes();
+    [b'K', b'E', b'M', hi, lo]
+}
@@ -0,0 +1,149 @@
+//! Elliptic Curve Diffie-Hellman using x-only scalar multiplication.
+//!
+//! These functions compute the same shared secrets as [`elliptic_curve::ecdh::diffie_hellman`],
+//! but only compute the x-coordinate of the shared point using [`x_only_mul`] rather than a full
+//! projective scalar multiplication, so y-coordinates are never computed.
+//! [`diffie_hellman_x_only`] also accepts compressed or x-only public keys without decompressing
+//! them.
+//!
+//! [`EphemeralSecret`] is a drop-in replacement for [`elliptic_curve::ecdh::EphemeralSecret`]
+//! which uses these functions.
+
+use crate::{AffinePoint, PrimeCurveParams, x_only_mul};
+use core::{borrow::Borrow, fmt};
+use elliptic_curve::{
+    Error, FieldBytes, FieldBytesSize, Generate, NonZeroScalar, PublicKey, Result,
+    array::typenum::Unsigned,
+    ecdh::SharedSecret,
+    ff::{Field, PrimeField},
+    rand_core::TryCryptoRng,
+    sec1::Sec1Point,
+    subtle::ConditionallySelectable,
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
+
+/// Ephemeral Diffie-Hellman Secret.
+///
+/// Like [`elliptic_curve::ecdh::EphemeralSecret`], but computes shared secrets using
+/// [`diffie_hellman`].
+pub struct EphemeralSecret<C: PrimeCurveParams> {
+    scalar: NonZeroScalar<C>,
+}
+
+impl<C: PrimeCurveParams> EphemeralSecret<C> {
+    /// Get the public key associated with this ephemeral secret.
+    pub fn public_key(&self) -> PublicKey<C> {
+        PublicKey::from_secret_scalar(&self.scalar)
+    }
+
+    /// Compute a Diffie-Hellman shared secret from an ephemeral secret and the public key of the
+    /// other participant in a key exchange.
+    pub fn diffie_hellman(&self, public_key: &PublicKey<C>) -> SharedSecret<C> {
+        diffie_hellman(self.scalar, public_key.as_affine())
+    }
+
+    /// Compute a Diffie-Hellman shared secret from an ephemeral secret and the serialized public
+    /// key of the other participant in a key exchange.
+    ///
+    /// See [`diffie_hellman_x_only`] for the supported public key encodings.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the public key encoding is malformed, or isn't a point on the curve.
+    pub fn diffie_hellman_x_only(&self, public_key: &[u8]) -> Result<SharedSecret<C>> {
+        diffie_hellman_x_only(self.scalar, public_key)
+    }
+}
+
+impl<C: PrimeCurveParams> fmt::Debug for EphemeralSecret<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EphemeralSecret").finish_non_exhaustive()
+    }
+}
+
+impl<C: PrimeCurveParams> Generate for EphemeralSecret<C> {
+    fn try_generate_from_rng<R: TryCryptoRng + ?Sized>(
+        rng: &mut R,
+    ) -> core::result::Result<Self, R::Error> {
+        Ok(Self {
+            scalar: NonZeroScalar::try_generate_from_rng(rng)?,
+        })
+    }
+}
+
+impl<C: PrimeCurveParams> From<&EphemeralSecret<C>> for PublicKey<C> {
+    fn from(ephemeral_secret: &EphemeralSecret<C>) -> Self {
+        ephemeral_secret.public_key()
+    }
+}
+
+impl<C: PrimeCurveParams> Zeroize for EphemeralSecret<C> {
+    fn zeroize(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+impl<C: PrimeCurveParams> ZeroizeOnDrop for EphemeralSecret<C> {}
+
+impl<C: PrimeCurveParams> Drop for EphemeralSecret<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Low-level Elliptic Curve Diffie-Hellman (ECDH) function.
+///
+/// Computes the same shared secret as [`elliptic_curve::ecdh::diffie_hellman`], using
+/// [`x_only_mul`].
+pub fn diffie_hellman<C: PrimeCurveParams>(
+    secret_key: impl Borrow<NonZeroScalar<C>>,
+    public_key: impl Borrow<AffinePoint<C>>,
+) -> SharedSecret<C> {
+    let public_key = public_key.borrow();
+    let x = x_only_mul::<C>(&public_key.x, secret_key.borrow());
+
+    // `public_key` is a point on the curve and `secret_key` is nonzero, so the shared point is
+    // only the identity if `public_key` is, in which case the shared secret is all zeroes
+    let x = C::FieldElement::conditional_select(
+        &x.unwrap_or(C::FieldElement::ZERO),
+        &C::FieldElement::ZERO,
+        public_key.is_identity(),
+    );
+
+    SharedSecret::from(x.to_repr())
+}
+
+/// Low-level Elliptic Curve Diffie-Hellman (ECDH) function which accepts a serialized public key.
+///
+/// The public key can be encoded either as a SEC1 point, or as the bare encoding of its
+/// x-coordinate, which is the same as a compressed SEC1 point without its tag byte. As the shared
+/// secret only depends on the x-coordinate of the public key, compressed public keys don't need
+/// to be decompressed.
+///
+/// # Errors
+/// Returns [`Error`] if the public key encoding is malformed, or isn't a point on the curve.
+pub fn diffie_hellman_x_only<C: PrimeCurveParams>(
+    secret_key: impl Borrow<NonZeroScalar<C>>,
+    public_key: &[u8],
+) -> Result<SharedSecret<C>> {
+    let x = if public_key.len() == FieldBytesSize::<C>::USIZE {
+        FieldBytes::<C>::try_from(public_key).map_err(|_| Error)?
+    } else {
+        let point = Sec1Point::<C>::from_bytes(public_key).map_err(|_| Error)?;
+
+        if point.is_compressed() {
+            *point.x().ok_or(Error)?
+        } else {
+            let point = AffinePoint::<C>::try_from(point)?;
+            if point.is_identity().into() {
+                return Err(Error);
+            }
+
+            point.x.to_repr()
+        }
+    };
+
+    let x = Option::from(C::FieldElement::from_repr(x)).ok_or(Error)?;
+    Option::from(x_only_mul::<C>(&x, secret_key.borrow()))
+        .map(|x: C::FieldElement| SharedSecret::from(x.to_repr()))
+        .ok_or(Error)
+}
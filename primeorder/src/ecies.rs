@@ -0,0 +1,556 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES) as described in [SEC 1 v2 § 5.1].
+//!
+//! ECIES is a hybrid public-key encryption scheme: the sender generates an ephemeral key pair,
+//! performs Diffie-Hellman with the [`EncryptingKey`] of the recipient, and derives an
+//! encryption key and a MAC key from the shared secret with a key derivation function. The
+//! ciphertext is the encoded ephemeral public key `R̄`, followed by the encrypted message `EM`
+//! and the MAC tag `D`:
+//!
+//! ```text
+//! R̄ || EM || D
+//! ```
+//!
+//! The options of the scheme are selected with [`Params`]:
+//!
+//! - the key derivation function, either the ANSI X9.63 KDF of [SEC 1 v2 § 3.6.1] (which is the
+//!   same as `KDF2` of ISO 18033-2) or HKDF as described in [RFC 5869];
+//! - whether the key derivation function is applied to the shared secret `Z` only, as specified
+//!   by SEC 1, or to `R̄ || Z`, as specified by ISO 18033-2 for ECIES-KEM;
+//! - whether `R̄` is encoded in compressed or uncompressed form;
+//! - whether the HMAC tag is used in full or truncated to half of its length, e.g.
+//!   `HMAC–SHA-256–128` in SEC 1;
+//! - whether the MAC input is followed by the length of the second shared information;
+//! - whether the encryption key or the MAC key comes first in the output of the key derivation
+//!   function, and the length of the MAC key.
+//!
+//! The hash function used by the key derivation function and the MAC is a type parameter.
+//!
+//! The only symmetric encryption scheme supported is the XOR encryption scheme of
+//! [SEC 1 v2 § 3.8.1], where the message is XORed with a key stream of the same length obtained
+//! from the key derivation function.
+//!
+//! The curves supported by this crate have prime order, i.e. a cofactor of 1, so the standard and
+//! the cofactor Diffie-Hellman primitives of SEC 1 compute the same shared secret.
+//!
+//! # Interoperability
+//!
+//! With the default [`Params`] and no shared information, this is the ECIES configuration
+//! `ecies-with-x9-63-kdf` with XOR encryption and full length HMAC of SEC 1.
+//!
+//! The remaining options describe the layout used by Bouncy Castle's `IESEngine` without a block
+//! cipher: the `KDF2` key derivation function over `R̄ || Z`
+//! ([`KdfInput::EphemeralKeyAndSharedSecret`]), the MAC key ahead of the encryption key
+//! ([`KeyOrder::MacKeyFirst`]) with the configured MAC key size ([`Params::mac_key_len`]), and
+//! the length of the second shared information appended to the MAC input
+//! ([`MacInput::SharedInfoAndLength`]). This has not been tested against ciphertexts produced by
+//! Bouncy Castle.
+//!
+//! The known answers of the curve crates, including those in the Bouncy Castle layout, were
+//! generated with the OpenSSL command line tools: ECDH with `pkeyutl -derive`, the X9.63 KDF and
+//! HKDF with `kdf`, and HMAC with `mac`.
+//!
+//! [SEC 1 v2 § 5.1]: https://www.secg.org/sec1-v2.pdf#page=47
+//! [SEC 1 v2 § 3.6.1]: https://www.secg.org/sec1-v2.pdf#page=38
+//! [SEC 1 v2 § 3.8.1]: https://www.secg.org/sec1-v2.pdf#page=41
+//! [RFC 5869]: https://www.rfc-editor.org/rfc/rfc5869
+
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use elliptic_curve::{
+    AffinePoint, CurveArithmetic, Error, FieldBytesSize, Generate, NonZeroScalar, PublicKey,
+    Result, SecretKey,
+    array::typenum::Unsigned,
+    ecdh::diffie_hellman,
+    rand_core::TryCryptoRng,
+    sec1::{FromSec1Point, ModulusSize, ToSec1Point},
+    subtle::ConstantTimeEq,
+    zeroize::{Zeroize, Zeroizing},
+};
+use hkdf::Hkdf;
+use hmac::{
+    Hmac, KeyInit, Mac,
+    digest::{Output, OutputSizeUser, block_api::EagerHash},
+};
+
+/// Key derivation function used to derive the encryption and MAC keys.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Kdf {
+    /// ANSI X9.63 key derivation function of [SEC 1 v2 § 3.6.1], also known as `KDF2`.
+    ///
+    /// [SEC 1 v2 § 3.6.1]: https://www.secg.org/sec1-v2.pdf#page=38
+    #[default]
+    AnsiX963,
+
+    /// HKDF as described in [RFC 5869], with an empty salt and the first shared information as
+    /// `info`.
+    ///
+    /// The output of HKDF is limited to 255 times the output size of the hash function, which
+    /// limits the length of messages.
+    ///
+    /// [RFC 5869]: https://www.rfc-editor.org/rfc/rfc5869
+    Hkdf,
+}
+
+/// Input keying material of the key derivation function.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum KdfInput {
+    /// The shared secret `Z` only, as specified by SEC 1.
+    #[default]
+    SharedSecret,
+
+    /// The encoded ephemeral public key followed by the shared secret, `R̄ || Z`, as specified
+    /// by ISO 18033-2 for ECIES-KEM.
+    EphemeralKeyAndSharedSecret,
+}
+
+/// Encoding of the ephemeral public key in the ciphertext.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PointEncoding {
+    /// Compressed SEC1 encoding.
+    Compressed,
+
+    /// Uncompressed SEC1 encoding.
+    #[default]
+    Uncompressed,
+}
+
+/// Length of the MAC tag.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MacTag {
+    /// Full output of HMAC.
+    #[default]
+    Full,
+
+    /// Output of HMAC truncated to half of its length, e.g. `HMAC–SHA-256–128`.
+    Truncated,
+}
+
+/// Input of the MAC, in addition to the encrypted message.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MacInput {
+    /// The second shared information, `EM || SharedInfo2`, as specified by SEC 1.
+    #[default]
+    SharedInfo,
+
+    /// The second shared information followed by its length in bits as a 64-bit big-endian
+    /// integer, `EM || SharedInfo2 || L2`, as used by Bouncy Castle's `IESEngine`.
+    SharedInfoAndLength,
+}
+
+/// Order of the encryption key and the MAC key in the output of the key derivation function.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum KeyOrder {
+    /// The encryption key followed by the MAC key, `EK || MK`, as specified by SEC 1.
+    #[default]
+    EncryptionKeyFirst,
+
+    /// The MAC key followed by the encryption key, `MK || EK`, as used by Bouncy Castle's
+    /// `IESEngine` without a block cipher.
+    MacKeyFirst,
+}
+
+/// Options of the encryption scheme.
+///
+/// Both parties must use the same options.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Params {
+    /// Key derivation function.
+    pub kdf: Kdf,
+
+    /// Input keying material of the key derivation function.
+    pub kdf_input: KdfInput,
+
+    /// Encoding of the ephemeral public key.
+    pub point_encoding: PointEncoding,
+
+    /// Length of the MAC tag.
+    pub mac_tag: MacTag,
+
+    /// Input of the MAC.
+    pub mac_input: MacInput,
+
+    /// Order of the encryption key and the MAC key.
+    pub key_order: KeyOrder,
+
+    /// Length of the MAC key in bytes, or the output size of the hash function if `None`.
+    pub mac_key_len: Option<usize>,
+}
+
+impl Params {
+    /// Length of the MAC tag in bytes when used with the hash function `D`.
+    fn tag_len<D: OutputSizeUser>(self) -> usize {
+        match self.mac_tag {
+            MacTag::Full => D::output_size(),
+            MacTag::Truncated => D::output_size() / 2,
+        }
+    }
+
+    /// Length of the MAC key in bytes when used with the hash function `D`.
+    fn mac_key_len<D: OutputSizeUser>(self) -> usize {
+        self.mac_key_len.unwrap_or_else(D::output_size)
+    }
+
+    /// Split the output of the key derivation function into the encryption key of `msg_len`
+    /// bytes and the MAC key.
+    fn split_keys(self, keys: &[u8], msg_len: usize) -> (&[u8], &[u8]) {
+        match self.key_order {
+            KeyOrder::EncryptionKeyFirst => keys.split_at(msg_len),
+            KeyOrder::MacKeyFirst => {
+                let (mac_key, enc_key) = keys.split_at(keys.len() - msg_len);
+                (enc_key, mac_key)
+            }
+        }
+    }
+}
+
+/// Public key of the recipient, used to encrypt messages.
+pub struct EncryptingKey<C: CurveArithmetic, D> {
+    public_key: PublicKey<C>,
+    params: Params,
+    hash: PhantomData<D>,
+}
+
+impl<C, D> EncryptingKey<C, D>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromSec1Point<C> + ToSec1Point<C>,
+    D: EagerHash,
+{
+    /// Initialize [`EncryptingKey`] from a public key with the default [`Params`].
+    #[must_use]
+    pub fn new(public_key: PublicKey<C>) -> Self {
+        Self::new_with_params(public_key, Params::default())
+    }
+
+    /// Initialize [`EncryptingKey`] from a public key with the given [`Params`].
+    #[must_use]
+    pub fn new_with_params(public_key: PublicKey<C>, params: Params) -> Self {
+        Self {
+            public_key,
+            params,
+            hash: PhantomData,
+        }
+    }
+
+    /// Initialize [`EncryptingKey`] from a SEC1-encoded public key with the default [`Params`].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if `bytes` is not a valid encoding of a point other than the identity.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        PublicKey::from_sec1_bytes(bytes).map(Self::new)
+    }
+
+    /// Borrow the inner [`PublicKey`].
+    #[must_use]
+    pub fn as_public_key(&self) -> &PublicKey<C> {
+        &self.public_key
+    }
+
+    /// Get the [`Params`] of this key.
+    #[must_use]
+    pub fn params(&self) -> Params {
+        self.params
+    }
+
+    /// Encrypt `msg` without shared information.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the random number generator fails, or if the message is too long
+    /// for the key derivation function.
+    pub fn encrypt<R: TryCryptoRng + ?Sized>(&self, rng: &mut R, msg: &[u8]) -> Result<Vec<u8>> {
+        self.encrypt_with_shared_info(rng, msg, &[], &[])
+    }
+
+    /// Encrypt `msg` with the shared information `shared_info1`, which is passed to the key
+    /// derivation function, and `shared_info2`, which is authenticated by the MAC.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the random number generator fails, or if the message is too long
+    /// for the key derivation function.
+    pub fn encrypt_with_shared_info<R: TryCryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+        msg: &[u8],
+        shared_info1: &[u8],
+        shared_info2: &[u8],
+    ) -> Result<Vec<u8>> {
+        let ephemeral = NonZeroScalar::<C>::try_generate_from_rng(rng).map_err(|_| Error)?;
+        self.encrypt_with_ephemeral(&ephemeral, msg, shared_info1, shared_info2)
+    }
+
+    /// Encrypt `msg` using the given ephemeral secret key.
+    ///
+    /// <div class="warning">
+    ///
+    /// The ephemeral secret key must never be reused. This method is intended for known-answer
+    /// tests; use [`EncryptingKey::encrypt_with_shared_info`] instead.
+    ///
+    /// </div>
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the message is too long for the key derivation function.
+    pub fn encrypt_with_ephemeral(
+        &self,
+        ephemeral: &NonZeroScalar<C>,
+        msg: &[u8],
+        shared_info1: &[u8],
+        shared_info2: &[u8],
+    ) -> Result<Vec<u8>> {
+        let encoded_point = PublicKey::from_secret_scalar(ephemeral)
+            .to_sec1_point(self.params.point_encoding == PointEncoding::Compressed);
+        let ephemeral_point = encoded_point.as_bytes();
+        let shared_secret = diffie_hellman(ephemeral, self.public_key.as_affine());
+        let keys = derive_keys::<D>(
+            self.params,
+            ephemeral_point,
+            shared_secret.raw_secret_bytes(),
+            shared_info1,
+            msg.len(),
+        )?;
+        let (enc_key, mac_key) = self.params.split_keys(&keys, msg.len());
+
+        let mut ciphertext =
+            Vec::with_capacity(ephemeral_point.len() + msg.len() + self.params.tag_len::<D>());
+        ciphertext.extend_from_slice(ephemeral_point);
+        ciphertext.extend(msg.iter().zip(enc_key).map(|(m, k)| m ^ k));
+
+        let tag = compute_tag::<D>(
+            self.params,
+            mac_key,
+            &ciphertext[ephemeral_point.len()..],
+            shared_info2,
+        );
+        ciphertext.extend_from_slice(&tag[..self.params.tag_len::<D>()]);
+        Ok(ciphertext)
+    }
+}
+
+impl<C, D> Clone for EncryptingKey<C, D>
+where
+    C: CurveArithmetic,
+{
+    fn clone(&self) -> Self {
+        Self {
+            public_key: self.public_key,
+            params: self.params,
+            hash: PhantomData,
+        }
+    }
+}
+
+impl<C, D> fmt::Debug for EncryptingKey<C, D>
+where
+    C: CurveArithmetic,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptingKey")
+            .field("public_key", &self.public_key)
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+/// Secret key of the recipient, used to decrypt messages.
+pub struct DecryptingKey<C: CurveArithmetic, D> {
+    secret: NonZeroScalar<C>,
+    encrypting_key: EncryptingKey<C, D>,
+}
+
+impl<C, D> DecryptingKey<C, D>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromSec1Point<C> + ToSec1Point<C>,
+    D: EagerHash,
+{
+    /// Initialize [`DecryptingKey`] from a secret scalar with the default [`Params`].
+    #[must_use]
+    pub fn new(secret: NonZeroScalar<C>) -> Self {
+        Self::new_with_params(secret, Params::default())
+    }
+
+    /// Initialize [`DecryptingKey`] from a secret scalar with the given [`Params`].
+    #[must_use]
+    pub fn new_with_params(secret: NonZeroScalar<C>, params: Params) -> Self {
+        let public_key = PublicKey::from_secret_scalar(&secret);
+        Self {
+            secret,
+            encrypting_key: EncryptingKey::new_with_params(public_key, params),
+        }
+    }
+
+    /// Get the [`EncryptingKey`] which corresponds to this key.
+    #[must_use]
+    pub fn encrypting_key(&self) -> &EncryptingKey<C, D> {
+        &self.encrypting_key
+    }
+
+    /// Decrypt a ciphertext produced without shared information.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the ciphertext is malformed, if the ephemeral public key is not a
+    /// valid point, or if the MAC tag does not match.
+    pub fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        self.decrypt_with_shared_info(ciphertext, &[], &[])
+    }
+
+    /// Decrypt a ciphertext produced with the shared information `shared_info1` and
+    /// `shared_info2`.
+    ///
+    /// Ephemeral public keys are accepted in both compressed and uncompressed form, regardless
+    /// of [`Params::point_encoding`].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the ciphertext is malformed, if the ephemeral public key is not a
+    /// valid point, or if the MAC tag does not match.
+    pub fn decrypt_with_shared_info(
+        &self,
+        ciphertext: &[u8],
+        shared_info1: &[u8],
+        shared_info2: &[u8],
+    ) -> Result<Vec<u8>> {
+        let params = self.encrypting_key.params;
+        let point_len = match ciphertext.first() {
+            Some(0x02 | 0x03) => 1 + FieldBytesSize::<C>::USIZE,
+            Some(0x04) => 1 + 2 * FieldBytesSize::<C>::USIZE,
+            _ => return Err(Error),
+        };
+        let tag_len = params.tag_len::<D>();
+        if ciphertext.len() < point_len + tag_len {
+            return Err(Error);
+        }
+
+        let (ephemeral_point, rest) = ciphertext.split_at(point_len);
+        let (encrypted, tag) = rest.split_at(rest.len() - tag_len);
+
+        // Rejects the identity, and checks that the point is on the curve.
+        let ephemeral = PublicKey::<C>::from_sec1_bytes(ephemeral_point)?;
+        let shared_secret = diffie_hellman(self.secret, ephemeral.as_affine());
+        let keys = derive_keys::<D>(
+            params,
+            ephemeral_point,
+            shared_secret.raw_secret_bytes(),
+            shared_info1,
+            encrypted.len(),
+        )?;
+        let (enc_key, mac_key) = params.split_keys(&keys, encrypted.len());
+
+        let expected_tag = compute_tag::<D>(params, mac_key, encrypted, shared_info2);
+        if !bool::from(expected_tag[..tag_len].ct_eq(tag)) {
+            return Err(Error);
+        }
+
+        Ok(encrypted.iter().zip(enc_key).map(|(c, k)| c ^ k).collect())
+    }
+}
+
+impl<C, D> From<&SecretKey<C>> for DecryptingKey<C, D>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromSec1Point<C> + ToSec1Point<C>,
+    D: EagerHash,
+{
+    fn from(secret_key: &SecretKey<C>) -> Self {
+        Self::new(secret_key.to_nonzero_scalar())
+    }
+}
+
+impl<C, D> Generate for DecryptingKey<C, D>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromSec1Point<C> + ToSec1Point<C>,
+    D: EagerHash,
+{
+    fn try_generate_from_rng<R: TryCryptoRng + ?Sized>(
+        rng: &mut R,
+    ) -> core::result::Result<Self, R::Error> {
+        NonZeroScalar::try_generate_from_rng(rng).map(Self::new)
+    }
+}
+
+impl<C, D> fmt::Debug for DecryptingKey<C, D>
+where
+    C: CurveArithmetic,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecryptingKey")
+            .field("encrypting_key", &self.encrypting_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: CurveArithmetic, D> Drop for DecryptingKey<C, D> {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// Derive the encryption key of `msg_len` bytes and the MAC key, in the order given by
+/// [`Params::key_order`].
+fn derive_keys<D: EagerHash>(
+    params: Params,
+    ephemeral_point: &[u8],
+    shared_secret: &[u8],
+    shared_info1: &[u8],
+    msg_len: usize,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let ephemeral_point = match params.kdf_input {
+        KdfInput::SharedSecret => &[][..],
+        KdfInput::EphemeralKeyAndSharedSecret => ephemeral_point,
+    };
+
+    let hash_len = <D as OutputSizeUser>::output_size();
+    let keys_len = msg_len
+        .checked_add(params.mac_key_len::<D>())
+        .ok_or(Error)?;
+    let mut keys = Zeroizing::new(vec![0u8; keys_len]);
+    match params.kdf {
+        Kdf::AnsiX963 => {
+            for (counter, chunk) in (1u32..).zip(keys.chunks_mut(hash_len)) {
+                let block = D::new()
+                    .chain_update(ephemeral_point)
+                    .chain_update(shared_secret)
+                    .chain_update(counter.to_be_bytes())
+                    .chain_update(shared_info1)
+                    .finalize();
+                chunk.copy_from_slice(&block[..chunk.len()]);
+            }
+        }
+        Kdf::Hkdf => {
+            let prk = <Hmac<D> as KeyInit>::new_from_slice(&[])
+                .map_err(|_| Error)?
+                .chain_update(ephemeral_point)
+                .chain_update(shared_secret)
+                .finalize()
+                .into_bytes();
+            Hkdf::<D>::from_prk(&prk)
+                .map_err(|_| Error)?
+                .expand(shared_info1, &mut keys)
+                .map_err(|_| Error)?;
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Compute the (untruncated) MAC tag of the encrypted message.
+fn compute_tag<D: EagerHash>(
+    params: Params,
+    mac_key: &[u8],
+    encrypted: &[u8],
+    shared_info2: &[u8],
+) -> Output<Hmac<D>> {
+    let mut mac = <Hmac<D> as KeyInit>::new_from_slice(mac_key)
+        .expect("HMAC accepts all key sizes")
+        .chain_update(encrypted)
+        .chain_update(shared_info2);
+
+    if params.mac_input == MacInput::SharedInfoAndLength {
+        mac.update(&(shared_info2.len() as u64 * 8).to_be_bytes());
+    }
+
+    mac.finalize().into_bytes()
+}
@@ -0,0 +1,223 @@
+//! Elligator Squared: encoding of curve points as uniformly random byte strings.
+//!
+//! A point `P` is represented by a pair of field elements `(u1, u2)` such that
+//! `P = f(u1) + f(u2)`, where `f` is a map to the curve such as the simplified SWU map. When
+//! `u1` and `u2` are sampled as described in the paper below, the pair is statistically close to
+//! uniform in `F_p^2`.
+//!
+//! Each field element `u` is then encoded as the integer `u + j * p`, with `j` chosen at random
+//! such that the result is uniformly distributed in `[0, 2^(8 * len))`, where the encoding is
+//! 16 bytes longer than the canonical encoding of a field element. This makes encoded points
+//! indistinguishable from random strings, with a statistical distance of about `2^-128`.
+//!
+//! <https://eprint.iacr.org/2014/043>
+
+use core::ops::Add;
+use elliptic_curve::{
+    Field, FieldBytesSize, Group,
+    array::{Array, ArraySize, typenum::Sum},
+    consts::U16,
+    ops::Reduce,
+    rand_core::{CryptoRng, TryCryptoRng},
+    subtle::CtOption,
+};
+
+use crate::{
+    AffinePoint, PrimeCurveParams, PrimeFieldExt, ProjectivePoint,
+    osswu::{AffineOsswuMap, OsswuMap},
+    primefield::ByteOrder,
+};
+
+/// Encoding of curve points as a pair of uniformly random byte strings using Elligator Squared.
+///
+/// Implementations only provide the map to the curve and its inverse; encoding and decoding are
+/// provided methods.
+pub trait ElligatorSquared: Group {
+    /// Base field element, which reduces [`ElligatorSquared::UniformBytes`] in the byte order of
+    /// its representation.
+    type FieldElement: PrimeFieldExt + Reduce<Self::UniformBytes>;
+
+    /// Uniformly random encoding of a field element, which must be 16 bytes longer than
+    /// [`PrimeField::Repr`][`elliptic_curve::PrimeField::Repr`].
+    type UniformBytes: AsRef<[u8]> + AsMut<[u8]> + Clone + Default;
+
+    /// Map a field element to a point.
+    fn map_to_curve(u: &Self::FieldElement) -> Self;
+
+    /// Compute a preimage of this point under [`ElligatorSquared::map_to_curve`].
+    ///
+    /// A point has at most four preimages, which are indexed by `branch` (only the two low bits
+    /// are used). Returns none if the selected preimage does not exist.
+    fn map_preimage(&self, branch: u8) -> CtOption<Self::FieldElement>;
+
+    /// Encode this point as a pair of byte strings which are indistinguishable from uniformly
+    /// random ones.
+    ///
+    /// Encoding uses rejection sampling and needs about four iterations on average, regardless of
+    /// the point being encoded. It is not constant-time with respect to the RNG output.
+    fn encode_uniform<R: CryptoRng + ?Sized>(&self, rng: &mut R) -> [Self::UniformBytes; 2] {
+        let Ok(repr) = self.try_encode_uniform(rng);
+        repr
+    }
+
+    /// Encode this point as a pair of byte strings which are indistinguishable from uniformly
+    /// random ones.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    fn try_encode_uniform<R: TryCryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<[Self::UniformBytes; 2], R::Error> {
+        loop {
+            let u1 = Self::FieldElement::try_random(rng)?;
+            let mut branch = 0u8;
+            rng.try_fill_bytes(core::slice::from_mut(&mut branch))?;
+
+            let q = *self - Self::map_to_curve(&u1);
+            if let Some(u2) = q.map_preimage(branch).into_option() {
+                return Ok([
+                    to_uniform_bytes::<_, Self::UniformBytes, R>(&u1, rng)?,
+                    to_uniform_bytes::<_, Self::UniformBytes, R>(&u2, rng)?,
+                ]);
+            }
+        }
+    }
+
+    /// Decode a point produced by [`ElligatorSquared::encode_uniform`].
+    ///
+    /// Every pair of byte strings decodes to a point.
+    fn decode_uniform(bytes: &[Self::UniformBytes; 2]) -> Self {
+        Self::map_to_curve(&from_uniform_bytes(&bytes[0]))
+            + Self::map_to_curve(&from_uniform_bytes(&bytes[1]))
+    }
+}
+
+impl<C> ElligatorSquared for ProjectivePoint<C>
+where
+    C: PrimeCurveParams<FieldElement: OsswuMap + Reduce<Array<u8, Sum<FieldBytesSize<C>, U16>>>>,
+    FieldBytesSize<C>: Add<U16, Output: ArraySize>,
+{
+    type FieldElement = C::FieldElement;
+    type UniformBytes = Array<u8, Sum<FieldBytesSize<C>, U16>>;
+
+    fn map_to_curve(u: &C::FieldElement) -> Self {
+        AffinePoint::<C>::osswu(u).into()
+    }
+
+    fn map_preimage(&self, branch: u8) -> CtOption<C::FieldElement> {
+        self.to_affine().osswu_preimage(branch)
+    }
+}
+
+/// Encode a field element `u` as a big endian integer `u + j * p` which is uniformly distributed
+/// in `[0, 2^(8 * len))`.
+///
+/// For `k = 8 * len` and `t = k - NUM_BITS`, the number of multiples `j` for which `u + j * p`
+/// fits in `k` bits is between `2^t` and `2^(t + 1)`, so `j` is sampled by rejection from
+/// `[0, 2^(t + 1))`.
+fn to_uniform_bytes<F, B, R>(u: &F, rng: &mut R) -> Result<B, R::Error>
+where
+    F: PrimeFieldExt,
+    B: AsMut<[u8]> + Default,
+    R: TryCryptoRng + ?Sized,
+{
+    let u = u.to_be_repr();
+    let u = u.as_ref();
+    let mut modulus = (-F::ONE).to_be_repr();
+    add_one(modulus.as_mut());
+
+    let mut out = B::default();
+    let len = out.as_mut().len();
+    let j_bits = 8 * len - F::NUM_BITS as usize + 1;
+    let mut j = [0u8; 32];
+    let j = &mut j[..j_bits.div_ceil(8)];
+
+    loop {
+        rng.try_fill_bytes(j)?;
+        j[0] &= 0xff >> (8 * j.len() - j_bits);
+
+        let bytes = out.as_mut();
+        bytes.fill(0);
+        bytes[len - u.len()..].copy_from_slice(u);
+
+        if !mul_add(bytes, modulus.as_ref(), j) {
+            return Ok(out);
+        }
+    }
+}
+
+/// Decode a field element encoded with [`to_uniform_bytes`], i.e. reduce a big endian integer
+/// modulo `p`.
+fn from_uniform_bytes<F, B>(bytes: &B) -> F
+where
+    F: PrimeFieldExt + Reduce<B>,
+    B: AsRef<[u8]> + AsMut<[u8]> + Clone,
+{
+    if F::REPR_ENDIANNESS == ByteOrder::BigEndian {
+        return F::reduce(bytes);
+    }
+
+    let mut bytes = bytes.clone();
+    bytes.as_mut().reverse();
+    F::reduce(&bytes)
+}
+
+/// Add one to a big endian integer, which must not overflow.
+fn add_one(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut().rev() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+
+        if !carry {
+            break;
+        }
+    }
+}
+
+/// Compute `acc += a * b` on big endian integers, returning whether the result overflowed `acc`.
+fn mul_add(acc: &mut [u8], a: &[u8], b: &[u8]) -> bool {
+    let len = acc.len();
+    let mut overflow = false;
+
+    for (i, &b_i) in b.iter().rev().enumerate() {
+        let mut carry = 0u32;
+
+        for pos in i.. {
+            let a_j = a.len().checked_sub(pos - i + 1).map_or(0, |idx| a[idx]);
+            if pos - i >= a.len() && carry == 0 {
+                break;
+            }
+
+            let mut sum = carry + u32::from(a_j) * u32::from(b_i);
+            if pos < len {
+                sum += u32::from(acc[len - 1 - pos]);
+                acc[len - 1 - pos] = sum.to_le_bytes()[0];
+            } else {
+                overflow |= sum & 0xff != 0;
+            }
+            carry = sum >> 8;
+        }
+    }
+
+    overflow
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mul_add;
+
+    #[test]
+    fn mul_add_carries() {
+        let mut acc = [0x00, 0x00, 0xff, 0xff];
+        assert!(!mul_add(&mut acc, &[0xff, 0xff], &[0x01, 0x01]));
+        assert_eq!(acc, [0x01, 0x01, 0xfe, 0xfe]);
+
+        let mut acc = [0xff, 0xff];
+        assert!(mul_add(&mut acc, &[0x01], &[0x01]));
+        assert_eq!(acc, [0x00, 0x00]);
+
+        let mut acc = [0x00, 0x00];
+        assert!(mul_add(&mut acc, &[0x01, 0x00], &[0x01, 0x00]));
+    }
+}
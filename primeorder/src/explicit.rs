@@ -0,0 +1,636 @@
+//! Short Weierstrass curves with explicit domain parameters known only at runtime.
+//!
+//! Keys and certificates sometimes describe their curve with an explicit SEC1/X9.62
+//! `ECParameters` structure (prime `p`, coefficients `a` and `b`, generator `G`, order `n` and
+//! cofactor `h`) rather than with a named curve OID. [`ExplicitCurve`] parses such parameters,
+//! validates them, decodes points and verifies ECDSA signatures. Parameters which match one of
+//! the built-in curves can be mapped to it with [`ExplicitCurve::named_curve`].
+//!
+//! Only prime order curves (`h = 1`) over prime fields are supported. Field arithmetic uses
+//! `crypto-bigint`'s boxed Montgomery forms, and points are added with the complete formulas from
+//! [Renes-Costello-Batina 2015] for arbitrary `a`.
+//!
+//! All operations are variable-time: they are intended for public data such as domain
+//! parameters, public keys and signatures, and must not be used with secret scalars.
+//!
+//! [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+
+mod der;
+mod ecdsa;
+mod field;
+mod named;
+mod point;
+mod primality;
+
+pub use self::{named::NamedCurve, point::ExplicitPoint};
+
+use self::field::FieldElement;
+use alloc::vec::Vec;
+use bigint::{BoxedUint, Limb, Odd, Resize, modular::BoxedMontyParams};
+use core::fmt;
+use elliptic_curve::pkcs8::ObjectIdentifier;
+
+/// Maximum size of the field modulus and group order in bits, i.e. the size of P-521's.
+///
+/// Larger parameters are rejected before performing any arithmetic, since primality testing and
+/// scalar multiplication costs grow quickly with their size.
+const MAX_BITS: usize = 521;
+
+/// Largest embedding degree rejected by [`ExplicitCurve::validate`], as recommended by [SEC1].
+///
+/// [SEC1]: https://www.secg.org/sec1-v2.pdf
+const MOV_DEGREE_BOUND: u32 = 100;
+
+/// Errors which can occur when parsing, validating or using explicit curve parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Malformed DER encoding.
+    Der,
+
+    /// The parameters describe a curve over a field other than a prime field.
+    UnsupportedField,
+
+    /// The named curve's OID is not recognized.
+    UnknownCurve,
+
+    /// The field modulus is not an odd prime greater than 3, or a coefficient is out of range.
+    InvalidField,
+
+    /// The curve is singular, i.e. `4a³ + 27b² = 0`.
+    Singular,
+
+    /// The generator is the identity or is not on the curve.
+    InvalidGenerator,
+
+    /// The order is not prime or is not the order of the generator.
+    InvalidOrder,
+
+    /// The cofactor is not 1.
+    UnsupportedCofactor,
+
+    /// The curve is anomalous, i.e. its order equals the field modulus.
+    Anomalous,
+
+    /// The curve's embedding degree is small enough for the MOV attack.
+    SmallEmbeddingDegree,
+
+    /// Malformed point encoding, or the point is not on the curve.
+    InvalidPoint,
+
+    /// Malformed or invalid signature.
+    InvalidSignature,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Der => "malformed DER encoding",
+            Self::UnsupportedField => "unsupported field type",
+            Self::UnknownCurve => "unknown named curve",
+            Self::InvalidField => "invalid field parameters",
+            Self::Singular => "singular curve",
+            Self::InvalidGenerator => "invalid generator",
+            Self::InvalidOrder => "invalid group order",
+            Self::UnsupportedCofactor => "unsupported cofactor",
+            Self::Anomalous => "anomalous curve",
+            Self::SmallEmbeddingDegree => "small embedding degree",
+            Self::InvalidPoint => "invalid point",
+            Self::InvalidSignature => "invalid signature",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Result type with the [`explicit`](self) module's [`Error`] type.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Short Weierstrass curve `y² = x³ + ax + b` of prime order, with domain parameters known only
+/// at runtime.
+#[derive(Clone, Debug)]
+pub struct ExplicitCurve {
+    /// Montgomery parameters for the field modulus `p`.
+    field: BoxedMontyParams,
+
+    /// Montgomery parameters for the group order `n`.
+    scalars: BoxedMontyParams,
+
+    /// Coefficient `a` in the curve equation.
+    a: FieldElement,
+
+    /// Coefficient `b` in the curve equation.
+    b: FieldElement,
+
+    /// `3b`, as used by the point arithmetic formulas.
+    b3: FieldElement,
+
+    /// Base point.
+    generator: ExplicitPoint,
+
+    /// Size of a serialized field element in bytes.
+    field_bytes: usize,
+
+    /// Size of a serialized scalar in bytes.
+    scalar_bytes: usize,
+}
+
+impl ExplicitCurve {
+    /// Create a curve from the big-endian encodings of its field modulus `p`, coefficients `a`
+    /// and `b`, and order `n`, along with the [SEC1] encoding of its generator.
+    ///
+    /// Only range checks are performed: call [`ExplicitCurve::validate`] before relying on
+    /// parameters from an untrusted source.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidField`] if `p` is not an odd integer greater than 3, is larger than 521
+    ///   bits, or `a` or `b` aren't reduced modulo `p`.
+    /// - [`Error::InvalidOrder`] if `n` is not an odd integer greater than 3, or is larger than
+    ///   521 bits.
+    /// - [`Error::InvalidGenerator`] if the generator is malformed, the identity or not on the
+    ///   curve.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    pub fn new(p: &[u8], a: &[u8], b: &[u8], generator: &[u8], n: &[u8]) -> Result<Self> {
+        let (p, n) = (trim(p), trim(n));
+        if p.is_empty() || bit_length(p) > MAX_BITS {
+            return Err(Error::InvalidField);
+        }
+        if bit_length(n) > MAX_BITS {
+            return Err(Error::InvalidOrder);
+        }
+
+        let bits_precision = u32::try_from(8 * p.len().max(n.len()))
+            .map_err(|_| Error::InvalidField)?
+            .next_multiple_of(Limb::BITS);
+
+        let field = modulus_params(p, bits_precision).ok_or(Error::InvalidField)?;
+        let scalars = modulus_params(n, bits_precision).ok_or(Error::InvalidOrder)?;
+        let a = decode_field_element(a, &field).ok_or(Error::InvalidField)?;
+        let b = decode_field_element(b, &field).ok_or(Error::InvalidField)?;
+        let b3 = &b.double() + &b;
+
+        let mut curve = Self {
+            generator: ExplicitPoint::identity(&field),
+            field,
+            scalars,
+            a,
+            b,
+            b3,
+            field_bytes: p.len(),
+            scalar_bytes: n.len(),
+        };
+
+        curve.generator = curve
+            .decode_point(generator)
+            .map_err(|_| Error::InvalidGenerator)?;
+
+        if curve.generator.is_identity() {
+            return Err(Error::InvalidGenerator);
+        }
+
+        Ok(curve)
+    }
+
+    /// Parse DER-encoded `ECParameters` as defined in [SEC1] § C.2.
+    ///
+    /// Accepts both explicit `specifiedCurve` parameters and the `namedCurve` OIDs of the curves
+    /// in [`NamedCurve`]. As with [`ExplicitCurve::new`], explicit parameters still need to be
+    /// checked with [`ExplicitCurve::validate`].
+    ///
+    /// # Errors
+    /// - [`Error::Der`] if the encoding is malformed.
+    /// - [`Error::UnsupportedField`] if the curve is not over a prime field.
+    /// - [`Error::UnknownCurve`] if the named curve is not one of [`NamedCurve`].
+    /// - [`Error::UnsupportedCofactor`] if the cofactor is present and not 1.
+    /// - Any error returned by [`ExplicitCurve::new`].
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        match der::parse_ec_parameters(bytes)? {
+            der::EcParameters::NamedCurve(oid) => ObjectIdentifier::from_bytes(oid)
+                .ok()
+                .and_then(NamedCurve::from_oid)
+                .map(Self::from)
+                .ok_or(Error::UnknownCurve),
+            der::EcParameters::Specified(domain) => {
+                if domain.cofactor.is_some_and(|cofactor| cofactor != [1]) {
+                    return Err(Error::UnsupportedCofactor);
+                }
+
+                Self::new(domain.p, domain.a, domain.b, domain.base, domain.order)
+            }
+        }
+    }
+
+    /// Find the built-in curve with the same domain parameters as this one, if any.
+    #[must_use]
+    pub fn named_curve(&self) -> Option<NamedCurve> {
+        let (gx, gy) = self.generator.to_affine()?;
+        let values = [
+            self.modulus().to_be_bytes(),
+            self.a.to_uint().to_be_bytes(),
+            self.b.to_uint().to_be_bytes(),
+            gx.to_uint().to_be_bytes(),
+            gy.to_uint().to_be_bytes(),
+            self.order().to_be_bytes(),
+        ];
+
+        NamedCurve::ALL.iter().copied().find(|curve| {
+            let params = curve.params();
+            [params.p, params.a, params.b, params.gx, params.gy, params.n]
+                .iter()
+                .zip(&values)
+                .all(|(hex, value)| trim(&decode_hex(hex)) == trim(value))
+        })
+    }
+
+    /// Validate the domain parameters as described in [SEC1] § 3.1.1.2.1.
+    ///
+    /// Checks that:
+    /// - `p` is prime, and the curve is not singular.
+    /// - `n` is prime and `nG` is the identity.
+    /// - The group order is exactly `n`, i.e. the cofactor is 1.
+    /// - The curve is not anomalous (`n ≠ p`).
+    /// - `p^k ≠ 1 (mod n)` for `1 ≤ k ≤ 100`, which rules out the MOV attack.
+    ///
+    /// Primality is checked with the Baillie-PSW test, which has no known counterexamples.
+    /// Twist security is not checked, since several standardized curves would fail it: see
+    /// [`ExplicitCurve::twist_security`].
+    ///
+    /// # Errors
+    /// - [`Error::InvalidField`] if `p` is not prime.
+    /// - [`Error::Singular`] if `4a³ + 27b² = 0`.
+    /// - [`Error::InvalidOrder`] if `n` is not prime or not the order of `G`.
+    /// - [`Error::UnsupportedCofactor`] if the group order is a proper multiple of `n`.
+    /// - [`Error::Anomalous`] if `n = p`.
+    /// - [`Error::SmallEmbeddingDegree`] if the embedding degree is at most 100.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    pub fn validate(&self) -> Result<()> {
+        let small_primes = primality::small_primes();
+        let (p, n) = (self.modulus(), self.order());
+
+        if !primality::is_prime(p, &small_primes) {
+            return Err(Error::InvalidField);
+        }
+
+        let four_a3 = &FieldElement::from_u64(4, &self.field) * &(&self.a.square() * &self.a);
+        let twenty_seven_b2 = &FieldElement::from_u64(27, &self.field) * &self.b.square();
+        if (&four_a3 + &twenty_seven_b2).is_zero() {
+            return Err(Error::Singular);
+        }
+
+        if !primality::is_prime(n, &small_primes)
+            || !self
+                .mul_vartime(&self.generator, &n.to_be_bytes())
+                .is_identity()
+        {
+            return Err(Error::InvalidOrder);
+        }
+
+        if !cofactor_is_one(p, n) {
+            return Err(Error::UnsupportedCofactor);
+        }
+
+        if p == n {
+            return Err(Error::Anomalous);
+        }
+
+        let p_mod_n = FieldElement::new(p.clone(), &self.scalars);
+        let one = FieldElement::one(&self.scalars);
+        let mut power = p_mod_n.clone();
+        for _ in 0..MOV_DEGREE_BOUND {
+            if power == one {
+                return Err(Error::SmallEmbeddingDegree);
+            }
+            power = &power * &p_mod_n;
+        }
+
+        Ok(())
+    }
+
+    /// Estimate the security level in bits of the curve's quadratic twist against Pollard's rho.
+    ///
+    /// Twist security matters for implementations which compute with points given only by their
+    /// x-coordinate without checking that they are on the curve. The twist has order
+    /// `2(p + 1) - n`: after removing factors below 2¹⁶ by trial division, if the rest is prime
+    /// then the estimate is half of its bit length. Otherwise returns `None`, since fully
+    /// factoring the twist order is infeasible in general.
+    ///
+    /// This should only be called on parameters accepted by [`ExplicitCurve::validate`].
+    #[must_use]
+    pub fn twist_security(&self) -> Option<u32> {
+        let (p, n) = (self.modulus(), self.order());
+        let bits_precision = p.bits_precision() + Limb::BITS;
+        let p_plus_one = p
+            .resize(bits_precision)
+            .wrapping_add(BoxedUint::one_with_precision(bits_precision));
+        let twice_p_plus_one = p_plus_one.wrapping_add(&p_plus_one);
+        let n = n.resize(bits_precision);
+
+        if n >= twice_p_plus_one {
+            return None;
+        }
+
+        let small_primes = primality::small_primes();
+        let mut bytes = twice_p_plus_one.wrapping_sub(&n).to_be_bytes().into_vec();
+        let mut largest_factor = 1;
+
+        while bytes.last().is_some_and(|byte| byte & 1 == 0) {
+            primality::div_small(&mut bytes, 2);
+            largest_factor = 2;
+        }
+
+        for &prime in &small_primes {
+            while primality::rem_small(&bytes, prime) == 0 {
+                primality::div_small(&mut bytes, prime);
+                largest_factor = prime;
+            }
+        }
+
+        let rest = decode_uint(&bytes, bits_precision)?;
+        if rest.bits_vartime() <= 1 {
+            return Some((u32::BITS - largest_factor.leading_zeros()) / 2);
+        }
+
+        primality::is_prime(&rest, &small_primes).then(|| rest.bits_vartime() / 2)
+    }
+
+    /// Field modulus `p`.
+    fn modulus(&self) -> &BoxedUint {
+        self.field.modulus().as_ref()
+    }
+
+    /// Group order `n`.
+    fn order(&self) -> &BoxedUint {
+        self.scalars.modulus().as_ref()
+    }
+
+    /// Decode a field element from big-endian bytes, rejecting unreduced values.
+    fn field_element(&self, bytes: &[u8]) -> Option<FieldElement> {
+        decode_field_element(bytes, &self.field)
+    }
+
+    /// Encode a field element as big-endian bytes of the size of the field modulus.
+    fn field_element_bytes(&self, element: &FieldElement) -> Vec<u8> {
+        let bytes = element.to_uint().to_be_bytes();
+        bytes[bytes.len() - self.field_bytes..].to_vec()
+    }
+}
+
+impl From<NamedCurve> for ExplicitCurve {
+    fn from(curve: NamedCurve) -> Self {
+        let params = curve.params();
+        let mut generator = vec![0x04];
+        generator.extend(decode_hex(params.gx));
+        generator.extend(decode_hex(params.gy));
+
+        Self::new(
+            &decode_hex(params.p),
+            &decode_hex(params.a),
+            &decode_hex(params.b),
+            &generator,
+            &decode_hex(params.n),
+        )
+        .expect("named curve parameters should be valid")
+    }
+}
+
+/// Check that `2n > p + 1 + 2√p` for a prime `n` dividing the group order.
+///
+/// By the Hasse bound the group order is at most `p + 1 + 2√p`, so the cofactor must then be 1.
+/// Conversely this always holds when the cofactor is 1 and `p` isn't tiny.
+fn cofactor_is_one(p: &BoxedUint, n: &BoxedUint) -> bool {
+    if n > p {
+        return true;
+    }
+
+    // `2n - (p + 1) = n - t` with `t = p + 1 - n`, and `2√p < 2^(⌈log₂(p) / 2⌉ + 1)`
+    let t = p
+        .wrapping_sub(n)
+        .wrapping_add(BoxedUint::one_with_precision(p.bits_precision()));
+    n > &t && n.wrapping_sub(&t).bits_vartime() > p.bits_vartime().div_ceil(2) + 1
+}
+
+/// Montgomery parameters for an odd modulus greater than 3 given as big-endian bytes.
+fn modulus_params(bytes: &[u8], bits_precision: u32) -> Option<BoxedMontyParams> {
+    let modulus = decode_uint(bytes, bits_precision).filter(|uint| uint.bits_vartime() > 2)?;
+    Odd::new(modulus).into_option().map(BoxedMontyParams::new)
+}
+
+/// Decode a field element from big-endian bytes, rejecting unreduced values.
+fn decode_field_element(bytes: &[u8], params: &BoxedMontyParams) -> Option<FieldElement> {
+    let modulus: &BoxedUint = params.modulus().as_ref();
+    let uint = decode_uint(bytes, params.bits_precision())?;
+    (uint < *modulus).then(|| FieldElement::new(uint, params))
+}
+
+/// Decode a big-endian integer with the given precision, if it fits.
+fn decode_uint(bytes: &[u8], bits_precision: u32) -> Option<BoxedUint> {
+    let bytes = trim(bytes);
+    let len = bits_precision as usize / 8;
+    if bytes.len() > len {
+        return None;
+    }
+
+    let mut padded = vec![0; len];
+    padded[len - bytes.len()..].copy_from_slice(bytes);
+    BoxedUint::from_be_slice(&padded, bits_precision).ok()
+}
+
+/// Bit length of a big-endian integer without leading zero bytes.
+fn bit_length(bytes: &[u8]) -> usize {
+    bytes
+        .first()
+        .map_or(0, |&byte| 8 * bytes.len() - byte.leading_zeros() as usize)
+}
+
+/// Strip leading zero bytes from a big-endian integer.
+fn trim(bytes: &[u8]) -> &[u8] {
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    &bytes[zeros..]
+}
+
+/// Decode a hexadecimal string known to be valid.
+fn decode_hex(hex: &str) -> Vec<u8> {
+    fn nibble(digit: u8) -> u8 {
+        match digit {
+            b'0'..=b'9' => digit - b'0',
+            b'a'..=b'f' => digit - b'a' + 10,
+            b'A'..=b'F' => digit - b'A' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+
+    hex.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| (nibble(pair[0]) << 4) | nibble(pair[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ExplicitCurve, NamedCurve, decode_hex};
+
+    /// `openssl ecparam -name prime256v1 -param_enc explicit -outform DER`
+    const P256_EXPLICIT: &str = "3081f7020101302c06072a8648ce3d0101022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff305b0420ffffffff00000001000000000000000000000000fffffffffffffffffffffffc04205ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b031500c49d360886e704936a6678e1139d26b7819f7e900441046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551020101";
+
+    /// `openssl ecparam -name brainpoolP256r1 -param_enc explicit -outform DER`
+    const BRAINPOOL_P256R1_EXPLICIT: &str = "3081e0020101302c06072a8648ce3d0101022100a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377304404207d5a0975fc2c3057eef67530417affe7fb8055c126dc5c6ce94a4b44f330b5d9042026dc5c6ce94a4b44f330b5d9bbd77cbf958416295cf7e1ce6bccdc18ff8c07b60441048bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997022100a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7020101";
+
+    /// `openssl ecparam -name secp256k1 -param_enc explicit -outform DER`
+    const SECP256K1_EXPLICIT: &str = "3081e0020101302c06072a8648ce3d0101022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f3044042000000000000000000000000000000000000000000000000000000000000000000420000000000000000000000000000000000000000000000000000000000000000704410479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141020101";
+
+    /// P-256 public key for the RFC 6979 § A.2.5 private key.
+    const P256_PUBLIC_KEY: &str = "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+
+    /// SHA-256 of `explicit`.
+    const PREHASH: &str = "3b283e93debf035e990dfce1f21468476dc57c69313c5574f43ad1a185840277";
+
+    const SIGNATURE: &str = "471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c2554ec600aa03317dbc828aa24dc56d7c94ba04a45fbce2564827a1ca0671ff3";
+
+    const SIGNATURE_DER: &str = "30440220471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c02202554ec600aa03317dbc828aa24dc56d7c94ba04a45fbce2564827a1ca0671ff3";
+
+    #[test]
+    fn named_curves() {
+        for &named in NamedCurve::ALL {
+            let curve = ExplicitCurve::from(named);
+            assert_eq!(curve.validate(), Ok(()), "{}", named.name());
+            assert_eq!(curve.named_curve(), Some(named));
+
+            for compress in [false, true] {
+                let encoded = curve.encode_point(curve.generator(), compress);
+                assert_eq!(curve.decode_point(&encoded).as_ref(), Ok(curve.generator()));
+            }
+        }
+    }
+
+    #[test]
+    fn from_der() {
+        for (der, named) in [
+            (P256_EXPLICIT, NamedCurve::NistP256),
+            (BRAINPOOL_P256R1_EXPLICIT, NamedCurve::BrainpoolP256r1),
+            (SECP256K1_EXPLICIT, NamedCurve::Secp256k1),
+        ] {
+            let curve = ExplicitCurve::from_der(&decode_hex(der)).unwrap();
+            assert_eq!(curve.validate(), Ok(()));
+            assert_eq!(curve.named_curve(), Some(named));
+        }
+
+        let curve = ExplicitCurve::from_der(&decode_hex("06082a8648ce3d030107")).unwrap();
+        assert_eq!(curve.named_curve(), Some(NamedCurve::NistP256));
+    }
+
+    #[test]
+    fn from_der_rejects_cofactor() {
+        let mut der = decode_hex(P256_EXPLICIT);
+        *der.last_mut().unwrap() = 2;
+        assert_eq!(
+            ExplicitCurve::from_der(&der).unwrap_err(),
+            Error::UnsupportedCofactor
+        );
+    }
+
+    #[test]
+    fn validate_rejects_invalid_parameters() {
+        let params = NamedCurve::NistP256.params();
+        let (p, a, b, n) = (
+            decode_hex(params.p),
+            decode_hex(params.a),
+            decode_hex(params.b),
+            decode_hex(params.n),
+        );
+        let mut generator = decode_hex("04");
+        generator.extend(decode_hex(params.gx));
+        generator.extend(decode_hex(params.gy));
+
+        // `n - 2` is not the order of the generator
+        let mut wrong_order = n.clone();
+        *wrong_order.last_mut().unwrap() -= 2;
+        let curve = ExplicitCurve::new(&p, &a, &b, &generator, &wrong_order).unwrap();
+        assert_eq!(curve.validate(), Err(Error::InvalidOrder));
+
+        // changing `b` moves the generator off the curve
+        let mut wrong_b = b.clone();
+        *wrong_b.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            ExplicitCurve::new(&p, &a, &wrong_b, &generator, &n).unwrap_err(),
+            Error::InvalidGenerator
+        );
+
+        // y² = x³ is singular
+        let mut one = vec![0; 32];
+        one[31] = 1;
+        let mut singular_generator = decode_hex("04");
+        singular_generator.extend(&one);
+        singular_generator.extend(&one);
+        let curve = ExplicitCurve::new(&p, &[0], &[0], &singular_generator, &n).unwrap();
+        assert_eq!(curve.validate(), Err(Error::Singular));
+    }
+
+    #[test]
+    fn new_rejects_oversized_parameters() {
+        let params = NamedCurve::NistP521.params();
+        let (p, a, b, n) = (
+            decode_hex(params.p),
+            decode_hex(params.a),
+            decode_hex(params.b),
+            decode_hex(params.n),
+        );
+        let mut generator = decode_hex("04");
+        generator.extend(decode_hex(params.gx));
+        generator.extend(decode_hex(params.gy));
+
+        // P-521's own parameters are the largest accepted ones
+        assert!(ExplicitCurve::new(&p, &a, &b, &generator, &n).is_ok());
+
+        let mut oversized = p.clone();
+        oversized[0] = 0x03;
+        assert_eq!(
+            ExplicitCurve::new(&oversized, &a, &b, &generator, &n).unwrap_err(),
+            Error::InvalidField
+        );
+        assert_eq!(
+            ExplicitCurve::new(&p, &a, &b, &generator, &oversized).unwrap_err(),
+            Error::InvalidOrder
+        );
+
+        let huge = [0xff; 4096];
+        assert_eq!(
+            ExplicitCurve::new(&huge, &a, &b, &generator, &n).unwrap_err(),
+            Error::InvalidField
+        );
+    }
+
+    #[test]
+    fn twist_security() {
+        assert_eq!(
+            ExplicitCurve::from(NamedCurve::NistP256).twist_security(),
+            Some(120)
+        );
+    }
+
+    #[test]
+    fn verify_ecdsa() {
+        let curve = ExplicitCurve::from_der(&decode_hex(P256_EXPLICIT)).unwrap();
+        let public_key = curve.decode_point(&decode_hex(P256_PUBLIC_KEY)).unwrap();
+        let prehash = decode_hex(PREHASH);
+
+        assert_eq!(
+            curve.verify_prehash(&public_key, &prehash, &decode_hex(SIGNATURE)),
+            Ok(())
+        );
+        assert_eq!(
+            curve.verify_prehash_der(&public_key, &prehash, &decode_hex(SIGNATURE_DER)),
+            Ok(())
+        );
+
+        let mut signature = decode_hex(SIGNATURE);
+        signature[40] ^= 1;
+        assert_eq!(
+            curve.verify_prehash(&public_key, &prehash, &signature),
+            Err(Error::InvalidSignature)
+        );
+    }
+}
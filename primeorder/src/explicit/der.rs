@@ -0,0 +1,175 @@
+//! Minimal DER reader for the `ECParameters` and `ECDSA-Sig-Value` structures from [SEC1].
+//!
+//! [SEC1]: https://www.secg.org/sec1-v2.pdf
+
+use super::{Error, Result, trim};
+
+const INTEGER: u8 = 0x02;
+const BIT_STRING: u8 = 0x03;
+const OCTET_STRING: u8 = 0x04;
+const OBJECT_IDENTIFIER: u8 = 0x06;
+const SEQUENCE: u8 = 0x30;
+
+/// DER encoding of the `prime-field` object identifier (1.2.840.10045.1.1).
+const PRIME_FIELD: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x01, 0x01];
+
+/// `ECParameters` as defined in [SEC1] § C.2.
+///
+/// The `implicitCA` choice is not supported.
+///
+/// [SEC1]: https://www.secg.org/sec1-v2.pdf
+pub(super) enum EcParameters<'a> {
+    /// Contents of the `namedCurve` object identifier.
+    NamedCurve(&'a [u8]),
+
+    /// Explicit `specifiedCurve` domain parameters.
+    Specified(SpecifiedDomain<'a>),
+}
+
+/// `SpecifiedECDomain` over a prime field, with integers as big-endian bytes.
+pub(super) struct SpecifiedDomain<'a> {
+    pub(super) p: &'a [u8],
+    pub(super) a: &'a [u8],
+    pub(super) b: &'a [u8],
+    pub(super) base: &'a [u8],
+    pub(super) order: &'a [u8],
+    pub(super) cofactor: Option<&'a [u8]>,
+}
+
+/// Parse DER-encoded `ECParameters`.
+pub(super) fn parse_ec_parameters(bytes: &[u8]) -> Result<EcParameters<'_>> {
+    let mut reader = Reader::new(bytes);
+    let params = if reader.peek_tag() == Some(OBJECT_IDENTIFIER) {
+        EcParameters::NamedCurve(reader.read(OBJECT_IDENTIFIER)?)
+    } else {
+        EcParameters::Specified(parse_specified_domain(reader.read(SEQUENCE)?)?)
+    };
+    reader.finish()?;
+    Ok(params)
+}
+
+/// Parse the contents of a `SpecifiedECDomain` sequence.
+fn parse_specified_domain(bytes: &[u8]) -> Result<SpecifiedDomain<'_>> {
+    let mut reader = Reader::new(bytes);
+    if !matches!(reader.read_uint()?, [1..=3]) {
+        return Err(Error::Der);
+    }
+
+    let mut field_id = Reader::new(reader.read(SEQUENCE)?);
+    if field_id.read(OBJECT_IDENTIFIER)? != PRIME_FIELD {
+        return Err(Error::UnsupportedField);
+    }
+    let p = field_id.read_uint()?;
+    field_id.finish()?;
+
+    let mut curve = Reader::new(reader.read(SEQUENCE)?);
+    let a = curve.read(OCTET_STRING)?;
+    let b = curve.read(OCTET_STRING)?;
+    if curve.peek_tag() == Some(BIT_STRING) {
+        // the seed is only needed to verify that the curve was generated verifiably at random
+        curve.read(BIT_STRING)?;
+    }
+    curve.finish()?;
+
+    let base = reader.read(OCTET_STRING)?;
+    let order = reader.read_uint()?;
+    let cofactor = if reader.peek_tag() == Some(INTEGER) {
+        Some(reader.read_uint()?)
+    } else {
+        None
+    };
+
+    // the optional hash algorithm of versions 2 and 3 is ignored
+    Ok(SpecifiedDomain {
+        p,
+        a,
+        b,
+        base,
+        order,
+        cofactor,
+    })
+}
+
+/// Parse a DER-encoded `ECDSA-Sig-Value`, returning `r` and `s` as big-endian bytes.
+pub(super) fn parse_signature(bytes: &[u8]) -> Result<(&[u8], &[u8])> {
+    let mut reader = Reader::new(bytes);
+    let mut sequence = Reader::new(reader.read(SEQUENCE)?);
+    reader.finish()?;
+
+    let r = sequence.read_uint()?;
+    let s = sequence.read_uint()?;
+    sequence.finish()?;
+    Ok((r, s))
+}
+
+/// Reader for a sequence of DER tag-length-value triples.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.bytes.first().copied()
+    }
+
+    /// Read a value with the given tag, returning its contents.
+    fn read(&mut self, tag: u8) -> Result<&'a [u8]> {
+        let (&actual, rest) = self.bytes.split_first().ok_or(Error::Der)?;
+        if actual != tag {
+            return Err(Error::Der);
+        }
+
+        let (&first, mut rest) = rest.split_first().ok_or(Error::Der)?;
+        let len = if first < 0x80 {
+            usize::from(first)
+        } else {
+            // long form, which DER only allows for lengths of at least 128 without leading zeros
+            let count = usize::from(first & 0x7f);
+            if count == 0 || count > size_of::<usize>() || count > rest.len() {
+                return Err(Error::Der);
+            }
+
+            let (len_bytes, tail) = rest.split_at(count);
+            rest = tail;
+            let len = len_bytes
+                .iter()
+                .fold(0usize, |len, &byte| (len << 8) | usize::from(byte));
+
+            if len_bytes[0] == 0 || len < 0x80 {
+                return Err(Error::Der);
+            }
+            len
+        };
+
+        if len > rest.len() {
+            return Err(Error::Der);
+        }
+
+        let (value, rest) = rest.split_at(len);
+        self.bytes = rest;
+        Ok(value)
+    }
+
+    /// Read a non-negative `INTEGER`, returning its big-endian bytes without leading zeros.
+    fn read_uint(&mut self) -> Result<&'a [u8]> {
+        match self.read(INTEGER)? {
+            [] => Err(Error::Der),
+            [first, ..] if first & 0x80 != 0 => Err(Error::Der),
+            [0, second, ..] if second & 0x80 == 0 => Err(Error::Der),
+            value => Ok(trim(value)),
+        }
+    }
+
+    /// Ensure all input has been consumed.
+    fn finish(self) -> Result<()> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Der)
+        }
+    }
+}
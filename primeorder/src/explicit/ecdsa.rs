@@ -0,0 +1,105 @@
+//! ECDSA signature verification.
+
+use super::{Error, ExplicitCurve, ExplicitPoint, Result, decode_uint, der, field::FieldElement};
+
+impl ExplicitCurve {
+    /// Verify an ECDSA signature over a message digest, as described in [SEC1] § 4.1.4.
+    ///
+    /// The signature is the concatenation of `r` and `s`, each encoded as a big-endian integer
+    /// of the same length as the group order. The public key must have been obtained from
+    /// [`ExplicitCurve::decode_point`] on this curve.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidPoint`] if the public key is the identity.
+    /// - [`Error::InvalidSignature`] if the signature is malformed or doesn't verify.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    pub fn verify_prehash(
+        &self,
+        public_key: &ExplicitPoint,
+        prehash: &[u8],
+        signature: &[u8],
+    ) -> Result<()> {
+        if signature.len() != 2 * self.scalar_bytes {
+            return Err(Error::InvalidSignature);
+        }
+
+        let (r, s) = signature.split_at(self.scalar_bytes);
+        self.verify_prehash_rs(public_key, prehash, r, s)
+    }
+
+    /// Verify an ASN.1 DER-encoded ECDSA signature over a message digest.
+    ///
+    /// See [`ExplicitCurve::verify_prehash`] for details.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidPoint`] if the public key is the identity.
+    /// - [`Error::InvalidSignature`] if the signature is malformed or doesn't verify.
+    pub fn verify_prehash_der(
+        &self,
+        public_key: &ExplicitPoint,
+        prehash: &[u8],
+        signature: &[u8],
+    ) -> Result<()> {
+        let (r, s) = der::parse_signature(signature).map_err(|_| Error::InvalidSignature)?;
+        self.verify_prehash_rs(public_key, prehash, r, s)
+    }
+
+    fn verify_prehash_rs(
+        &self,
+        public_key: &ExplicitPoint,
+        prehash: &[u8],
+        r: &[u8],
+        s: &[u8],
+    ) -> Result<()> {
+        if public_key.is_identity() {
+            return Err(Error::InvalidPoint);
+        }
+
+        let r = self.scalar(r).ok_or(Error::InvalidSignature)?;
+        let s = self.scalar(s).ok_or(Error::InvalidSignature)?;
+        let z = self
+            .prehash_to_scalar(prehash)
+            .ok_or(Error::InvalidSignature)?;
+        let s_inv = s.invert().ok_or(Error::InvalidSignature)?;
+
+        let u1 = (&z * &s_inv).to_uint().to_be_bytes();
+        let u2 = (&r * &s_inv).to_uint().to_be_bytes();
+        let point = self.add(
+            &self.mul_vartime(&self.generator, &u1),
+            &self.mul_vartime(public_key, &u2),
+        );
+
+        let (x, _) = point.to_affine().ok_or(Error::InvalidSignature)?;
+        if FieldElement::new(x.to_uint(), &self.scalars) == r {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+
+    /// Decode a scalar in the range `[1, n)` from big-endian bytes.
+    fn scalar(&self, bytes: &[u8]) -> Option<FieldElement> {
+        let uint = decode_uint(bytes, self.scalars.bits_precision())?;
+        (uint.bits_vartime() != 0 && uint < *self.order())
+            .then(|| FieldElement::new(uint, &self.scalars))
+    }
+
+    /// Convert a message digest to a scalar, keeping only as many of its leftmost bits as the
+    /// group order has, as described in [SEC1] § 4.1.3.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    fn prehash_to_scalar(&self, prehash: &[u8]) -> Option<FieldElement> {
+        let order_bits = self.order().bits_vartime();
+        let len = order_bits.div_ceil(8);
+
+        let uint = if prehash.len() < len as usize {
+            decode_uint(prehash, self.scalars.bits_precision())?
+        } else {
+            decode_uint(&prehash[..len as usize], self.scalars.bits_precision())?
+                .wrapping_shr_vartime(8 * len - order_bits)
+        };
+
+        Some(FieldElement::new(uint, &self.scalars))
+    }
+}
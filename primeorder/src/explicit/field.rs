@@ -0,0 +1,242 @@
+//! Elements of fields whose modulus is only known at runtime.
+
+use crate::point_arithmetic::generic_a::GenericAField;
+use bigint::{
+    BoxedUint, Resize,
+    modular::{BoxedMontyForm, BoxedMontyParams},
+};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Maximum number of candidates tried when searching for a quadratic non-residue.
+///
+/// Half of all nonzero elements are non-residues when the modulus is prime, so this is only
+/// reached when it isn't.
+const MAX_NON_RESIDUE_CANDIDATES: u64 = 256;
+
+/// Element of the integers modulo an odd runtime modulus, in Montgomery form.
+///
+/// Used both for the base field (modulo `p`) and for scalars (modulo `n`).
+#[derive(Clone, Debug)]
+pub(super) struct FieldElement(BoxedMontyForm);
+
+impl FieldElement {
+    /// Create a field element from an integer with the same precision as the modulus, reducing
+    /// it if necessary.
+    pub(super) fn new(uint: BoxedUint, params: &BoxedMontyParams) -> Self {
+        Self(BoxedMontyForm::new(uint, params))
+    }
+
+    /// Create a field element from a small integer.
+    pub(super) fn from_u64(n: u64, params: &BoxedMontyParams) -> Self {
+        Self::new(BoxedUint::from(n).resize(params.bits_precision()), params)
+    }
+
+    /// Additive identity.
+    pub(super) fn zero(params: &BoxedMontyParams) -> Self {
+        Self(BoxedMontyForm::zero(params))
+    }
+
+    /// Multiplicative identity.
+    pub(super) fn one(params: &BoxedMontyParams) -> Self {
+        Self(BoxedMontyForm::one(params))
+    }
+
+    /// Canonical integer representative of this element.
+    pub(super) fn to_uint(&self) -> BoxedUint {
+        self.0.retrieve()
+    }
+
+    /// Is this element zero?
+    pub(super) fn is_zero(&self) -> bool {
+        self.to_uint().bits_vartime() == 0
+    }
+
+    /// Is the canonical representative of this element odd?
+    pub(super) fn is_odd(&self) -> bool {
+        self.to_uint().bit_vartime(0)
+    }
+
+    /// Compute `2 * self`.
+    pub(super) fn double(&self) -> Self {
+        self + self
+    }
+
+    /// Compute `self²`.
+    pub(super) fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    /// Compute `self^exp`.
+    pub(super) fn pow(&self, exp: &BoxedUint) -> Self {
+        Self(self.0.pow(exp))
+    }
+
+    /// Compute the multiplicative inverse, if it exists.
+    pub(super) fn invert(&self) -> Option<Self> {
+        self.0.invert().into_option().map(Self)
+    }
+
+    /// Compute a square root using the Tonelli-Shanks algorithm, in variable time.
+    ///
+    /// Returns `None` if this element is not a square, or if the modulus turns out not to be
+    /// prime.
+    pub(super) fn sqrt_vartime(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(self.clone());
+        }
+
+        let params = self.0.params();
+        let one = Self::one(params);
+        let minus_one = -&one;
+
+        // p - 1 = q * 2^s with q odd
+        let p_minus_one = minus_one.to_uint();
+        let s = trailing_zeros(&p_minus_one);
+        let q = p_minus_one.wrapping_shr_vartime(s);
+
+        // Euler's criterion
+        let legendre_exp = p_minus_one.wrapping_shr_vartime(1);
+        if self.pow(&legendre_exp) != one {
+            return None;
+        }
+
+        let mut z = one.double();
+        let mut candidates = 0;
+        while z.pow(&legendre_exp) != minus_one {
+            candidates += 1;
+            if candidates == MAX_NON_RESIDUE_CANDIDATES {
+                return None;
+            }
+            z = &z + &one;
+        }
+
+        let mut m = s;
+        let mut c = z.pow(&q);
+        let mut t = self.pow(&q);
+        let mut root = self.pow(
+            &q.wrapping_shr_vartime(1)
+                .wrapping_add(BoxedUint::one_with_precision(q.bits_precision())),
+        );
+
+        while t != one {
+            // find the least `i` such that `t^(2^i) = 1`
+            let mut i = 0;
+            let mut t2i = t.clone();
+            while t2i != one {
+                t2i = t2i.square();
+                i += 1;
+                if i == m {
+                    return None;
+                }
+            }
+
+            let mut b = c;
+            for _ in 0..(m - i - 1) {
+                b = b.square();
+            }
+
+            m = i;
+            c = b.square();
+            t = &t * &c;
+            root = &root * &b;
+        }
+
+        (root.square() == *self).then_some(root)
+    }
+}
+
+impl PartialEq for FieldElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_uint() == other.to_uint()
+    }
+}
+
+impl Eq for FieldElement {}
+
+impl Add for &FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement(&self.0 + &rhs.0)
+    }
+}
+
+impl Sub for &FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement(&self.0 - &rhs.0)
+    }
+}
+
+impl Mul for &FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement(&self.0 * &rhs.0)
+    }
+}
+
+impl Neg for &FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        FieldElement(-&self.0)
+    }
+}
+
+impl<'a> Add<&'a FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, rhs: &'a FieldElement) -> FieldElement {
+        &self + rhs
+    }
+}
+
+impl<'a> Sub<&'a FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, rhs: &'a FieldElement) -> FieldElement {
+        &self - rhs
+    }
+}
+
+impl<'a> Mul<&'a FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, rhs: &'a FieldElement) -> FieldElement {
+        &self * rhs
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        -&self
+    }
+}
+
+impl GenericAField for FieldElement {
+    fn square(&self) -> Self {
+        FieldElement::square(self)
+    }
+
+    fn add_and_mul(&self, add: &Self, mul: &Self) -> Self {
+        &(self + add) * mul
+    }
+
+    fn sum_of_products(a: &[Self; 2], b: &[Self; 2]) -> Self {
+        &(&a[0] * &b[0]) + &(&a[1] * &b[1])
+    }
+}
+
+/// Number of trailing zero bits of a nonzero integer.
+pub(super) fn trailing_zeros(uint: &BoxedUint) -> u32 {
+    debug_assert!(uint.bits_vartime() != 0);
+    let mut zeros = 0;
+    while !uint.bit_vartime(zeros) {
+        zeros += 1;
+    }
+    zeros
+}
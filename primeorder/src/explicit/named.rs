@@ -0,0 +1,239 @@
+//! Built-in curves which explicit parameters can be mapped to.
+
+use elliptic_curve::pkcs8::ObjectIdentifier;
+
+/// Named curves recognized by [`ExplicitCurve::named_curve`].
+///
+/// [`ExplicitCurve::named_curve`]: super::ExplicitCurve::named_curve
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum NamedCurve {
+    /// NIST P-192 (secp192r1).
+    NistP192,
+    /// NIST P-224 (secp224r1).
+    NistP224,
+    /// NIST P-256 (secp256r1).
+    NistP256,
+    /// NIST P-384 (secp384r1).
+    NistP384,
+    /// NIST P-521 (secp521r1).
+    NistP521,
+    /// SEC2 secp256k1.
+    Secp256k1,
+    /// RFC 5639 brainpoolP256r1.
+    BrainpoolP256r1,
+    /// RFC 5639 brainpoolP256t1.
+    BrainpoolP256t1,
+    /// RFC 5639 brainpoolP384r1.
+    BrainpoolP384r1,
+    /// RFC 5639 brainpoolP384t1.
+    BrainpoolP384t1,
+    /// STB 34.101.45 bign-curve256v1.
+    BignP256,
+    /// GB/T 32918 SM2.
+    Sm2,
+}
+
+impl NamedCurve {
+    /// All named curves, in no particular order.
+    pub const ALL: &[Self] = &[
+        Self::NistP192,
+        Self::NistP224,
+        Self::NistP256,
+        Self::NistP384,
+        Self::NistP521,
+        Self::Secp256k1,
+        Self::BrainpoolP256r1,
+        Self::BrainpoolP256t1,
+        Self::BrainpoolP384r1,
+        Self::BrainpoolP384t1,
+        Self::BignP256,
+        Self::Sm2,
+    ];
+
+    /// Object identifier of this curve, as used in the `namedCurve` choice of `ECParameters`.
+    #[must_use]
+    pub const fn oid(self) -> ObjectIdentifier {
+        ObjectIdentifier::new_unwrap(match self {
+            Self::NistP192 => "1.2.840.10045.3.1.1",
+            Self::NistP224 => "1.3.132.0.33",
+            Self::NistP256 => "1.2.840.10045.3.1.7",
+            Self::NistP384 => "1.3.132.0.34",
+            Self::NistP521 => "1.3.132.0.35",
+            Self::Secp256k1 => "1.3.132.0.10",
+            Self::BrainpoolP256r1 => "1.3.36.3.3.2.8.1.1.7",
+            Self::BrainpoolP256t1 => "1.3.36.3.3.2.8.1.1.8",
+            Self::BrainpoolP384r1 => "1.3.36.3.3.2.8.1.1.11",
+            Self::BrainpoolP384t1 => "1.3.36.3.3.2.8.1.1.12",
+            Self::BignP256 => "1.2.112.0.2.0.34.101.45.3.1",
+            Self::Sm2 => "1.2.156.10197.1.301",
+        })
+    }
+
+    /// Conventional name of this curve.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::NistP192 => "P-192",
+            Self::NistP224 => "P-224",
+            Self::NistP256 => "P-256",
+            Self::NistP384 => "P-384",
+            Self::NistP521 => "P-521",
+            Self::Secp256k1 => "secp256k1",
+            Self::BrainpoolP256r1 => "brainpoolP256r1",
+            Self::BrainpoolP256t1 => "brainpoolP256t1",
+            Self::BrainpoolP384r1 => "brainpoolP384r1",
+            Self::BrainpoolP384t1 => "brainpoolP384t1",
+            Self::BignP256 => "bign-curve256v1",
+            Self::Sm2 => "SM2",
+        }
+    }
+
+    /// Look up a curve by its object identifier.
+    #[must_use]
+    pub fn from_oid(oid: ObjectIdentifier) -> Option<Self> {
+        Self::ALL.iter().copied().find(|curve| curve.oid() == oid)
+    }
+
+    /// Domain parameters of this curve.
+    pub(super) const fn params(self) -> &'static Params {
+        match self {
+            Self::NistP192 => &NIST_P192,
+            Self::NistP224 => &NIST_P224,
+            Self::NistP256 => &NIST_P256,
+            Self::NistP384 => &NIST_P384,
+            Self::NistP521 => &NIST_P521,
+            Self::Secp256k1 => &SECP256K1,
+            Self::BrainpoolP256r1 => &BRAINPOOL_P256R1,
+            Self::BrainpoolP256t1 => &BRAINPOOL_P256T1,
+            Self::BrainpoolP384r1 => &BRAINPOOL_P384R1,
+            Self::BrainpoolP384t1 => &BRAINPOOL_P384T1,
+            Self::BignP256 => &BIGN_P256,
+            Self::Sm2 => &SM2,
+        }
+    }
+}
+
+/// Domain parameters of a named curve as big-endian hexadecimal strings.
+pub(super) struct Params {
+    /// Field modulus.
+    pub(super) p: &'static str,
+    /// Coefficient `a` in the curve equation.
+    pub(super) a: &'static str,
+    /// Coefficient `b` in the curve equation.
+    pub(super) b: &'static str,
+    /// Generator's affine x-coordinate.
+    pub(super) gx: &'static str,
+    /// Generator's affine y-coordinate.
+    pub(super) gy: &'static str,
+    /// Order of the generator.
+    pub(super) n: &'static str,
+}
+
+const NIST_P192: Params = Params {
+    p: "fffffffffffffffffffffffffffffffeffffffffffffffff",
+    a: "fffffffffffffffffffffffffffffffefffffffffffffffc",
+    b: "64210519e59c80e70fa7e9ab72243049feb8deecc146b9b1",
+    gx: "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+    gy: "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",
+    n: "ffffffffffffffffffffffff99def836146bc9b1b4d22831",
+};
+
+const NIST_P224: Params = Params {
+    p: "ffffffffffffffffffffffffffffffff000000000000000000000001",
+    a: "fffffffffffffffffffffffffffffffefffffffffffffffffffffffe",
+    b: "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+    gx: "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+    gy: "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+    n: "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+};
+
+const NIST_P256: Params = Params {
+    p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+    a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+    b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+    gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+    gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+    n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+};
+
+const NIST_P384: Params = Params {
+    p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+    a: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000fffffffc",
+    b: "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+    gx: "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+    gy: "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+    n: "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+};
+
+const NIST_P521: Params = Params {
+    p: "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    a: "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc",
+    b: "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+    gx: "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+    gy: "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+    n: "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+};
+
+const SECP256K1: Params = Params {
+    p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    a: "0000000000000000000000000000000000000000000000000000000000000000",
+    b: "0000000000000000000000000000000000000000000000000000000000000007",
+    gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+};
+
+const BRAINPOOL_P256R1: Params = Params {
+    p: "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377",
+    a: "7d5a0975fc2c3057eef67530417affe7fb8055c126dc5c6ce94a4b44f330b5d9",
+    b: "26dc5c6ce94a4b44f330b5d9bbd77cbf958416295cf7e1ce6bccdc18ff8c07b6",
+    gx: "8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262",
+    gy: "547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997",
+    n: "a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7",
+};
+
+const BRAINPOOL_P256T1: Params = Params {
+    p: "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377",
+    a: "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5374",
+    b: "662c61c430d84ea4fe66a7733d0b76b7bf93ebc4af2f49256ae58101fee92b04",
+    gx: "a3e8eb3cc1cfe7b7732213b23a656149afa142c47aafbc2b79a191562e1305f4",
+    gy: "2d996c823439c56d7f7b22e14644417e69bcb6de39d027001dabe8f35b25c9be",
+    n: "a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7",
+};
+
+const BRAINPOOL_P384R1: Params = Params {
+    p: "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b412b1da197fb71123acd3a729901d1a71874700133107ec53",
+    a: "7bc382c63d8c150c3c72080ace05afa0c2bea28e4fb22787139165efba91f90f8aa5814a503ad4eb04a8c7dd22ce2826",
+    b: "04a8c7dd22ce28268b39b55416f0447c2fb77de107dcd2a62e880ea53eeb62d57cb4390295dbc9943ab78696fa504c11",
+    gx: "1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e",
+    gy: "8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864e19c054ff99129280e4646217791811142820341263c5315",
+    n: "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565",
+};
+
+const BRAINPOOL_P384T1: Params = Params {
+    p: "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b412b1da197fb71123acd3a729901d1a71874700133107ec53",
+    a: "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b412b1da197fb71123acd3a729901d1a71874700133107ec50",
+    b: "7f519eada7bda81bd826dba647910f8c4b9346ed8ccdc64e4b1abd11756dce1d2074aa263b88805ced70355a33b471ee",
+    gx: "18de98b02db9a306f2afcd7235f72a819b80ab12ebd653172476fecd462aabffc4ff191b946a5f54d8d0aa2f418808cc",
+    gy: "25ab056962d30651a114afd2755ad336747f93475b7a1fca3b88f2b6a208ccfe469408584dc2b2912675bf5b9e582928",
+    n: "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565",
+};
+
+const BIGN_P256: Params = Params {
+    p: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff43",
+    a: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff40",
+    b: "77ce6c1515f3a8edd2c13aabe4d8fbbe4cf55069978b9253b22e7d6bd69c03f1",
+    gx: "0000000000000000000000000000000000000000000000000000000000000000",
+    gy: "6bf7fc3cfb16d69f5ce4c9a351d6835d78913966c408f6521e29cf1804516a93",
+    n: "ffffffffffffffffffffffffffffffffd95c8ed60dfb4dfc7e5abf99263d6607",
+};
+
+const SM2: Params = Params {
+    p: "fffffffeffffffffffffffffffffffffffffffff00000000ffffffffffffffff",
+    a: "fffffffeffffffffffffffffffffffffffffffff00000000fffffffffffffffc",
+    b: "28e9fa9e9d9f5e344d5a9e4bcf6509a7f39789f515ab8f92ddbcbd414d940e93",
+    gx: "32c4ae2c1f1981195f9904466a39c9948fe30bbff2660be1715a4589334c74c7",
+    gy: "bc3736a2f4f6779c59bdcee36b692153d0a9877cc62a474002df32e52139f0a0",
+    n: "fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54123",
+};
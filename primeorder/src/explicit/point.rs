@@ -0,0 +1,186 @@
+//! Points on curves with explicit parameters.
+
+use super::{Error, ExplicitCurve, Result, field::FieldElement};
+use crate::point_arithmetic::generic_a;
+use alloc::vec::Vec;
+use bigint::modular::BoxedMontyParams;
+
+/// Point on an [`ExplicitCurve`] in projective coordinates.
+///
+/// Points don't keep a reference to their curve: arithmetic is performed by methods of
+/// [`ExplicitCurve`], which must be the curve the point was decoded with.
+#[derive(Clone, Debug)]
+pub struct ExplicitPoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl ExplicitPoint {
+    /// Point at infinity on a curve over the field with the given parameters.
+    pub(super) fn identity(field: &BoxedMontyParams) -> Self {
+        Self {
+            x: FieldElement::zero(field),
+            y: FieldElement::one(field),
+            z: FieldElement::zero(field),
+        }
+    }
+
+    /// Is this point the identity (point at infinity)?
+    #[must_use]
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Affine coordinates of this point, or `None` if it is the identity.
+    pub(super) fn to_affine(&self) -> Option<(FieldElement, FieldElement)> {
+        let z_inv = self.z.invert()?;
+        Some((&self.x * &z_inv, &self.y * &z_inv))
+    }
+}
+
+impl PartialEq for ExplicitPoint {
+    fn eq(&self, other: &Self) -> bool {
+        &self.x * &other.z == &other.x * &self.z && &self.y * &other.z == &other.y * &self.z
+    }
+}
+
+impl Eq for ExplicitPoint {}
+
+impl ExplicitCurve {
+    /// Additive identity of the group a.k.a. the point at infinity.
+    #[must_use]
+    pub fn identity(&self) -> ExplicitPoint {
+        ExplicitPoint::identity(&self.field)
+    }
+
+    /// Base point of the curve.
+    #[must_use]
+    pub fn generator(&self) -> &ExplicitPoint {
+        &self.generator
+    }
+
+    /// Decode a point from its [SEC1] encoding, checking that it is on the curve.
+    ///
+    /// Accepts the identity, compressed and uncompressed encodings.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidPoint`] if the encoding is malformed or the point is not on the
+    /// curve.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<ExplicitPoint> {
+        match bytes.split_first() {
+            Some((0x00, [])) => Ok(self.identity()),
+            Some((&tag @ (0x02 | 0x03), x)) if x.len() == self.field_bytes => {
+                let x = self.field_element(x).ok_or(Error::InvalidPoint)?;
+                let mut y = self
+                    .curve_rhs(&x)
+                    .sqrt_vartime()
+                    .ok_or(Error::InvalidPoint)?;
+                if y.is_odd() != (tag == 0x03) {
+                    y = -&y;
+
+                    // `y = 0` has no odd counterpart
+                    if y.is_odd() != (tag == 0x03) {
+                        return Err(Error::InvalidPoint);
+                    }
+                }
+
+                Ok(self.affine_point(x, y))
+            }
+            Some((0x04, coordinates)) if coordinates.len() == 2 * self.field_bytes => {
+                let (x, y) = coordinates.split_at(self.field_bytes);
+                let x = self.field_element(x).ok_or(Error::InvalidPoint)?;
+                let y = self.field_element(y).ok_or(Error::InvalidPoint)?;
+
+                if y.square() != self.curve_rhs(&x) {
+                    return Err(Error::InvalidPoint);
+                }
+
+                Ok(self.affine_point(x, y))
+            }
+            _ => Err(Error::InvalidPoint),
+        }
+    }
+
+    /// Encode a point using [SEC1] compressed or uncompressed encoding.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    #[must_use]
+    pub fn encode_point(&self, point: &ExplicitPoint, compress: bool) -> Vec<u8> {
+        let Some((x, y)) = point.to_affine() else {
+            return vec![0x00];
+        };
+
+        let mut bytes = Vec::with_capacity(1 + 2 * self.field_bytes);
+        if compress {
+            bytes.push(0x02 | u8::from(y.is_odd()));
+            bytes.extend_from_slice(&self.field_element_bytes(&x));
+        } else {
+            bytes.push(0x04);
+            bytes.extend_from_slice(&self.field_element_bytes(&x));
+            bytes.extend_from_slice(&self.field_element_bytes(&y));
+        }
+        bytes
+    }
+
+    /// Add two points.
+    ///
+    /// Uses the same complete formulas as [`ProjectivePoint`](crate::ProjectivePoint) on curves
+    /// with arbitrary `a`.
+    #[must_use]
+    pub fn add(&self, lhs: &ExplicitPoint, rhs: &ExplicitPoint) -> ExplicitPoint {
+        let [x, y, z] = generic_a::add_with_generic_a(
+            [&lhs.x, &lhs.y, &lhs.z],
+            [&rhs.x, &rhs.y, &rhs.z],
+            &self.a,
+            &self.b3,
+        );
+
+        ExplicitPoint { x, y, z }
+    }
+
+    /// Double a point.
+    #[must_use]
+    pub fn double(&self, point: &ExplicitPoint) -> ExplicitPoint {
+        let [x, y, z] =
+            generic_a::double_with_generic_a([&point.x, &point.y, &point.z], &self.a, &self.b3);
+
+        ExplicitPoint { x, y, z }
+    }
+
+    /// Multiply a point by a scalar given as big-endian bytes, in variable time.
+    ///
+    /// The scalar need not be reduced modulo the group order.
+    #[must_use]
+    pub fn mul_vartime(&self, point: &ExplicitPoint, scalar: &[u8]) -> ExplicitPoint {
+        let mut result = self.identity();
+
+        for byte in scalar {
+            for i in (0..8).rev() {
+                result = self.double(&result);
+
+                if (byte >> i) & 1 == 1 {
+                    result = self.add(&result, point);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Create a point from affine coordinates known to be on the curve.
+    fn affine_point(&self, x: FieldElement, y: FieldElement) -> ExplicitPoint {
+        ExplicitPoint {
+            x,
+            y,
+            z: FieldElement::one(&self.field),
+        }
+    }
+
+    /// Compute the right-hand side of the curve equation, `x³ + ax + b`.
+    pub(super) fn curve_rhs(&self, x: &FieldElement) -> FieldElement {
+        &(&(&x.square() + &self.a) * x) + &self.b
+    }
+}
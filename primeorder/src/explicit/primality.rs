@@ -0,0 +1,246 @@
+//! Primality testing and trial division for domain parameter validation.
+
+use super::{
+    field::{FieldElement, trailing_zeros},
+    trim,
+};
+use alloc::vec::Vec;
+use bigint::{BoxedUint, Odd, modular::BoxedMontyParams};
+
+/// Trial division uses all primes below this bound.
+const TRIAL_DIVISION_BOUND: u32 = 1 << 16;
+
+/// Maximum number of candidates for `D` in Selfridge's method.
+///
+/// Only perfect squares never yield a suitable `D`, and for other inputs each candidate succeeds
+/// with probability about one half.
+const MAX_SELFRIDGE_CANDIDATES: u32 = 256;
+
+/// Odd primes below [`TRIAL_DIVISION_BOUND`], computed with the sieve of Eratosthenes.
+pub(super) fn small_primes() -> Vec<u32> {
+    let mut composite = vec![false; TRIAL_DIVISION_BOUND as usize];
+    let mut primes = Vec::new();
+
+    for candidate in (3..TRIAL_DIVISION_BOUND).step_by(2) {
+        if !composite[candidate as usize] {
+            primes.push(candidate);
+
+            for multiple in
+                (candidate * candidate..TRIAL_DIVISION_BOUND).step_by(2 * candidate as usize)
+            {
+                composite[multiple as usize] = true;
+            }
+        }
+    }
+
+    primes
+}
+
+/// Baillie-PSW probable prime test: trial division, followed by a Miller-Rabin test to base 2
+/// and a strong Lucas test.
+///
+/// No composite number is known to pass this test.
+pub(super) fn is_prime(candidate: &BoxedUint, small_primes: &[u32]) -> bool {
+    let bytes = candidate.to_be_bytes();
+    let small = to_small(&bytes);
+
+    if !candidate.bit_vartime(0) {
+        return small == Some(2);
+    }
+
+    if small.is_some_and(|n| n < 2) {
+        return false;
+    }
+
+    for &prime in small_primes {
+        if small == Some(prime) {
+            return true;
+        }
+
+        if rem_small(&bytes, prime) == 0 {
+            return false;
+        }
+    }
+
+    // trial division is exhaustive for candidates below the square of its bound
+    if small.is_some() {
+        return true;
+    }
+
+    let Some(modulus) = Odd::new(candidate.clone()).into_option() else {
+        return false;
+    };
+
+    let params = BoxedMontyParams::new(modulus);
+    miller_rabin_base_2(&params) && strong_lucas(&params, &bytes)
+}
+
+/// Compute the remainder of a big-endian integer divided by a small divisor.
+pub(super) fn rem_small(bytes: &[u8], divisor: u32) -> u32 {
+    debug_assert!(divisor < TRIAL_DIVISION_BOUND);
+    bytes
+        .iter()
+        .fold(0, |rem, &byte| ((rem << 8) | u32::from(byte)) % divisor)
+}
+
+/// Divide a big-endian integer by a small divisor in place, discarding the remainder.
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn div_small(bytes: &mut [u8], divisor: u32) {
+    debug_assert!(divisor < TRIAL_DIVISION_BOUND);
+    let mut rem = 0;
+
+    for byte in bytes {
+        let acc = (rem << 8) | u32::from(*byte);
+        // the quotient digit is below 256 since `rem < divisor`
+        *byte = (acc / divisor) as u8;
+        rem = acc % divisor;
+    }
+}
+
+/// Interpret a big-endian integer as a `u32`, if it fits.
+fn to_small(bytes: &[u8]) -> Option<u32> {
+    let bytes = trim(bytes);
+    (bytes.len() <= 4).then(|| {
+        bytes
+            .iter()
+            .fold(0, |acc, &byte| (acc << 8) | u32::from(byte))
+    })
+}
+
+/// Strong probable prime test to base 2.
+fn miller_rabin_base_2(params: &BoxedMontyParams) -> bool {
+    let one = FieldElement::one(params);
+    let minus_one = -&one;
+
+    // n - 1 = d * 2^s with d odd
+    let n_minus_one = minus_one.to_uint();
+    let s = trailing_zeros(&n_minus_one);
+    let d = n_minus_one.wrapping_shr_vartime(s);
+
+    let mut x = one.double().pow(&d);
+    if x == one || x == minus_one {
+        return true;
+    }
+
+    for _ in 1..s {
+        x = x.square();
+        if x == minus_one {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Strong Lucas probable prime test with parameters chosen by Selfridge's method A.
+///
+/// `bytes` is the big-endian encoding of the modulus of `params`, which must be odd and larger
+/// than every candidate for `D`.
+fn strong_lucas(params: &BoxedMontyParams, bytes: &[u8]) -> bool {
+    let n_mod_4 = bytes.last().map_or(0, |byte| byte & 3);
+
+    // find the first `D` in 5, -7, 9, -11, ... with Jacobi symbol `(D/n) = -1`
+    let mut abs_d = 5;
+    let mut negative = false;
+    let mut candidates = 0;
+
+    loop {
+        // quadratic reciprocity reduces `(|D|/n)` to `(n mod |D| / |D|)` for odd `|D|`
+        let mut symbol = jacobi(rem_small(bytes, abs_d), abs_d);
+        if abs_d % 4 == 3 && n_mod_4 == 3 {
+            symbol = -symbol;
+        }
+        if negative && n_mod_4 == 3 {
+            symbol = -symbol;
+        }
+
+        match symbol {
+            -1 => break,
+            0 => return false,
+            _ => (),
+        }
+
+        candidates += 1;
+        if candidates == MAX_SELFRIDGE_CANDIDATES {
+            return false;
+        }
+
+        abs_d += 2;
+        negative = !negative;
+    }
+
+    // P = 1 and Q = (1 - D) / 4
+    let d = FieldElement::from_u64(abs_d.into(), params);
+    let (d, q) = if negative {
+        (
+            -&d,
+            FieldElement::from_u64(((abs_d + 1) / 4).into(), params),
+        )
+    } else {
+        (
+            d,
+            -&FieldElement::from_u64(((abs_d - 1) / 4).into(), params),
+        )
+    };
+
+    let one = FieldElement::one(params);
+    let Some(half) = one.double().invert() else {
+        return false;
+    };
+
+    // n + 1 = k * 2^s with k odd
+    let modulus: &BoxedUint = params.modulus().as_ref();
+    let n_plus_one = modulus.wrapping_add(BoxedUint::one_with_precision(params.bits_precision()));
+    let s = trailing_zeros(&n_plus_one);
+    let k = n_plus_one.wrapping_shr_vartime(s);
+
+    // compute `U_k`, `V_k` and `Q^k` with a left-to-right binary ladder, starting from `k = 1`
+    let (mut u, mut v, mut qk) = (one.clone(), one, q.clone());
+    for i in (0..k.bits_vartime() - 1).rev() {
+        u = &u * &v;
+        v = &v.square() - &qk.double();
+        qk = qk.square();
+
+        if k.bit_vartime(i) {
+            (u, v) = (&(&u + &v) * &half, &(&(&d * &u) + &v) * &half);
+            qk = &qk * &q;
+        }
+    }
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+
+    for _ in 1..s {
+        v = &v.square() - &qk.double();
+        qk = qk.square();
+        if v.is_zero() {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Jacobi symbol `(a/n)` for odd `n`.
+fn jacobi(mut a: u32, mut n: u32) -> i8 {
+    let mut result = 1;
+    a %= n;
+
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if matches!(n % 8, 3 | 5) {
+                result = -result;
+            }
+        }
+
+        core::mem::swap(&mut a, &mut n);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+
+    if n == 1 { result } else { 0 }
+}
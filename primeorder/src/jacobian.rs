@@ -0,0 +1,275 @@
+//! Jacobian coordinates for variable-time point arithmetic.
+//!
+//! The complete formulas used by [`ProjectivePoint`] are exception-free, which makes them suitable
+//! for constant-time code, but they are slower than the incomplete Jacobian formulas, especially
+//! for doubling. Variable-time routines don't need to avoid branches, so they can use Jacobian
+//! coordinates and handle the exceptional cases (the identity, and adding a point to itself or
+//! its negation) explicitly.
+
+use crate::{
+    AffinePoint, Field, PrimeCurveParams, ProjectivePoint, point_arithmetic::PointArithmetic,
+    projective::WnafScalar,
+};
+use core::iter::zip;
+use elliptic_curve::array::Array;
+use wnaf::WindowSize;
+
+/// Point in Jacobian coordinates `(X : Y : Z)`, which represents the affine point
+/// `(X / Z², Y / Z³)` if `Z` is nonzero, or the identity if `Z` is zero.
+///
+/// Only used internally by variable-time routines.
+#[derive(Clone, Copy, Debug)]
+pub struct JacobianPoint<C: PrimeCurveParams> {
+    pub(crate) x: C::FieldElement,
+    pub(crate) y: C::FieldElement,
+    pub(crate) z: C::FieldElement,
+}
+
+impl<C> JacobianPoint<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Additive identity of the group a.k.a. the point at infinity.
+    pub(crate) const IDENTITY: Self = Self {
+        x: C::FieldElement::ONE,
+        y: C::FieldElement::ONE,
+        z: C::FieldElement::ZERO,
+    };
+
+    /// Is this point the identity?
+    fn is_identity(&self) -> bool {
+        self.z.is_zero().into()
+    }
+
+    /// Returns `self + self`.
+    pub(crate) fn double(&self) -> Self {
+        // As the curve has prime order, there are no points of order 2, so only the identity
+        // needs to be handled separately
+        if self.is_identity() {
+            return *self;
+        }
+
+        C::PointArithmetic::double_jacobian(self)
+    }
+
+    /// Returns `self + other`.
+    ///
+    /// Uses the `add-2007-bl` formulas from the [Explicit-Formulas Database].
+    ///
+    /// [Explicit-Formulas Database]: https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        if self.is_identity() {
+            return *other;
+        }
+
+        if other.is_identity() {
+            return *self;
+        }
+
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+        let h = u2 - u1;
+        let r = (s2 - s1).double();
+
+        if bool::from(h.is_zero()) {
+            return if bool::from(r.is_zero()) {
+                self.double()
+            } else {
+                Self::IDENTITY
+            };
+        }
+
+        let i = h.double().square();
+        let j = h * i;
+        let v = u1 * i;
+        let x = r.square() - j - v.double();
+        let y = r * (v - x) - (s1 * j).double();
+        let z = ((self.z + other.z).square() - z1z1 - z2z2) * h;
+
+        Self { x, y, z }
+    }
+
+    /// Returns `self + other`.
+    ///
+    /// Uses the `madd-2007-bl` formulas from the [Explicit-Formulas Database].
+    ///
+    /// [Explicit-Formulas Database]: https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html
+    pub(crate) fn add_mixed(&self, other: &AffinePoint<C>) -> Self {
+        if bool::from(other.is_identity()) {
+            return *self;
+        }
+
+        if self.is_identity() {
+            return Self::from(other);
+        }
+
+        let z1z1 = self.z.square();
+        let u2 = other.x * z1z1;
+        let s2 = other.y * self.z * z1z1;
+        let h = u2 - self.x;
+        let r = (s2 - self.y).double();
+
+        if bool::from(h.is_zero()) {
+            return if bool::from(r.is_zero()) {
+                self.double()
+            } else {
+                Self::IDENTITY
+            };
+        }
+
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h * i;
+        let v = self.x * i;
+        let x = r.square() - j - v.double();
+        let y = r * (v - x) - (self.y * j).double();
+        let z = (self.z + h).square() - z1z1 - hh;
+
+        Self { x, y, z }
+    }
+
+    /// Returns `self - other`.
+    pub(crate) fn sub_mixed(&self, other: &AffinePoint<C>) -> Self {
+        self.add_mixed(&-other)
+    }
+}
+
+impl<C> From<&AffinePoint<C>> for JacobianPoint<C>
+where
+    C: PrimeCurveParams,
+{
+    fn from(p: &AffinePoint<C>) -> Self {
+        if bool::from(p.is_identity()) {
+            Self::IDENTITY
+        } else {
+            Self {
+                x: p.x,
+                y: p.y,
+                z: C::FieldElement::ONE,
+            }
+        }
+    }
+}
+
+impl<C> From<&ProjectivePoint<C>> for JacobianPoint<C>
+where
+    C: PrimeCurveParams,
+{
+    /// `(X : Y : Z)` in homogeneous coordinates is `(XZ : YZ² : Z)` in Jacobian coordinates.
+    fn from(p: &ProjectivePoint<C>) -> Self {
+        if bool::from(p.z.is_zero()) {
+            Self::IDENTITY
+        } else {
+            Self {
+                x: p.x * p.z,
+                y: p.y * p.z.square(),
+                z: p.z,
+            }
+        }
+    }
+}
+
+impl<C> From<&JacobianPoint<C>> for ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    /// `(X : Y : Z)` in Jacobian coordinates is `(XZ : Y : Z³)` in homogeneous coordinates.
+    fn from(p: &JacobianPoint<C>) -> Self {
+        if p.is_identity() {
+            ProjectivePoint::IDENTITY
+        } else {
+            ProjectivePoint {
+                x: p.x * p.z,
+                y: p.y,
+                z: p.z.square() * p.z,
+            }
+        }
+    }
+}
+
+/// Table size for the default wNAF window size.
+type TableSize = <crate::projective::DefaultWnafWindowSize as WindowSize>::TableSize;
+
+/// Table of the odd multiples `[1]P, [3]P, ..., [2^(w-1) - 1]P` of a point `P` in affine
+/// coordinates, where `w` is the default wNAF window size.
+///
+/// Keeping the table in affine coordinates makes it possible to use mixed additions, which are
+/// significantly cheaper than Jacobian additions, at the cost of a single inversion per table.
+#[derive(Clone, Debug)]
+pub(crate) struct OddMultiples<C: PrimeCurveParams>(Array<AffinePoint<C>, TableSize>);
+
+impl<C> OddMultiples<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Compute the table of odd multiples of the given point.
+    pub(crate) fn new(point: &ProjectivePoint<C>) -> Self {
+        let point = JacobianPoint::from(point);
+
+        if point.is_identity() {
+            return Self(Array::from_fn(|_| AffinePoint::IDENTITY));
+        }
+
+        let double = point.double();
+        let mut multiples = Array::<JacobianPoint<C>, TableSize>::from_fn(|_| point);
+        for i in 1..multiples.len() {
+            multiples[i] = multiples[i - 1].add(&double);
+        }
+
+        // The group has prime order, so none of the multiples are the identity and all of the
+        // `Z` coordinates are nonzero
+        let mut z_inverses = Array::<C::FieldElement, TableSize>::from_fn(|i| multiples[i].z);
+        let mut scratch = z_inverses;
+        primefield::batch_invert_vartime(&mut z_inverses, &mut scratch);
+
+        Self(Array::from_fn(|i| {
+            let z_inv2 = z_inverses[i].square();
+            AffinePoint {
+                x: multiples[i].x * z_inv2,
+                y: multiples[i].y * z_inv2 * z_inverses[i],
+                infinity: 0,
+            }
+        }))
+    }
+
+    /// Get the multiple `[|digit|]P` for an odd `digit`.
+    fn get(&self, digit: wnaf::Digit) -> &AffinePoint<C> {
+        &self.0[usize::from(digit.unsigned_abs() / 2)]
+    }
+}
+
+/// Compute a linear combination of points given their tables of odd multiples and the wNAF
+/// representations of the scalars in variable-time, using the interleaved window method (Straus's
+/// method) in Jacobian coordinates.
+pub(crate) fn lincomb_vartime<C: PrimeCurveParams>(
+    tables: &[OddMultiples<C>],
+    scalars: &[WnafScalar<C>],
+) -> ProjectivePoint<C> {
+    let len = scalars
+        .iter()
+        .map(|scalar| scalar.digits().len())
+        .max()
+        .unwrap_or(0);
+
+    let mut acc = JacobianPoint::<C>::IDENTITY;
+
+    for i in (0..len).rev() {
+        acc = acc.double();
+
+        for (table, scalar) in zip(tables, scalars) {
+            let digit = scalar.digits().get(i).copied().unwrap_or(0);
+
+            if digit > 0 {
+                acc = acc.add_mixed(table.get(digit));
+            } else if digit < 0 {
+                acc = acc.sub_mixed(table.get(digit));
+            }
+        }
+    }
+
+    ProjectivePoint::from(&acc)
+}
@@ -0,0 +1,161 @@
+//! x-only scalar multiplication using a co-Z Montgomery ladder.
+//!
+//! Uses the x-only differential addition and doubling formulas from Brier and Joye,
+//! "Weierstraß Elliptic Curves and Side-Channel Attacks" (PKC 2002), on `(X : Z)` coordinates,
+//! keeping both points of the ladder in co-Z representation as described by Hutter, Joye and
+//! Sierra, "Memory-Constrained Implementations of Elliptic Curve Cryptography in Co-Z Coordinate
+//! Representation" (AFRICACRYPT 2011).
+
+use crate::{Field, PrimeCurveParams, PrimeField, PrimeFieldExt};
+use elliptic_curve::{
+    Scalar,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+};
+
+/// Compute the affine x-coordinate of `[k] P`, where `P` is the curve point with the affine
+/// x-coordinate `x`, in constant-time.
+///
+/// Only the x-coordinate of a scalar multiplication is needed for ECDH. Computing it with a
+/// Montgomery ladder on x-coordinates avoids decompressing `P` and computing y-coordinates, and
+/// each step of the ladder performs the same sequence of field operations regardless of the bits
+/// of `k`. As `x(P) = x(-P)`, the result doesn't depend on which of the two points with the given
+/// x-coordinate is meant.
+///
+/// Returns none if `x` is not the x-coordinate of a point on the curve, or if `[k] P` is the
+/// identity, i.e. if `k` is zero.
+pub fn x_only_mul<C: PrimeCurveParams>(
+    x: &C::FieldElement,
+    k: &Scalar<C>,
+) -> CtOption<C::FieldElement> {
+    let a = C::EQUATION_A;
+    let b4 = C::EQUATION_B.double().double();
+
+    // `x` is the x-coordinate of a point on the curve iff `x^3 + ax + b` is a square, otherwise
+    // it's a point on the quadratic twist, which must be rejected.
+    let is_on_curve = (x.square() * x + a * x + C::EQUATION_B).sqrt().is_some();
+
+    // Start with `(P, [2]P)` in co-Z representation: as `k` is recoded so that its most
+    // significant bit is always set, the identity never needs to be represented.
+    let (x2, z2) = double::<C>(x, &a, &b4);
+    let mut x0 = *x * z2;
+    let mut x1 = x2;
+    let mut z = z2;
+
+    let recoded = recode::<C>(k);
+    let bytes: &[u8] = recoded.as_ref();
+    let mut swap = Choice::from(0);
+
+    for i in (0..Scalar::<C>::NUM_BITS as usize).rev() {
+        let bit = Choice::from((bytes[bytes.len() - 1 - i / 8] >> (i % 8)) & 1);
+        C::FieldElement::conditional_swap(&mut x0, &mut x1, swap ^ bit);
+        swap = bit;
+
+        (x0, x1, z) = ladder_step::<C>(&x0, &x1, &z, x, &a, &b4);
+    }
+
+    C::FieldElement::conditional_swap(&mut x0, &mut x1, swap);
+
+    // The ladder hits the identity for `k = 0, 1, n - 2, n - 1`, in which case the co-Z
+    // representation degenerates. Except for `k = 0`, the results are known in advance.
+    let one = Scalar::<C>::ONE;
+    let k_is_one = k.ct_eq(&one) | k.ct_eq(&-one);
+    let k_is_two = k.ct_eq(&-one.double());
+    x0.conditional_assign(x, k_is_one);
+    z.conditional_assign(&C::FieldElement::ONE, k_is_one);
+    x0.conditional_assign(&x2, k_is_two);
+    z.conditional_assign(&z2, k_is_two);
+
+    let z_inv = z.invert();
+    CtOption::new(
+        x0 * z_inv.unwrap_or(C::FieldElement::ZERO),
+        z_inv.is_some() & is_on_curve,
+    )
+}
+
+/// Combined co-Z differential addition and doubling.
+///
+/// Given the points `(X0 : Z)` and `(X1 : Z)` whose difference has the affine x-coordinate `xd`,
+/// returns `[2](X0 : Z)` and `(X0 : Z) + (X1 : Z)`, along with their new common `Z` coordinate.
+#[inline]
+fn ladder_step<C: PrimeCurveParams>(
+    x0: &C::FieldElement,
+    x1: &C::FieldElement,
+    z: &C::FieldElement,
+    xd: &C::FieldElement,
+    a: &C::FieldElement,
+    b4: &C::FieldElement,
+) -> (C::FieldElement, C::FieldElement, C::FieldElement) {
+    let zz = z.square();
+    let ta = *a * zz;
+    let tb = *b4 * zz * z;
+    let td = *xd * z;
+
+    // (X0 : Z) + (X1 : Z) = (xs : Z * zs)
+    let zs = (*x0 - x1).square();
+    let xs = (*x0 + x1).double() * (*x0 * x1 + ta) + tb - td * zs;
+
+    // [2](X0 : Z) = (xd2 : Z * zd2)
+    let (xd2, zd2) = double::<C>(x0, &ta, &tb);
+
+    (xd2 * zs, xs * zd2, *z * zs * zd2)
+}
+
+/// x-only doubling.
+///
+/// Given `x = X`, `ta = aZ^2` and `tb = 4bZ^3`, returns `(x2, z2)` such that
+/// `[2](X : Z) = (x2 : Z * z2)`.
+#[inline]
+fn double<C: PrimeCurveParams>(
+    x: &C::FieldElement,
+    ta: &C::FieldElement,
+    tb: &C::FieldElement,
+) -> (C::FieldElement, C::FieldElement) {
+    let xx = x.square();
+    let x2 = (xx - ta).square() - (*tb * x).double();
+    let z2 = (*x * (xx + ta)).double().double() + tb;
+    (x2, z2)
+}
+
+/// Recode `k` as `k + n` or `k + 2n`, whichever has bit `NUM_BITS` as its most significant bit,
+/// so the ladder always runs for the same number of steps regardless of leading zeros of `k`.
+///
+/// Returns the big endian encoding of the result modulo `2^(8 * len)`, where `len` is the length
+/// of the scalar encoding.
+fn recode<C: PrimeCurveParams>(k: &Scalar<C>) -> <Scalar<C> as PrimeField>::Repr {
+    let n_minus_one = (-Scalar::<C>::ONE).to_be_repr();
+
+    let mut k1 = k.to_be_repr();
+    let carry = add_be(k1.as_mut(), n_minus_one.as_ref(), 1);
+    let mut k2 = k1;
+    add_be(k2.as_mut(), n_minus_one.as_ref(), 1);
+
+    let num_bits = Scalar::<C>::NUM_BITS as usize;
+    let k1_bytes: &[u8] = k1.as_ref();
+    let msb = if num_bits == 8 * k1_bytes.len() {
+        carry
+    } else {
+        (k1_bytes[k1_bytes.len() - 1 - num_bits / 8] >> (num_bits % 8)) & 1
+    };
+
+    let use_k1 = Choice::from(msb);
+    for (byte2, byte1) in k2.iter_mut().zip(k1_bytes) {
+        byte2.conditional_assign(byte1, use_k1);
+    }
+
+    k2
+}
+
+/// Add the big endian integers `a` and `b` of the same length, plus `carry`, in place. Returns the
+/// carry out.
+#[allow(clippy::cast_possible_truncation)]
+fn add_be(a: &mut [u8], b: &[u8], carry: u8) -> u8 {
+    let mut carry = u16::from(carry);
+
+    for (x, y) in a.iter_mut().zip(b).rev() {
+        let sum = u16::from(*x) + u16::from(*y) + carry;
+        *x = sum as u8;
+        carry = sum >> 8;
+    }
+
+    carry as u8
+}
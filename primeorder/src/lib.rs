@@ -12,22 +12,44 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "dhkem")]
+pub mod dhkem;
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+#[cfg(feature = "ecies")]
+pub mod ecies;
+#[cfg(feature = "hash2curve")]
+pub mod elligator_squared;
+#[cfg(feature = "explicit")]
+pub mod explicit;
 pub mod mul_backend;
 #[cfg(feature = "hash2curve")]
 pub mod osswu;
 pub mod point_arithmetic;
+#[cfg(feature = "spake2")]
+pub mod spake2;
+#[cfg(feature = "vrf")]
+pub mod vrf;
 
 mod affine;
+mod blinding;
 #[cfg(feature = "dev")]
 mod dev;
+mod jacobian;
+mod ladder;
+mod macros;
 mod projective;
 mod tables;
+mod validation;
 
 pub use crate::{
     affine::AffinePoint,
+    blinding::BlindingRng,
+    ladder::x_only_mul,
     mul_backend::MulBackend,
     projective::ProjectivePoint,
     tables::{LookupTable, Radix16Decomposition, Radix16Digits},
+    validation::{AffinePointValidation, PublicKeyValidation, ValidationError},
 };
 pub use elliptic_curve::{
     self, Field, FieldBytes, PrimeCurve, PrimeField, Scalar,
@@ -36,13 +58,15 @@ pub use elliptic_curve::{
     hazmat::FieldArithmetic,
     ops::Double,
 };
-pub use primefield::{FieldExt, PrimeFieldExt};
+pub use primefield::{self, FieldExt, PrimeFieldExt};
 pub use wnaf::{self, WnafSize};
 
 use elliptic_curve::{Curve, CurveArithmetic, sec1};
 
 #[cfg(feature = "basepoint-table")]
 pub use crate::tables::BasepointTable;
+#[cfg(feature = "alloc")]
+pub use crate::tables::FixedBaseTable;
 
 /// Parameters for elliptic curves of prime order which can be described by the short Weierstrass
 /// equation.
@@ -0,0 +1,532 @@
+//! Macros for defining curves.
+
+/// Define a complete prime order short Weierstrass curve from its domain parameters.
+///
+/// Emits the following items in the module where it's invoked:
+///
+/// - a zero-sized type representing the curve, which impls [`Curve`], [`PrimeCurve`] and
+///   [`PointCompression`]
+/// - the `CompressedPoint`, `Sec1Point`, `FieldBytes` and `SecretKey` type aliases
+/// - everything emitted by [`impl_curve_arithmetic!`](crate::impl_curve_arithmetic), including
+///   the `PublicKey` type alias and the standard test suite
+///
+/// Accepts the following parameters:
+///
+/// - name of the curve type and its documentation string
+/// - `crypto-bigint` unsigned integer type large enough to hold field elements and scalars
+/// - hex serializations of the field modulus and of the group order, along with multiplicative
+///   generators of the corresponding fields. All hex strings must be big endian and have the
+///   full width of the unsigned integer type
+/// - hex serializations of the curve equation coefficients and of the generator's affine
+///   coordinates
+/// - [point arithmetic](crate::point_arithmetic) implementation to use, and whether points are
+///   compressed by default
+/// - optionally, the curve's OID, which is used to impl [`AssociatedOid`]. Together with the
+///   `SecretKey` and `PublicKey` aliases, this enables the SEC1 and PKCS#8 key encodings
+///   provided by `elliptic-curve` when its `pkcs8` feature is enabled
+/// - optionally, the ECDSA implementation crate along with its defaults: whether to normalize
+///   `s`, and the default digest algorithm. The defaults are checked by the emitted tests
+/// - optionally, test vectors for repeated additions and multiplications of the generator, as
+///   accepted by `test_projective_arithmetic!` (requires the `dev` feature)
+///
+/// The value of each optional parameter may be preceded by attributes such as `#[cfg(...)]`,
+/// which are applied to all items emitted for it.
+///
+/// Field elements and scalars are encoded as big endian, and scalar multiplication uses the
+/// [`VariableOnly`] backend.
+///
+/// ```
+/// use primeorder::elliptic_curve::{
+///     bigint::U192,
+///     group::{Group, GroupEncoding},
+/// };
+///
+/// primeorder::define_curve! {
+///     name: NistP192,
+///     doc: "NIST P-192 elliptic curve.",
+///     uint: U192,
+///     modulus: "fffffffffffffffffffffffffffffffeffffffffffffffff",
+///     field_multiplicative_generator: 11,
+///     order: "ffffffffffffffffffffffff99def836146bc9b1b4d22831",
+///     scalar_multiplicative_generator: 3,
+///     equation_a: "fffffffffffffffffffffffffffffffefffffffffffffffc",
+///     equation_b: "64210519e59c80e70fa7e9ab72243049feb8deecc146b9b1",
+///     generator: (
+///         "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+///         "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",
+///     ),
+///     point_arithmetic: EquationAIsMinusThree,
+///     compress_points: false,
+///     oid: #[cfg(feature = "pkcs8")] "1.2.840.10045.3.1.1",
+/// }
+///
+/// fn main() {
+///     let secret_key = SecretKey::from_bytes(&FieldBytes::from([7; 24])).unwrap();
+///     let public_key: PublicKey = secret_key.public_key();
+///     assert_eq!(
+///         public_key.to_projective(),
+///         ProjectivePoint::GENERATOR * *secret_key.to_nonzero_scalar()
+///     );
+///
+///     let bytes: CompressedPoint = public_key.to_projective().to_bytes();
+///     assert_eq!(PublicKey::from_sec1_bytes(&bytes).unwrap(), public_key);
+/// }
+/// ```
+///
+/// [`Curve`]: elliptic_curve::Curve
+/// [`PrimeCurve`]: elliptic_curve::PrimeCurve
+/// [`PointCompression`]: elliptic_curve::point::PointCompression
+/// [`AssociatedOid`]: https://docs.rs/const-oid/latest/const_oid/trait.AssociatedOid.html
+/// [`VariableOnly`]: crate::mul_backend::VariableOnly
+#[macro_export]
+macro_rules! define_curve {
+    (
+        name: $name:ident,
+        doc: $doc:expr,
+        uint: $uint:ident,
+        modulus: $modulus_hex:expr,
+        field_multiplicative_generator: $field_generator:expr,
+        order: $order_hex:expr,
+        scalar_multiplicative_generator: $scalar_generator:expr,
+        equation_a: $a_hex:expr,
+        equation_b: $b_hex:expr,
+        generator: ($gx_hex:expr, $gy_hex:expr $(,)?),
+        point_arithmetic: $point_arithmetic:ident,
+        compress_points: $compress_points:expr,
+        $(oid: $(#[$oid_attr:meta])* $oid:literal,)?
+        $(
+            ecdsa: $(#[$ecdsa_attr:meta])*
+            $ecdsa:ident { normalize_s: $normalize_s:expr $(, digest: $digest:ty)? $(,)? },
+        )?
+        $(test_vectors: $(#[$vectors_attr:meta])* ($add_vectors:expr, $mul_vectors:expr),)?
+    ) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+        pub struct $name;
+
+        impl $crate::elliptic_curve::Curve for $name {
+            type FieldBytesSize = <field::FieldParams as $crate::primefield::MontyFieldParams<
+                { <$uint>::LIMBS },
+            >>::ByteSize;
+            type Uint = $uint;
+
+            const ORDER: $crate::elliptic_curve::bigint::Odd<$uint> =
+                $crate::elliptic_curve::bigint::Odd::<$uint>::from_be_hex($order_hex);
+        }
+
+        impl $crate::elliptic_curve::PrimeCurve for $name {}
+
+        impl $crate::elliptic_curve::point::PointCompression for $name {
+            const COMPRESS_POINTS: bool = $compress_points;
+        }
+
+        $(
+            $(#[$oid_attr])*
+            impl $crate::elliptic_curve::pkcs8::AssociatedOid for $name {
+                const OID: $crate::elliptic_curve::pkcs8::ObjectIdentifier =
+                    $crate::elliptic_curve::pkcs8::ObjectIdentifier::new_unwrap($oid);
+            }
+        )?
+
+        $(
+            $(#[$ecdsa_attr])*
+            const _: () = {
+                impl $ecdsa::EcdsaCurve for $name {
+                    const NORMALIZE_S: bool = $normalize_s;
+                }
+
+                $(
+                    impl $ecdsa::DigestAlgorithm for $name {
+                        type Digest = $digest;
+                    }
+                )?
+            };
+
+            $(#[$ecdsa_attr])*
+            #[cfg(test)]
+            mod ecdsa_tests {
+                use super::*;
+
+                #[test]
+                fn ecdsa_defaults() {
+                    assert_eq!(
+                        <$name as $ecdsa::EcdsaCurve>::NORMALIZE_S,
+                        $normalize_s
+                    );
+
+                    $(
+                        assert_eq!(
+                            core::any::TypeId::of::<<$name as $ecdsa::DigestAlgorithm>::Digest>(),
+                            core::any::TypeId::of::<$digest>()
+                        );
+                    )?
+                }
+            }
+        )?
+
+        /// Compressed SEC1-encoded curve point.
+        pub type CompressedPoint = $crate::array::Array<
+            u8,
+            <<$name as $crate::elliptic_curve::Curve>::FieldBytesSize
+                as $crate::elliptic_curve::sec1::ModulusSize>::CompressedPointSize,
+        >;
+
+        /// SEC1-encoded curve point.
+        pub type Sec1Point = $crate::elliptic_curve::sec1::Sec1Point<$name>;
+
+        /// Field element serialized as bytes.
+        ///
+        /// Byte array containing a serialized field element value (base field or scalar).
+        pub type FieldBytes = $crate::elliptic_curve::FieldBytes<$name>;
+
+        /// Secret key.
+        pub type SecretKey = $crate::elliptic_curve::SecretKey<$name>;
+
+        $crate::impl_curve_arithmetic! {
+            curve: $name,
+            uint: $uint,
+            modulus: $modulus_hex,
+            field_multiplicative_generator: $field_generator,
+            order: $order_hex,
+            scalar_multiplicative_generator: $scalar_generator,
+            equation_a: $a_hex,
+            equation_b: $b_hex,
+            generator: ($gx_hex, $gy_hex),
+            point_arithmetic: $point_arithmetic,
+            $(test_vectors: $(#[$vectors_attr])* ($add_vectors, $mul_vectors),)?
+        }
+    };
+}
+
+/// Implement arithmetic for an existing prime order short Weierstrass curve type.
+///
+/// This is the part of [`define_curve!`](crate::define_curve) which depends on this crate, for
+/// curve crates which define their curve type, its [`Curve`] impl and the associated SEC1 and
+/// PKCS#8 items themselves so they are available without arithmetic.
+///
+/// Emits the following items in the module where it's invoked:
+///
+/// - impls of [`CurveArithmetic`], [`FieldArithmetic`], [`PrimeCurveArithmetic`] and
+///   [`PrimeCurveParams`] for the curve type
+/// - `field` and `scalar` modules containing the `FieldElement` and `Scalar` types, which are
+///   implemented using `primefield`'s generic Montgomery arithmetic and are re-exported
+/// - the `AffinePoint`, `ProjectivePoint`, `NonZeroScalar` and `PublicKey` type aliases
+/// - the standard test suite: tests for the field and scalar types, tests of the generator and
+///   of the SEC1 and [`GroupEncoding`] point encodings and, if test vectors are given, tests of
+///   projective point arithmetic
+///
+/// Accepts the same parameters as [`define_curve!`](crate::define_curve), except that the
+/// curve's `name` is replaced by the path of the existing type as `curve`, and only the
+/// arithmetic-related ones are accepted. The curve's [`Curve::FieldBytesSize`] must match the
+/// size of the field modulus.
+///
+/// Curve crates with their own field and scalar implementations, e.g. ones synthesized with
+/// fiat-crypto, pass them as `field_element` and `scalar` instead of the moduli and
+/// multiplicative generators. In that case no `field` and `scalar` modules are emitted, and the
+/// tests for the field and scalar types are left to the curve crate:
+///
+/// ```ignore
+/// primeorder::impl_curve_arithmetic! {
+///     curve: NistP192,
+///     field_element: FieldElement,
+///     scalar: Scalar,
+///     equation_a: "fffffffffffffffffffffffffffffffefffffffffffffffc",
+///     equation_b: "64210519e59c80e70fa7e9ab72243049feb8deecc146b9b1",
+///     generator: (
+///         "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+///         "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",
+///     ),
+///     point_arithmetic: EquationAIsMinusThree,
+/// }
+/// ```
+///
+/// [`Curve`]: elliptic_curve::Curve
+/// [`Curve::FieldBytesSize`]: elliptic_curve::Curve::FieldBytesSize
+/// [`CurveArithmetic`]: elliptic_curve::CurveArithmetic
+/// [`FieldArithmetic`]: elliptic_curve::hazmat::FieldArithmetic
+/// [`GroupEncoding`]: elliptic_curve::group::GroupEncoding
+/// [`PrimeCurveArithmetic`]: elliptic_curve::PrimeCurveArithmetic
+/// [`PrimeCurveParams`]: crate::PrimeCurveParams
+#[macro_export]
+macro_rules! impl_curve_arithmetic {
+    (
+        curve: $name:path,
+        field_element: $field_element:ty,
+        scalar: $scalar:ty,
+        equation_a: $a_hex:expr,
+        equation_b: $b_hex:expr,
+        generator: ($gx_hex:expr, $gy_hex:expr $(,)?),
+        point_arithmetic: $point_arithmetic:ident,
+        $(test_vectors: $(#[$vectors_attr:meta])* ($add_vectors:expr, $mul_vectors:expr),)?
+    ) => {
+        impl $crate::elliptic_curve::CurveArithmetic for $name {
+            type AffinePoint = AffinePoint;
+            type ProjectivePoint = ProjectivePoint;
+            type Scalar = $scalar;
+        }
+
+        impl $crate::FieldArithmetic for $name {
+            type FieldElement = $field_element;
+        }
+
+        impl $crate::elliptic_curve::PrimeCurveArithmetic for $name {
+            type CurveGroup = ProjectivePoint;
+        }
+
+        impl $crate::PrimeCurveParams for $name {
+            type PointArithmetic = $crate::point_arithmetic::$point_arithmetic;
+            type Backend = $crate::mul_backend::VariableOnly;
+
+            const EQUATION_A: $field_element = <$field_element>::from_hex_vartime($a_hex);
+            const EQUATION_B: $field_element = <$field_element>::from_hex_vartime($b_hex);
+            const GENERATOR: ($field_element, $field_element) = (
+                <$field_element>::from_hex_vartime($gx_hex),
+                <$field_element>::from_hex_vartime($gy_hex),
+            );
+        }
+
+        /// Elliptic curve point in affine coordinates.
+        pub type AffinePoint = $crate::AffinePoint<$name>;
+
+        /// Elliptic curve point in projective coordinates.
+        pub type ProjectivePoint = $crate::ProjectivePoint<$name>;
+
+        /// Non-zero scalar field element.
+        pub type NonZeroScalar = $crate::elliptic_curve::NonZeroScalar<$name>;
+
+        /// Public key.
+        pub type PublicKey = $crate::elliptic_curve::PublicKey<$name>;
+
+        #[cfg(test)]
+        mod curve_tests {
+            use super::*;
+            #[allow(unused_imports)]
+            use $crate::elliptic_curve::{
+                Field as _,
+                group::{Curve as _, Group as _, GroupEncoding as _},
+                sec1::ToSec1Point as _,
+            };
+
+            #[test]
+            fn generator_has_prime_order() {
+                let order_minus_one = <$scalar>::ZERO - <$scalar>::ONE;
+                let point = ProjectivePoint::GENERATOR * order_minus_one;
+                assert_eq!(point, -ProjectivePoint::GENERATOR);
+                assert!(bool::from((point + ProjectivePoint::GENERATOR).is_identity()));
+            }
+
+            #[test]
+            fn generator_is_on_curve() {
+                let bytes = AffinePoint::GENERATOR.to_bytes();
+                assert_eq!(
+                    AffinePoint::from_bytes(&bytes).unwrap(),
+                    AffinePoint::GENERATOR
+                );
+            }
+
+            #[test]
+            fn group_encoding_round_trip() {
+                let point = ProjectivePoint::GENERATOR.double();
+                let bytes = point.to_bytes();
+                assert_eq!(ProjectivePoint::from_bytes(&bytes).unwrap(), point);
+                assert_eq!(point.to_affine().to_bytes(), bytes);
+            }
+
+            #[test]
+            fn public_key_from_secret_scalar() {
+                let scalar = NonZeroScalar::new(<$scalar>::ONE.double()).unwrap();
+                let public_key = PublicKey::from_secret_scalar(&scalar);
+                assert_eq!(
+                    public_key.to_projective(),
+                    ProjectivePoint::GENERATOR * *scalar
+                );
+
+                for compress in [false, true] {
+                    let sec1 = public_key.to_sec1_point(compress);
+                    assert_eq!(
+                        PublicKey::from_sec1_bytes(sec1.as_bytes()).unwrap(),
+                        public_key
+                    );
+                }
+            }
+        }
+
+        $(
+            $(#[$vectors_attr])*
+            #[cfg(test)]
+            mod projective_tests {
+                use super::*;
+                use $crate::elliptic_curve::{
+                    ff::PrimeField,
+                    group::Group as _,
+                    sec1::{self, ToSec1Point},
+                };
+
+                $crate::test_projective_arithmetic!(
+                    AffinePoint,
+                    ProjectivePoint,
+                    $scalar,
+                    $add_vectors,
+                    $mul_vectors
+                );
+            }
+        )?
+    };
+    (
+        curve: $name:path,
+        uint: $uint:ident,
+        modulus: $modulus_hex:expr,
+        field_multiplicative_generator: $field_generator:expr,
+        order: $order_hex:expr,
+        scalar_multiplicative_generator: $scalar_generator:expr,
+        equation_a: $a_hex:expr,
+        equation_b: $b_hex:expr,
+        generator: ($gx_hex:expr, $gy_hex:expr $(,)?),
+        point_arithmetic: $point_arithmetic:ident,
+        $(test_vectors: $(#[$vectors_attr:meta])* ($add_vectors:expr, $mul_vectors:expr),)?
+    ) => {
+        const _: () = assert!(
+            <<$name as $crate::elliptic_curve::Curve>::FieldBytesSize
+                as $crate::array::typenum::Unsigned>::USIZE
+                == <<field::FieldParams as $crate::primefield::MontyFieldParams<
+                    { <$uint>::LIMBS },
+                >>::ByteSize as $crate::array::typenum::Unsigned>::USIZE,
+            "`Curve::FieldBytesSize` must match the size of the field modulus"
+        );
+
+        $crate::impl_curve_arithmetic! {
+            curve: $name,
+            field_element: FieldElement,
+            scalar: Scalar,
+            equation_a: $a_hex,
+            equation_b: $b_hex,
+            generator: ($gx_hex, $gy_hex),
+            point_arithmetic: $point_arithmetic,
+            $(test_vectors: $(#[$vectors_attr])* ($add_vectors, $mul_vectors),)?
+        }
+
+        pub use self::{field::FieldElement, scalar::Scalar};
+
+        /// Base field elements.
+        pub mod field {
+            #[allow(unused_imports)]
+            use super::*;
+            #[allow(unused_imports)]
+            use $crate::elliptic_curve::{
+                ff::PrimeField,
+                subtle::{Choice, ConstantTimeEq, CtOption},
+            };
+
+            #[cfg(doc)]
+            use core::ops::{Add, Mul, Neg, Sub};
+
+            $crate::primefield::monty_field_params! {
+                name: FieldParams,
+                modulus: $modulus_hex,
+                uint: $uint,
+                byte_order: $crate::primefield::ByteOrder::BigEndian,
+                multiplicative_generator: $field_generator,
+                doc: "Montgomery parameters for the field modulus `p`."
+            }
+
+            $crate::primefield::monty_field_element! {
+                name: FieldElement,
+                params: FieldParams,
+                uint: $uint,
+                doc: "Element in the finite field modulo `p`."
+            }
+
+            $crate::primefield::monty_field_arithmetic! {
+                name: FieldElement,
+                params: FieldParams,
+                uint: $uint
+            }
+
+            impl $crate::elliptic_curve::ops::BatchInvert for FieldElement {}
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+
+                $crate::primefield::test_primefield!(FieldElement, $uint);
+            }
+        }
+
+        /// Scalar field elements.
+        pub mod scalar {
+            #[allow(unused_imports)]
+            use super::*;
+            #[allow(unused_imports)]
+            use $crate::elliptic_curve::{
+                Curve as _,
+                ff::PrimeField,
+                scalar::{FromUintUnchecked, IsHigh},
+                subtle::{Choice, ConstantTimeEq, ConstantTimeGreater, CtOption},
+            };
+
+            #[cfg(doc)]
+            use core::ops::{Add, Mul, Neg, Sub};
+
+            $crate::primefield::monty_field_params! {
+                name: ScalarParams,
+                modulus: $order_hex,
+                uint: $uint,
+                byte_order: $crate::primefield::ByteOrder::BigEndian,
+                multiplicative_generator: $scalar_generator,
+                doc: "Montgomery parameters for the scalar modulus `n`."
+            }
+
+            $crate::primefield::monty_field_element! {
+                name: Scalar,
+                params: ScalarParams,
+                uint: $uint,
+                doc: "Element in the scalar field modulo `n`."
+            }
+
+            $crate::primefield::monty_field_arithmetic! {
+                name: Scalar,
+                params: ScalarParams,
+                uint: $uint
+            }
+
+            $crate::primefield::monty_field_reduce! {
+                name: Scalar,
+                params: ScalarParams,
+                uint: $uint,
+            }
+
+            $crate::elliptic_curve::scalar_impls!($name, Scalar);
+
+            $crate::wnaf::impl_wnaf_size_for_scalar!(Scalar);
+
+            impl AsRef<Scalar> for Scalar {
+                fn as_ref(&self) -> &Scalar {
+                    self
+                }
+            }
+
+            impl FromUintUnchecked for Scalar {
+                type Uint = $uint;
+
+                fn from_uint_unchecked(uint: Self::Uint) -> Self {
+                    Self::from_uint_unchecked(uint)
+                }
+            }
+
+            impl IsHigh for Scalar {
+                fn is_high(&self) -> Choice {
+                    const MODULUS_SHR1: $uint =
+                        <$name as $crate::elliptic_curve::Curve>::ORDER.as_ref().shr_vartime(1);
+                    self.to_canonical().ct_gt(&MODULUS_SHR1)
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+
+                $crate::primefield::test_primefield!(Scalar, $uint);
+            }
+        }
+    };
+}
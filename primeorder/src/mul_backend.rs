@@ -3,6 +3,7 @@
 use crate::{PrimeCurveParams, ProjectivePoint};
 use elliptic_curve::Scalar;
 use elliptic_curve::ops::LinearCombination;
+use elliptic_curve::rand_core::TryCryptoRng;
 
 #[cfg(feature = "basepoint-table")]
 use crate::PrimeCurveWithBasepointTable;
@@ -25,6 +26,20 @@ pub trait MulBackend<C: PrimeCurveParams> {
         ProjectivePoint::GENERATOR.mul_vartime(k)
     }
 
+    /// Multiplication by the generator, blinded using randomness from `rng`.
+    ///
+    /// This is overridable to make it possible to plug in a basepoint table.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    #[inline]
+    fn try_mul_by_generator_blinded<R: TryCryptoRng + ?Sized>(
+        k: &Scalar<C>,
+        rng: &mut R,
+    ) -> Result<ProjectivePoint<C>, R::Error> {
+        ProjectivePoint::GENERATOR.try_mul_blinded(k, rng)
+    }
+
     /// Multiply `a` by the generator of the prime-order subgroup, adding the result to the point
     /// `P` multiplied by the scalar `b`, i.e. compute `aG + bP`.
     #[inline]
@@ -65,4 +80,12 @@ where
     fn mul_by_generator_vartime(k: &Scalar<C>) -> ProjectivePoint<C> {
         C::BASEPOINT_TABLE.mul_vartime(k)
     }
+
+    #[inline]
+    fn try_mul_by_generator_blinded<R: TryCryptoRng + ?Sized>(
+        k: &Scalar<C>,
+        rng: &mut R,
+    ) -> Result<ProjectivePoint<C>, R::Error> {
+        C::BASEPOINT_TABLE.try_mul_blinded(k, rng)
+    }
 }
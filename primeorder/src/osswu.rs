@@ -5,7 +5,7 @@
 use elliptic_curve::{
     Field,
     hazmat::FieldArithmetic,
-    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
 };
 
 use crate::{AffinePoint, PrimeCurveParams};
@@ -14,6 +14,11 @@ use crate::{AffinePoint, PrimeCurveParams};
 pub trait AffineOsswuMap<C: PrimeCurveParams + FieldArithmetic<FieldElement: OsswuMap>> {
     /// [`OsswuMap::osswu()`] to [`AffinePoint`].
     fn osswu(u: &C::FieldElement) -> Self;
+
+    /// [`OsswuMap::osswu_preimage()`] of an [`AffinePoint`].
+    ///
+    /// Returns none for the identity.
+    fn osswu_preimage(&self, branch: u8) -> CtOption<C::FieldElement>;
 }
 
 impl<C> AffineOsswuMap<C> for AffinePoint<C>
@@ -24,6 +29,11 @@ where
         let (x, y) = u.osswu();
         Self { x, y, infinity: 0 }
     }
+
+    fn osswu_preimage(&self, branch: u8) -> CtOption<C::FieldElement> {
+        C::FieldElement::osswu_preimage(&self.x, &self.y, branch)
+            .and_then(|u| CtOption::new(u, !self.is_identity()))
+    }
 }
 
 /// The Optimized Simplified Shallue-van de Woestijne-Ulas parameters
@@ -147,4 +157,46 @@ pub trait OsswuMap: Field + Sgn0 {
         // 26. return (x, y)
         (x, y)
     }
+
+    /// Compute a preimage of the point `(x, y)` under [`OsswuMap::osswu`].
+    ///
+    /// A point has at most four preimages, which are indexed by `branch` (only the two low bits
+    /// are used): bit 0 selects whether `x` is recovered as `x1` or `x2 = Z * u^2 * x1` in the
+    /// forward map, and bit 1 selects which square root is used when solving for `Z * u^2`.
+    /// Returns none if the selected preimage does not exist.
+    ///
+    /// Each candidate is checked against the forward map, so points which are not in the image
+    /// of [`OsswuMap::osswu`] are rejected. The exceptional inputs `u` for which
+    /// `Z^2 * u^4 + Z * u^2 = 0` are never returned.
+    fn osswu_preimage(x: &Self, y: &Self, branch: u8) -> CtOption<Self> {
+        let is_x2 = Choice::from(branch & 1);
+        let is_neg = Choice::from((branch >> 1) & 1);
+
+        // Let t = Z * u^2. For the x1 branch, x = -B/A * (1 + 1/(t^2 + t)), so
+        // t^2 + t + B/(A*x + B) = 0 which has the discriminant (A*x - 3*B)/(A*x + B).
+        // For the x2 branch, x = -B/A * (t + 1/(t + 1)), so s = t + 1 is a root of
+        // s^2 - k*s + 1 = 0 where k = 1 - A*x/B, which has the discriminant k^2 - 4.
+        let ax = Self::PARAMS.map_a * x;
+        let b3 = Self::PARAMS.map_b.double() + Self::PARAMS.map_b;
+        let k = Self::ONE - ax * Self::PARAMS.map_b.invert().unwrap();
+        let four = Self::ONE.double().double();
+        let two_inv = Self::ONE.double().invert().unwrap();
+
+        let num = Self::conditional_select(&(ax - b3), &(k.square() - four), is_x2);
+        let div = Self::conditional_select(&(ax + Self::PARAMS.map_b), &Self::ONE, is_x2);
+        let (is_square, r) = Self::sqrt_ratio(&num, &div);
+        let r = Self::conditional_select(&r, &-r, is_neg);
+
+        // t = (-1 ± r)/2 for x1, and t = (k ± r)/2 - 1 = (k - 2 ± r)/2 for x2
+        let c = Self::conditional_select(&-Self::ONE, &(k - Self::ONE.double()), is_x2);
+        let t = (c + r) * two_inv;
+
+        // u = ±sqrt(t/Z), with the sign chosen so that sgn0(u) == sgn0(y)
+        let (is_u_square, u) = Self::sqrt_ratio(&t, &Self::PARAMS.z);
+        let u = Self::conditional_select(&u, &-u, u.sgn0() ^ y.sgn0());
+
+        let (x2, y2) = u.osswu();
+        let is_preimage = is_square & is_u_square & x2.ct_eq(x) & y2.ct_eq(y);
+        CtOption::new(u, is_preimage)
+    }
 }
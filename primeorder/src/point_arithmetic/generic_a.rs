@@ -0,0 +1,119 @@
+//! Complete formulas for short Weierstrass curves with any `a`, generic over the field
+//! implementation.
+
+use crate::FieldExt;
+use core::ops::{Add, Mul, Neg, Sub};
+use elliptic_curve::Field;
+
+/// Field arithmetic used by the formulas for curves with any `a`.
+///
+/// These formulas are shared with curves with explicit parameters, whose field elements have a
+/// modulus only known at runtime and therefore can't implement [`Field`].
+pub(crate) trait GenericAField:
+    Clone
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + Neg<Output = Self>
+{
+    /// Compute `self²`.
+    fn square(&self) -> Self;
+
+    /// Compute `(self + add) * mul`.
+    fn add_and_mul(&self, add: &Self, mul: &Self) -> Self;
+
+    /// Compute `a[0] * b[0] + a[1] * b[1]`.
+    fn sum_of_products(a: &[Self; 2], b: &[Self; 2]) -> Self;
+}
+
+impl<F: FieldExt> GenericAField for F {
+    fn square(&self) -> Self {
+        Field::square(self)
+    }
+
+    fn add_and_mul(&self, add: &Self, mul: &Self) -> Self {
+        FieldExt::add_and_mul(self, add, mul)
+    }
+
+    fn sum_of_products(a: &[Self; 2], b: &[Self; 2]) -> Self {
+        FieldExt::sum_of_products(a, b)
+    }
+}
+
+/// Complete addition of two points in projective coordinates on a curve with any `a`, given `a`
+/// and `b3 = 3b`.
+///
+/// Implements the complete addition formula from [Renes-Costello-Batina 2015]
+/// (Algorithm 1). The comments after each line indicate which algorithm steps
+/// are being performed.
+///
+/// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+pub(crate) fn add_with_generic_a<F: GenericAField>(
+    [x1, y1, z1]: [&F; 3],
+    [x2, y2, z2]: [&F; 3],
+    a: &F,
+    b3: &F,
+) -> [F; 3] {
+    let t0 = x1.clone() * x2; // 1
+    let t1 = y1.clone() * y2; // 2
+    let t2 = z1.clone() * z2; // 3
+    let t3 = x1.add_and_mul(y1, &(x2.clone() + y2)); // 4, 5, 6
+    let t4 = t0.clone() + &t1; // 7
+    let t3 = t3 - &t4; // 8
+    let t4 = x1.add_and_mul(z1, &(x2.clone() + z2)); // 9, 10, 11
+    let t5 = t0.clone() + &t2; // 12
+    let t4 = t4 - &t5; // 13
+    let t5 = y1.add_and_mul(z1, &(y2.clone() + z2)); // 14, 15, 16
+    let x3 = t1.clone() + &t2; // 17
+    let t5 = t5 - &x3; // 18
+    let z3 = F::sum_of_products(&[a.clone(), b3.clone()], &[t4.clone(), t2.clone()]); // 19, 20, 21
+    let x3 = t1.clone() - &z3; // 22
+    let z3 = t1 + &z3; // 23
+    let t1 = t0.clone() + &t0; // 25
+    let t1 = t1 + &t0; // 26
+    let t2 = a.clone() * &t2; // 27
+    let t1 = t1 + &t2; // 29
+    let t2 = t0 - &t2; // 30
+    let t4 = F::sum_of_products(&[b3.clone(), a.clone()], &[t4, t2]); // 28, 31, 32
+    let y3 = F::sum_of_products(&[x3.clone(), t1.clone()], &[z3.clone(), t4.clone()]); // 24, 33, 34
+    let x3 = F::sum_of_products(&[t3.clone(), -t5.clone()], &[x3, t4]); // 35, 36, 37
+    let z3 = F::sum_of_products(&[t5, t3], &[z3, t1]); // 38, 39, 40
+
+    [x3, y3, z3]
+}
+
+/// Doubling of a point in projective coordinates on a curve with any `a`, given `a` and
+/// `b3 = 3b`.
+///
+/// Implements the exception-free point doubling formula from [Renes-Costello-Batina 2015]
+/// (Algorithm 3). The comments after each line indicate which algorithm
+/// steps are being performed.
+///
+/// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+pub(crate) fn double_with_generic_a<F: GenericAField>([x, y, z]: [&F; 3], a: &F, b3: &F) -> [F; 3] {
+    let t0 = x.square(); // 1
+    let t1 = y.square(); // 2
+    let t2 = z.square(); // 3
+    let t3 = x.clone() * y; // 4
+    let t3 = t3.clone() + &t3; // 5
+    let z3 = x.clone() * z; // 6
+    let z3 = z3.clone() + &z3; // 7
+    let y3 = F::sum_of_products(&[a.clone(), b3.clone()], &[z3.clone(), t2.clone()]); // 8, 9, 10
+    let x3 = t1.clone() - &y3; // 11
+    let y3 = t1.clone() + &y3; // 12
+    let t2 = a.clone() * &t2; // 16
+    let t4 = t0.clone() - &t2; // 17
+    let t4 = F::sum_of_products(&[a.clone(), b3.clone()], &[t4, z3]); // 15, 18, 19
+    let z3 = t0.clone() + &t0; // 20
+    let t0 = z3 + &t0; // 21
+    let t0 = t0 + &t2; // 22
+    let y3 = F::sum_of_products(&[x3.clone(), t0], &[y3, t4.clone()]); // 13, 23, 24
+    let t2 = y.clone() * z; // 25
+    let t2 = t2.clone() + &t2; // 26
+    let x3 = F::sum_of_products(&[t3, -t2.clone()], &[x3, t4]); // 14, 27, 28
+    let z3 = t2 * &t1; // 29
+    let z3 = z3.clone() + &z3; // 30
+    let z3 = z3.clone() + &z3; // 31
+
+    [x3, y3, z3]
+}
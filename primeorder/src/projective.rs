@@ -3,8 +3,10 @@
 #![allow(clippy::needless_range_loop, clippy::op_ref)]
 
 use crate::{
-    AffinePoint, ArraySize, Field, LookupTable, MulBackend, PrimeCurveParams, Radix16Decomposition,
-    Radix16Digits, point_arithmetic::PointArithmetic,
+    AffinePoint, ArraySize, Field, FieldExt, LookupTable, MulBackend, PrimeCurveParams,
+    Radix16Decomposition, Radix16Digits,
+    jacobian::{self, OddMultiples},
+    point_arithmetic::PointArithmetic,
 };
 use core::{array, borrow::Borrow, iter::Sum, iter::zip};
 use elliptic_curve::{
@@ -17,11 +19,11 @@ use elliptic_curve::{
         prime::{PrimeCurve, PrimeGroup},
     },
     ops::{
-        Add, AddAssign, BatchInvert, Double, LinearCombination, Mul, MulAssign,
-        MulByGeneratorVartime, MulVartime, Neg, Sub, SubAssign,
+        Add, AddAssign, Double, LinearCombination, Mul, MulAssign, MulByGeneratorVartime,
+        MulVartime, Neg, Sub, SubAssign,
     },
     point::NonIdentity,
-    rand_core::{TryCryptoRng, TryRng},
+    rand_core::{CryptoRng, TryCryptoRng, TryRng},
     sec1::{CompressedPoint, FromSec1Point, Sec1Point, ToSec1Point},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
@@ -31,15 +33,14 @@ use wnaf::WnafGroup;
 use crate::array::Array;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "blinding")]
+use elliptic_curve::common::getrandom::SysRng;
 #[cfg(feature = "serde")]
 use serdect::serde::{Deserialize, Serialize, de, ser};
 
 /// Default w-NAF window size to use.
 // TODO(tarcieri): per-curve customization?
-type DefaultWnafWindowSize = U5;
-
-/// `WnafBase` generic around an elliptic curve `C` using default window size for this curve.
-pub(crate) type WnafBase<C> = wnaf::WnafBase<ProjectivePoint<C>, DefaultWnafWindowSize>;
+pub(crate) type DefaultWnafWindowSize = U5;
 
 /// `WnafScalar` generic around an elliptic curve `C` using default window size for this curve.
 pub(crate) type WnafScalar<C> = wnaf::WnafScalar<Scalar<C>, DefaultWnafWindowSize>;
@@ -140,7 +141,84 @@ where
     #[inline]
     #[must_use]
     pub fn mul_vartime(&self, k: &Scalar<C>) -> Self {
-        WnafBase::<C>::new(self) * WnafScalar::<C>::new(k)
+        jacobian::lincomb_vartime(&[OddMultiples::new(self)], &[WnafScalar::<C>::new(k)])
+    }
+
+    /// Returns `[k] self`, using randomness from `rng` to blind the computation.
+    ///
+    /// This is a countermeasure against side-channel attacks such as differential power or EM
+    /// analysis. The scalar is split into the random shares `k - r` and `r`, and the projective
+    /// coordinates of the point are multiplied by a random nonzero field element, so neither the
+    /// selected table entries nor the intermediate coordinates can be predicted from `k`.
+    ///
+    /// This is about twice as slow as [`ProjectivePoint::mul`].
+    ///
+    /// The SM2DSA and Bign signers in this repository blind `[k]G` this way. ECDSA signing keys
+    /// from the `ecdsa` crate compute it with [`Group::mul_by_generator`], which is blinded using
+    /// the system RNG when the `blinding` crate feature is enabled.
+    ///
+    /// When `self` is a fixed base with a precomputed table, the table entries themselves are
+    /// public and are not randomized; only the accumulated coordinates are.
+    #[must_use]
+    pub fn mul_blinded<R: CryptoRng + ?Sized>(&self, k: &Scalar<C>, rng: &mut R) -> Self {
+        let Ok(point) = self.try_mul_blinded(k, rng);
+        point
+    }
+
+    /// Returns `[k] self`, using randomness from `rng` to blind the computation.
+    ///
+    /// See [`ProjectivePoint::mul_blinded`] for more information.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_mul_blinded<R: TryCryptoRng + ?Sized>(
+        &self,
+        k: &Scalar<C>,
+        rng: &mut R,
+    ) -> core::result::Result<Self, R::Error> {
+        let r = Scalar::<C>::try_random(rng)?;
+        let lambda = C::FieldElement::try_random_nonzero(rng)?;
+        let table = LookupTable::new(self.rescale(&lambda));
+        let digits = [
+            Radix16Decomposition::new(&(*k - r)),
+            Radix16Decomposition::new(&r),
+        ];
+        Ok(lincomb::<C>(&[table, table], &digits))
+    }
+
+    /// Returns `[k] G`, where `G` is the generator, using randomness from `rng` to blind the
+    /// computation.
+    ///
+    /// See [`ProjectivePoint::mul_blinded`] for more information.
+    #[must_use]
+    pub fn mul_by_generator_blinded<R: CryptoRng + ?Sized>(k: &Scalar<C>, rng: &mut R) -> Self {
+        let Ok(point) = Self::try_mul_by_generator_blinded(k, rng);
+        point
+    }
+
+    /// Returns `[k] G`, where `G` is the generator, using randomness from `rng` to blind the
+    /// computation.
+    ///
+    /// See [`ProjectivePoint::mul_blinded`] for more information.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_mul_by_generator_blinded<R: TryCryptoRng + ?Sized>(
+        k: &Scalar<C>,
+        rng: &mut R,
+    ) -> core::result::Result<Self, R::Error> {
+        C::Backend::try_mul_by_generator_blinded(k, rng)
+    }
+
+    /// Returns a representation of the same point with its projective coordinates multiplied by
+    /// `lambda`, which must be nonzero.
+    #[inline]
+    pub(crate) fn rescale(&self, lambda: &C::FieldElement) -> Self {
+        Self {
+            x: self.x * lambda,
+            y: self.y * lambda,
+            z: self.z * lambda,
+        }
     }
 }
 
@@ -356,9 +434,23 @@ where
         Double::double(self)
     }
 
+    /// Multiply the generator by `scalar`.
+    ///
+    /// When the `blinding` crate feature is enabled this is computed with
+    /// [`ProjectivePoint::try_mul_by_generator_blinded`] using the system RNG. If the system RNG
+    /// fails, the unblinded multiplication is used instead.
     #[inline]
     fn mul_by_generator(scalar: &Self::Scalar) -> Self {
-        C::Backend::mul_by_generator(scalar)
+        #[cfg(feature = "blinding")]
+        {
+            Self::try_mul_by_generator_blinded(scalar, &mut SysRng)
+                .unwrap_or_else(|_| C::Backend::mul_by_generator(scalar))
+        }
+
+        #[cfg(not(feature = "blinding"))]
+        {
+            C::Backend::mul_by_generator(scalar)
+        }
     }
 }
 
@@ -466,7 +558,7 @@ fn batch_normalize_generic<C>(
     }
 
     // Zero `zs` (identity) are handled explicitly below, so the `Choice` here is informational only
-    let _ = C::FieldElement::batch_invert_in_place(zs, scratch);
+    let _ = primefield::batch_invert(zs, scratch);
 
     for i in 0..out.len() {
         out[i] = C::AffinePoint::conditional_select(
@@ -497,16 +589,16 @@ where
 
     #[cfg(feature = "alloc")]
     fn lincomb_vartime(points_and_scalars: &[(Self, Scalar<C>)]) -> Self {
-        let bases: Vec<_> = points_and_scalars
+        let tables: Vec<_> = points_and_scalars
             .iter()
-            .map(|(point, _)| WnafBase::<C>::new(point))
+            .map(|(point, _)| OddMultiples::new(point))
             .collect();
         let scalars: Vec<_> = points_and_scalars
             .iter()
             .map(|(_, scalar)| WnafScalar::<C>::new(scalar))
             .collect();
 
-        WnafBase::multiscalar_mul(bases.iter().zip(scalars.iter()))
+        jacobian::lincomb_vartime(&tables, &scalars)
     }
 }
 
@@ -523,9 +615,13 @@ where
     }
 
     fn lincomb_vartime(points_and_scalars: &[(Self, Scalar<C>); N]) -> Self {
-        let bases = points_and_scalars.map(|(point, _)| WnafBase::<C>::new(&point));
-        let scalars = points_and_scalars.map(|(_, scalar)| WnafScalar::<C>::new(&scalar));
-        WnafBase::multiscalar_mul(bases.iter().zip(scalars.iter()))
+        let tables = points_and_scalars
+            .each_ref()
+            .map(|(point, _)| OddMultiples::new(point));
+        let scalars = points_and_scalars
+            .each_ref()
+            .map(|(_, scalar)| WnafScalar::<C>::new(scalar));
+        jacobian::lincomb_vartime(&tables, &scalars)
     }
 }
 
@@ -0,0 +1,348 @@
+//! SPAKE2 password-authenticated key exchange as described in [RFC 9382].
+//!
+//! SPAKE2 is a balanced PAKE: both parties share a password, from which they derive the same
+//! scalar `w`, and exchange a single message each to agree on a shared key. An attacker who
+//! observes or modifies the exchange can only test a single password guess per run of the
+//! protocol.
+//!
+//! The password must be turned into `w` with a memory-hard function by the caller, e.g. with
+//! Argon2 or scrypt, and reduced modulo the order of the group as described in
+//! [RFC 9382 § 3.2].
+//!
+//! [RFC 9382]: https://www.rfc-editor.org/rfc/rfc9382
+//! [RFC 9382 § 3.2]: https://www.rfc-editor.org/rfc/rfc9382#section-3.2
+
+use crate::{AffinePoint, PrimeCurveParams, ProjectivePoint};
+use core::{fmt, marker::PhantomData};
+use elliptic_curve::{
+    Error, Generate, NonZeroScalar, Result, Scalar,
+    ff::PrimeField,
+    rand_core::{CryptoRng, TryCryptoRng},
+    sec1::{FromSec1Point, Sec1Point, ToSec1Point},
+    subtle::ConstantTimeEq,
+    zeroize::Zeroize,
+};
+use hkdf::Hkdf;
+use hmac::{
+    Hmac, KeyInit, Mac,
+    digest::{Digest, Output, block_api::EagerHash},
+};
+
+/// Curves which have SPAKE2 parameters defined in [RFC 9382 § 6].
+///
+/// The RFC gives `M` and `N` as compressed SEC1 encodings. They're stored as affine coordinates
+/// like [`PrimeCurveParams::GENERATOR`] so they don't need to be decompressed, and implementations
+/// should test that decoding the RFC's encodings with [`FromSec1Point`] gives the same points.
+///
+/// [RFC 9382 § 6]: https://www.rfc-editor.org/rfc/rfc9382#section-6
+pub trait Spake2Params: PrimeCurveParams {
+    /// Affine coordinates `(x, y)` of the point `M`, which blinds the message of party A.
+    const M: (Self::FieldElement, Self::FieldElement);
+
+    /// Affine coordinates `(x, y)` of the point `N`, which blinds the message of party B.
+    const N: (Self::FieldElement, Self::FieldElement);
+}
+
+/// Role of a party in the SPAKE2 protocol.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Role {
+    /// Party A, whose message is blinded with `M`.
+    A,
+
+    /// Party B, whose message is blinded with `N`.
+    B,
+}
+
+/// State of a party in the SPAKE2 protocol, after it has computed its message.
+///
+/// `D` is the hash function of the ciphersuite, which is also used for HKDF and HMAC, e.g.
+/// SHA-256 for `SPAKE2-P256-SHA256-HKDF-HMAC`.
+pub struct Spake2<'a, C: Spake2Params, D> {
+    role: Role,
+    w: Scalar<C>,
+    secret: NonZeroScalar<C>,
+    id_a: &'a [u8],
+    id_b: &'a [u8],
+    message: AffinePoint<C>,
+    hash: PhantomData<D>,
+}
+
+impl<'a, C, D> Spake2<'a, C, D>
+where
+    C: Spake2Params,
+    D: EagerHash,
+{
+    /// Start the protocol as the party with the given role, using the scalar `w` derived from
+    /// the password and the identities of both parties, which may be empty.
+    #[must_use]
+    pub fn new<R: CryptoRng + ?Sized>(
+        role: Role,
+        w: &Scalar<C>,
+        id_a: &'a [u8],
+        id_b: &'a [u8],
+        rng: &mut R,
+    ) -> Self {
+        let secret = NonZeroScalar::generate_from_rng(rng);
+        Self::new_with_secret(role, w, secret, id_a, id_b)
+    }
+
+    /// Start the protocol as the party with the given role, using the scalar `w` derived from
+    /// the password and the identities of both parties, which may be empty.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_new<R: TryCryptoRng + ?Sized>(
+        role: Role,
+        w: &Scalar<C>,
+        id_a: &'a [u8],
+        id_b: &'a [u8],
+        rng: &mut R,
+    ) -> core::result::Result<Self, R::Error> {
+        let secret = NonZeroScalar::try_generate_from_rng(rng)?;
+        Ok(Self::new_with_secret(role, w, secret, id_a, id_b))
+    }
+
+    /// Start the protocol with the given ephemeral secret scalar (`x` for party A, `y` for
+    /// party B).
+    ///
+    /// <div class = "warning">
+    /// <b>Security Warning</b>
+    ///
+    /// The secret scalar MUST be generated uniformly at random and never reused. This is only
+    /// intended for testing against known answers; use [`Spake2::new`] otherwise.
+    /// </div>
+    #[must_use]
+    pub fn new_with_secret(
+        role: Role,
+        w: &Scalar<C>,
+        secret: NonZeroScalar<C>,
+        id_a: &'a [u8],
+        id_b: &'a [u8],
+    ) -> Self {
+        // pA = x * P + w * M, pB = y * P + w * N
+        let blinding = match role {
+            Role::A => m::<C>(),
+            Role::B => n::<C>(),
+        };
+        let message = (ProjectivePoint::<C>::GENERATOR * *secret + blinding * w).to_affine();
+
+        Self {
+            role,
+            w: *w,
+            secret,
+            id_a,
+            id_b,
+            message,
+            hash: PhantomData,
+        }
+    }
+
+    /// Role of this party.
+    #[must_use]
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    /// Message to send to the other party, as an uncompressed SEC1 point.
+    #[must_use]
+    pub fn message(&self) -> Sec1Point<C> {
+        self.message.to_sec1_point(false)
+    }
+
+    /// Process the message received from the other party and compute the shared keys.
+    ///
+    /// `aad` is the associated data which is bound to the key confirmation keys, and must be the
+    /// same for both parties. It may be empty.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the message isn't an encoded point on the curve, or it is or leads
+    /// to the identity.
+    pub fn finish(&self, peer_message: &[u8], aad: &[u8]) -> Result<Spake2Keys<D>> {
+        let peer = Sec1Point::<C>::from_bytes(peer_message).map_err(|_| Error)?;
+        let peer = AffinePoint::<C>::from_sec1_point(&peer)
+            .into_option()
+            .filter(|point| !bool::from(point.is_identity()))
+            .ok_or(Error)?;
+
+        // K = h * x * (pB - w * N) for party A, K = h * y * (pA - w * M) for party B, where
+        // the cofactor `h` is one
+        let (p_a, p_b, unblinding) = match self.role {
+            Role::A => (self.message, peer, n::<C>()),
+            Role::B => (peer, self.message, m::<C>()),
+        };
+        let k = ((ProjectivePoint::from(peer) - unblinding * self.w) * *self.secret).to_affine();
+        if k.is_identity().into() {
+            return Err(Error);
+        }
+
+        let transcript = Transcript::<C> {
+            id_a: self.id_a,
+            id_b: self.id_b,
+            p_a: p_a.to_sec1_point(false),
+            p_b: p_b.to_sec1_point(false),
+            k: k.to_sec1_point(false),
+            w: self.w.to_repr(),
+        };
+
+        // Ke || Ka = Hash(TT)
+        let mut hash = D::new();
+        transcript.write(|bytes| Digest::update(&mut hash, bytes));
+        let mut hash = hash.finalize();
+        let (ke, ka) = hash.split_at(hash.len() / 2);
+
+        // KcA || KcB = KDF(nil, Ka, "ConfirmationKeys" || AAD, L)
+        let mut kc = Output::<D>::default();
+        Hkdf::<D>::new(None, ka)
+            .expand_multi_info(&[b"ConfirmationKeys".as_slice(), aad], &mut kc)
+            .map_err(|_| Error)?;
+        let (kc_a, kc_b) = kc.split_at(kc.len() / 2);
+
+        let mut shared_key = Output::<D>::default();
+        shared_key[..ke.len()].copy_from_slice(ke);
+
+        let (kc_own, kc_peer) = match self.role {
+            Role::A => (kc_a, kc_b),
+            Role::B => (kc_b, kc_a),
+        };
+        let keys = Spake2Keys {
+            shared_key,
+            confirmation: transcript.mac::<D>(kc_own)?,
+            peer_confirmation: transcript.mac::<D>(kc_peer)?,
+        };
+
+        hash.as_mut_slice().zeroize();
+        kc.as_mut_slice().zeroize();
+        Ok(keys)
+    }
+}
+
+impl<C, D> fmt::Debug for Spake2<'_, C, D>
+where
+    C: Spake2Params,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spake2")
+            .field("role", &self.role)
+            .field("message", &self.message)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C, D> Drop for Spake2<'_, C, D>
+where
+    C: Spake2Params,
+{
+    fn drop(&mut self) {
+        self.w.zeroize();
+        self.secret.zeroize();
+    }
+}
+
+/// Keys resulting from a run of the SPAKE2 protocol.
+///
+/// The shared key MUST NOT be used before the confirmation message received from the other party
+/// has been checked with [`Spake2Keys::verify_confirmation`].
+pub struct Spake2Keys<D: EagerHash> {
+    /// `Ke`, stored in the first half of a hash output.
+    shared_key: Output<D>,
+    confirmation: Output<D>,
+    peer_confirmation: Output<D>,
+}
+
+impl<D: EagerHash> Spake2Keys<D> {
+    /// Shared key `Ke`, which is half the size of the hash output.
+    #[must_use]
+    pub fn shared_key(&self) -> &[u8] {
+        &self.shared_key[..self.shared_key.len() / 2]
+    }
+
+    /// Key confirmation message to send to the other party.
+    #[must_use]
+    pub fn confirmation(&self) -> &[u8] {
+        &self.confirmation
+    }
+
+    /// Check the key confirmation message received from the other party in constant time.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the confirmation message is invalid, which means that the parties
+    /// used different passwords or the exchange was tampered with.
+    pub fn verify_confirmation(&self, confirmation: &[u8]) -> Result<()> {
+        if self.peer_confirmation.as_slice().ct_eq(confirmation).into() {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl<D: EagerHash> fmt::Debug for Spake2Keys<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spake2Keys").finish_non_exhaustive()
+    }
+}
+
+impl<D: EagerHash> Drop for Spake2Keys<D> {
+    fn drop(&mut self) {
+        self.shared_key.as_mut_slice().zeroize();
+    }
+}
+
+/// Protocol transcript `TT` as described in [RFC 9382 § 4].
+///
+/// [RFC 9382 § 4]: https://www.rfc-editor.org/rfc/rfc9382#section-4
+struct Transcript<'a, C: Spake2Params> {
+    id_a: &'a [u8],
+    id_b: &'a [u8],
+    p_a: Sec1Point<C>,
+    p_b: Sec1Point<C>,
+    k: Sec1Point<C>,
+    w: elliptic_curve::FieldBytes<C>,
+}
+
+impl<C: Spake2Params> Transcript<'_, C> {
+    /// Write the transcript, where each field is prefixed with its length as a little-endian
+    /// 64-bit integer.
+    fn write(&self, mut update: impl FnMut(&[u8])) {
+        for field in [
+            self.id_a,
+            self.id_b,
+            self.p_a.as_bytes(),
+            self.p_b.as_bytes(),
+            self.k.as_bytes(),
+            self.w.as_slice(),
+        ] {
+            update(&(field.len() as u64).to_le_bytes());
+            update(field);
+        }
+    }
+
+    /// Compute `MAC(key, TT)`.
+    fn mac<D: EagerHash>(&self, key: &[u8]) -> Result<Output<D>> {
+        let mut mac = Hmac::<D>::new_from_slice(key).map_err(|_| Error)?;
+        self.write(|bytes| mac.update(bytes));
+        let mut tag = Output::<D>::default();
+        tag.copy_from_slice(&mac.finalize().into_bytes());
+        Ok(tag)
+    }
+}
+
+/// The point `M`.
+fn m<C: Spake2Params>() -> ProjectivePoint<C> {
+    AffinePoint {
+        x: C::M.0,
+        y: C::M.1,
+        infinity: 0,
+    }
+    .into()
+}
+
+/// The point `N`.
+fn n<C: Spake2Params>() -> ProjectivePoint<C> {
+    AffinePoint {
+        x: C::N.0,
+        y: C::N.1,
+        infinity: 0,
+    }
+    .into()
+}
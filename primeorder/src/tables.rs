@@ -2,6 +2,8 @@
 
 #[cfg(feature = "basepoint-table")]
 mod basepoint;
+#[cfg(feature = "alloc")]
+mod fixed_base;
 mod lookup;
 mod radix16;
 
@@ -10,3 +12,70 @@ pub use radix16::{Radix16Decomposition, Radix16Digits};
 
 #[cfg(feature = "basepoint-table")]
 pub use basepoint::BasepointTable;
+#[cfg(feature = "alloc")]
+pub use fixed_base::FixedBaseTable;
+
+#[cfg(any(feature = "alloc", feature = "basepoint-table"))]
+use crate::{FieldExt, PrimeCurveParams, ProjectivePoint, Scalar};
+#[cfg(any(feature = "alloc", feature = "basepoint-table"))]
+use elliptic_curve::{
+    FieldBytesSize, array::typenum::Unsigned, ff::Field, ops::Double, rand_core::TryCryptoRng,
+};
+
+/// Multiply a point by a scalar using precomputed `tables`, where `tables[i]` contains the
+/// multiples of `256^i` times the point, selecting entries with the given `select` function.
+///
+/// If `lambda` is provided, the projective coordinates of the accumulators are multiplied by it,
+/// which randomizes all intermediate coordinates when `lambda` is random.
+#[cfg(any(feature = "alloc", feature = "basepoint-table"))]
+fn mul_fixed_base<C: PrimeCurveParams>(
+    tables: &[LookupTable<ProjectivePoint<C>>],
+    k: &Scalar<C>,
+    select: impl Fn(&LookupTable<ProjectivePoint<C>>, i8) -> ProjectivePoint<C>,
+    lambda: Option<&C::FieldElement>,
+) -> ProjectivePoint<C> {
+    let digits = Radix16Decomposition::<Radix16Digits<C>>::new(k);
+    let len = FieldBytesSize::<C>::USIZE;
+    let mut acc = select(&tables[len], digits[len * 2]);
+    let mut acc2 = ProjectivePoint::<C>::IDENTITY;
+
+    if let Some(lambda) = lambda {
+        acc = acc.rescale(lambda);
+        acc2 = acc2.rescale(lambda);
+    }
+
+    for i in (0..len).rev() {
+        acc2 += &select(&tables[i], digits[i * 2 + 1]);
+        acc += &select(&tables[i], digits[i * 2]);
+    }
+
+    // This is the price of halving the precomputed table size.
+    for _ in 0..4 {
+        acc2 = acc2.double();
+    }
+
+    acc + acc2
+}
+
+/// Constant-time variant of [`mul_fixed_base`] which splits the scalar into the random shares
+/// `k - r` and `r` and randomizes the projective coordinates, using randomness from `rng`.
+///
+/// The precomputed table entries are shared between calls and are not randomized: an entry
+/// selected by a digit is always the same public value, and only the accumulators it is added to
+/// have randomized coordinates. Re-randomizing the tables would cost as much as rebuilding them.
+#[cfg(any(feature = "alloc", feature = "basepoint-table"))]
+fn mul_fixed_base_blinded<C, R>(
+    tables: &[LookupTable<ProjectivePoint<C>>],
+    k: &Scalar<C>,
+    rng: &mut R,
+) -> Result<ProjectivePoint<C>, R::Error>
+where
+    C: PrimeCurveParams,
+    R: TryCryptoRng + ?Sized,
+{
+    let r = Scalar::<C>::try_random(rng)?;
+    let lambda = C::FieldElement::try_random_nonzero(rng)?;
+    let lhs = mul_fixed_base::<C>(tables, &(*k - r), LookupTable::select, Some(&lambda));
+    let rhs = mul_fixed_base::<C>(tables, &r, LookupTable::select, Some(&lambda));
+    Ok(lhs + rhs)
+}
@@ -6,10 +6,12 @@
 compile_error!("`basepoint-table` feature requires either `critical-section` or `std`");
 
 use super::LookupTable;
-use crate::{PrimeCurveParams, ProjectivePoint, Radix16Decomposition, Radix16Digits, Scalar};
+use crate::{PrimeCurveParams, ProjectivePoint, Scalar};
 use core::ops::Deref;
 use elliptic_curve::{
-    FieldBytesSize, array::typenum::Unsigned, ff::PrimeField, group::Group,
+    ff::PrimeField,
+    group::Group,
+    rand_core::{CryptoRng, TryCryptoRng},
     subtle::ConditionallySelectable,
 };
 
@@ -80,21 +82,36 @@ impl<C: PrimeCurveParams, const WINDOW_SIZE: usize>
     /// Multiply `Point::generator` by the given scalar in constant-time, using the precomputed
     /// basepoint table to accelerate the scalar multiplication.
     pub fn mul(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
-        let digits = Radix16Decomposition::<Radix16Digits<C>>::new(k);
-        let len = FieldBytesSize::<C>::USIZE;
-        let mut acc = self[len].select(digits[len * 2]);
-        let mut acc2 = ProjectivePoint::<C>::IDENTITY;
-        for i in (0..len).rev() {
-            acc2 += &self[i].select(digits[i * 2 + 1]);
-            acc += &self[i].select(digits[i * 2]);
-        }
+        super::mul_fixed_base::<C>(&**self, k, LookupTable::select, None)
+    }
 
-        // This is the price of halving the precomputed table size.
-        for _ in 0..4 {
-            acc2 = acc2.double();
-        }
+    /// Multiply `Point::generator` by the given scalar in constant-time, using randomness from
+    /// `rng` to blind the computation.
+    ///
+    /// The table entries are not randomized, only the coordinates of the accumulators. See
+    /// [`ProjectivePoint::mul_blinded`] for more information.
+    pub fn mul_blinded<R: CryptoRng + ?Sized>(
+        &self,
+        k: &Scalar<C>,
+        rng: &mut R,
+    ) -> ProjectivePoint<C> {
+        let Ok(point) = self.try_mul_blinded(k, rng);
+        point
+    }
 
-        acc + acc2
+    /// Multiply `Point::generator` by the given scalar in constant-time, using randomness from
+    /// `rng` to blind the computation.
+    ///
+    /// See [`ProjectivePoint::mul_blinded`] for more information.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_mul_blinded<R: TryCryptoRng + ?Sized>(
+        &self,
+        k: &Scalar<C>,
+        rng: &mut R,
+    ) -> Result<ProjectivePoint<C>, R::Error> {
+        super::mul_fixed_base_blinded::<C, R>(&**self, k, rng)
     }
 
     /// Multiply `Point::generator` by the given scalar in constant-time, using the precomputed
@@ -107,21 +124,7 @@ impl<C: PrimeCurveParams, const WINDOW_SIZE: usize>
     /// used with them.
     /// </div>
     pub fn mul_vartime(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
-        let digits = Radix16Decomposition::<Radix16Digits<C>>::new(k);
-        let len = FieldBytesSize::<C>::USIZE;
-        let mut acc = self[len].select_vartime(digits[len * 2]);
-        let mut acc2 = ProjectivePoint::<C>::IDENTITY;
-        for i in (0..len).rev() {
-            acc2 += &self[i].select_vartime(digits[i * 2 + 1]);
-            acc += &self[i].select_vartime(digits[i * 2]);
-        }
-
-        // This is the price of halving the precomputed table size.
-        for _ in 0..4 {
-            acc2 = acc2.double();
-        }
-
-        acc + acc2
+        super::mul_fixed_base::<C>(&**self, k, LookupTable::select_vartime, None)
     }
 }
 
@@ -0,0 +1,226 @@
+//! Precomputed tables for accelerating scalar multiplication of arbitrary fixed points.
+
+use super::LookupTable;
+use crate::{AffinePoint, PrimeCurveParams, ProjectivePoint, Scalar};
+use alloc::vec::Vec;
+use elliptic_curve::{
+    CurveGroup, Error, FieldBytesSize, Result,
+    array::typenum::Unsigned,
+    ops::Double,
+    rand_core::{CryptoRng, TryCryptoRng},
+    sec1::{Sec1Point, ToSec1Point},
+};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{Deserialize, Serialize, de, ser};
+
+/// Precomputed lookup table of multiples of an arbitrary point, computed at runtime.
+///
+/// This is the counterpart of [`BasepointTable`][`crate::BasepointTable`] for points other than
+/// the generator, e.g. a long-term public key or a Pedersen commitment base. Computing the table
+/// costs roughly as much as a few scalar multiplications, after which each scalar multiplication
+/// needs no doublings.
+///
+/// Tables can be serialized with [`FixedBaseTable::to_bytes`] to be cached, and restored with
+/// [`FixedBaseTable::from_bytes`].
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<C: PrimeCurveParams> {
+    tables: Vec<LookupTable<ProjectivePoint<C>>>,
+}
+
+impl<C: PrimeCurveParams> FixedBaseTable<C> {
+    /// Compute a new [`FixedBaseTable`] for the given point.
+    #[must_use]
+    pub fn new(point: &ProjectivePoint<C>) -> Self {
+        let mut point = *point;
+        let tables = (0..Self::num_tables())
+            .map(|_| {
+                let table = LookupTable::new(point);
+
+                // We are storing tables spaced by two radix steps,
+                // to decrease the size of the precomputed data.
+                for _ in 0..8 {
+                    point = point.double();
+                }
+
+                table
+            })
+            .collect();
+
+        Self { tables }
+    }
+
+    /// Multiply the point this table was computed for by the given scalar in constant-time.
+    #[must_use]
+    pub fn mul(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
+        super::mul_fixed_base::<C>(&self.tables, k, LookupTable::select, None)
+    }
+
+    /// Multiply the point this table was computed for by the given scalar in constant-time, using
+    /// randomness from `rng` to blind the computation.
+    ///
+    /// The table entries are not randomized, only the coordinates of the accumulators. See
+    /// [`ProjectivePoint::mul_blinded`] for more information.
+    #[must_use]
+    pub fn mul_blinded<R: CryptoRng + ?Sized>(
+        &self,
+        k: &Scalar<C>,
+        rng: &mut R,
+    ) -> ProjectivePoint<C> {
+        let Ok(point) = self.try_mul_blinded(k, rng);
+        point
+    }
+
+    /// Multiply the point this table was computed for by the given scalar in constant-time, using
+    /// randomness from `rng` to blind the computation.
+    ///
+    /// See [`ProjectivePoint::mul_blinded`] for more information.
+    ///
+    /// # Errors
+    /// Propagates `R::Error` on RNG failure.
+    pub fn try_mul_blinded<R: TryCryptoRng + ?Sized>(
+        &self,
+        k: &Scalar<C>,
+        rng: &mut R,
+    ) -> core::result::Result<ProjectivePoint<C>, R::Error> {
+        super::mul_fixed_base_blinded::<C, R>(&self.tables, k, rng)
+    }
+
+    /// Multiply the point this table was computed for by the given scalar in variable-time.
+    ///
+    /// <div class = "warning">
+    /// <b>Security Warning</b>
+    ///
+    /// Variable-time scalar multiplication can potentially leak secret values and should NOT be
+    /// used with them.
+    /// </div>
+    #[must_use]
+    pub fn mul_vartime(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
+        super::mul_fixed_base::<C>(&self.tables, k, LookupTable::select_vartime, None)
+    }
+
+    /// Serialize this table as the concatenation of the uncompressed SEC1 encodings of its
+    /// entries.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let projective = self
+            .tables
+            .iter()
+            .flat_map(LookupTable::points)
+            .copied()
+            .collect::<Vec<_>>();
+
+        let mut affine = vec![AffinePoint::<C>::IDENTITY; projective.len()];
+        ProjectivePoint::batch_normalize(&projective, &mut affine);
+
+        let mut bytes = Vec::with_capacity(affine.len() * Self::uncompressed_point_len());
+        for point in &affine {
+            bytes.extend_from_slice(point.to_sec1_point(false).as_bytes());
+        }
+
+        bytes
+    }
+
+    /// Deserialize a table serialized with [`FixedBaseTable::to_bytes`].
+    ///
+    /// <div class = "warning">
+    /// <b>Security Warning</b>
+    ///
+    /// Each entry is checked to be a valid point on the curve, but checking that the entries are
+    /// the expected multiples of each other would cost as much as recomputing the table. Only
+    /// deserialize tables from a trusted source, e.g. a cache whose integrity is protected.
+    /// </div>
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the encoding is malformed, has the wrong number of entries, or any
+    /// entry is not a point on the curve.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        let mut points = Vec::with_capacity(Self::num_entries());
+
+        while let Some(&tag) = bytes.first() {
+            // the identity is encoded as a single zero byte
+            let len = if tag == 0 {
+                1
+            } else {
+                Self::uncompressed_point_len()
+            };
+
+            if bytes.len() < len {
+                return Err(Error);
+            }
+
+            let (encoded, rest) = bytes.split_at(len);
+            let encoded = Sec1Point::<C>::from_bytes(encoded).map_err(|_| Error)?;
+            if encoded.is_compressed() {
+                return Err(Error);
+            }
+
+            points.push(ProjectivePoint::from(AffinePoint::try_from(encoded)?));
+            bytes = rest;
+        }
+
+        if points.len() != Self::num_entries() {
+            return Err(Error);
+        }
+
+        let tables = points
+            .chunks_exact(LookupTable::<ProjectivePoint<C>>::SIZE)
+            .map(|chunk| chunk.try_into().map(LookupTable::from_points))
+            .collect::<core::result::Result<_, _>>()
+            .map_err(|_| Error)?;
+
+        Ok(Self { tables })
+    }
+
+    /// Number of lookup tables: one per pair of radix-16 digits, plus one for the carry digit.
+    fn num_tables() -> usize {
+        FieldBytesSize::<C>::USIZE + 1
+    }
+
+    /// Total number of entries in all lookup tables.
+    fn num_entries() -> usize {
+        Self::num_tables() * LookupTable::<ProjectivePoint<C>>::SIZE
+    }
+
+    /// Length of an uncompressed SEC1 point encoding.
+    fn uncompressed_point_len() -> usize {
+        1 + 2 * FieldBytesSize::<C>::USIZE
+    }
+}
+
+impl<C: PrimeCurveParams> From<&ProjectivePoint<C>> for FixedBaseTable<C> {
+    fn from(point: &ProjectivePoint<C>) -> Self {
+        Self::new(point)
+    }
+}
+
+//
+// serde support
+//
+
+#[cfg(feature = "serde")]
+impl<C> Serialize for FixedBaseTable<C>
+where
+    C: PrimeCurveParams,
+{
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serdect::slice::serialize_hex_lower_or_bin(&self.to_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> Deserialize<'de> for FixedBaseTable<C>
+where
+    C: PrimeCurveParams,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+        Self::from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
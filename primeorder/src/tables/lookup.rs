@@ -37,6 +37,18 @@ where
         Self { points }
     }
 
+    /// Create a lookup table from previously computed entries `[p, 2p, 3p, ..., 8p]`.
+    #[cfg(feature = "alloc")]
+    pub(super) fn from_points(points: [Point; LUT_SIZE]) -> Self {
+        Self { points }
+    }
+
+    /// Entries of the lookup table: `[p, 2p, 3p, ..., 8p]`.
+    #[cfg(feature = "alloc")]
+    pub(super) fn points(&self) -> &[Point; LUT_SIZE] {
+        &self.points
+    }
+
     /// Given `-8 <= x <= 8`, returns `x * p` in constant time.
     #[allow(clippy::cast_sign_loss)]
     #[inline]
@@ -0,0 +1,276 @@
+//! Public key validation as described in NIST SP 800-56A Rev. 3 § 5.6.2.3.
+//!
+//! The conversions from SEC1 encodings to [`AffinePoint`] and [`PublicKey`] perform the same
+//! checks, but report any failure as an opaque [`elliptic_curve::Error`]. The functions in this
+//! module report which check failed using [`ValidationError`].
+//!
+//! [`PublicKeyValidation`] is implemented for the [`PublicKey`] of any curve whose affine point
+//! type implements [`AffinePointValidation`], which includes [`AffinePoint`].
+
+use crate::{AffinePoint, PrimeCurveParams};
+use core::fmt;
+use elliptic_curve::{
+    CurveArithmetic, Field, FieldBytes, PrimeField, PublicKey, Scalar,
+    group::Group,
+    point::{AffineCoordinates, DecompactPoint, DecompressPoint},
+    sec1::{self, ModulusSize, Sec1Point},
+    subtle::{Choice, ConstantTimeEq},
+};
+
+/// Public key validation errors.
+///
+/// Each variant corresponds to one of the checks of the full public key validation routine of
+/// NIST SP 800-56A Rev. 3 § 5.6.2.3.3, in the order in which they are performed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ValidationError {
+    /// Malformed encoding.
+    Encoding,
+
+    /// The point is the identity (step 1).
+    Identity,
+
+    /// A coordinate is not in the range `[0, p - 1]` (step 2).
+    Range,
+
+    /// The point is not on the curve (step 3).
+    NotOnCurve,
+
+    /// The point is not in the subgroup of prime order `n` (step 4).
+    Order,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Encoding => "malformed point encoding",
+            Self::Identity => "point is the identity",
+            Self::Range => "coordinate out of range",
+            Self::NotOnCurve => "point is not on the curve",
+            Self::Order => "point is not in the prime order subgroup",
+        })
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+impl From<ValidationError> for elliptic_curve::Error {
+    fn from(_: ValidationError) -> Self {
+        elliptic_curve::Error
+    }
+}
+
+impl<C> AffinePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Perform the partial public key validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.4
+    /// on this point.
+    ///
+    /// Checks that the point is not the identity and is on the curve. The coordinates of an
+    /// [`AffinePoint`] are always in range.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::Identity`] or [`ValidationError::NotOnCurve`] if the respective
+    /// check fails.
+    pub fn validate_partial(&self) -> Result<(), ValidationError> {
+        AffinePointValidation::validate_partial(self)
+    }
+
+    /// Perform the full public key validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.3 on
+    /// this point.
+    ///
+    /// In addition to the checks of [`AffinePoint::validate_partial`], checks that `[n] Q` is
+    /// the identity, where `n` is the order of the group. As the curve has prime order, this
+    /// check can only fail for points which aren't on the curve, but it is performed anyway as
+    /// required by the standard.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    pub fn validate_full(&self) -> Result<(), ValidationError> {
+        AffinePointValidation::validate_full(self)
+    }
+
+    /// Decode a SEC1 encoded point, performing the partial public key validation routine of
+    /// NIST SP 800-56A Rev. 3 § 5.6.2.3.4.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    pub fn from_sec1_point_partial(point: &Sec1Point<C>) -> Result<Self, ValidationError> {
+        AffinePointValidation::from_sec1_point_partial(point)
+    }
+
+    /// Decode a SEC1 encoded point, performing the full public key validation routine of
+    /// NIST SP 800-56A Rev. 3 § 5.6.2.3.3.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    pub fn from_sec1_point_full(point: &Sec1Point<C>) -> Result<Self, ValidationError> {
+        let point = Self::from_sec1_point_partial(point)?;
+        point.validate_full()?;
+        Ok(point)
+    }
+}
+
+/// NIST SP 800-56A Rev. 3 § 5.6.2.3 public key validation for affine points.
+///
+/// Curve crates with their own affine point type can implement this trait to get a
+/// [`PublicKeyValidation`] impl for their [`PublicKey`]. Only the range and curve equation
+/// checks are curve specific: the validation routines themselves are provided.
+pub trait AffinePointValidation<C: CurveArithmetic<FieldBytesSize: ModulusSize>>:
+    Copy
+    + Into<C::ProjectivePoint>
+    + AffineCoordinates<FieldRepr = FieldBytes<C>>
+    + DecompactPoint<C>
+    + DecompressPoint<C>
+{
+    /// Check that the encoded coordinate is in the range `[0, p - 1]`, i.e. that it is the
+    /// canonical encoding of a field element.
+    fn is_in_range(coordinate: &FieldBytes<C>) -> Choice;
+
+    /// Check that this point satisfies the curve equation.
+    ///
+    /// The result is unspecified for the identity.
+    fn is_on_curve(&self) -> Choice;
+
+    /// Perform the partial public key validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.4
+    /// on this point.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    fn validate_partial(&self) -> Result<(), ValidationError> {
+        let point: C::ProjectivePoint = (*self).into();
+        if point.is_identity().into() {
+            return Err(ValidationError::Identity);
+        }
+
+        if !bool::from(self.is_on_curve()) {
+            return Err(ValidationError::NotOnCurve);
+        }
+
+        Ok(())
+    }
+
+    /// Perform the full public key validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.3 on
+    /// this point.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    fn validate_full(&self) -> Result<(), ValidationError> {
+        self.validate_partial()?;
+
+        // [n] Q = [n - 1] Q + Q
+        let point: C::ProjectivePoint = (*self).into();
+        if !bool::from((point * -Scalar::<C>::ONE + point).is_identity()) {
+            return Err(ValidationError::Order);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a SEC1 encoded point, performing the partial public key validation routine of
+    /// NIST SP 800-56A Rev. 3 § 5.6.2.3.4.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    fn from_sec1_point_partial(point: &Sec1Point<C>) -> Result<Self, ValidationError> {
+        let point = match point.coordinates() {
+            sec1::Coordinates::Identity => return Err(ValidationError::Identity),
+            sec1::Coordinates::Compact { x } => {
+                check_range::<C, Self>(x)?;
+                Self::decompact(x)
+            }
+            sec1::Coordinates::Compressed { x, y_is_odd } => {
+                check_range::<C, Self>(x)?;
+                Self::decompress(x, Choice::from(u8::from(y_is_odd)))
+            }
+            sec1::Coordinates::Uncompressed { x, y } => {
+                check_range::<C, Self>(x)?;
+                check_range::<C, Self>(y)?;
+                Self::from_coordinates(x, y)
+            }
+        };
+
+        point.into_option().ok_or(ValidationError::NotOnCurve)
+    }
+}
+
+impl<C> AffinePointValidation<C> for AffinePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    fn is_in_range(coordinate: &FieldBytes<C>) -> Choice {
+        C::FieldElement::from_repr(*coordinate).is_some()
+    }
+
+    fn is_on_curve(&self) -> Choice {
+        let rhs = (self.x.square() + C::EQUATION_A) * self.x + C::EQUATION_B;
+        self.y.square().ct_eq(&rhs)
+    }
+}
+
+/// NIST SP 800-56A Rev. 3 § 5.6.2.3 public key validation for [`PublicKey`].
+pub trait PublicKeyValidation: Sized {
+    /// Perform the partial public key validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.4.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    fn validate_partial(&self) -> Result<(), ValidationError>;
+
+    /// Perform the full public key validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.3.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    fn validate_full(&self) -> Result<(), ValidationError>;
+
+    /// Decode a public key from SEC1 encoded bytes, performing the partial public key
+    /// validation routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.4.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    fn from_sec1_bytes_partial(bytes: &[u8]) -> Result<Self, ValidationError>;
+
+    /// Decode a public key from SEC1 encoded bytes, performing the full public key validation
+    /// routine of NIST SP 800-56A Rev. 3 § 5.6.2.3.3.
+    ///
+    /// # Errors
+    /// Returns the [`ValidationError`] corresponding to the first check that fails.
+    fn from_sec1_bytes_full(bytes: &[u8]) -> Result<Self, ValidationError>;
+}
+
+impl<C> PublicKeyValidation for PublicKey<C>
+where
+    C: CurveArithmetic<FieldBytesSize: ModulusSize, AffinePoint: AffinePointValidation<C>>,
+{
+    fn validate_partial(&self) -> Result<(), ValidationError> {
+        AffinePointValidation::validate_partial(self.as_affine())
+    }
+
+    fn validate_full(&self) -> Result<(), ValidationError> {
+        AffinePointValidation::validate_full(self.as_affine())
+    }
+
+    fn from_sec1_bytes_partial(bytes: &[u8]) -> Result<Self, ValidationError> {
+        let point = Sec1Point::<C>::from_bytes(bytes).map_err(|_| ValidationError::Encoding)?;
+        let point = C::AffinePoint::from_sec1_point_partial(&point)?;
+        Self::from_affine(point).map_err(|_| ValidationError::Identity)
+    }
+
+    fn from_sec1_bytes_full(bytes: &[u8]) -> Result<Self, ValidationError> {
+        let public_key = Self::from_sec1_bytes_partial(bytes)?;
+        public_key.validate_full()?;
+        Ok(public_key)
+    }
+}
+
+/// Check that the encoded coordinate is in the range `[0, p - 1]`.
+fn check_range<C, P>(coordinate: &FieldBytes<C>) -> Result<(), ValidationError>
+where
+    C: CurveArithmetic<FieldBytesSize: ModulusSize>,
+    P: AffinePointValidation<C>,
+{
+    if P::is_in_range(coordinate).into() {
+        Ok(())
+    } else {
+        Err(ValidationError::Range)
+    }
+}
@@ -0,0 +1,420 @@
+//! Elliptic Curve Verifiable Random Function (ECVRF) as described in [RFC 9381].
+//!
+//! A VRF is the public-key version of a keyed hash: only the holder of the [`ProvingKey`] can
+//! compute the output `beta` for an input `alpha`, but anyone can check with the
+//! [`VerifyingKey`] and a [`Proof`] that `beta` is the correct output for `alpha`.
+//!
+//! This module implements the construction generically over the curve. Ciphersuites are defined
+//! in the crates of the respective curves by implementing [`Suite`].
+//!
+//! [RFC 9381]: https://www.rfc-editor.org/rfc/rfc9381
+
+use core::{fmt, marker::PhantomData};
+use elliptic_curve::{
+    AffinePoint, Curve, CurveArithmetic, Error, FieldBytes, FieldBytesSize, Group, NonZeroScalar,
+    ProjectivePoint, PublicKey, Result, Scalar, SecretKey,
+    array::{Array, ArraySize, typenum::Unsigned},
+    ff::PrimeField,
+    ops::{LinearCombination, Reduce},
+    sec1::{FromSec1Point, ModulusSize, Sec1Point, ToSec1Point},
+    zeroize::Zeroize,
+};
+use rfc6979::hmac::digest::{Digest, Output, block_api::BlockSizeUser};
+
+/// Size of the challenge (`cLen`), for all supported ciphersuites.
+const C_LEN: usize = 16;
+
+/// Version of the ECVRF construction used by a ciphersuite.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Version {
+    /// The construction of [RFC 9381].
+    ///
+    /// [RFC 9381]: https://www.rfc-editor.org/rfc/rfc9381
+    Rfc9381,
+
+    /// The construction of [draft-irtf-cfrg-vrf-05], which is still used by deployed VRFs. Unlike
+    /// RFC 9381, the challenge doesn't hash the public key, and the inputs of the hash functions
+    /// don't end with a zero byte.
+    ///
+    /// [draft-irtf-cfrg-vrf-05]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-vrf-05
+    Draft05,
+}
+
+/// ECVRF ciphersuite.
+pub trait Suite: Clone + Copy + fmt::Debug + Default + Eq + PartialEq {
+    /// Curve of the ciphersuite.
+    type Curve: CurveArithmetic<AffinePoint: FromSec1Point<Self::Curve> + ToSec1Point<Self::Curve>>
+        + Curve<FieldBytesSize: ModulusSize>;
+
+    /// Hash function of the ciphersuite.
+    type Hash: BlockSizeUser + Digest;
+
+    /// Size of an encoded [`Proof`] in bytes: the size of a compressed SEC1 point (`ptLen`), plus
+    /// 16 bytes for the challenge (`cLen`), plus the size of a scalar (`qLen`).
+    type ProofSize: ArraySize;
+
+    /// `suite_string` which identifies the ciphersuite.
+    const SUITE_STRING: u8;
+
+    /// Version of the construction.
+    const VERSION: Version = Version::Rfc9381;
+
+    /// `ECVRF_encode_to_curve` function of the ciphersuite, which hashes `alpha` to the curve.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if `alpha` can't be encoded to the curve.
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> Result<ProjectivePoint<Self::Curve>>;
+}
+
+/// ECVRF secret key, which computes proofs.
+#[derive(Clone)]
+pub struct ProvingKey<S: Suite> {
+    secret: NonZeroScalar<S::Curve>,
+    verifying_key: VerifyingKey<S>,
+}
+
+impl<S: Suite> ProvingKey<S> {
+    /// Create a proving key from a non-zero scalar.
+    #[must_use]
+    pub fn from_nonzero_scalar(secret: NonZeroScalar<S::Curve>) -> Self {
+        let point = (ProjectivePoint::<S::Curve>::generator() * *secret).into();
+
+        Self {
+            secret,
+            verifying_key: VerifyingKey {
+                point,
+                suite: PhantomData,
+            },
+        }
+    }
+
+    /// Parse a proving key from an encoded scalar.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the scalar is zero or out of range.
+    pub fn from_bytes(bytes: &FieldBytes<S::Curve>) -> Result<Self> {
+        NonZeroScalar::from_repr(*bytes)
+            .into_option()
+            .map(Self::from_nonzero_scalar)
+            .ok_or(Error)
+    }
+
+    /// Serialize this proving key as an encoded scalar.
+    #[must_use]
+    pub fn to_bytes(&self) -> FieldBytes<S::Curve> {
+        self.secret.to_repr()
+    }
+
+    /// Verifying key associated with this proving key.
+    #[must_use]
+    pub fn verifying_key(&self) -> &VerifyingKey<S> {
+        &self.verifying_key
+    }
+
+    /// Compute the proof for the input `alpha`, as described in [RFC 9381 § 5.1].
+    ///
+    /// The proof is deterministic, and its output `beta` can be computed with
+    /// [`Proof::proof_to_hash`].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if `alpha` can't be encoded to the curve.
+    ///
+    /// [RFC 9381 § 5.1]: https://www.rfc-editor.org/rfc/rfc9381#section-5.1
+    pub fn prove(&self, alpha: &[u8]) -> Result<Proof<S>> {
+        let y = self.verifying_key.point;
+        let h: AffinePoint<S::Curve> =
+            S::encode_to_curve(y.to_sec1_point(true).as_bytes(), alpha)?.into();
+        let gamma = (ProjectivePoint::<S::Curve>::from(h) * *self.secret).into();
+
+        let mut k = generate_nonce::<S>(&self.secret, h.to_sec1_point(true).as_bytes());
+        let k_b = (ProjectivePoint::<S::Curve>::generator() * k).into();
+        let k_h = (ProjectivePoint::<S::Curve>::from(h) * k).into();
+        let c = generate_challenge::<S>(&y, &[h, gamma, k_b, k_h]);
+        let s = k + c * *self.secret;
+        k.zeroize();
+
+        Ok(Proof {
+            gamma,
+            c,
+            s,
+            suite: PhantomData,
+        })
+    }
+}
+
+impl<S: Suite> From<NonZeroScalar<S::Curve>> for ProvingKey<S> {
+    fn from(secret: NonZeroScalar<S::Curve>) -> Self {
+        Self::from_nonzero_scalar(secret)
+    }
+}
+
+impl<S: Suite> From<&SecretKey<S::Curve>> for ProvingKey<S> {
+    fn from(secret_key: &SecretKey<S::Curve>) -> Self {
+        Self::from_nonzero_scalar(secret_key.to_nonzero_scalar())
+    }
+}
+
+impl<S: Suite> fmt::Debug for ProvingKey<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProvingKey")
+            .field("verifying_key", &self.verifying_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Suite> Drop for ProvingKey<S> {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// ECVRF public key, which verifies proofs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VerifyingKey<S: Suite> {
+    point: AffinePoint<S::Curve>,
+    suite: PhantomData<S>,
+}
+
+impl<S: Suite> VerifyingKey<S> {
+    /// Parse a verifying key from a SEC1-encoded point.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the bytes aren't a valid encoding of a point on the curve, or it is
+    /// the identity.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        PublicKey::from_sec1_bytes(bytes).map(|public_key| Self::from(&public_key))
+    }
+
+    /// Borrow the inner [`AffinePoint`] of this verifying key.
+    #[must_use]
+    pub fn as_affine(&self) -> &AffinePoint<S::Curve> {
+        &self.point
+    }
+
+    /// Serialize this verifying key as a compressed SEC1 point.
+    #[must_use]
+    pub fn to_sec1_point(&self) -> Sec1Point<S::Curve> {
+        self.point.to_sec1_point(true)
+    }
+
+    /// Verify the proof for the input `alpha` and return the output `beta`, as described in
+    /// [RFC 9381 § 5.3].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the proof is invalid.
+    ///
+    /// [RFC 9381 § 5.3]: https://www.rfc-editor.org/rfc/rfc9381#section-5.3
+    pub fn verify(&self, alpha: &[u8], proof: &Proof<S>) -> Result<Output<S::Hash>> {
+        let y = self.point;
+        let h: AffinePoint<S::Curve> =
+            S::encode_to_curve(y.to_sec1_point(true).as_bytes(), alpha)?.into();
+
+        // U = s * B - c * Y, V = s * H - c * Gamma
+        let u = ProjectivePoint::<S::Curve>::lincomb_vartime(&[
+            (ProjectivePoint::<S::Curve>::generator(), proof.s),
+            (y.into(), -proof.c),
+        ]);
+        let v = ProjectivePoint::<S::Curve>::lincomb_vartime(&[
+            (h.into(), proof.s),
+            (proof.gamma.into(), -proof.c),
+        ]);
+
+        let c = generate_challenge::<S>(&y, &[h, proof.gamma, u.into(), v.into()]);
+        if c == proof.c {
+            Ok(proof.proof_to_hash())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl<S: Suite> From<&PublicKey<S::Curve>> for VerifyingKey<S> {
+    fn from(public_key: &PublicKey<S::Curve>) -> Self {
+        Self {
+            point: *public_key.as_affine(),
+            suite: PhantomData,
+        }
+    }
+}
+
+/// ECVRF proof `pi`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Proof<S: Suite> {
+    gamma: AffinePoint<S::Curve>,
+    c: Scalar<S::Curve>,
+    s: Scalar<S::Curve>,
+    suite: PhantomData<S>,
+}
+
+impl<S: Suite> Proof<S> {
+    /// Parse a proof from its encoding `Gamma || c || s`, as described in [RFC 9381 § 5.4.4].
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the proof has the wrong length, `Gamma` isn't a valid compressed
+    /// point, or `s` is out of range.
+    ///
+    /// [RFC 9381 § 5.4.4]: https://www.rfc-editor.org/rfc/rfc9381#section-5.4.4
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != S::ProofSize::USIZE {
+            return Err(Error);
+        }
+        let (gamma, rest) = bytes.split_at(pt_len::<S>());
+        let (c, s) = rest.split_at(C_LEN);
+
+        let gamma = Sec1Point::<S::Curve>::from_bytes(gamma).map_err(|_| Error)?;
+        if !gamma.is_compressed() {
+            return Err(Error);
+        }
+        let gamma = AffinePoint::<S::Curve>::from_sec1_point(&gamma)
+            .into_option()
+            .ok_or(Error)?;
+
+        let mut c_bytes = FieldBytes::<S::Curve>::default();
+        c_bytes[q_len::<S>() - C_LEN..].copy_from_slice(c);
+        let c = Scalar::<S::Curve>::from_repr(c_bytes)
+            .into_option()
+            .ok_or(Error)?;
+        let s = FieldBytes::<S::Curve>::try_from(s).map_err(|_| Error)?;
+        let s = Scalar::<S::Curve>::from_repr(s)
+            .into_option()
+            .ok_or(Error)?;
+
+        Ok(Self {
+            gamma,
+            c,
+            s,
+            suite: PhantomData,
+        })
+    }
+
+    /// Serialize this proof as `Gamma || c || s`.
+    #[must_use]
+    pub fn to_bytes(&self) -> Array<u8, S::ProofSize> {
+        let mut bytes = Array::<u8, S::ProofSize>::default();
+        let (gamma, rest) = bytes.split_at_mut(pt_len::<S>());
+        let (c, s) = rest.split_at_mut(C_LEN);
+
+        gamma.copy_from_slice(self.gamma.to_sec1_point(true).as_bytes());
+        c.copy_from_slice(&self.c.to_repr()[q_len::<S>() - C_LEN..]);
+        s.copy_from_slice(&self.s.to_repr());
+        bytes
+    }
+
+    /// Compute the VRF output `beta` from this proof, as described in [RFC 9381 § 5.2].
+    ///
+    /// <div class = "warning">
+    /// <b>Security Warning</b>
+    ///
+    /// This doesn't verify the proof: `beta` must not be trusted unless it was returned by
+    /// [`VerifyingKey::verify`].
+    /// </div>
+    ///
+    /// [RFC 9381 § 5.2]: https://www.rfc-editor.org/rfc/rfc9381#section-5.2
+    #[must_use]
+    pub fn proof_to_hash(&self) -> Output<S::Hash> {
+        // The supported curves have a cofactor of one, so `cofactor * Gamma = Gamma`
+        let mut hash = S::Hash::new()
+            .chain_update([S::SUITE_STRING, 0x03])
+            .chain_update(self.gamma.to_sec1_point(true).as_bytes());
+        if S::VERSION == Version::Rfc9381 {
+            hash.update([0x00]);
+        }
+        hash.finalize()
+    }
+}
+
+/// `ECVRF_encode_to_curve_try_and_increment` as described in [RFC 9381 § 5.4.1.1], for
+/// ciphersuites whose hash function outputs as many bytes as a field element.
+///
+/// # Errors
+/// Returns [`Error`] in the negligibly unlikely case that none of the 256 candidates is the
+/// x-coordinate of a point on the curve.
+///
+/// [RFC 9381 § 5.4.1.1]: https://www.rfc-editor.org/rfc/rfc9381#section-5.4.1.1
+pub fn encode_to_curve_tai<S: Suite>(
+    salt: &[u8],
+    alpha: &[u8],
+) -> Result<ProjectivePoint<S::Curve>> {
+    for ctr in 0..=u8::MAX {
+        let mut hash = S::Hash::new()
+            .chain_update([S::SUITE_STRING, 0x01])
+            .chain_update(salt)
+            .chain_update(alpha)
+            .chain_update([ctr]);
+        if S::VERSION == Version::Rfc9381 {
+            hash.update([0x00]);
+        }
+
+        // interpret_hash_value_as_a_point(hash_string) = string_to_point(0x02 || hash_string)
+        let mut bytes =
+            Array::<u8, <FieldBytesSize<S::Curve> as ModulusSize>::CompressedPointSize>::default();
+        bytes[0] = 0x02;
+        bytes[1..].copy_from_slice(&hash.finalize());
+        let point = Sec1Point::<S::Curve>::from_bytes(bytes)
+            .ok()
+            .and_then(|point| AffinePoint::<S::Curve>::from_sec1_point(&point).into_option());
+
+        if let Some(point) = point {
+            return Ok(point.into());
+        }
+    }
+
+    Err(Error)
+}
+
+/// Size of an encoded point (`ptLen`), which is a compressed SEC1 point.
+fn pt_len<S: Suite>() -> usize {
+    <FieldBytesSize<S::Curve> as ModulusSize>::CompressedPointSize::USIZE
+}
+
+/// Size of an encoded scalar (`qLen`).
+fn q_len<S: Suite>() -> usize {
+    FieldBytesSize::<S::Curve>::USIZE
+}
+
+/// `ECVRF_nonce_generation` using [RFC 6979 § 3.2] as described in [RFC 9381 § 5.4.2.1].
+///
+/// [RFC 6979 § 3.2]: https://www.rfc-editor.org/rfc/rfc6979#section-3.2
+/// [RFC 9381 § 5.4.2.1]: https://www.rfc-editor.org/rfc/rfc9381#section-5.4.2.1
+fn generate_nonce<S: Suite>(secret: &NonZeroScalar<S::Curve>, h_string: &[u8]) -> Scalar<S::Curve> {
+    let order = S::Curve::ORDER;
+    let mut kgen = rfc6979::KGenerator::<S::Hash, <S::Curve as Curve>::Uint>::new(
+        &secret.to_repr(),
+        &S::Hash::digest(h_string),
+        &[],
+        &order,
+    );
+
+    loop {
+        let mut k = FieldBytes::<S::Curve>::default();
+        kgen.fill_next_k(&mut k);
+
+        if let Some(k) = NonZeroScalar::<S::Curve>::from_repr(k).into_option() {
+            return *k;
+        }
+    }
+}
+
+/// `ECVRF_challenge_generation` as described in [RFC 9381 § 5.4.3].
+///
+/// [RFC 9381 § 5.4.3]: https://www.rfc-editor.org/rfc/rfc9381#section-5.4.3
+fn generate_challenge<S: Suite>(
+    y: &AffinePoint<S::Curve>,
+    points: &[AffinePoint<S::Curve>; 4],
+) -> Scalar<S::Curve> {
+    let mut hash = S::Hash::new_with_prefix([S::SUITE_STRING, 0x02]);
+    if S::VERSION == Version::Rfc9381 {
+        hash.update(y.to_sec1_point(true).as_bytes());
+    }
+    for point in points {
+        hash.update(point.to_sec1_point(true).as_bytes());
+    }
+    if S::VERSION == Version::Rfc9381 {
+        hash.update([0x00]);
+    }
+
+    // c = string_to_int(truncate(c_string, cLen)), which is always smaller than the order
+    let mut c = FieldBytes::<S::Curve>::default();
+    c[q_len::<S>() - C_LEN..].copy_from_slice(&hash.finalize()[..C_LEN]);
+    <Scalar<S::Curve> as Reduce<FieldBytes<S::Curve>>>::reduce(&c)
+}
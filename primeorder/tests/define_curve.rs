@@ -0,0 +1,128 @@
+//! Tests for curves defined with `define_curve!`, using NIST P-192 as an example.
+
+#![cfg(feature = "dev")]
+
+use hex_literal::hex;
+use primeorder::elliptic_curve::{Curve, bigint::U192, group::GroupEncoding};
+
+/// Repeated addition of the generator.
+///
+/// Test vectors for P-192 from: <http://point-at-infinity.org/ecc/nisttv>
+const ADD_TEST_VECTORS: &[([u8; 24], [u8; 24])] = &[
+    (
+        hex!("188DA80EB03090F67CBF20EB43A18800F4FF0AFD82FF1012"),
+        hex!("07192B95FFC8DA78631011ED6B24CDD573F977A11E794811"),
+    ),
+    (
+        hex!("DAFEBF5828783F2AD35534631588A3F629A70FB16982A888"),
+        hex!("DD6BDA0D993DA0FA46B27BBC141B868F59331AFA5C7E93AB"),
+    ),
+    (
+        hex!("76E32A2557599E6EDCD283201FB2B9AADFD0D359CBB263DA"),
+        hex!("782C37E372BA4520AA62E0FED121D49EF3B543660CFD05FD"),
+    ),
+    (
+        hex!("35433907297CC378B0015703374729D7A4FE46647084E4BA"),
+        hex!("A2649984F2135C301EA3ACB0776CD4F125389B311DB3BE32"),
+    ),
+];
+
+/// Scalar multiplication of the generator.
+const MUL_TEST_VECTORS: &[([u8; 24], [u8; 24], [u8; 24])] = &[
+    (
+        hex!("00000000000000000000000000000000018ebbb95eed0e13"),
+        hex!("81E6E0F14C9302C8A8DCA8A038B73165E9687D0490CD9F85"),
+        hex!("F58067119EED8579388C4281DC645A27DB7764750E812477"),
+    ),
+    (
+        hex!("000000000000000000159d893d4cdd747246cdca43590e13"),
+        hex!("B357B10AC985C891B29FB37DA56661CCCF50CEC21128D4F6"),
+        hex!("BA20DC2FA1CC228D3C2D8B538C2177C2921884C6B7F0D96F"),
+    ),
+];
+
+primeorder::define_curve! {
+    name: NistP192,
+    doc: "NIST P-192 elliptic curve.",
+    uint: U192,
+    modulus: "fffffffffffffffffffffffffffffffeffffffffffffffff",
+    field_multiplicative_generator: 11,
+    order: "ffffffffffffffffffffffff99def836146bc9b1b4d22831",
+    scalar_multiplicative_generator: 3,
+    equation_a: "fffffffffffffffffffffffffffffffefffffffffffffffc",
+    equation_b: "64210519e59c80e70fa7e9ab72243049feb8deecc146b9b1",
+    generator: (
+        "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+        "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",
+    ),
+    point_arithmetic: EquationAIsMinusThree,
+    compress_points: false,
+    oid: #[cfg(feature = "pkcs8")] "1.2.840.10045.3.1.1",
+    ecdsa: ecdsa { normalize_s: false, digest: sha2::Sha256 },
+    test_vectors: (ADD_TEST_VECTORS, MUL_TEST_VECTORS),
+}
+
+#[test]
+fn order() {
+    assert_eq!(
+        (Scalar::ZERO - Scalar::ONE).to_canonical(),
+        NistP192::ORDER.as_ref().wrapping_sub(&U192::ONE)
+    );
+}
+
+#[test]
+fn compressed_point() {
+    let bytes: CompressedPoint = ProjectivePoint::GENERATOR.to_bytes();
+    assert_eq!(bytes.len(), 1 + 24);
+    assert_eq!(
+        PublicKey::from_sec1_bytes(&bytes).unwrap().to_projective(),
+        ProjectivePoint::GENERATOR
+    );
+}
+
+#[test]
+fn ecdsa_sign_verify() {
+    use ecdsa::signature::{Signer, Verifier};
+
+    let secret_key = SecretKey::from_bytes(&FieldBytes::from([1; 24])).unwrap();
+    let signing_key = ecdsa::SigningKey::<NistP192>::from(&secret_key);
+    let signature: ecdsa::Signature<NistP192> = signing_key.sign(b"example message");
+    signing_key
+        .verifying_key()
+        .verify(b"example message", &signature)
+        .unwrap();
+    assert!(
+        signing_key
+            .verifying_key()
+            .verify(b"other message", &signature)
+            .is_err()
+    );
+}
+
+#[cfg(all(feature = "pkcs8", feature = "alloc"))]
+#[test]
+fn pkcs8_round_trip() {
+    use primeorder::elliptic_curve::pkcs8::{
+        AssociatedOid, DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey,
+        ObjectIdentifier,
+    };
+
+    assert_eq!(
+        NistP192::OID,
+        ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.1")
+    );
+
+    let secret_key = SecretKey::from_bytes(&FieldBytes::from([1; 24])).unwrap();
+    let der = secret_key.to_pkcs8_der().unwrap();
+    assert_eq!(
+        SecretKey::from_pkcs8_der(der.as_bytes()).unwrap(),
+        secret_key
+    );
+
+    let public_key = secret_key.public_key();
+    let der = public_key.to_public_key_der().unwrap();
+    assert_eq!(
+        PublicKey::from_public_key_der(der.as_bytes()).unwrap(),
+        public_key
+    );
+}
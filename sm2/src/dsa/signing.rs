@@ -20,12 +20,13 @@ use crate::{
 };
 use core::fmt::{self, Debug};
 use elliptic_curve::{
-    Curve, Group, PrimeField,
+    Curve, PrimeField,
     array::typenum::Unsigned,
     ops::Reduce,
     point::AffineCoordinates,
     subtle::{Choice, ConstantTimeEq},
 };
+use primeorder::BlindingRng;
 use signature::{
     Error, KeypairRef, MultipartSigner, RandomizedMultipartSigner, RandomizedSigner, Result,
     Signer,
@@ -239,7 +240,14 @@ fn sign_prehash_rfc6979(secret_scalar: &Scalar, prehash: &[u8], data: &[u8]) ->
         .ok_or_else(Error::new)?;
 
     // A4: calculate the elliptic curve point (x1, y1)=[k]G
-    let R = ProjectivePoint::mul_by_generator(&k).to_affine();
+    //
+    // The scalar multiplication is blinded with further output of the `k` generator, which is
+    // unpredictable without the secret key.
+    let mut rng = BlindingRng::new(|block: &mut FieldBytes| {
+        kgen.fill_next_k(block);
+    });
+    let Ok(R) = ProjectivePoint::try_mul_by_generator_blinded(&k, &mut rng);
+    let R = R.to_affine();
 
     // A5: calculate r=(e+x1) modn, return to A3 if r=0 or r+k=n
     let r = e + Scalar::reduce(&R.x());
@@ -56,6 +56,8 @@ extern crate alloc;
 
 #[cfg(feature = "dsa")]
 pub mod dsa;
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
 #[cfg(feature = "pke")]
 pub mod pke;
 
@@ -68,6 +70,8 @@ pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "arithmetic")]
 pub use arithmetic::{AffinePoint, ProjectivePoint, scalar::Scalar};
+#[cfg(feature = "arithmetic")]
+pub use primeorder::{PublicKeyValidation, ValidationError};
 
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
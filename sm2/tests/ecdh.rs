@@ -0,0 +1,89 @@
+//! ECDH tests.
+
+#![cfg(feature = "ecdh")]
+
+use elliptic_curve::{ops::ReduceNonZero, point::DecompressPoint, sec1::ToSec1Point};
+use hex_literal::hex;
+use proptest::prelude::*;
+use sm2::{
+    AffinePoint, FieldBytes, NonZeroScalar, PublicKey, Scalar, SecretKey,
+    ecdh::{diffie_hellman, diffie_hellman_x_only},
+};
+
+const ALICE_SECRET: [u8; 32] =
+    hex!("f3d4eb514abb61d5b66764fec503672dd111ef2cf949018c0851ce289866f62b");
+const ALICE_PUBLIC: [u8; 65] = hex!(
+    "041055608fa573a9bb8716e4182658a9c08f6b2b06794a195955ce6cf39dde732e"
+    "ec131c3d57083be5cb46b197b55ae08f8a4d2abebad18d62d834770ec54cd597"
+);
+const BOB_SECRET: [u8; 32] =
+    hex!("53158bf7dd1c3a125ebe437c0f1113a55e928c93a0a10a6c4f9a02c12570c048");
+const BOB_PUBLIC: [u8; 65] = hex!(
+    "04d25f471cf22a1c37412a8eeb4829434cbf3f156cd9bdf92b3b67f7aa30a6bb9a"
+    "b38d9f91be114db9d848a8db815ad98b5813621ae1c3cbc1b008fe000ad28a0f"
+);
+
+// Computed with OpenSSL's `EC_POINT_mul` on the `SM2` curve
+const SHARED_SECRET: [u8; 32] =
+    hex!("c862c9326974d577d3a70a7d1e17f0c7c622712103402d0005e513414d6ae904");
+
+prop_compose! {
+    fn nonzero_scalar()(bytes in any::<[u8; 32]>()) -> NonZeroScalar {
+        NonZeroScalar::reduce_nonzero(&FieldBytes::from(bytes))
+    }
+}
+
+proptest! {
+    #[test]
+    fn diffie_hellman_matches_projective(a in nonzero_scalar(), b in nonzero_scalar()) {
+        let public = PublicKey::from_secret_scalar(&b);
+        let expected = elliptic_curve::ecdh::diffie_hellman(a, public.as_affine());
+        let shared = diffie_hellman(a, public.as_affine());
+        prop_assert_eq!(expected.raw_secret_bytes(), shared.raw_secret_bytes());
+    }
+}
+
+#[test]
+fn known_answer() {
+    for (secret, public) in [(ALICE_SECRET, BOB_PUBLIC), (BOB_SECRET, ALICE_PUBLIC)] {
+        let secret = SecretKey::from_bytes(&secret.into()).unwrap();
+        let public = PublicKey::from_sec1_bytes(&public).unwrap();
+
+        let shared = diffie_hellman(secret.to_nonzero_scalar(), public.as_affine());
+        assert_eq!(shared.raw_secret_bytes().as_slice(), &SHARED_SECRET);
+
+        let compressed = public.as_affine().to_sec1_point(true);
+        let shared = diffie_hellman_x_only(secret.to_nonzero_scalar(), compressed.as_bytes());
+        assert_eq!(
+            shared.unwrap().raw_secret_bytes().as_slice(),
+            &SHARED_SECRET
+        );
+    }
+}
+
+#[test]
+fn rejects_invalid_public_keys() {
+    let secret = NonZeroScalar::new(Scalar::ONE).unwrap();
+
+    // identity
+    assert!(PublicKey::from_sec1_bytes(&[0]).is_err());
+    assert!(diffie_hellman_x_only(secret, &[0]).is_err());
+
+    // off-curve point
+    let mut off_curve = BOB_PUBLIC;
+    off_curve[64] ^= 1;
+    assert!(PublicKey::from_sec1_bytes(&off_curve).is_err());
+
+    // first x-coordinate which is on the quadratic twist rather than the curve
+    let twist_x = (0u64..)
+        .map(|i| {
+            let mut x = FieldBytes::default();
+            x[24..].copy_from_slice(&i.to_be_bytes());
+            x
+        })
+        .find(|x| AffinePoint::decompress(x, 0.into()).is_none().into())
+        .unwrap();
+
+    assert!(diffie_hellman_x_only(secret, &twist_x).is_err());
+    assert!(diffie_hellman_x_only(secret, &twist_x[1..]).is_err());
+}
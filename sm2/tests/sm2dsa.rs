@@ -13,6 +13,9 @@ use sm2::{
     },
 };
 
+#[cfg(feature = "getrandom")]
+use {elliptic_curve::common::getrandom::SysRng, sm2::dsa::signature::RandomizedSigner};
+
 const PUBLIC_KEY: [u8; 65] = hex!(
     "0408D77AE04C01CC4C1104360DD8AF6B6F7DF334283D7C1A6AFD5652407B87BEE5014E2A57C36C150D16324DC664E31E6432359609C4E79847A5B161C8C7364C8A"
 );
@@ -63,6 +66,13 @@ proptest! {
         prop_assert!(sk.verifying_key().verify(MSG, &signature).is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn sign_and_verify_with_rng(sk in signing_key()) {
+        let signature = sk.try_sign_with_rng(&mut SysRng, MSG).unwrap();
+        prop_assert!(sk.verifying_key().verify(MSG, &signature).is_ok());
+    }
+
     #[test]
     #[cfg(feature = "der")]
     fn sign_and_verify_der(sk in signing_key()) {
@@ -1,8 +1,19 @@
-use crate::{WindowSize, WnafScalar, WnafSize, wnaf_multi_exp, wnaf_table};
-use array::Array;
+use crate::{
+    Error, FixedWindowScalar, WindowSize, WnafScalar, WnafSize, decode_table, encode_table,
+    encoded_len, fixed_window_exp, wnaf_affine_table, wnaf_multi_exp,
+};
+use array::{Array, typenum::Unsigned};
 use core::iter;
 use core::ops::Mul;
-use group::Group;
+use group::{Curve, CurveAffine, GroupEncoding};
+use subtle::ConditionallySelectable;
+
+#[cfg(feature = "serde")]
+use {
+    crate::check_table,
+    core::{fmt, marker::PhantomData},
+    serdect::serde::{Deserialize, Serialize, de, ser},
+};
 
 /// Fixed window table for a group element, precomputed to improve scalar multiplication speed.
 ///
@@ -15,6 +26,8 @@ use group::Group;
 /// will consume more memory and take longer to precompute, but result in faster scalar
 /// multiplications.
 ///
+/// The table is stored in affine coordinates, so scalar multiplications use mixed additions.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -30,11 +43,11 @@ use group::Group;
 /// in the type system that the base and scalar `Wnaf`s were computed with the same window
 /// size, allowing the result to be computed infallibly.
 #[derive(Clone, Debug)]
-pub struct WnafBase<G: Group, W: WindowSize> {
-    table: Array<G, W::TableSize>,
+pub struct WnafBase<G: Curve, W: WindowSize> {
+    pub(crate) table: Array<G::Affine, W::TableSize>,
 }
 
-impl<G: Group, W: WindowSize> WnafBase<G, W> {
+impl<G: Curve, W: WindowSize> WnafBase<G, W> {
     /// Computes a window table for the given base with the specified window size `W`.
     #[inline]
     pub fn new(base: &G) -> Self {
@@ -46,7 +59,8 @@ impl<G: Group, W: WindowSize> WnafBase<G, W> {
     /// Initialize an already allocated window table from the given base.
     #[inline]
     pub fn init_from_base(&mut self, base: &G) {
-        wnaf_table(&mut self.table, base, W::USIZE);
+        let mut scratch = Array::<G, W::TableSize>::from_fn(|_| G::identity());
+        wnaf_affine_table(&mut self.table, &mut scratch, base, W::USIZE);
     }
 
     /// Perform a multiscalar multiplication.
@@ -59,21 +73,73 @@ impl<G: Group, W: WindowSize> WnafBase<G, W> {
         G::Scalar: WnafSize,
         I: Clone + Iterator<Item = (&'a Self, &'a WnafScalar<G::Scalar, W>)>,
     {
-        wnaf_multi_exp(pairs.map(|(b, s)| (b.table.as_slice(), s.wnaf.as_slice(), s.digits)))
+        wnaf_multi_exp(pairs.map(|(b, s)| (b.table.as_slice(), s.digits())))
+    }
+
+    /// Perform a scalar multiplication in constant time.
+    ///
+    /// Unlike multiplying by a [`WnafScalar`], this is suitable for secret scalars. See
+    /// [`FixedWindowScalar`] for details.
+    #[must_use]
+    pub fn mul_fixed_window(&self, scalar: &FixedWindowScalar<G::Scalar, W>) -> G
+    where
+        G: ConditionallySelectable,
+        G::Scalar: WnafSize,
+    {
+        let result = fixed_window_exp::<G>(&self.table, &scalar.digits[..scalar.len], W::USIZE);
+
+        // `table[0]` is the base itself
+        G::conditional_select(&result, &(result - self.table[0]), scalar.subtract_base)
     }
 }
 
-impl<G: Group, W: WindowSize> Default for WnafBase<G, W> {
+impl<G, W> WnafBase<G, W>
+where
+    G: Curve<Affine: GroupEncoding>,
+    W: WindowSize,
+{
+    /// Length of the serialization of a window table with window size `W`.
+    #[must_use]
+    pub fn encoded_len() -> usize {
+        W::TableSize::USIZE * encoded_len::<G::Affine>()
+    }
+
+    /// Serialize this window table into `out` as the concatenation of the [`GroupEncoding`]s of
+    /// its entries.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the length of `out` isn't [`WnafBase::encoded_len`].
+    pub fn write_bytes(&self, out: &mut [u8]) -> Result<(), Error> {
+        encode_table(&self.table, out)
+    }
+
+    /// Deserialize a window table serialized with [`WnafBase::write_bytes`].
+    ///
+    /// Each entry is decoded and checked to be a valid point with [`GroupEncoding::from_bytes`],
+    /// and the entries are checked to be the odd multiples `[P, 3P, 5P, ...]` of the first entry
+    /// `P`, which costs one mixed addition per entry.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the encoding has the wrong length for the window size `W`, any entry
+    /// is not a valid encoding of a point, or the entries aren't the expected multiples.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut ret = Self::default();
+        decode_table::<G>(&mut ret.table, bytes)?;
+        Ok(ret)
+    }
+}
+
+impl<G: Curve, W: WindowSize> Default for WnafBase<G, W> {
     fn default() -> Self {
         Self {
-            table: Array::from_fn(|_| G::generator()),
+            table: Array::from_fn(|_| G::Affine::generator()),
         }
     }
 }
 
 impl<G, W> Mul<&WnafScalar<G::Scalar, W>> for &WnafBase<G, W>
 where
-    G: Group<Scalar: WnafSize>,
+    G: Curve<Scalar: WnafSize>,
     W: WindowSize,
 {
     type Output = G;
@@ -85,7 +151,7 @@ where
 
 impl<G, W> Mul<&WnafScalar<G::Scalar, W>> for WnafBase<G, W>
 where
-    G: Group<Scalar: WnafSize>,
+    G: Curve<Scalar: WnafSize>,
     W: WindowSize,
 {
     type Output = G;
@@ -98,7 +164,7 @@ where
 
 impl<G, W> Mul<WnafScalar<G::Scalar, W>> for WnafBase<G, W>
 where
-    G: Group<Scalar: WnafSize>,
+    G: Curve<Scalar: WnafSize>,
     W: WindowSize,
 {
     type Output = G;
@@ -108,3 +174,123 @@ where
         &self * &rhs
     }
 }
+
+impl<G, W> Mul<&FixedWindowScalar<G::Scalar, W>> for &WnafBase<G, W>
+where
+    G: Curve<Scalar: WnafSize> + ConditionallySelectable,
+    W: WindowSize,
+{
+    type Output = G;
+
+    #[inline]
+    fn mul(self, rhs: &FixedWindowScalar<G::Scalar, W>) -> Self::Output {
+        self.mul_fixed_window(rhs)
+    }
+}
+
+impl<G, W> Mul<&FixedWindowScalar<G::Scalar, W>> for WnafBase<G, W>
+where
+    G: Curve<Scalar: WnafSize> + ConditionallySelectable,
+    W: WindowSize,
+{
+    type Output = G;
+
+    #[inline]
+    fn mul(self, rhs: &FixedWindowScalar<G::Scalar, W>) -> Self::Output {
+        self.mul_fixed_window(rhs)
+    }
+}
+
+//
+// serde support
+//
+
+/// Serializes a window table as a tuple of the [`GroupEncoding`]s of its entries, so it
+/// doesn't need to be buffered.
+#[cfg(feature = "serde")]
+impl<G, W> Serialize for WnafBase<G, W>
+where
+    G: Curve<Affine: GroupEncoding>,
+    W: WindowSize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        use ser::SerializeTuple;
+
+        struct Entry<T>(T);
+
+        impl<T: AsRef<[u8]>> Serialize for Entry<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                serdect::array::serialize_hex_lower_or_bin(&self.0, serializer)
+            }
+        }
+
+        let mut tuple = serializer.serialize_tuple(W::TableSize::USIZE)?;
+        for entry in &self.table {
+            tuple.serialize_element(&Entry(entry.to_bytes()))?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, G, W> Deserialize<'de> for WnafBase<G, W>
+where
+    G: Curve<Affine: GroupEncoding>,
+    W: WindowSize,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct Entry<A>(PhantomData<A>);
+
+        impl<'de, A: GroupEncoding> de::DeserializeSeed<'de> for Entry<A> {
+            type Value = A;
+
+            fn deserialize<D>(self, deserializer: D) -> Result<A, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                let mut repr = A::Repr::default();
+                serdect::array::deserialize_hex_or_bin(repr.as_mut(), deserializer)?;
+                Option::from(A::from_bytes(&repr)).ok_or_else(|| de::Error::custom(Error))
+            }
+        }
+
+        struct TableVisitor<G, W>(PhantomData<(G, W)>);
+
+        impl<'de, G, W> de::Visitor<'de> for TableVisitor<G, W>
+        where
+            G: Curve<Affine: GroupEncoding>,
+            W: WindowSize,
+        {
+            type Value = WnafBase<G, W>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a wNAF window table of {} points", W::TableSize::USIZE)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut ret = WnafBase::<G, W>::default();
+                for (i, entry) in ret.table.iter_mut().enumerate() {
+                    *entry = seq
+                        .next_element_seed(Entry::<G::Affine>(PhantomData))?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                check_table::<G>(&ret.table).map_err(de::Error::custom)?;
+                Ok(ret)
+            }
+        }
+
+        deserializer.deserialize_tuple(W::TableSize::USIZE, TableVisitor(PhantomData))
+    }
+}
@@ -1,15 +1,24 @@
 //! Dynamic wNAF API (requires `alloc` feature).
 
-use crate::{Digit, WnafGroup, le_repr, wnaf_form, wnaf_multi_exp, wnaf_table};
+use crate::{
+    Digit, Endomorphism, Error, W_MAX, WnafGroup, decode_table, encoded_len, le_repr,
+    negate_digits, wnaf_affine_table, wnaf_form, wnaf_multi_exp, wnaf_table,
+};
 use alloc::vec::Vec;
 use ff::PrimeField;
-use group::Group;
+use group::{Curve, CurveAffine, GroupEncoding};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{Deserialize, Serialize, de, ser};
 
 #[cfg(doc)]
-use crate::{WnafBase, WnafScalar};
+use crate::{GlvWnafBase, GlvWnafScalar, WnafBase, WnafScalar};
 
 /// A "w-ary non-adjacent form" scalar multiplication (also known as exponentiation) context.
 ///
+/// Window tables are stored in affine coordinates, so scalar multiplications use mixed additions.
+/// Computing a table costs a single batch normalization on top of the point additions.
+///
 /// # Examples
 ///
 /// This struct can be used to implement several patterns:
@@ -88,13 +97,13 @@ pub struct BoxedWnaf<W, B, S> {
     window_size: W,
 }
 
-impl<G: Group> Default for BoxedWnaf<(), Vec<G>, Vec<Digit>> {
+impl<A: CurveAffine> Default for BoxedWnaf<(), Vec<A>, Vec<Digit>> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<G: Group> BoxedWnaf<(), Vec<G>, Vec<Digit>> {
+impl<A: CurveAffine> BoxedWnaf<(), Vec<A>, Vec<Digit>> {
     /// Create a new [`BoxedWnaf`].
     #[must_use]
     pub fn new() -> Self {
@@ -106,17 +115,18 @@ impl<G: Group> BoxedWnaf<(), Vec<G>, Vec<Digit>> {
     }
 }
 
-impl<G: WnafGroup> BoxedWnaf<(), Vec<G>, Vec<Digit>> {
+impl<A: CurveAffine> BoxedWnaf<(), Vec<A>, Vec<Digit>> {
     /// Construct wNAF base for the provided group element `G`.
-    pub fn base(
+    pub fn base<G: WnafGroup + Curve<Affine = A>>(
         &mut self,
         base: &G,
         num_scalars: usize,
-    ) -> BoxedWnaf<usize, &[G], &mut Vec<Digit>> {
+    ) -> BoxedWnaf<usize, &[A], &mut Vec<Digit>> {
         let window_size = G::recommended_wnaf_for_num_scalars(num_scalars);
 
-        self.base.resize_with(1 << (window_size - 2), G::identity);
-        wnaf_table(&mut self.base, base, window_size);
+        let mut scratch = vec![G::identity(); 1 << (window_size - 2)];
+        self.base.resize_with(scratch.len(), A::identity);
+        wnaf_affine_table(&mut self.base, &mut scratch, base, window_size);
 
         BoxedWnaf {
             base: &self.base[..],
@@ -126,13 +136,9 @@ impl<G: WnafGroup> BoxedWnaf<(), Vec<G>, Vec<Digit>> {
     }
 
     /// Construct wNAF context for `scalar`.
-    pub fn scalar(&mut self, scalar: &G::Scalar) -> BoxedWnaf<usize, &mut Vec<G>, &[Digit]> {
+    pub fn scalar(&mut self, scalar: &A::Scalar) -> BoxedWnaf<usize, &mut Vec<A>, &[Digit]> {
         let window_size = 4;
-
-        let repr = le_repr(scalar);
-        let bit_len = init_storage::<G::Scalar>(&mut self.scalar, repr);
-        let digits = wnaf_form(&mut self.scalar, repr, bit_len, window_size);
-        self.scalar.truncate(digits);
+        init_scalar(&mut self.scalar, scalar, window_size);
 
         BoxedWnaf {
             base: &mut self.base,
@@ -142,11 +148,11 @@ impl<G: WnafGroup> BoxedWnaf<(), Vec<G>, Vec<Digit>> {
     }
 }
 
-impl<'a, G: Group> BoxedWnaf<usize, &'a [G], &'a mut Vec<Digit>> {
+impl<'a, A: CurveAffine> BoxedWnaf<usize, &'a [A], &'a mut Vec<Digit>> {
     /// Constructs new space for the scalar representation while borrowing the computed window
     /// table, for sending the window table across threads.
     #[must_use]
-    pub fn shared(&self) -> BoxedWnaf<usize, &'a [G], Vec<Digit>> {
+    pub fn shared(&self) -> BoxedWnaf<usize, &'a [A], Vec<Digit>> {
         BoxedWnaf {
             base: self.base,
             scalar: vec![],
@@ -155,11 +161,11 @@ impl<'a, G: Group> BoxedWnaf<usize, &'a [G], &'a mut Vec<Digit>> {
     }
 }
 
-impl<'a, G: Group> BoxedWnaf<usize, &'a mut Vec<G>, &'a [Digit]> {
+impl<'a, A: CurveAffine> BoxedWnaf<usize, &'a mut Vec<A>, &'a [Digit]> {
     /// Constructs new space for the window table while borrowing the computed scalar
     /// representation, for sending the scalar representation across threads.
     #[must_use]
-    pub fn shared(&self) -> BoxedWnaf<usize, Vec<G>, &'a [Digit]> {
+    pub fn shared(&self) -> BoxedWnaf<usize, Vec<A>, &'a [Digit]> {
         BoxedWnaf {
             base: vec![],
             scalar: self.scalar,
@@ -170,43 +176,213 @@ impl<'a, G: Group> BoxedWnaf<usize, &'a mut Vec<G>, &'a [Digit]> {
 
 impl<B, S: AsRef<[Digit]>> BoxedWnaf<usize, B, S> {
     /// Construct wNAF base for the provided group element `G`.
-    pub fn base<G: Group>(&mut self, base: &G) -> G
+    pub fn base<G: Curve>(&mut self, base: &G) -> G
     where
-        B: AsMut<Vec<G>>,
+        B: AsMut<Vec<G::Affine>>,
     {
-        self.base
-            .as_mut()
-            .resize_with(1 << (self.window_size - 2), G::identity);
-        wnaf_table(self.base.as_mut(), base, self.window_size);
-        wnaf_exp(self.base.as_mut(), self.scalar.as_ref())
+        let mut scratch = vec![G::identity(); 1 << (self.window_size - 2)];
+        let table = self.base.as_mut();
+        table.resize_with(scratch.len(), G::Affine::identity);
+        wnaf_affine_table(table, &mut scratch, base, self.window_size);
+        wnaf_exp(table, self.scalar.as_ref())
     }
 }
 
 impl<B, S: AsMut<Vec<Digit>>> BoxedWnaf<usize, B, S> {
     /// Construct wNAF context for `scalar`.
-    pub fn scalar<G: Group>(&mut self, scalar: &G::Scalar) -> G
+    pub fn scalar<A: CurveAffine>(&mut self, scalar: &A::Scalar) -> A::Curve
     where
-        B: AsRef<[G]>,
+        B: AsRef<[A]>,
     {
-        let repr = le_repr(scalar);
-        let bit_len = init_storage::<G::Scalar>(self.scalar.as_mut(), repr);
-        let digits = wnaf_form(self.scalar.as_mut(), repr, bit_len, self.window_size);
-        self.scalar.as_mut().truncate(digits);
+        init_scalar(self.scalar.as_mut(), scalar, self.window_size);
         wnaf_exp(self.base.as_ref(), self.scalar.as_mut())
     }
 }
 
-/// Initialize storage for wNAF `Digit`s.
+impl<B, S: AsMut<Vec<Digit>>> BoxedWnaf<usize, B, S> {
+    /// Serialize the window table of this base context as the concatenation of the
+    /// [`GroupEncoding`]s of its entries.
+    ///
+    /// The window size is implied by the number of entries.
+    #[must_use]
+    pub fn to_bytes<A>(&self) -> Vec<u8>
+    where
+        A: GroupEncoding,
+        B: AsRef<[A]>,
+    {
+        let table = self.base.as_ref();
+        let mut bytes = Vec::with_capacity(table.len() * encoded_len::<A>());
+        for entry in table {
+            bytes.extend_from_slice(entry.to_bytes().as_ref());
+        }
+        bytes
+    }
+}
+
+impl<A: CurveAffine + GroupEncoding> BoxedWnaf<usize, Vec<A>, Vec<Digit>> {
+    /// Deserialize the window table of a base context serialized with [`BoxedWnaf::to_bytes`].
+    ///
+    /// Each entry is decoded and checked to be a valid point with [`GroupEncoding::from_bytes`],
+    /// and the entries are checked to be the odd multiples `[P, 3P, 5P, ...]` of the first entry
+    /// `P`, which costs one mixed addition per entry.
+    ///
+    /// # Errors
+    /// Returns [`Error`] if the number of entries doesn't correspond to a supported window size,
+    /// any entry is not a valid encoding of a point, or the entries aren't the expected
+    /// multiples.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let len = bytes.len().checked_div(encoded_len::<A>()).ok_or(Error)?;
+        if !len.is_power_of_two() || len > 1 << (W_MAX - 2) {
+            return Err(Error);
+        }
+
+        let mut base = vec![A::identity(); len];
+        decode_table::<A::Curve>(&mut base, bytes)?;
+
+        Ok(BoxedWnaf {
+            base,
+            scalar: vec![],
+            window_size: len.trailing_zeros() as usize + 2,
+        })
+    }
+}
+
+/// Computes the wNAF representation of `scalar` in `digits`, resizing it to the number of
+/// digits.
+#[inline]
+fn init_scalar<F: PrimeField>(digits: &mut Vec<Digit>, scalar: &F, window_size: usize) {
+    let repr = le_repr(scalar);
+    let bit_len = F::NUM_BITS as usize;
+    init_digits(
+        digits,
+        &repr.as_ref()[..bit_len.div_ceil(8)],
+        bit_len,
+        window_size,
+    );
+}
+
+/// Computes the wNAF representation of the `bit_len`-bit little-endian integer `bytes` in
+/// `digits`, resizing it to the number of digits.
 #[inline]
-fn init_storage<F: PrimeField>(digits: &mut Vec<Digit>, repr: F::Repr) -> usize {
-    let bit_len = (repr.as_ref().len() * 8).min(F::NUM_BITS as usize);
+fn init_digits(digits: &mut Vec<Digit>, bytes: &[u8], bit_len: usize, window_size: usize) {
     digits.resize(bit_len + 1, 0);
-    bit_len
+    let len = wnaf_form(digits, bytes, bit_len, window_size);
+    digits.truncate(len);
 }
 
-/// Performs wNAF exponentiation with the provided window table and w-NAF form scalar, whose
-/// lengths must match.
+/// Performs wNAF exponentiation with the provided affine window table and w-NAF form scalar,
+/// whose lengths must match.
 #[inline]
-fn wnaf_exp<G: Group>(table: &[G], wnaf: &[Digit]) -> G {
-    wnaf_multi_exp(core::iter::once((table, wnaf, wnaf.len())))
+fn wnaf_exp<A: CurveAffine>(table: &[A], wnaf: &[Digit]) -> A::Curve {
+    wnaf_multi_exp(core::iter::once((table, wnaf)))
+}
+
+/// A "w-ary non-adjacent form" scalar multiplication context for a single base and many scalars,
+/// which uses an [`Endomorphism`] to halve the number of doublings (the GLV method).
+///
+/// This is the dynamic equivalent of [`GlvWnafBase`] and [`GlvWnafScalar`], which chooses the
+/// window size based on the number of scalars.
+///
+/// # Examples
+///
+/// ```ignore
+/// use wnaf::BoxedGlvWnaf;
+///
+/// let mut wnaf = BoxedGlvWnaf::new(&base, scalars.len());
+/// let results: Vec<_> = scalars.iter().map(|scalar| wnaf.scalar(scalar)).collect();
+/// ```
+#[derive(Debug)]
+pub struct BoxedGlvWnaf<G: Curve> {
+    tables: [Vec<G::Affine>; 2],
+    digits: [Vec<Digit>; 2],
+    window_size: usize,
+}
+
+impl<G: WnafGroup + Endomorphism + Curve> BoxedGlvWnaf<G> {
+    /// Compute the window tables for `base` and its image under the endomorphism, with a window
+    /// size suitable for multiplying by `num_scalars` scalars.
+    #[must_use]
+    pub fn new(base: &G, num_scalars: usize) -> Self {
+        let window_size = G::recommended_wnaf_for_num_scalars(num_scalars);
+
+        let mut table = vec![G::identity(); 1 << (window_size - 2)];
+        wnaf_table(&mut table, base, window_size);
+        let endomorphism: Vec<_> = table.iter().map(Endomorphism::endomorphism).collect();
+
+        let mut tables = [
+            vec![G::Affine::identity(); table.len()],
+            vec![G::Affine::identity(); table.len()],
+        ];
+        G::batch_normalize(&table, &mut tables[0]);
+        G::batch_normalize(&endomorphism, &mut tables[1]);
+
+        Self {
+            tables,
+            digits: [vec![], vec![]],
+            window_size,
+        }
+    }
+
+    /// Multiply the base by `scalar`.
+    pub fn scalar(&mut self, scalar: &G::Scalar) -> G {
+        let bit_len = G::SUB_SCALAR_BITS as usize;
+
+        for (digits, (k, negative)) in self.digits.iter_mut().zip(G::decompose_scalar(scalar)) {
+            let repr = le_repr(&k);
+            init_digits(
+                digits,
+                &repr.as_ref()[..bit_len.div_ceil(8)],
+                bit_len,
+                self.window_size,
+            );
+
+            if negative {
+                negate_digits(digits);
+            }
+        }
+
+        wnaf_multi_exp(
+            self.tables
+                .iter()
+                .zip(&self.digits)
+                .map(|(table, digits)| (table.as_slice(), digits.as_slice())),
+        )
+    }
+}
+
+//
+// serde support
+//
+
+#[cfg(feature = "serde")]
+impl<A: GroupEncoding> Serialize for BoxedWnaf<usize, Vec<A>, Vec<Digit>> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serdect::slice::serialize_hex_lower_or_bin(&self.to_bytes::<A>(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A: GroupEncoding> Serialize for BoxedWnaf<usize, &[A], &mut Vec<Digit>> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serdect::slice::serialize_hex_lower_or_bin(&self.to_bytes::<A>(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A: CurveAffine + GroupEncoding> Deserialize<'de>
+    for BoxedWnaf<usize, Vec<A>, Vec<Digit>>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+        Self::from_bytes(&bytes).map_err(de::Error::custom)
+    }
 }
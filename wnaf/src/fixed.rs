@@ -0,0 +1,66 @@
+use crate::{Digit, WindowSize, WnafSize, fixed_window_form, le_repr};
+use array::Array;
+use core::marker::PhantomData;
+use ff::PrimeField;
+use subtle::Choice;
+
+#[cfg(doc)]
+use crate::{WnafBase, WnafScalar};
+
+/// A scalar in regular signed fixed-window form, for constant-time scalar multiplication with
+/// secret scalars.
+///
+/// Unlike [`WnafScalar`], whose number and position of nonzero digits depend on the value of the
+/// scalar, every digit of this representation is odd and nonzero, and the number of digits only
+/// depends on the window size `W`. Multiplying a [`WnafBase`] by a [`FixedWindowScalar`]
+/// performs the same sequence of group operations for every scalar, and looks up each multiple
+/// of the base by scanning the whole table.
+///
+/// Each digit covers `W - 1` bits of the scalar, so the window table of a [`WnafBase`] with
+/// window size `W` contains exactly the odd multiples needed by this representation.
+///
+/// # Examples
+///
+/// ```ignore
+/// type MyWnafBase = WnafBase<EdwardsPoint, U5>;
+/// type MyFixedWindowScalar = FixedWindowScalar<EdwardsScalar, U5>;
+///
+/// let base = MyWnafBase::new(&EdwardsPoint::GENERATOR);
+/// let scalar = MyFixedWindowScalar::new(&secret);
+/// let result = &base * &scalar;
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedWindowScalar<F: PrimeField + WnafSize, W: WindowSize> {
+    pub(crate) digits: Array<Digit, F::StorageSize>,
+    pub(crate) len: usize,
+    pub(crate) subtract_base: Choice,
+    _field: PhantomData<(F, W)>,
+}
+
+impl<F: PrimeField + WnafSize, W: WindowSize> FixedWindowScalar<F, W> {
+    /// Computes the regular signed fixed-window representation of the given scalar with window
+    /// size `W`, in constant time.
+    #[must_use]
+    pub fn new(scalar: &F) -> Self {
+        // The recoding only supports odd scalars. If the scalar `k` is even, the integer `k + 1`
+        // is recoded instead, and the base is subtracted from the result. This is computed on the
+        // integer rather than in the scalar field, so it is also correct for bases whose order
+        // isn't the order of the scalar field, e.g. points with a torsion component. As `k` is
+        // even, adding one only sets its lowest bit, and `k + 1 <= n < 2^NUM_BITS`.
+        let subtract_base = !scalar.is_odd();
+        let mut repr = le_repr(scalar);
+        if let Some(byte) = repr.as_mut().first_mut() {
+            *byte |= 1;
+        }
+
+        let mut digits = Array::<Digit, F::StorageSize>::default();
+        let len = fixed_window_form(&mut digits, repr.as_ref(), F::NUM_BITS as usize, W::USIZE);
+
+        Self {
+            digits,
+            len,
+            subtract_base,
+            _field: PhantomData,
+        }
+    }
+}
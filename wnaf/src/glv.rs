@@ -0,0 +1,128 @@
+use crate::{
+    Digit, Endomorphism, WindowSize, WnafBase, WnafScalar, WnafSize, le_repr, wnaf_multi_exp,
+    wnaf_table,
+};
+use array::Array;
+use core::iter::{self, zip};
+use core::ops::Mul;
+use group::Curve;
+
+/// Fixed window tables for a group element `P` and its image `φ(P)` under an [`Endomorphism`],
+/// precomputed to improve scalar multiplication speed using the GLV method.
+///
+/// The table for `φ(P)` is computed by applying the endomorphism to each entry of the table for
+/// `P`, which is much cheaper than computing it from scratch. Both tables are then converted to
+/// affine coordinates so scalar multiplications use mixed additions.
+///
+/// # Examples
+///
+/// ```ignore
+/// type MyGlvWnafBase = GlvWnafBase<ProjectivePoint, U5>;
+/// type MyGlvWnafScalar = GlvWnafScalar<ProjectivePoint, U5>;
+///
+/// let base = MyGlvWnafBase::new(&ProjectivePoint::GENERATOR);
+/// let scalar = MyGlvWnafScalar::new(&s);
+/// let result = &base * &scalar;
+/// ```
+#[derive(Clone, Debug)]
+pub struct GlvWnafBase<G: Endomorphism + Curve, W: WindowSize> {
+    bases: [WnafBase<G, W>; 2],
+}
+
+impl<G: Endomorphism + Curve, W: WindowSize> GlvWnafBase<G, W> {
+    /// Computes the window tables for the given base and its image under the endomorphism with
+    /// the specified window size `W`.
+    #[must_use]
+    pub fn new(base: &G) -> Self {
+        let mut table = Array::<G, W::TableSize>::from_fn(|_| G::identity());
+        wnaf_table(&mut table, base, W::USIZE);
+        let endomorphism = Array::<G, W::TableSize>::from_fn(|i| table[i].endomorphism());
+
+        let mut bases = [WnafBase::default(), WnafBase::default()];
+        G::batch_normalize(&table, &mut bases[0].table);
+        G::batch_normalize(&endomorphism, &mut bases[1].table);
+        Self { bases }
+    }
+
+    /// Perform a multiscalar multiplication.
+    ///
+    /// Computes a sum-of-products `aA + bB + ...` in variable time with wNAF multi-exponentiation
+    /// using the interleaved window method, where each term is split into two terms of half the
+    /// size using the endomorphism.
+    #[must_use]
+    pub fn multiscalar_mul<'a, I>(pairs: I) -> G
+    where
+        G::Scalar: WnafSize,
+        I: Clone + Iterator<Item = (&'a Self, &'a GlvWnafScalar<G, W>)>,
+    {
+        wnaf_multi_exp(pairs.flat_map(|(b, s)| {
+            zip(&b.bases, &s.scalars).map(|(b, s)| (b.table.as_slice(), s.digits()))
+        }))
+    }
+}
+
+/// A scalar decomposed into two sub-scalars of about half its size using an [`Endomorphism`], in
+/// "w-ary non-adjacent form", precomputed to improve the speed of scalar multiplication.
+///
+/// # Examples
+///
+/// See [`GlvWnafBase`] for usage examples.
+#[derive(Clone, Debug)]
+pub struct GlvWnafScalar<G: Endomorphism<Scalar: WnafSize>, W: WindowSize> {
+    scalars: [WnafScalar<G::Scalar, W>; 2],
+}
+
+impl<G: Endomorphism<Scalar: WnafSize>, W: WindowSize> GlvWnafScalar<G, W> {
+    /// Decomposes the given scalar and computes the wNAF representation of each sub-scalar with
+    /// window size `W`.
+    #[must_use]
+    pub fn new(scalar: &G::Scalar) -> Self {
+        let len = G::SUB_SCALAR_BITS.div_ceil(8) as usize;
+
+        Self {
+            scalars: G::decompose_scalar(scalar).map(|(k, negative)| {
+                let mut wnaf = WnafScalar::from_le_bytes(&le_repr(&k).as_ref()[..len]);
+                if negative {
+                    wnaf.negate();
+                }
+                wnaf
+            }),
+        }
+    }
+
+    /// Get the digits of the wNAF representations of the sub-scalars `k1` and `k2`, least
+    /// significant first, where `k2` multiplies the image of the base under the endomorphism.
+    ///
+    /// This can be used to implement multiplication for group element representations other
+    /// than `G` itself, e.g. with a different coordinate system.
+    #[inline]
+    #[must_use]
+    pub fn digits(&self) -> (&[Digit], &[Digit]) {
+        (self.scalars[0].digits(), self.scalars[1].digits())
+    }
+}
+
+impl<G, W> Mul<&GlvWnafScalar<G, W>> for &GlvWnafBase<G, W>
+where
+    G: Endomorphism<Scalar: WnafSize> + Curve,
+    W: WindowSize,
+{
+    type Output = G;
+
+    fn mul(self, rhs: &GlvWnafScalar<G, W>) -> Self::Output {
+        GlvWnafBase::multiscalar_mul(iter::once((self, rhs)))
+    }
+}
+
+impl<G, W> Mul<&GlvWnafScalar<G, W>> for GlvWnafBase<G, W>
+where
+    G: Endomorphism<Scalar: WnafSize> + Curve,
+    W: WindowSize,
+{
+    type Output = G;
+
+    #[inline]
+    fn mul(self, rhs: &GlvWnafScalar<G, W>) -> Self::Output {
+        &self * rhs
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{Digit, WnafSize, jsf_exp, jsf_form, le_repr};
+use array::Array;
+use core::marker::PhantomData;
+use core::ops::Mul;
+use ff::PrimeField;
+use group::Group;
+
+/// Table for a pair of group elements `(A, B)`, precomputed to compute `[a] A + [b] B` using
+/// Shamir's trick with the Joint Sparse Form (JSF) of `(a, b)`.
+///
+/// This is well suited to signature verification, which is dominated by computing
+/// `[u1] G + [u2] Q` for a single public key `Q`: the table only contains `A`, `B`, `A + B` and
+/// `A - B`, so it's cheap to compute for one-off pairs of bases.
+///
+/// Like [`WnafBase`](crate::WnafBase), this should NOT be used with secret scalars.
+///
+/// # Examples
+///
+/// ```ignore
+/// let base = JsfBase::new(&ProjectivePoint::GENERATOR, &public_key);
+/// let scalars = JsfScalar::new(&u1, &u2);
+/// let result = &base * &scalars;
+/// ```
+#[derive(Clone, Debug)]
+pub struct JsfBase<G: Group> {
+    table: [G; 4],
+}
+
+impl<G: Group> JsfBase<G> {
+    /// Computes the table for the given pair of bases.
+    #[inline]
+    #[must_use]
+    pub fn new(a: &G, b: &G) -> Self {
+        Self {
+            table: [*a, *b, *a + b, *a - b],
+        }
+    }
+}
+
+/// Joint Sparse Form (JSF) of a pair of scalars `(a, b)`, precomputed to compute
+/// `[a] A + [b] B` with a [`JsfBase`].
+///
+/// The JSF is a signed binary representation of both scalars with digits in `{-1, 0, 1}`, which
+/// minimizes the number of positions where either of the digits is nonzero. On average, only
+/// half of the positions need an addition.
+#[derive(Clone, Debug, Default)]
+pub struct JsfScalar<F: PrimeField + WnafSize> {
+    a: Array<Digit, F::StorageSize>,
+    b: Array<Digit, F::StorageSize>,
+    digits: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField + WnafSize> JsfScalar<F> {
+    /// Computes the JSF of the given pair of scalars.
+    #[inline]
+    #[must_use]
+    pub fn new(a: &F, b: &F) -> Self {
+        Self::from_le_bytes(le_repr(a).as_ref(), le_repr(b).as_ref())
+    }
+
+    /// Computes the JSF directly from a pair of raw little-endian byte strings.
+    ///
+    /// Both are interpreted as little-endian unsigned integers (trailing zero bytes may be
+    /// omitted). Only the low `F::NUM_BITS` bits of each are used: any higher bits are ignored,
+    /// i.e. the integers are reduced modulo `2^F::NUM_BITS`, not modulo the order of the field.
+    #[must_use]
+    pub fn from_le_bytes(a: &[u8], b: &[u8]) -> Self {
+        let mut jsf = Self::default();
+        let bit_len = (a.len().max(b.len()) * 8).min(F::NUM_BITS as usize);
+        jsf.digits = jsf_form(&mut jsf.a, &mut jsf.b, [a, b], bit_len);
+        jsf
+    }
+
+    /// Get the digits of the JSF of `a` and `b`, least significant first.
+    #[inline]
+    #[must_use]
+    pub fn digits(&self) -> (&[Digit], &[Digit]) {
+        (&self.a[..self.digits], &self.b[..self.digits])
+    }
+}
+
+impl<G> Mul<&JsfScalar<G::Scalar>> for &JsfBase<G>
+where
+    G: Group<Scalar: WnafSize>,
+{
+    type Output = G;
+
+    fn mul(self, rhs: &JsfScalar<G::Scalar>) -> Self::Output {
+        let (a, b) = rhs.digits();
+        jsf_exp(&self.table, a, b)
+    }
+}
+
+impl<G> Mul<&JsfScalar<G::Scalar>> for JsfBase<G>
+where
+    G: Group<Scalar: WnafSize>,
+{
+    type Output = G;
+
+    #[inline]
+    fn mul(self, rhs: &JsfScalar<G::Scalar>) -> Self::Output {
+        &self * rhs
+    }
+}
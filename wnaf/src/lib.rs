@@ -13,6 +13,9 @@
 extern crate alloc;
 
 mod base;
+mod fixed;
+mod glv;
+mod jsf;
 mod limb_buffer;
 mod scalar;
 mod traits;
@@ -22,17 +25,27 @@ mod boxed;
 
 pub use crate::{
     base::WnafBase,
+    fixed::FixedWindowScalar,
+    glv::{GlvWnafBase, GlvWnafScalar},
+    jsf::{JsfBase, JsfScalar},
     scalar::WnafScalar,
-    traits::{WindowSize, WnafGroup, WnafSize},
+    traits::{Endomorphism, WindowSize, WnafGroup, WnafSize},
 };
 pub use array;
 pub use group::Group;
 
 #[cfg(feature = "alloc")]
-pub use crate::boxed::BoxedWnaf;
+pub use crate::boxed::{BoxedGlvWnaf, BoxedWnaf};
 
 use crate::limb_buffer::LimbBuffer;
+use core::{
+    fmt,
+    iter::zip,
+    ops::{AddAssign, SubAssign},
+};
 use ff::PrimeField;
+use group::{Curve, CurveAffine, GroupEncoding};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 /// Type used to represent wNAF digits.
 ///
@@ -46,6 +59,18 @@ pub type Digit = i8;
 // NOTE: this is also the maximum impl size we support for the `WindowSize` trait
 pub const W_MAX: usize = 8;
 
+/// Error decoding a serialized window table.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Error;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid wNAF window table encoding")
+    }
+}
+
+impl core::error::Error for Error {}
+
 /// Computes a wNAF window table for the given base and window size.
 ///
 /// For a window of size `w` non-zero wNAF digits are odd and have magnitude at most `2^(w-1) - 1`.
@@ -64,6 +89,80 @@ fn wnaf_table<G: Group>(table: &mut [G], base: &G, window: usize) {
     }
 }
 
+/// Computes a wNAF window table for the given base and window size in affine coordinates, so
+/// that scalar multiplications can use mixed additions.
+///
+/// The entries are computed in `scratch`, which must be as long as `table`, and then converted
+/// with a single batch normalization.
+fn wnaf_affine_table<G: Curve>(
+    table: &mut [G::Affine],
+    scratch: &mut [G],
+    base: &G,
+    window: usize,
+) {
+    wnaf_table(scratch, base, window);
+    G::batch_normalize(scratch, table);
+}
+
+/// Checks that a window table holds the odd multiples `[P, 3P, 5P, ...]` of its first entry `P`,
+/// i.e. that `T[i + 1] = T[i] + 2P` for each `i`.
+///
+/// The sums are compared with the entries in projective coordinates, so this needs no inversions
+/// and is cheaper than recomputing and normalizing the table.
+fn check_table<G: Curve>(table: &[G::Affine]) -> Result<(), Error> {
+    let dbl = table.first().ok_or(Error)?.to_curve().double();
+
+    if table
+        .windows(2)
+        .all(|pair| dbl + pair[0] == pair[1].to_curve())
+    {
+        Ok(())
+    } else {
+        Err(Error)
+    }
+}
+
+/// Length of the [`GroupEncoding`] of a point.
+fn encoded_len<A: GroupEncoding>() -> usize {
+    A::Repr::default().as_ref().len()
+}
+
+/// Encodes the entries of a window table, converted to affine coordinates, into `out` as the
+/// concatenation of their [`GroupEncoding`]s, checking that the length of `out` matches.
+fn encode_table<A: GroupEncoding>(affine: &[A], out: &mut [u8]) -> Result<(), Error> {
+    let len = encoded_len::<A>();
+    if out.len() != affine.len() * len {
+        return Err(Error);
+    }
+
+    for (entry, encoded) in zip(affine, out.chunks_exact_mut(len)) {
+        encoded.copy_from_slice(entry.to_bytes().as_ref());
+    }
+
+    Ok(())
+}
+
+/// Decodes a window table encoded with `encode_table` into `table`, checking that the number of
+/// encoded entries matches the length of `table`, that each entry is a valid point, and that the
+/// entries are the odd multiples of the first one (see `check_table`).
+fn decode_table<G>(table: &mut [G::Affine], bytes: &[u8]) -> Result<(), Error>
+where
+    G: Curve<Affine: GroupEncoding>,
+{
+    let len = encoded_len::<G::Affine>();
+    if len == 0 || bytes.len() != table.len() * len {
+        return Err(Error);
+    }
+
+    for (entry, encoded) in zip(table.iter_mut(), bytes.chunks_exact(len)) {
+        let mut repr = <G::Affine as GroupEncoding>::Repr::default();
+        repr.as_mut().copy_from_slice(encoded);
+        *entry = Option::from(G::Affine::from_bytes(&repr)).ok_or(Error)?;
+    }
+
+    check_table::<G>(table)
+}
+
 /// Fills `wnaf` with the wNAF representation of a little-endian scalar, and returns the
 /// number of digits written.
 #[allow(clippy::cast_possible_wrap)]
@@ -149,20 +248,208 @@ fn wnaf_form<S: AsRef<[u8]>>(wnaf: &mut [Digit], c: S, bit_len: usize, window: u
     cursor
 }
 
+/// Negate the scalar represented by the given signed digits.
+fn negate_digits(digits: &mut [Digit]) {
+    for digit in digits {
+        *digit = -*digit;
+    }
+}
+
+/// Fills `digits` with the regular signed fixed-window representation of an odd little-endian
+/// scalar, and returns the number of digits written.
+///
+/// Each digit covers `window - 1` bits of the scalar and is odd, with magnitude at most
+/// `2^(window-1) - 1`, so the digits index the same table as wNAF digits. The number of digits
+/// only depends on `bit_len`, and each digit is computed from a fixed set of bits, so the
+/// recoding runs in constant time.
+///
+/// This uses the fact that an odd `k < 2^(rm)` can be written as
+/// `k = Σ (2 v_i - (2^r - 1)) 2^(ri)`, where `v_i` are the `r`-bit digits of
+/// `v = (k >> 1) + 2^(rm-1)`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn fixed_window_form(digits: &mut [Digit], c: &[u8], bit_len: usize, window: usize) -> usize {
+    debug_assert!(window >= 2);
+    debug_assert!(window <= W_MAX);
+    debug_assert!(
+        c.first().is_none_or(|byte| byte & 1 == 1),
+        "scalar must be odd"
+    );
+
+    let r = window - 1;
+    let len = bit_len.div_ceil(r);
+    debug_assert!(len <= digits.len(), "digit storage too small");
+
+    for (i, digit) in digits[..len].iter_mut().enumerate() {
+        let mut v = (0..r).fold(0u8, |acc, j| acc | (bit(c, r * i + j + 1) << j));
+
+        if i == len - 1 {
+            v |= 1 << (r - 1);
+        }
+
+        *digit = (v.wrapping_mul(2).wrapping_sub((1 << r) - 1)) as Digit;
+    }
+
+    len
+}
+
+/// Performs constant-time scalar multiplication with a table of the odd multiples of the base
+/// and the regular signed fixed-window representation of the scalar.
+///
+/// Every digit is nonzero, so the sequence of group operations doesn't depend on the scalar, and
+/// each table lookup scans the whole table.
+fn fixed_window_exp<G>(table: &[G::Affine], digits: &[Digit], window: usize) -> G
+where
+    G: Curve + ConditionallySelectable,
+{
+    let Some((top, rest)) = digits.split_last() else {
+        return G::identity();
+    };
+
+    let mut result = fixed_window_lookup::<G>(table, *top);
+
+    for &digit in rest.iter().rev() {
+        for _ in 1..window {
+            result = result.double();
+        }
+
+        result += fixed_window_lookup::<G>(table, digit);
+    }
+
+    result
+}
+
+/// Look up `[digit] P` for an odd `digit` in the table of odd multiples of `P` in constant time.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn fixed_window_lookup<G>(table: &[G::Affine], digit: Digit) -> G
+where
+    G: Curve + ConditionallySelectable,
+{
+    let sign = digit >> 7;
+    let abs = ((digit ^ sign) - sign) as u8;
+    let index = abs >> 1;
+
+    let mut point = G::identity();
+    for (i, entry) in table.iter().enumerate() {
+        point.conditional_assign(&entry.to_curve(), (i as u8).ct_eq(&index));
+    }
+
+    G::conditional_select(&point, &-point, Choice::from((sign as u8) & 1))
+}
+
+/// Fills `a` and `b` with the Joint Sparse Form (JSF) of a pair of little-endian scalars, and
+/// returns the number of digits written.
+///
+/// The JSF of a pair of scalars is a signed binary representation with digits in `{-1, 0, 1}`
+/// which minimizes the number of positions where either digit is nonzero, i.e. the number of
+/// additions needed by Shamir's trick, to `1/2` of the positions on average, compared with `3/4`
+/// for unsigned binary and `5/9` for the NAF of each scalar. See Solinas, "Low-Weight Binary
+/// Representations for Pairs of Integers" (2001), or algorithm 3.50 of Hankerson, Menezes and
+/// Vanstone, "Guide to Elliptic Curve Cryptography".
+///
+/// Only the low `bit_len` bits of each scalar are used: any higher bits are treated as zero.
+fn jsf_form(a: &mut [Digit], b: &mut [Digit], k: [&[u8]; 2], bit_len: usize) -> usize {
+    debug_assert!(
+        bit_len < a.len() && bit_len < b.len(),
+        "jsf storage too small"
+    );
+
+    // Carries of both scalars
+    let mut d = [0u8; 2];
+    let mut len = 0;
+
+    for j in 0..=bit_len {
+        // Low three bits of `k_i / 2^j + d_i`, where `k_i` is truncated to `bit_len` bits
+        let l = [0, 1].map(|i| {
+            let bits = (0..3)
+                .filter(|n| j + n < bit_len)
+                .fold(0, |acc, n| acc | (bit(k[i], j + n) << n));
+            (bits + d[i]) & 7
+        });
+
+        let u = [0, 1].map(|i| {
+            if l[i] & 1 == 0 {
+                return 0;
+            }
+
+            let u: Digit = if l[i] & 3 == 1 { 1 } else { -1 };
+            if (l[i] == 3 || l[i] == 5) && l[1 - i] & 3 == 2 {
+                -u
+            } else {
+                u
+            }
+        });
+
+        for i in 0..2 {
+            if 2 * i16::from(d[i]) == 1 + i16::from(u[i]) {
+                d[i] = 1 - d[i];
+            }
+        }
+
+        a[j] = u[0];
+        b[j] = u[1];
+
+        if u != [0, 0] {
+            len = j + 1;
+        }
+    }
+
+    len
+}
+
+/// Performs two-scalar multiplication `[a] A + [b] B` in variable time using Shamir's trick,
+/// given the table `[A, B, A + B, A - B]` and the JSF of `(a, b)`.
+fn jsf_exp<G: Group>(table: &[G; 4], a: &[Digit], b: &[Digit]) -> G {
+    let mut result = G::identity();
+    let mut found_one = false;
+
+    for (&u, &v) in a.iter().zip(b).rev() {
+        if found_one {
+            result = result.double();
+        }
+
+        let (point, negate) = match (u, v) {
+            (0, 0) => continue,
+            (_, 0) => (&table[0], u < 0),
+            (0, _) => (&table[1], v < 0),
+            _ if u == v => (&table[2], u < 0),
+            _ => (&table[3], u < 0),
+        };
+
+        found_one = true;
+
+        if negate {
+            result -= point;
+        } else {
+            result += point;
+        }
+    }
+
+    result
+}
+
+/// Get bit `n` of a little-endian integer, which is zero past the end of `c`.
+fn bit(c: &[u8], n: usize) -> u8 {
+    c.get(n / 8).map_or(0, |byte| (byte >> (n % 8)) & 1)
+}
+
 /// Performs wNAF multi-exponentiation using the interleaved window method, also known as
 /// Straus's method.
 ///
 /// The key insight is that when computing this sum by means of additions and doublings, the
 /// doublings can be shared by performing the additions within an inner loop.
-fn wnaf_multi_exp<'a, G, I>(terms: I) -> G
+///
+/// The window tables can use another representation of the group elements than `G`, e.g. affine
+/// coordinates to use mixed additions.
+fn wnaf_multi_exp<'a, G, T, I>(terms: I) -> G
 where
-    G: Group,
-    I: Clone + IntoIterator<Item = (&'a [G], &'a [Digit], usize)>,
+    G: Group + AddAssign<T> + SubAssign<T>,
+    T: Copy + 'a,
+    I: Clone + IntoIterator<Item = (&'a [T], &'a [Digit])>,
 {
     let window_size = terms
         .clone()
         .into_iter()
-        .map(|(_, _, len)| len)
+        .map(|(_, wnaf)| wnaf.len())
         .max()
         .unwrap_or(0);
 
@@ -174,17 +461,17 @@ where
             result = result.double();
         }
 
-        for (table, wnaf, _) in terms.clone() {
+        for (table, wnaf) in terms.clone() {
             let n = wnaf.get(i).copied().unwrap_or(0);
 
             if n != 0 {
                 found_one = true;
 
-                #[allow(clippy::cast_sign_loss)]
+                let point = table[usize::from(n.unsigned_abs() / 2)];
                 if n > 0 {
-                    result += table[(n / 2) as usize];
+                    result += point;
                 } else {
-                    result -= table[((-n) / 2) as usize];
+                    result -= point;
                 }
             }
         }
@@ -194,9 +481,27 @@ where
 }
 
 /// Get the little endian representation of a field, namely a scalar.
+///
+/// [`PrimeField::Repr`] is big endian for the NIST and Brainpool curves, secp256k1, SM2 and
+/// bign-curve256v1, but little endian for Curve448.
 fn le_repr<F: PrimeField>(fe: &F) -> F::Repr {
     let mut ret = fe.to_repr();
-    // TODO(tarcieri): determine endianness via `PrimeField` trait. See zkcrypto/rfcs#4
-    ret.as_mut().reverse();
+
+    if !repr_is_little_endian::<F>() {
+        ret.as_mut().reverse();
+    }
+
     ret
 }
+
+/// Determine whether [`PrimeField::Repr`] is little endian.
+///
+/// `ff` has no way to specify the endianness of `Repr` (see zkcrypto/rfcs#4), and this crate
+/// doesn't depend on `primefield` for `PrimeFieldExt::REPR_ENDIANNESS`, which isn't implemented
+/// by every field anyway. Instead it's detected from the canonical encoding of one, which is
+/// `01 00 .. 00` if little endian and `00 .. 00 01` if big endian. When `Repr` is a single
+/// byte, both byte orders are the same.
+// TODO(tarcieri): determine endianness via `PrimeField` trait. See zkcrypto/rfcs#4
+fn repr_is_little_endian<F: PrimeField>() -> bool {
+    F::ONE.to_repr().as_ref().first() == Some(&1)
+}
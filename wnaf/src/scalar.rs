@@ -1,4 +1,4 @@
-use crate::{Digit, WindowSize, WnafSize, le_repr, wnaf_form};
+use crate::{Digit, WindowSize, WnafSize, le_repr, negate_digits, wnaf_form};
 use array::{Array, typenum::Unsigned};
 use core::marker::PhantomData;
 use ff::PrimeField;
@@ -26,7 +26,8 @@ impl<F: PrimeField + WnafSize, W: WindowSize> WnafScalar<F, W> {
     /// Computes the wNAF representation of the given scalar with window size `W`.
     #[inline]
     pub fn new(scalar: &F) -> Self {
-        Self::from_le_bytes(le_repr(scalar).as_ref())
+        let repr = le_repr(scalar);
+        Self::from_le_bytes(&repr.as_ref()[..F::NUM_BITS.div_ceil(8) as usize])
     }
 
     /// Computes the wNAF representation directly from raw little-endian bytes.
@@ -48,6 +49,16 @@ impl<F: PrimeField + WnafSize, W: WindowSize> WnafScalar<F, W> {
         wnaf
     }
 
+    /// Get the digits of the wNAF representation, least significant first.
+    ///
+    /// This can be used to implement multiplication for group element representations other
+    /// than `G` itself, e.g. with a different coordinate system.
+    #[inline]
+    #[must_use]
+    pub fn digits(&self) -> &[Digit] {
+        &self.wnaf[..self.digits]
+    }
+
     /// Initialize wNAF representation directly from raw little-endian bytes, for an already
     /// allocated [`WnafScalar`].
     ///
@@ -67,4 +78,9 @@ impl<F: PrimeField + WnafSize, W: WindowSize> WnafScalar<F, W> {
         let bit_len = (bytes.len() * 8).min(F::NUM_BITS as usize);
         self.digits = wnaf_form(&mut self.wnaf, bytes, bit_len, W::USIZE);
     }
+
+    /// Negate the represented scalar.
+    pub(crate) fn negate(&mut self) {
+        negate_digits(&mut self.wnaf[..self.digits]);
+    }
 }
@@ -21,6 +21,33 @@ pub trait WnafGroup: Group {
     fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize;
 }
 
+/// Efficiently computable endomorphism `φ` of a [`Group`] which acts on its elements as
+/// multiplication by a scalar `λ`, i.e. `φ(P) = [λ] P`, such as the endomorphisms used by the
+/// Gallant-Lambert-Vanstone (GLV) method.
+///
+/// Decomposing a scalar `k` into `k1 + k2 λ`, where `k1` and `k2` are about half the size of
+/// `k`, allows computing `[k] P = [k1] P + [k2] φ(P)` with a multiscalar multiplication which
+/// needs half as many doublings. This is used by [`crate::GlvWnafBase`], [`crate::GlvWnafScalar`]
+/// and `BoxedGlvWnaf`.
+///
+/// The endomorphism and the scalar decomposition are defined together so they can't disagree
+/// on `λ`.
+pub trait Endomorphism: Group {
+    /// Upper bound on the bit length of the absolute values of the sub-scalars returned by
+    /// [`Endomorphism::decompose_scalar`].
+    const SUB_SCALAR_BITS: u32;
+
+    /// Apply the endomorphism: computes `[λ] self`.
+    #[must_use]
+    fn endomorphism(&self) -> Self;
+
+    /// Decompose `k` into sub-scalars `k1` and `k2` such that `k = k1 + k2 λ`.
+    ///
+    /// Each sub-scalar is returned as its absolute value, which must be less than
+    /// `2^SUB_SCALAR_BITS`, along with whether it is negative.
+    fn decompose_scalar(k: &Self::Scalar) -> [(Self::Scalar, bool); 2];
+}
+
 /// Size of the wNAF representation: this should be the type-level equivalent of
 /// `PrimeField::NUM_BITS + 1`, which includes an extra entry for any remaining carry.
 pub trait WnafSize: PrimeField {
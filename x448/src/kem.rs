@@ -0,0 +1,653 @@
+//! Diffie-Hellman based key encapsulation mechanism `DHKEM(X448, HKDF-SHA512)` as described in
+//! [RFC 9180 § 4.1], for use with HPKE.
+//!
+//! # Usage
+//!
+#![cfg_attr(feature = "getrandom", doc = "```")]
+#![cfg_attr(not(feature = "getrandom"), doc = "```ignore")]
+//! // NOTE: requires the `kem` and `getrandom` crate features are enabled
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! use ed448_goldilocks::elliptic_curve::{
+//!     Generate,
+//!     common::getrandom::{SysRng, rand_core::UnwrapErr},
+//! };
+//! use x448::kem::{DecapsulationKey, Encapsulate, TryDecapsulate};
+//!
+//! // Recipient
+//! let decapsulation_key = DecapsulationKey::generate();
+//! let encapsulation_key = decapsulation_key.encapsulation_key();
+//!
+//! // Sender
+//! let (enc, sender_secret) = encapsulation_key.encapsulate_with_rng(&mut UnwrapErr(SysRng));
+//!
+//! // Recipient
+//! let recipient_secret = decapsulation_key.try_decapsulate(&enc)?;
+//! assert_eq!(sender_secret, recipient_secret);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The base mode is also available through the [`Encapsulate`] and [`TryDecapsulate`] traits of
+//! the [`kem`] crate, with [`DhKem`] as the [`Kem`] type.
+//!
+//! [RFC 9180 § 4.1]: https://www.rfc-editor.org/rfc/rfc9180#section-4.1
+
+pub use ::kem::{
+    self, Ciphertext, Decapsulator, Encapsulate, InvalidKey, Kem, Key, KeyExport, KeySizeUser,
+    SharedKey, TryDecapsulate, TryKeyInit,
+};
+
+use crate::{PublicKey, StaticSecret};
+use core::fmt;
+use ed448_goldilocks::elliptic_curve::{
+    Error, Generate,
+    array::typenum::{U56, U64},
+    rand_core::{CryptoRng, TryCryptoRng},
+    zeroize::Zeroize,
+};
+use hkdf::Hkdf;
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha512;
+
+/// `suite_id` of the KEM, which is `"KEM" || I2OSP(kem_id, 2)` with the KEM identifier `0x0021`.
+const SUITE_ID: [u8; 5] = *b"KEM\x00\x21";
+
+/// Size of the shared secret (`Nsecret`).
+const SHARED_SECRET_SIZE: usize = 64;
+
+/// `DHKEM(X448, HKDF-SHA512)`, which ties its [`DecapsulationKey`] and [`EncapsulationKey`]
+/// together as a [`Kem`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DhKem;
+
+impl Kem for DhKem {
+    type DecapsulationKey = DecapsulationKey;
+    type EncapsulationKey = EncapsulationKey;
+
+    /// `Nsecret`, which is the output size of SHA-512.
+    type SharedKeySize = U64;
+
+    /// `Nenc`, which is the size of a serialized X448 public key.
+    type CiphertextSize = U56;
+}
+
+/// `DHKEM(X448, HKDF-SHA512)` decapsulation key.
+pub struct DecapsulationKey {
+    secret: StaticSecret,
+    encapsulation_key: EncapsulationKey,
+}
+
+impl DecapsulationKey {
+    /// Deterministically derive a key pair from the input keying material `ikm` with the
+    /// `DeriveKeyPair` function of [RFC 9180 § 7.1.3].
+    ///
+    /// `ikm` must have at least 56 bytes of entropy.
+    ///
+    /// [RFC 9180 § 7.1.3]: https://www.rfc-editor.org/rfc/rfc9180#section-7.1.3
+    pub fn derive(ikm: &[u8]) -> Self {
+        let dkp_prk = labeled_extract(b"", b"dkp_prk", [ikm, &[]]);
+        let mut bytes = [0; 56];
+        labeled_expand(&dkp_prk, b"sk", [&[], &[], &[]], &mut bytes);
+
+        let key = Self::from_bytes(&bytes);
+        bytes.zeroize();
+        key
+    }
+
+    /// Deserialize a secret key, which is clamped as described in [RFC 9180 § 7.1.2].
+    ///
+    /// [RFC 9180 § 7.1.2]: https://www.rfc-editor.org/rfc/rfc9180#section-7.1.2
+    pub fn from_bytes(bytes: &[u8; 56]) -> Self {
+        Self::from_secret(StaticSecret::from(*bytes))
+    }
+
+    /// Serialize the clamped secret key.
+    pub fn to_bytes(&self) -> [u8; 56] {
+        *self.secret.as_bytes()
+    }
+
+    /// Encapsulation key which corresponds to this decapsulation key.
+    pub fn encapsulation_key(&self) -> &EncapsulationKey {
+        &self.encapsulation_key
+    }
+
+    /// Recover the shared secret from the encapsulated key `enc` (`Decap`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if `enc` isn't a valid encapsulation key, see
+    /// [`EncapsulationKey::from_bytes`].
+    pub fn decapsulate(&self, enc: &[u8]) -> Result<SharedSecret, Error> {
+        let ephemeral = EncapsulationKey::from_bytes(enc).ok_or(Error)?;
+        let dh = self.secret.diffie_hellman(&ephemeral.0);
+        let pk_r = self.encapsulation_key.as_bytes();
+
+        Ok(extract_and_expand(
+            [dh.as_bytes(), &[]],
+            [ephemeral.as_bytes(), pk_r, &[]],
+        ))
+    }
+
+    /// Recover the shared secret from the encapsulated key `enc`, and authenticate that it was
+    /// encapsulated by the holder of the decapsulation key which corresponds to `sender`
+    /// (`AuthDecap`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if `enc` isn't a valid encapsulation key, see
+    /// [`EncapsulationKey::from_bytes`].
+    pub fn auth_decapsulate(
+        &self,
+        enc: &[u8],
+        sender: &EncapsulationKey,
+    ) -> Result<SharedSecret, Error> {
+        let ephemeral = EncapsulationKey::from_bytes(enc).ok_or(Error)?;
+        let dh_e = self.secret.diffie_hellman(&ephemeral.0);
+        let dh_s = self.secret.diffie_hellman(&sender.0);
+        let pk_r = self.encapsulation_key.as_bytes();
+
+        Ok(extract_and_expand(
+            [dh_e.as_bytes(), dh_s.as_bytes()],
+            [ephemeral.as_bytes(), pk_r, sender.as_bytes()],
+        ))
+    }
+
+    fn from_secret(secret: StaticSecret) -> Self {
+        let encapsulation_key = EncapsulationKey(PublicKey::from(&secret));
+
+        Self {
+            secret,
+            encapsulation_key,
+        }
+    }
+}
+
+impl Generate for DecapsulationKey {
+    fn try_generate_from_rng<R>(csprng: &mut R) -> Result<Self, R::Error>
+    where
+        R: TryCryptoRng + ?Sized,
+    {
+        StaticSecret::try_generate_from_rng(csprng).map(Self::from_secret)
+    }
+}
+
+impl Decapsulator for DecapsulationKey {
+    type Kem = DhKem;
+
+    fn encapsulation_key(&self) -> &EncapsulationKey {
+        &self.encapsulation_key
+    }
+}
+
+impl TryDecapsulate for DecapsulationKey {
+    type Error = Error;
+
+    fn try_decapsulate(&self, enc: &Ciphertext<DhKem>) -> Result<SharedKey<DhKem>, Error> {
+        self.decapsulate(enc)
+            .map(|shared_secret| shared_secret.0.into())
+    }
+}
+
+impl fmt::Debug for DecapsulationKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecapsulationKey")
+            .field("encapsulation_key", &self.encapsulation_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// `DHKEM(X448, HKDF-SHA512)` encapsulation key.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct EncapsulationKey(PublicKey);
+
+impl EncapsulationKey {
+    /// Deserialize an encapsulation key.
+    ///
+    /// Returns `None` if:
+    /// -  The length of the slice is not 56
+    /// -  The point is a low order point, or a non-canonical encoding of one
+    ///
+    /// This ensures that the Diffie-Hellman output is never all-zero, see
+    /// [`PublicKey::from_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        PublicKey::from_bytes(bytes).map(Self)
+    }
+
+    /// Serialize the encapsulation key.
+    pub fn as_bytes(&self) -> &[u8; 56] {
+        self.0.as_bytes()
+    }
+
+    /// Encapsulate a fresh shared secret to this key (`Encap`), propagating RNG failures.
+    ///
+    /// Returns the encapsulated key `enc` to send to the recipient, and the shared secret. See
+    /// also [`Encapsulate::encapsulate_with_rng`] for infallible RNGs.
+    pub fn try_encapsulate<R: TryCryptoRng + ?Sized>(
+        &self,
+        csprng: &mut R,
+    ) -> Result<(PublicKey, SharedSecret), R::Error> {
+        let ephemeral = DecapsulationKey::try_generate_from_rng(csprng)?;
+        Ok(self.encapsulate_with_ephemeral(&ephemeral))
+    }
+
+    /// Encapsulate a shared secret to this key with the given ephemeral key pair.
+    ///
+    /// # Warning
+    ///
+    /// The ephemeral key MUST be generated uniformly at random and never reused. This is only
+    /// intended for testing against known answers; use [`EncapsulationKey::try_encapsulate`]
+    /// otherwise.
+    pub fn encapsulate_with_ephemeral(
+        &self,
+        ephemeral: &DecapsulationKey,
+    ) -> (PublicKey, SharedSecret) {
+        let dh = ephemeral.secret.diffie_hellman(&self.0);
+        let enc = ephemeral.encapsulation_key.0;
+
+        let shared_secret =
+            extract_and_expand([dh.as_bytes(), &[]], [enc.as_bytes(), self.as_bytes(), &[]]);
+        (enc, shared_secret)
+    }
+
+    /// Encapsulate a fresh shared secret to this key, authenticated with the decapsulation key
+    /// of the sender (`AuthEncap`).
+    ///
+    /// Returns the encapsulated key `enc` to send to the recipient, and the shared secret.
+    pub fn auth_encapsulate<R: CryptoRng + ?Sized>(
+        &self,
+        sender: &DecapsulationKey,
+        csprng: &mut R,
+    ) -> (PublicKey, SharedSecret) {
+        let ephemeral = DecapsulationKey::generate_from_rng(csprng);
+        self.auth_encapsulate_with_ephemeral(sender, &ephemeral)
+    }
+
+    /// Encapsulate a fresh shared secret to this key, authenticated with the decapsulation key
+    /// of the sender (`AuthEncap`), propagating RNG failures.
+    pub fn try_auth_encapsulate<R: TryCryptoRng + ?Sized>(
+        &self,
+        sender: &DecapsulationKey,
+        csprng: &mut R,
+    ) -> Result<(PublicKey, SharedSecret), R::Error> {
+        let ephemeral = DecapsulationKey::try_generate_from_rng(csprng)?;
+        Ok(self.auth_encapsulate_with_ephemeral(sender, &ephemeral))
+    }
+
+    /// Encapsulate a shared secret to this key with the given ephemeral key pair, authenticated
+    /// with the decapsulation key of the sender.
+    ///
+    /// # Warning
+    ///
+    /// The ephemeral key MUST be generated uniformly at random and never reused. This is only
+    /// intended for testing against known answers; use [`EncapsulationKey::auth_encapsulate`]
+    /// otherwise.
+    pub fn auth_encapsulate_with_ephemeral(
+        &self,
+        sender: &DecapsulationKey,
+        ephemeral: &DecapsulationKey,
+    ) -> (PublicKey, SharedSecret) {
+        let dh_e = ephemeral.secret.diffie_hellman(&self.0);
+        let dh_s = sender.secret.diffie_hellman(&self.0);
+        let enc = ephemeral.encapsulation_key.0;
+
+        let shared_secret = extract_and_expand(
+            [dh_e.as_bytes(), dh_s.as_bytes()],
+            [
+                enc.as_bytes(),
+                self.as_bytes(),
+                sender.encapsulation_key.as_bytes(),
+            ],
+        );
+        (enc, shared_secret)
+    }
+}
+
+impl Encapsulate for EncapsulationKey {
+    type Kem = DhKem;
+
+    fn encapsulate_with_rng<R: CryptoRng + ?Sized>(
+        &self,
+        csprng: &mut R,
+    ) -> (Ciphertext<DhKem>, SharedKey<DhKem>) {
+        let ephemeral = DecapsulationKey::generate_from_rng(csprng);
+        let (enc, shared_secret) = self.encapsulate_with_ephemeral(&ephemeral);
+        ((*enc.as_bytes()).into(), shared_secret.0.into())
+    }
+}
+
+impl KeySizeUser for EncapsulationKey {
+    type KeySize = U56;
+}
+
+impl TryKeyInit for EncapsulationKey {
+    fn new(key: &Key<Self>) -> Result<Self, InvalidKey> {
+        Self::from_bytes(key).ok_or(InvalidKey)
+    }
+}
+
+impl KeyExport for EncapsulationKey {
+    fn to_bytes(&self) -> Key<Self> {
+        (*self.as_bytes()).into()
+    }
+}
+
+/// A SharedSecret is the output of encapsulation or decapsulation.
+#[derive(Zeroize)]
+#[zeroize(drop)]
+pub struct SharedSecret([u8; SHARED_SECRET_SIZE]);
+
+impl SharedSecret {
+    /// Converts a shared secret into a byte slice
+    pub fn as_bytes(&self) -> &[u8; SHARED_SECRET_SIZE] {
+        &self.0
+    }
+}
+
+/// `ExtractAndExpand`, where the Diffie-Hellman output and the KEM context are the
+/// concatenation of the given slices.
+fn extract_and_expand(dh: [&[u8]; 2], kem_context: [&[u8]; 3]) -> SharedSecret {
+    let eae_prk = labeled_extract(b"", b"eae_prk", dh);
+    let mut shared_secret = SharedSecret([0; SHARED_SECRET_SIZE]);
+    labeled_expand(
+        &eae_prk,
+        b"shared_secret",
+        kem_context,
+        &mut shared_secret.0,
+    );
+    shared_secret
+}
+
+/// `LabeledExtract` as described in [RFC 9180 § 4], where `ikm` is the concatenation of the given
+/// slices.
+///
+/// [RFC 9180 § 4]: https://www.rfc-editor.org/rfc/rfc9180#section-4
+fn labeled_extract(salt: &[u8], label: &[u8], ikm: [&[u8]; 2]) -> Hkdf<Sha512> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(salt).expect("HMAC accepts keys of any size");
+    for bytes in [b"HPKE-v1".as_slice(), &SUITE_ID, label, ikm[0], ikm[1]] {
+        mac.update(bytes);
+    }
+
+    let mut prk = mac.finalize().into_bytes();
+    let hkdf = Hkdf::<Sha512>::from_prk(&prk).expect("PRK has the size of the hash output");
+    prk.as_mut_slice().zeroize();
+    hkdf
+}
+
+/// `LabeledExpand` as described in [RFC 9180 § 4], where `info` is the concatenation of the given
+/// slices.
+///
+/// [RFC 9180 § 4]: https://www.rfc-editor.org/rfc/rfc9180#section-4
+fn labeled_expand(prk: &Hkdf<Sha512>, label: &[u8], info: [&[u8]; 3], okm: &mut [u8]) {
+    let len = u16::try_from(okm.len()).expect("output size fits in two bytes");
+    prk.expand_multi_info(
+        &[
+            &len.to_be_bytes(),
+            b"HPKE-v1",
+            &SUITE_ID,
+            label,
+            info[0],
+            info[1],
+            info[2],
+        ],
+        okm,
+    )
+    .expect("output size is at most the size of the hash output");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hex_literal::hex;
+
+    /// Key pair derived with `DeriveKeyPair`, where `sk` is the unclamped output.
+    struct KeyPair {
+        ikm: [u8; 56],
+        sk: [u8; 56],
+        pk: [u8; 56],
+    }
+
+    /// Test vector for one of the four HPKE modes, as RFC 9180 doesn't include test vectors for
+    /// X448. The PSK modes use the KEM like the base mode, and the authenticated modes use
+    /// `AuthEncap`/`AuthDecap`.
+    ///
+    /// The key pairs and `enc` were generated with the HPKE implementation of OpenSSL 3.5, and
+    /// the shared secrets with an independent implementation of RFC 9180, which reproduces the
+    /// exporter secrets of OpenSSL in each mode with `info`, `psk` and `psk_id` as in RFC 9180
+    /// Appendix A. They are not the `kem_id` `0x0021` entries of the CFRG HPKE
+    /// `test-vectors.json`.
+    struct TestVector {
+        e: KeyPair,
+        r: KeyPair,
+        /// Key pair of the sender in the authenticated mode.
+        s: Option<KeyPair>,
+        shared_secret: [u8; SHARED_SECRET_SIZE],
+    }
+
+    const TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            // mode_base
+            e: KeyPair {
+                ikm: hex!(
+                    "83528a0a20a8e91312428b45cbfdd461f6d2b6831c4e561b86339e311d0edbeea837a95c02a4a9257ae806aab5976002d60f0d408c6050fb"
+                ),
+                sk: hex!(
+                    "00d983e6ca96fe39eda70f1e2bc796eaaf8397bf56f2fc926844f480333d63a17ffa6a4747fc605375fde08cb752f7b20f0ddef49bef0f7b"
+                ),
+                pk: hex!(
+                    "6d6853bc2c207adb998e7ac488a38caefaac8651e17698d870d05ea45326d75b8bceb633f0651b49b0c30188dc8231836bdae65901d24e30"
+                ),
+            },
+            r: KeyPair {
+                ikm: hex!(
+                    "db72ac59356eb01497541d96e880b9a650409a2ad03e05fc907b0b07c0cb3540e728366e4b406faab983525148ac9fde120f04700fef23bd"
+                ),
+                sk: hex!(
+                    "8a34a13ecbe66d76e31b8476d472c97ed8ff0a4114924ffdc0fc3a4e7701cb521c467d28d5a1e6894f1093d99487777b01588b7f04345bd7"
+                ),
+                pk: hex!(
+                    "f3b93202ec6d3f6c27db87aaf6ffd4b54ecbb0c6f71a47f5a7bc310cceac79d9c0816d34cb4a28add20918af89ba2a526f704cc798c7a4e2"
+                ),
+            },
+            s: None,
+            shared_secret: hex!(
+                "03c92548bd5a5527171ff1fece25da8b27248050503eb85f1fccac7269f7d895894edd619b98d8b9379395983ba38a67291ec3b0380b16fb528ab916c5542389"
+            ),
+        },
+        TestVector {
+            // mode_psk
+            e: KeyPair {
+                ikm: hex!(
+                    "1d91a510ccda05ae9d8280623fae20974d7222a0af829a0bc25fe18414a36fb961d30dfaeec79d0925f11d51484f7fed3f10682c5f037f35"
+                ),
+                sk: hex!(
+                    "b58cf53bf46a1a78f9ee4001415683d61691cb6879cb3987178627633060f9178befa4f69b4398787268d86d8953eaf4281ceb3656eaa428"
+                ),
+                pk: hex!(
+                    "faaa1dc2712b824c4507177a51f0505ca99c9c9974c2c99d7fc3ca1e50e4fc16c37c3b0904a8af958c878e9ede49e8de609b475e6d2ad9f2"
+                ),
+            },
+            r: KeyPair {
+                ikm: hex!(
+                    "193d65869f122a6c902baf53e21704dadfd455acfc9e5f752d23ed1f2531b4415de499e59c35cda9b871111fae06378c5fc47da88badf0d0"
+                ),
+                sk: hex!(
+                    "4da51a7c1bda2df64f955f2b5d5357ec5fe8113874f0a31ecbafdaa4af89aa568076dd18c0cd6c6a1ee0bd5b39d16da0db8a3964d0305539"
+                ),
+                pk: hex!(
+                    "f43f6a7e801be080307b075d3c61f631bc23fc2efe5187ce224f0dedb30337cb64acde7c2d8232837bd1385e2be5ee6868fd92951565b29f"
+                ),
+            },
+            s: None,
+            shared_secret: hex!(
+                "b70af06d98969c51f59459439a2b2b1d4d16fa8ee754cb9a1f9c1f54c9acd961797253c831cc84aa538d3ec181e1b273ec675a3ad97aaa42c0faffdf03a4dfeb"
+            ),
+        },
+        TestVector {
+            // mode_auth
+            e: KeyPair {
+                ikm: hex!(
+                    "0f8411535c2a24db5b8ee4d0f39c0fa4ec7c3f15364179a874bd6ef68697aa890e3ecff0d52c129e45dba1937fe02debcc99e6cad3a3433b"
+                ),
+                sk: hex!(
+                    "97ef4c721a546a2091d4c0487eb4dfd06bc7622317e41e64b41a84e2f74a5d364fc6709cf34fb3161018683708f2e35f72b7b7decf63639e"
+                ),
+                pk: hex!(
+                    "3f8d0dbaeb6ab6350fef8a87f1c39a64974b744d9365fde6eb7846ba3508a06614f37ef04b3b4b0c3773c2018b7613d168117e8fd3d2fdc4"
+                ),
+            },
+            r: KeyPair {
+                ikm: hex!(
+                    "db95f0c40ebdff02b811ef3f880ac88dab80248827381e523826df18f7ada2311681f99fcbd04546204b790d00ee4a010afd3d17969e48e2"
+                ),
+                sk: hex!(
+                    "a94edc59c210a169b84286d5f9ec67a310320661bb8dcca8b933a6485e0b91f5229006385eafaeb76cd0b10f201852cafcc1eba2796b96f5"
+                ),
+                pk: hex!(
+                    "89a63b5c86c4e3174888efb25ba4cb3ea5382b0f3c54467d9870762b23c45b369faed883a6217a66e3b894e4cb9f845d7fe3f32859bc2797"
+                ),
+            },
+            s: Some(KeyPair {
+                ikm: hex!(
+                    "9d7d8b3c13c73afda3546bbd4c8e8b52038bc079b85e877f3835213d05c48775a6094902897ef1a970718ef5bdecbdd11740984715193814"
+                ),
+                sk: hex!(
+                    "5cff87f95469fa82b747ec33fffa6c049cd5be7416b5fddad9c1c574bef187044075ef3777ce90d996048dfbad62a584cda59ef1429803b4"
+                ),
+                pk: hex!(
+                    "86b6501cb87fb661119646b85952a73b97c667b56a3b217f99d14bee73008b0cbb91efef401de00081c837df6704a5c971dc4789ffb9c5f4"
+                ),
+            }),
+            shared_secret: hex!(
+                "8b86bd1f622e3d8f12bb450fb41ff19bfddf09f625315e9c8a64dc8aa3a7bb47458f9073a48bb2433cfd8038d2323f3ae4d09f7782c4c8cbdef8d3fede5b6460"
+            ),
+        },
+        TestVector {
+            // mode_auth_psk
+            e: KeyPair {
+                ikm: hex!(
+                    "689a35fb468877927430f815227a0139b9be9a3621cbfd6b543205c86a00f4ae28b578655f2689f707f448bcec09d640e4b3ca34255dd8e8"
+                ),
+                sk: hex!(
+                    "61dc06c0f2edf381de3a184e88c8199844f5bc1997815191201e3f6a0b4107a148da1cdcd7552d218ebcf6db250c576bf712000b2e371091"
+                ),
+                pk: hex!(
+                    "aaec1747cbb27d83bedb8860fe3a274fc85b393041a8e5191560fc8b337284f4fd79af423a10ada584af64ce41a87c7eddff8bd3117793db"
+                ),
+            },
+            r: KeyPair {
+                ikm: hex!(
+                    "175e44ad982f7566f8d8e472b3e9ee6cba3aa5222eeca5ade7398d69a4d1f212af3e4839a749ba6b6c9d8e6a59c9d7949a7b6428d32ee6ef"
+                ),
+                sk: hex!(
+                    "56e9c4d31dd1830b6c678f49952c5e50a8abc69a75f212f043141ebb8224e88c45844b3c170bb029bde07c3d5cd3b445158fc2c5816e7bb3"
+                ),
+                pk: hex!(
+                    "a05baf26a2f769a71759cb382584c26dc26812a8f7763622732cb5d346eea5b743ddeb85a28cb180b33aaa413eaa6e562146ee269ffffe8b"
+                ),
+            },
+            s: Some(KeyPair {
+                ikm: hex!(
+                    "4bd85b3a1ff1f4a7940a6d8d397bd3fb498a1456833e0d2224ad5f0ee7caa06401acab5dc3d0a6c4c8deb69b1b05cb4ee72963f72b5c878d"
+                ),
+                sk: hex!(
+                    "b792d3301dc045cf598e662e5913a525e5c471a116c89afca5ee6b16efb07dc175bfda9c89f7cbe4d6acf326dabf65c44af41173aae97576"
+                ),
+                pk: hex!(
+                    "cf9bcc63feb89981991ef91b9e173d577798d9a3a7b5013361c5404a85b130804324ec3ba17c6b64a1afd123010b78dad129357b43c5cd5f"
+                ),
+            }),
+            shared_secret: hex!(
+                "6247ca504d79f590d03bb1b3ec7f300bdad18615a4dc97ed3414e00acdb22346292f93a7018785a46ee2aab414bd8ccfc0599a4281e660831defd5754c98136b"
+            ),
+        },
+    ];
+
+    #[test]
+    fn derive_key_pair() {
+        for vector in TEST_VECTORS {
+            for key_pair in [Some(&vector.e), Some(&vector.r), vector.s.as_ref()]
+                .into_iter()
+                .flatten()
+            {
+                let key = DecapsulationKey::derive(&key_pair.ikm);
+                assert_eq!(
+                    key.to_bytes(),
+                    DecapsulationKey::from_bytes(&key_pair.sk).to_bytes()
+                );
+                assert_eq!(key.encapsulation_key().as_bytes(), &key_pair.pk);
+            }
+        }
+    }
+
+    #[test]
+    fn encapsulate_and_decapsulate() {
+        for vector in TEST_VECTORS {
+            let ephemeral = DecapsulationKey::from_bytes(&vector.e.sk);
+            let recipient = DecapsulationKey::from_bytes(&vector.r.sk);
+            let encapsulation_key = EncapsulationKey::from_bytes(&vector.r.pk).unwrap();
+
+            let (enc, shared_secret) = match &vector.s {
+                None => encapsulation_key.encapsulate_with_ephemeral(&ephemeral),
+                Some(s) => {
+                    let sender = DecapsulationKey::from_bytes(&s.sk);
+                    encapsulation_key.auth_encapsulate_with_ephemeral(&sender, &ephemeral)
+                }
+            };
+            assert_eq!(enc.as_bytes(), &vector.e.pk);
+            assert_eq!(shared_secret.as_bytes(), &vector.shared_secret);
+
+            let shared_secret = match &vector.s {
+                None => recipient.decapsulate(&vector.e.pk).unwrap(),
+                Some(s) => {
+                    let sender = EncapsulationKey::from_bytes(&s.pk).unwrap();
+                    recipient.auth_decapsulate(&vector.e.pk, &sender).unwrap()
+                }
+            };
+            assert_eq!(shared_secret.as_bytes(), &vector.shared_secret);
+        }
+    }
+
+    #[test]
+    fn auth_decapsulate_with_wrong_sender() {
+        let vector = &TEST_VECTORS[2];
+        let recipient = DecapsulationKey::from_bytes(&vector.r.sk);
+        let sender = EncapsulationKey::from_bytes(&vector.e.pk).unwrap();
+        let shared_secret = recipient.auth_decapsulate(&vector.e.pk, &sender).unwrap();
+        assert_ne!(shared_secret.as_bytes(), &vector.shared_secret);
+    }
+
+    #[test]
+    fn kem_traits() {
+        let vector = &TEST_VECTORS[0];
+        let recipient = DecapsulationKey::from_bytes(&vector.r.sk);
+        let encapsulation_key = EncapsulationKey::new(&vector.r.pk.into()).unwrap();
+        assert_eq!(recipient.encapsulation_key(), &encapsulation_key);
+        assert_eq!(encapsulation_key.to_bytes().as_slice(), vector.r.pk);
+
+        let shared_secret = recipient.try_decapsulate(&vector.e.pk.into()).unwrap();
+        assert_eq!(shared_secret.as_slice(), vector.shared_secret);
+        assert!(recipient.try_decapsulate_slice(&vector.e.pk[1..]).is_err());
+        assert!(EncapsulationKey::new(&[0; 56].into()).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_encapsulated_keys() {
+        let vector = &TEST_VECTORS[0];
+        let recipient = DecapsulationKey::from_bytes(&vector.r.sk);
+
+        // wrong length
+        assert!(recipient.decapsulate(&vector.e.pk[1..]).is_err());
+
+        // low order points and non-canonical encodings of them, which lead to an all-zero
+        // Diffie-Hellman output
+        let mut one = [0; 56];
+        one[0] = 1;
+        let mut p = [0xff; 56];
+        p[28] = 0xfe;
+        let mut p_plus_one = [0xff; 56];
+        p_plus_one[..28].fill(0x00);
+        for enc in [[0; 56], one, p, p_plus_one] {
+            assert!(recipient.decapsulate(&enc).is_err());
+        }
+    }
+}
@@ -6,6 +6,9 @@
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
 )]
 
+#[cfg(feature = "kem")]
+pub mod kem;
+
 use ed448_goldilocks::{
     MontgomeryPoint,
     elliptic_curve::{
@@ -20,6 +23,11 @@ use ed448_goldilocks::{
 
 type MontgomeryScalar = ed448_goldilocks::Scalar<ed448_goldilocks::Ed448>;
 
+/// Field modulus `p = 2^448 - 2^224 - 1`.
+const MODULUS: U448 = U448::from_be_hex(
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+);
+
 /// Given an [`EphemeralSecret`] Key, compute the corresponding public key
 /// using the generator specified in RFC7748
 impl From<&EphemeralSecret> for PublicKey {
@@ -38,12 +46,19 @@ impl PublicKey {
     /// Converts a bytes slice into a Public key
     /// Returns None if:
     /// -  The length of the slice is not 56
-    /// -  The point is a low order point
+    /// -  The point is a low order point, or a non-canonical encoding of one
     pub fn from_bytes(bytes: &[u8]) -> Option<PublicKey> {
         let public_key = PublicKey::from_bytes_unchecked(bytes)?;
         if public_key.0.is_low_order() {
             return None;
         }
+
+        // `p` and `p + 1` are the only non-canonical encodings of low order points
+        let u = U448::from_le_slice(bytes);
+        if u == MODULUS || u == MODULUS.wrapping_add(&U448::ONE) {
+            return None;
+        }
+
         Some(public_key)
     }
 
@@ -226,6 +241,18 @@ impl StaticSecret {
     }
 }
 
+#[cfg(feature = "static_secrets")]
+impl Generate for StaticSecret {
+    fn try_generate_from_rng<R>(csprng: &mut R) -> Result<Self, R::Error>
+    where
+        R: TryCryptoRng + ?Sized,
+    {
+        let mut bytes = Array::default();
+        csprng.try_fill_bytes(bytes.as_mut_slice())?;
+        Ok(StaticSecret::new(bytes))
+    }
+}
+
 #[cfg(feature = "static_secrets")]
 impl From<[u8; 56]> for StaticSecret {
     /// Load a secret key from a byte array.
@@ -445,6 +472,20 @@ mod test {
         *secret = *result;
     }
 
+    #[test]
+    fn test_low_order_points_rejected() {
+        let mut one = [0; 56];
+        one[0] = 1;
+        let mut p = [0xff; 56];
+        p[28] = 0xfe;
+        let mut p_plus_one = [0xff; 56];
+        p_plus_one[..28].fill(0x00);
+        for bytes in [[0; 56], one, p, p_plus_one] {
+            assert!(PublicKey::from_bytes(&bytes).is_none());
+            assert!(x448(X448_BASEPOINT_BYTES, bytes).is_none());
+        }
+    }
+
     #[test]
     #[ignore]
     fn test_rfc_test_vectors_iteration() {